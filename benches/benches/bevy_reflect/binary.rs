@@ -0,0 +1,142 @@
+use core::{hint::black_box, time::Duration};
+
+use benches::bench;
+use bevy_reflect::{
+    binary,
+    serde::{ReflectDeserializer, ReflectSerializer},
+    Reflect, TypeRegistry,
+};
+use criterion::{
+    criterion_group, measurement::Measurement, AxisScale, BenchmarkGroup, BenchmarkId, Criterion,
+    PlotConfiguration, Throughput,
+};
+
+criterion_group!(benches, encode, decode);
+
+const WARM_UP_TIME: Duration = Duration::from_millis(500);
+const MEASUREMENT_TIME: Duration = Duration::from_secs(4);
+
+/// The numbers of players encoded in each benchmark.
+const SIZES: [usize; 3] = [10, 100, 1000];
+
+/// Creates a [`BenchmarkGroup`] with common configuration shared by all benchmarks within this
+/// module.
+fn create_group<'a, M: Measurement>(c: &'a mut Criterion<M>, name: &str) -> BenchmarkGroup<'a, M> {
+    let mut group = c.benchmark_group(name);
+
+    group
+        .warm_up_time(WARM_UP_TIME)
+        .measurement_time(MEASUREMENT_TIME)
+        // Make the plots logarithmic, matching `SIZES`' scale.
+        .plot_config(PlotConfiguration::default().summary_scale(AxisScale::Logarithmic));
+
+    group
+}
+
+#[derive(Reflect)]
+struct Player {
+    name: String,
+    position: (f32, f32, f32),
+    health: u32,
+    inventory: Vec<Item>,
+}
+
+#[derive(Reflect)]
+enum Item {
+    Potion(u8),
+    Sword { damage: u32 },
+    Key,
+}
+
+fn players(count: usize) -> Vec<Player> {
+    (0..count)
+        .map(|index| Player {
+            name: format!("player_{index}"),
+            position: (index as f32, 0.5, -(index as f32)),
+            health: 100,
+            inventory: vec![
+                Item::Potion(3),
+                Item::Sword {
+                    damage: index as u32,
+                },
+                Item::Key,
+            ],
+        })
+        .collect()
+}
+
+fn registry() -> TypeRegistry {
+    let mut registry = TypeRegistry::default();
+    registry.register::<Vec<Player>>();
+    registry
+}
+
+/// Encodes the players with the binary format and with `bincode` through the serde integration.
+fn encode(criterion: &mut Criterion) {
+    let mut group = create_group(criterion, bench!("encode"));
+    let registry = registry();
+    let config = bincode::config::standard();
+
+    for size in SIZES {
+        let players = players(size);
+        group.throughput(Throughput::Elements(size as u64));
+
+        group.bench_with_input(
+            BenchmarkId::new("binary", size),
+            &players,
+            |bencher, players| {
+                bencher.iter(|| binary::to_bytes(black_box(players), &registry).unwrap());
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("serde_bincode", size),
+            &players,
+            |bencher, players| {
+                bencher.iter(|| {
+                    let serializer = ReflectSerializer::new(black_box(players), &registry);
+                    bincode::serde::encode_to_vec(&serializer, config).unwrap()
+                });
+            },
+        );
+    }
+
+    group.finish();
+}
+
+/// Decodes the players with the binary format and with `bincode` through the serde integration.
+fn decode(criterion: &mut Criterion) {
+    let mut group = create_group(criterion, bench!("decode"));
+    let registry = registry();
+    let config = bincode::config::standard();
+
+    for size in SIZES {
+        let players = players(size);
+        group.throughput(Throughput::Elements(size as u64));
+
+        let bytes = binary::to_bytes(&players, &registry).unwrap();
+        group.bench_with_input(
+            BenchmarkId::new("binary", size),
+            &bytes,
+            |bencher, bytes| {
+                bencher.iter(|| binary::from_bytes(black_box(bytes), &registry).unwrap());
+            },
+        );
+
+        let serializer = ReflectSerializer::new(&players, &registry);
+        let bytes = bincode::serde::encode_to_vec(&serializer, config).unwrap();
+        group.bench_with_input(
+            BenchmarkId::new("serde_bincode", size),
+            &bytes,
+            |bencher, bytes| {
+                bencher.iter(|| {
+                    let deserializer = ReflectDeserializer::new(&registry);
+                    bincode::serde::seed_decode_from_slice(deserializer, black_box(bytes), config)
+                        .unwrap()
+                });
+            },
+        );
+    }
+
+    group.finish();
+}
@@ -1,5 +1,6 @@
 use criterion::criterion_main;
 
+mod binary;
 mod function;
 mod list;
 mod map;
@@ -7,6 +8,7 @@ mod path;
 mod r#struct;
 
 criterion_main!(
+    binary::benches,
     function::benches,
     list::benches,
     map::benches,
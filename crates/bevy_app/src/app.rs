@@ -629,6 +629,31 @@ impl App {
         self
     }
 
+    /// Registers `alias` as another type path of type `T` in the [`AppTypeRegistry`] resource.
+    ///
+    /// This keeps scenes, saves and other serialized data that refer to a type by an old path
+    /// working after the type is renamed or moved to another module.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_app::App;
+    /// use bevy_reflect::Reflect;
+    ///
+    /// #[derive(Reflect)]
+    /// struct Health(f32);
+    ///
+    /// App::new()
+    ///     .register_type::<Health>()
+    ///     .register_type_alias::<Health>("my_game::stats::Health");
+    /// ```
+    ///
+    /// See [`bevy_reflect::TypeRegistry::register_alias`] for more information.
+    #[cfg(feature = "bevy_reflect")]
+    pub fn register_type_alias<T: 'static>(&mut self, alias: impl Into<String>) -> &mut Self {
+        self.main_mut().register_type_alias::<T>(alias);
+        self
+    }
+
     /// Registers the given function into the [`AppFunctionRegistry`] resource.
     ///
     /// The given function will internally be stored as a [`DynamicFunction`]
@@ -444,6 +444,14 @@ impl SubApp {
         self
     }
 
+    /// See [`App::register_type_alias`].
+    #[cfg(feature = "bevy_reflect")]
+    pub fn register_type_alias<T: 'static>(&mut self, alias: impl Into<String>) -> &mut Self {
+        let registry = self.world.resource_mut::<AppTypeRegistry>();
+        registry.write().register_alias::<T>(alias);
+        self
+    }
+
     /// See [`App::register_function`].
     #[cfg(feature = "reflect_functions")]
     pub fn register_function<F, Marker>(&mut self, function: F) -> &mut Self
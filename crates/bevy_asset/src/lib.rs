@@ -182,7 +182,9 @@ mod id;
 mod loader;
 mod loader_builders;
 mod path;
+mod redirect;
 mod reflect;
+mod reflect_loader;
 mod render_asset;
 mod server;
 
@@ -199,7 +201,9 @@ pub use loader_builders::{
     Deferred, DynamicTyped, Immediate, NestedLoader, StaticTyped, UnknownTyped,
 };
 pub use path::*;
+pub use redirect::*;
 pub use reflect::*;
+pub use reflect_loader::*;
 pub use render_asset::*;
 pub use server::*;
 
@@ -210,10 +214,12 @@ pub use uuid;
 use crate::{
     io::{embedded::EmbeddedAssetRegistry, AssetSourceBuilder, AssetSourceBuilders, AssetSourceId},
     processor::{AssetProcessor, Process},
+    transformer::IdentityAssetTransformer,
 };
 use alloc::{
     string::{String, ToString},
     sync::Arc,
+    vec,
     vec::Vec,
 };
 use bevy_app::{App, Plugin, PostUpdate, PreUpdate};
@@ -263,6 +269,13 @@ pub struct AssetPlugin {
     /// Approved folders are [`AssetPlugin::file_path`] and the folder of each
     /// [`AssetSource`](io::AssetSource). Subfolders within these folders are also valid.
     pub unapproved_path_mode: UnapprovedPathMode,
+    /// The path of an [`AssetRedirects`] file to read when the app starts, so that loads of renamed or moved assets are
+    /// redirected to their new paths. If the path does not specify an asset source, the default source is used.
+    ///
+    /// The file is read from the same source as the assets, which means it is copied to the processed assets by the
+    /// [`AssetProcessor`] in [`AssetMode::Processed`]. The processor uses the redirects as well, so processed assets
+    /// refer to the new paths.
+    pub redirects_file_path: Option<String>,
 }
 
 /// Determines how to react to attempts to load assets not inside the approved folders.
@@ -339,6 +352,7 @@ impl Default for AssetPlugin {
             watch_for_changes_override: None,
             meta_check: AssetMetaCheck::default(),
             unapproved_path_mode: UnapprovedPathMode::default(),
+            redirects_file_path: None,
         }
     }
 }
@@ -416,6 +430,24 @@ impl Plugin for AssetPlugin {
                 }
             }
         }
+        if let Some(redirects_file_path) = &self.redirects_file_path {
+            let redirects_file_path = AssetPath::from(redirects_file_path).into_owned();
+            let asset_server = app.world().resource::<AssetServer>().clone();
+            #[cfg(feature = "asset_processor")]
+            let processor_server = app
+                .world()
+                .get_resource::<AssetProcessor>()
+                .map(|processor| processor.server().clone());
+            #[cfg(not(feature = "asset_processor"))]
+            let processor_server: Option<AssetServer> = None;
+            match processor_server {
+                // The processor reads the unprocessed redirects file and shares it with the app's server.
+                Some(processor_server) => {
+                    processor_server.load_shared_redirects(redirects_file_path, vec![asset_server])
+                }
+                None => asset_server.load_redirects(redirects_file_path),
+            }
+        }
         app.insert_resource(embedded)
             .init_asset::<LoadedFolder>()
             .init_asset::<LoadedUntypedAsset>()
@@ -538,6 +570,22 @@ pub trait AssetApp {
     ///
     /// This enables reflection code to access assets. For detailed information, see the docs on [`ReflectAsset`] and [`ReflectHandle`].
     fn register_asset_reflect<A>(&mut self) -> &mut Self
+    where
+        A: Asset + Reflect + FromReflect + GetTypeRegistration;
+    /// Registers the asset type `A` using [`AssetApp::register_asset_reflect`], and registers a [`ReflectAssetLoader`]
+    /// for `A` that loads the given `extensions` from RON or JSON.
+    ///
+    /// The asset must already be initialized with [`AssetApp::init_asset`]. Any [`Handle`] fields of `A` are loaded from
+    /// asset paths, which requires their asset types to be registered with [`AssetApp::register_asset_reflect`] as well.
+    fn register_reflect_asset_loader<A>(&mut self, extensions: &[&'static str]) -> &mut Self
+    where
+        A: Asset + Reflect + FromReflect + GetTypeRegistration;
+    /// Registers a [`ReflectAssetLoader`] for `A` using [`AssetApp::register_reflect_asset_loader`], and a
+    /// [`ReflectAssetProcessor`] that is the default processor for the given `extensions`.
+    ///
+    /// When processing, the processor loads each asset and saves it again, rewriting references to assets that were
+    /// moved with [`AssetRedirects`] to their new paths, and reporting references to assets that do not exist.
+    fn register_reflect_asset_processor<A>(&mut self, extensions: &[&'static str]) -> &mut Self
     where
         A: Asset + Reflect + FromReflect + GetTypeRegistration;
     /// Preregisters a loader for the given extensions, that will block asset loads until a real loader
@@ -640,6 +688,31 @@ impl AssetApp for App {
         self
     }
 
+    fn register_reflect_asset_loader<A>(&mut self, extensions: &[&'static str]) -> &mut Self
+    where
+        A: Asset + Reflect + FromReflect + GetTypeRegistration,
+    {
+        self.register_asset_reflect::<A>();
+        let type_registry = self.world().resource::<AppTypeRegistry>().0.clone();
+        self.register_asset_loader(ReflectAssetLoader::<A>::new(type_registry, extensions))
+    }
+
+    fn register_reflect_asset_processor<A>(&mut self, extensions: &[&'static str]) -> &mut Self
+    where
+        A: Asset + Reflect + FromReflect + GetTypeRegistration,
+    {
+        self.register_reflect_asset_loader::<A>(extensions);
+        let type_registry = self.world().resource::<AppTypeRegistry>().0.clone();
+        self.register_asset_processor(ReflectAssetProcessor::<A>::new(
+            IdentityAssetTransformer::new(),
+            ReflectAssetSaver::new(type_registry),
+        ));
+        for extension in extensions {
+            self.set_default_asset_processor::<ReflectAssetProcessor<A>>(extension);
+        }
+        self
+    }
+
     fn preregister_asset_loader<L: AssetLoader>(&mut self, extensions: &[&str]) -> &mut Self {
         self.world_mut()
             .resource_mut::<AssetServer>()
@@ -670,7 +743,8 @@ mod tests {
         },
         loader::{AssetLoader, LoadContext},
         Asset, AssetApp, AssetEvent, AssetId, AssetLoadError, AssetLoadFailedEvent, AssetPath,
-        AssetPlugin, AssetServer, Assets, LoadState, UnapprovedPathMode,
+        AssetPlugin, AssetServer, Assets, LoadState, RecursiveDependencyLoadState,
+        UnapprovedPathMode,
     };
     use alloc::{
         boxed::Box,
@@ -1873,6 +1947,144 @@ mod tests {
         });
     }
 
+    #[test]
+    fn load_redirected_dependencies() {
+        let dir = Dir::default();
+        dir.insert_asset_text(
+            Path::new("a.cool.ron"),
+            r#"(
+    text: "a",
+    dependencies: ["old.cool.ron"],
+    embedded_dependencies: [],
+    sub_texts: [],
+)"#,
+        );
+        dir.insert_asset_text(
+            Path::new("textures/new.cool.ron"),
+            r#"(
+    text: "new",
+    dependencies: [],
+    embedded_dependencies: [],
+    sub_texts: [],
+)"#,
+        );
+        dir.insert_asset_text(
+            Path::new("redirects.ron"),
+            r#"{ "old.cool.ron": "textures/new.cool.ron" }"#,
+        );
+
+        let mut app = App::new();
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSource::build()
+                .with_reader(move || Box::new(MemoryAssetReader { root: dir.clone() })),
+        )
+        .add_plugins((
+            TaskPoolPlugin::default(),
+            AssetPlugin {
+                redirects_file_path: Some("redirects.ron".to_string()),
+                ..Default::default()
+            },
+        ))
+        .init_asset::<CoolText>()
+        .init_asset::<SubText>()
+        .register_asset_loader(CoolTextLoader);
+
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let handle: Handle<CoolText> = asset_server.load("a.cool.ron");
+        run_app_until(&mut app, |world| {
+            match world
+                .resource::<AssetServer>()
+                .recursive_dependency_load_state(&handle)
+            {
+                RecursiveDependencyLoadState::Loaded => Some(()),
+                RecursiveDependencyLoadState::Failed(err) => panic!("{err}"),
+                _ => None,
+            }
+        });
+
+        let a = get::<CoolText>(app.world(), handle.id()).unwrap();
+        let dependency = &a.dependencies[0];
+        assert_eq!(
+            dependency.path(),
+            Some(&AssetPath::from("textures/new.cool.ron"))
+        );
+        assert_eq!(
+            get::<CoolText>(app.world(), dependency.id()).unwrap().text,
+            "new"
+        );
+        assert_eq!(
+            asset_server.get_handle::<CoolText>("old.cool.ron"),
+            Some(dependency.clone())
+        );
+    }
+
+    #[test]
+    fn load_before_redirects_are_read() {
+        let dir = Dir::default();
+        dir.insert_asset_text(
+            Path::new("textures/new.cool.ron"),
+            r#"(
+    text: "new",
+    dependencies: [],
+    embedded_dependencies: [],
+    sub_texts: [],
+)"#,
+        );
+        dir.insert_asset_text(
+            Path::new("redirects.ron"),
+            r#"{ "old.cool.ron": "textures/new.cool.ron" }"#,
+        );
+        dir.insert_asset_text(
+            Path::new("more_redirects.ron"),
+            r#"{ "older.cool.ron": "old.cool.ron" }"#,
+        );
+
+        let mut app = App::new();
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSource::build()
+                .with_reader(move || Box::new(MemoryAssetReader { root: dir.clone() })),
+        )
+        .add_plugins((
+            TaskPoolPlugin::default(),
+            AssetPlugin {
+                redirects_file_path: Some("redirects.ron".to_string()),
+                ..Default::default()
+            },
+        ))
+        .init_asset::<CoolText>()
+        .init_asset::<SubText>()
+        .register_asset_loader(CoolTextLoader);
+
+        // Both redirects files are being read while these loads start.
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        asset_server.load_redirects("more_redirects.ron");
+        let old: Handle<CoolText> = asset_server.load("old.cool.ron");
+        let older: Handle<CoolText> = asset_server.load("older.cool.ron");
+        run_app_until(&mut app, |world| {
+            let server = world.resource::<AssetServer>();
+            match (server.load_state(&old), server.load_state(&older)) {
+                (LoadState::Loaded, LoadState::Loaded) => Some(()),
+                (LoadState::Failed(err), _) | (_, LoadState::Failed(err)) => panic!("{err}"),
+                _ => None,
+            }
+        });
+
+        assert_eq!(get::<CoolText>(app.world(), old.id()).unwrap().text, "new");
+        assert_eq!(
+            get::<CoolText>(app.world(), older.id()).unwrap().text,
+            "new"
+        );
+        let new: Handle<CoolText> = asset_server.load("textures/new.cool.ron");
+        assert_eq!(new, old);
+        assert_eq!(new, older);
+        assert_eq!(
+            asset_server.get_path(old.id()),
+            Some(AssetPath::from("textures/new.cool.ron"))
+        );
+    }
+
     // validate the Asset derive macro for various asset types
     #[derive(Asset, TypePath)]
     pub struct TestAsset;
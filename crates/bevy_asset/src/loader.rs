@@ -1,7 +1,9 @@
 use crate::{
     io::{AssetReaderError, MissingAssetSourceError, MissingProcessedAssetReaderError, Reader},
     loader_builders::{Deferred, NestedLoader, StaticTyped},
-    meta::{AssetHash, AssetMeta, AssetMetaDyn, ProcessedInfoMinimal, Settings},
+    meta::{
+        AssetHash, AssetMeta, AssetMetaDyn, ProcessedInfoMinimal, Settings, SettingsMigrations,
+    },
     path::AssetPath,
     Asset, AssetLoadError, AssetServer, AssetServerMode, Assets, Handle, UntypedAssetId,
     UntypedHandle,
@@ -14,6 +16,7 @@ use alloc::{
 use atomicow::CowArc;
 use bevy_ecs::world::World;
 use bevy_platform::collections::{HashMap, HashSet};
+use bevy_reflect::PartialReflect;
 use bevy_tasks::{BoxedFuture, ConditionalSendFuture};
 use core::any::{Any, TypeId};
 use downcast_rs::{impl_downcast, Downcast};
@@ -48,6 +51,15 @@ pub trait AssetLoader: Send + Sync + 'static {
     fn extensions(&self) -> &[&str] {
         &[]
     }
+
+    /// Returns the [`SettingsMigrations`] used to upgrade [`AssetLoader::Settings`] stored in `.meta` files written for
+    /// older versions of the settings. The number of migrations is the current settings version.
+    ///
+    /// Add a migration whenever [`AssetLoader::Settings`] changes in a way that would make existing `.meta` files fail to
+    /// deserialize.
+    fn settings_migrations() -> SettingsMigrations<Self::Settings> {
+        SettingsMigrations::new()
+    }
 }
 
 /// Provides type-erased access to an [`AssetLoader`].
@@ -313,6 +325,10 @@ pub enum DeserializeMetaError {
     DeserializeSettings(#[from] SpannedError),
     #[error("Failed to deserialize minimal asset meta: {0:?}")]
     DeserializeMinimal(SpannedError),
+    #[error("Asset meta settings version {version} is newer than the latest supported settings version {latest}")]
+    UnsupportedSettingsVersion { version: u32, latest: u32 },
+    #[error("Asset meta settings cannot be migrated because the asset action has no settings")]
+    MissingSettings,
 }
 
 /// A context that provides access to assets in [`AssetLoader`]s, tracks dependencies, and collects asset load state.
@@ -551,6 +567,13 @@ impl<'a> LoadContext<'a> {
         Ok(loaded_asset)
     }
 
+    /// Replaces every [`AssetPath`] stored in `value` with the path it is redirected to by the [`AssetServer`]'s
+    /// redirects. Loaders call this on values that store asset paths directly rather than as [`Handle`]s, so that
+    /// processed assets do not keep the stale paths. See [`AssetRedirects::redirect_paths`](crate::AssetRedirects::redirect_paths).
+    pub fn redirect_paths(&self, value: &mut dyn PartialReflect) {
+        self.asset_server.redirect_paths(value);
+    }
+
     /// Create a builder for loading nested assets in this context.
     #[must_use]
     pub fn loader(&mut self) -> NestedLoader<'a, '_, StaticTyped, Deferred> {
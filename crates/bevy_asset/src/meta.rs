@@ -1,6 +1,8 @@
 use alloc::{
     boxed::Box,
     string::{String, ToString},
+    sync::Arc,
+    vec,
     vec::Vec,
 };
 
@@ -25,6 +27,10 @@ pub struct AssetMeta<L: AssetLoader, P: Process> {
     /// The version of the meta format being used. This will change whenever a breaking change is made to
     /// the meta format.
     pub meta_format_version: String,
+    /// The version of the loader or processor settings stored in [`AssetMeta::asset`]. Older settings are upgraded using
+    /// the [`SettingsMigrations`] of the [`AssetLoader`] or [`Process`] when the meta is deserialized.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub settings_version: u32,
     /// Information produced by the [`AssetProcessor`] _after_ processing this asset.
     /// This will only exist alongside processed versions of assets. You should not manually set it in your asset source files.
     ///
@@ -39,17 +45,80 @@ impl<L: AssetLoader, P: Process> AssetMeta<L, P> {
     pub fn new(asset: AssetAction<L::Settings, P::Settings>) -> Self {
         Self {
             meta_format_version: META_FORMAT_VERSION.to_string(),
+            settings_version: latest_settings_version::<L, P>(&asset),
             processed_info: None,
             asset,
         }
     }
 
     /// Deserializes the given serialized byte representation of the asset meta.
+    ///
+    /// If the meta was written with an older [`AssetMeta::settings_version`], the settings are upgraded to the latest version
+    /// using [`AssetLoader::settings_migrations`] or [`Process::settings_migrations`].
     pub fn deserialize(bytes: &[u8]) -> Result<Self, DeserializeMetaError> {
-        Ok(ron::de::from_bytes(bytes)?)
+        if let Ok(meta) = ron::de::from_bytes::<Self>(bytes) {
+            if meta.settings_version == latest_settings_version::<L, P>(&meta.asset)
+                || matches!(meta.asset, AssetAction::Ignore)
+            {
+                return Ok(meta);
+            }
+        }
+
+        let minimal: AssetMetaMinimal =
+            ron::de::from_bytes(bytes).map_err(DeserializeMetaError::DeserializeMinimal)?;
+        let version = minimal.settings_version;
+        let (asset, settings_version) = match minimal.asset {
+            AssetActionMinimal::Load { loader } => {
+                let migrations = L::settings_migrations();
+                if version == migrations.version() {
+                    // The settings are already at their latest version, so report why they failed to deserialize.
+                    return Ok(ron::de::from_bytes(bytes)?);
+                }
+                let asset = AssetAction::Load {
+                    loader,
+                    settings: migrations.migrate(version, bytes)?,
+                };
+                (asset, migrations.version())
+            }
+            AssetActionMinimal::Process { processor } => {
+                let migrations = P::settings_migrations();
+                if version == migrations.version() {
+                    // The settings are already at their latest version, so report why they failed to deserialize.
+                    return Ok(ron::de::from_bytes(bytes)?);
+                }
+                let asset = AssetAction::Process {
+                    processor,
+                    settings: migrations.migrate(version, bytes)?,
+                };
+                (asset, migrations.version())
+            }
+            AssetActionMinimal::Ignore => return Ok(ron::de::from_bytes(bytes)?),
+        };
+        let ProcessedInfoMinimal { processed_info } = ron::de::from_bytes(bytes)?;
+        Ok(Self {
+            meta_format_version: META_FORMAT_VERSION.to_string(),
+            settings_version,
+            processed_info,
+            asset,
+        })
     }
 }
 
+/// Returns the latest settings version for the loader or processor used by `asset`.
+fn latest_settings_version<L: AssetLoader, P: Process>(
+    asset: &AssetAction<L::Settings, P::Settings>,
+) -> u32 {
+    match asset {
+        AssetAction::Load { .. } => L::settings_migrations().version(),
+        AssetAction::Process { .. } => P::settings_migrations().version(),
+        AssetAction::Ignore => 0,
+    }
+}
+
+fn is_zero(value: &u32) -> bool {
+    *value == 0
+}
+
 /// Configures how an asset source file should be handled by the asset system.
 #[derive(Serialize, Deserialize)]
 pub enum AssetAction<LoaderSettings, ProcessSettings> {
@@ -101,6 +170,8 @@ pub struct ProcessDependencyInfo {
 // using a type registry.
 #[derive(Serialize, Deserialize)]
 pub struct AssetMetaMinimal {
+    #[serde(default)]
+    pub settings_version: u32,
     pub asset: AssetActionMinimal,
 }
 
@@ -133,6 +204,12 @@ pub trait AssetMetaDyn: Downcast + Send + Sync {
     fn processed_info(&self) -> &Option<ProcessedInfo>;
     /// Returns a mutable reference to the [`ProcessedInfo`] if it exists.
     fn processed_info_mut(&mut self) -> &mut Option<ProcessedInfo>;
+    /// Returns the [`AssetMeta::settings_version`].
+    ///
+    /// Defaults to `0`, the version of settings that were never migrated.
+    fn settings_version(&self) -> u32 {
+        0
+    }
 }
 
 impl<L: AssetLoader, P: Process> AssetMetaDyn for AssetMeta<L, P> {
@@ -161,6 +238,9 @@ impl<L: AssetLoader, P: Process> AssetMetaDyn for AssetMeta<L, P> {
     fn processed_info_mut(&mut self) -> &mut Option<ProcessedInfo> {
         &mut self.processed_info
     }
+    fn settings_version(&self) -> u32 {
+        self.settings_version
+    }
 }
 
 impl_downcast!(AssetMetaDyn);
@@ -174,6 +254,118 @@ impl<T: 'static> Settings for T where T: Send + Sync {}
 
 impl_downcast!(Settings);
 
+type MigrateSettingsFn<S> = dyn Fn(&[u8]) -> Result<S, DeserializeMetaError> + Send + Sync;
+
+/// An ordered chain of migrations that upgrade [`Settings`] stored in older `.meta` files to the latest settings type `S`.
+///
+/// Each migration converts one version of the settings into the next. The chain starts with the oldest settings type, and
+/// the number of migrations is the current settings version, which is stored in [`AssetMeta::settings_version`].
+/// `.meta` files without a settings version are considered to be at version `0`.
+///
+/// ```
+/// # use bevy_asset::meta::SettingsMigrations;
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Serialize, Deserialize, Default)]
+/// struct TextSettingsV0 {
+///     uppercase: bool,
+/// }
+///
+/// #[derive(Serialize, Deserialize, Default)]
+/// enum Case {
+///     #[default]
+///     Keep,
+///     Upper,
+/// }
+///
+/// #[derive(Serialize, Deserialize, Default)]
+/// struct TextSettings {
+///     case: Case,
+/// }
+///
+/// let migrations = SettingsMigrations::<TextSettingsV0>::new().then(|old| TextSettings {
+///     case: if old.uppercase { Case::Upper } else { Case::Keep },
+/// });
+/// assert_eq!(migrations.version(), 1);
+/// ```
+///
+/// The resulting [`SettingsMigrations<TextSettings>`] is returned from [`AssetLoader::settings_migrations`] or
+/// [`Process::settings_migrations`].
+pub struct SettingsMigrations<S> {
+    /// `migrations[version]` deserializes settings stored with `version` from meta bytes and migrates them to `S`.
+    migrations: Vec<Box<MigrateSettingsFn<S>>>,
+}
+
+impl<S: Settings + for<'a> Deserialize<'a>> SettingsMigrations<S> {
+    /// Creates a new chain without any migrations, where `S` is the oldest version of the settings.
+    pub fn new() -> Self {
+        Self {
+            migrations: vec![Box::new(deserialize_settings::<S>)],
+        }
+    }
+
+    /// Adds a migration from the current latest settings type `S` to the new settings type `T`, incrementing the settings version.
+    pub fn then<T: Settings + for<'a> Deserialize<'a>>(
+        self,
+        migrate: impl Fn(S) -> T + Send + Sync + 'static,
+    ) -> SettingsMigrations<T> {
+        let migrate = Arc::new(migrate);
+        let mut migrations = self
+            .migrations
+            .into_iter()
+            .map(|previous| {
+                let migrate = migrate.clone();
+                Box::new(move |bytes: &[u8]| previous(bytes).map(|settings| migrate(settings)))
+                    as Box<MigrateSettingsFn<T>>
+            })
+            .collect::<Vec<_>>();
+        migrations.push(Box::new(deserialize_settings::<T>));
+        SettingsMigrations { migrations }
+    }
+
+    /// Returns the latest settings version, which is the number of migrations in this chain.
+    pub fn version(&self) -> u32 {
+        (self.migrations.len() - 1) as u32
+    }
+
+    /// Deserializes the settings stored with `version` in the given serialized asset meta, and migrates them to `S`.
+    /// The meta must have an [`AssetAction::Load`] or [`AssetAction::Process`] action.
+    pub(crate) fn migrate(
+        &self,
+        version: u32,
+        meta_bytes: &[u8],
+    ) -> Result<S, DeserializeMetaError> {
+        let migrate = self.migrations.get(version as usize).ok_or(
+            DeserializeMetaError::UnsupportedSettingsVersion {
+                version,
+                latest: self.version(),
+            },
+        )?;
+        migrate(meta_bytes)
+    }
+}
+
+impl<S: Settings + for<'a> Deserialize<'a>> Default for SettingsMigrations<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Deserializes only the settings of the [`AssetAction`] in the given serialized asset meta.
+fn deserialize_settings<S: for<'a> Deserialize<'a>>(
+    meta_bytes: &[u8],
+) -> Result<S, DeserializeMetaError> {
+    #[derive(Deserialize)]
+    struct SettingsMinimal<S> {
+        asset: AssetAction<S, S>,
+    }
+
+    let minimal: SettingsMinimal<S> = ron::de::from_bytes(meta_bytes)?;
+    match minimal.asset {
+        AssetAction::Load { settings, .. } | AssetAction::Process { settings, .. } => Ok(settings),
+        AssetAction::Ignore => Err(DeserializeMetaError::MissingSettings),
+    }
+}
+
 /// The () processor should never be called. This implementation exists to make the meta format nicer to work with.
 impl Process for () {
     type Settings = ();
@@ -260,3 +452,165 @@ pub(crate) fn get_full_asset_hash(
     }
     *hasher.finalize().as_bytes()
 }
+
+/// Returns the key used to store a processed asset in a [`ProcessedAssetCache`]. This combines the
+/// [`ProcessedInfo::hash`] (the asset bytes and the asset .meta data) with the processor version.
+///
+/// NOTE: changing the hashing logic here invalidates every existing [`ProcessedAssetCache`] entry.
+///
+/// [`ProcessedAssetCache`]: crate::processor::ProcessedAssetCache
+pub(crate) fn get_processed_asset_cache_key(
+    asset_hash: AssetHash,
+    processor_version: u32,
+) -> AssetHash {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(&asset_hash);
+    hasher.update(&processor_version.to_le_bytes());
+    *hasher.finalize().as_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AssetAction, AssetMeta, AssetMetaDyn, SettingsMigrations};
+    use crate::{io::Reader, Asset, AssetLoader, DeserializeMetaError, LoadContext};
+    use alloc::{
+        format,
+        string::{String, ToString},
+    };
+    use bevy_reflect::TypePath;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Asset, TypePath)]
+    struct Text;
+
+    #[derive(Serialize, Deserialize)]
+    struct TextSettingsV0 {
+        uppercase: bool,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct TextSettingsV1 {
+        case: String,
+    }
+
+    #[derive(Serialize, Deserialize, Default, Debug, PartialEq)]
+    enum Case {
+        #[default]
+        Keep,
+        Upper,
+    }
+
+    #[derive(Serialize, Deserialize, Default, Debug, PartialEq)]
+    struct TextSettings {
+        case: Case,
+        trim: bool,
+    }
+
+    struct TextLoader;
+
+    impl AssetLoader for TextLoader {
+        type Asset = Text;
+        type Settings = TextSettings;
+        type Error = std::io::Error;
+
+        async fn load(
+            &self,
+            _reader: &mut dyn Reader,
+            _settings: &Self::Settings,
+            _load_context: &mut LoadContext<'_>,
+        ) -> Result<Self::Asset, Self::Error> {
+            Ok(Text)
+        }
+
+        fn settings_migrations() -> SettingsMigrations<Self::Settings> {
+            SettingsMigrations::<TextSettingsV0>::new()
+                .then(|old| TextSettingsV1 {
+                    case: if old.uppercase { "upper" } else { "keep" }.to_string(),
+                })
+                .then(|old| TextSettings {
+                    case: if old.case == "upper" {
+                        Case::Upper
+                    } else {
+                        Case::Keep
+                    },
+                    trim: false,
+                })
+        }
+    }
+
+    const LOADER: &str = "bevy_asset::meta::tests::TextLoader";
+
+    #[test]
+    fn migrate_settings_from_unversioned_meta() {
+        let bytes = format!(
+            r#"(meta_format_version: "1.0", asset: Load(loader: "{LOADER}", settings: (uppercase: true)))"#
+        );
+        let meta = AssetMeta::<TextLoader, ()>::deserialize(bytes.as_bytes()).unwrap();
+        assert_eq!(meta.settings_version, 2);
+        let AssetAction::Load { loader, settings } = &meta.asset else {
+            panic!("expected a load action");
+        };
+        assert_eq!(loader, LOADER);
+        assert_eq!(
+            settings,
+            &TextSettings {
+                case: Case::Upper,
+                trim: false,
+            }
+        );
+
+        // The migrated meta round trips at the latest version
+        let migrated =
+            AssetMeta::<TextLoader, ()>::deserialize(&AssetMetaDyn::serialize(&meta)).unwrap();
+        assert_eq!(migrated.settings_version, 2);
+    }
+
+    #[test]
+    fn migrate_settings_from_intermediate_version() {
+        let bytes = format!(
+            r#"(meta_format_version: "1.0", settings_version: 1, asset: Load(loader: "{LOADER}", settings: (case: "keep")))"#
+        );
+        let meta = AssetMeta::<TextLoader, ()>::deserialize(bytes.as_bytes()).unwrap();
+        assert_eq!(meta.settings_version(), 2);
+        assert_eq!(
+            meta.loader_settings()
+                .unwrap()
+                .downcast_ref::<TextSettings>(),
+            Some(&TextSettings::default())
+        );
+    }
+
+    #[test]
+    fn reject_newer_settings_version() {
+        let bytes = format!(
+            r#"(meta_format_version: "1.0", settings_version: 3, asset: Load(loader: "{LOADER}", settings: (case: Keep, trim: true)))"#
+        );
+        assert_eq!(
+            AssetMeta::<TextLoader, ()>::deserialize(bytes.as_bytes()).err(),
+            Some(DeserializeMetaError::UnsupportedSettingsVersion {
+                version: 3,
+                latest: 2
+            })
+        );
+    }
+
+    #[test]
+    fn ignored_assets_have_no_settings_to_migrate() {
+        let bytes = r#"(meta_format_version: "1.0", settings_version: 1, asset: Ignore)"#;
+        let meta = AssetMeta::<TextLoader, ()>::deserialize(bytes.as_bytes()).unwrap();
+        assert!(matches!(meta.asset, AssetAction::Ignore));
+        assert_eq!(
+            TextLoader::settings_migrations()
+                .migrate(1, bytes.as_bytes())
+                .err(),
+            Some(DeserializeMetaError::MissingSettings)
+        );
+    }
+
+    #[test]
+    fn unversioned_settings_are_not_serialized() {
+        let meta = AssetMeta::<(), ()>::new(AssetAction::Ignore);
+        let serialized = String::from_utf8(AssetMetaDyn::serialize(&meta)).unwrap();
+        assert!(!serialized.contains("settings_version"));
+    }
+}
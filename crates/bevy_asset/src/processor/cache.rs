@@ -0,0 +1,302 @@
+use crate::{
+    io::{AssetReaderError, AssetWriterError},
+    meta::AssetHash,
+    AssetPath,
+};
+use alloc::{boxed::Box, string::String, vec::Vec};
+use bevy_tasks::{BoxedFuture, ConditionalSendFuture};
+use core::fmt::Write;
+use std::path::PathBuf;
+use thiserror::Error;
+
+#[cfg(not(target_arch = "wasm32"))]
+use {
+    crate::io::{
+        file::{FileAssetReader, FileAssetWriter},
+        AssetReader, AssetWriter,
+    },
+    alloc::format,
+    bevy_platform::sync::atomic::{AtomicU64, Ordering},
+    futures_lite::AsyncReadExt,
+    std::path::Path,
+};
+
+/// The output of a single [`Process`](crate::processor::Process) run, as stored in a [`ProcessedAssetCache`].
+///
+/// `meta_bytes` is the serialized processed [`AssetMeta`](crate::meta::AssetMeta), including its
+/// [`ProcessedInfo`](crate::meta::ProcessedInfo). The [`AssetProcessor`](crate::processor::AssetProcessor) uses the
+/// recorded "process dependencies" to verify that a cached result is still valid before reusing it.
+#[derive(Debug, Clone, Default)]
+pub struct CachedProcessedAsset {
+    /// The processed asset bytes.
+    pub asset_bytes: Vec<u8>,
+    /// The serialized processed asset meta.
+    pub meta_bytes: Vec<u8>,
+    /// The paths of the assets referenced by the source asset, used to report dangling references when the
+    /// entry is reused.
+    pub references: Vec<AssetPath<'static>>,
+}
+
+/// An error that occurs when reading from or writing to a [`ProcessedAssetCache`].
+#[derive(Error, Debug)]
+pub enum ProcessedAssetCacheError {
+    /// Failed to read a cache entry.
+    #[error("Failed to read processed asset cache entry: {0}")]
+    Read(#[from] AssetReaderError),
+    /// Failed to write a cache entry.
+    #[error("Failed to write processed asset cache entry: {0}")]
+    Write(#[from] AssetWriterError),
+    /// Failed to parse the references of a cache entry.
+    #[error("Failed to parse the references of a processed asset cache entry: {0}")]
+    InvalidReferences(#[from] ron::error::SpannedError),
+}
+
+/// A content-addressed store of processed assets that can be shared across processor runs, machines, and CI runners.
+///
+/// Entries are keyed by a hash of the source asset bytes, the source `.meta` bytes (which include the processor and its settings),
+/// and the processor [`Process::VERSION`]. Because "process dependencies" are only discovered while processing, each entry records
+/// the full hashes of its dependencies and is only reused if they match the dependencies' current full hashes.
+///
+/// Caches are configured with [`AssetProcessor::set_cache`]. Failing to read or write the cache is never fatal: the asset is simply
+/// processed as usual.
+///
+/// This trait is not object safe, if needed use a dyn [`ErasedProcessedAssetCache`] instead.
+///
+/// [`Process::VERSION`]: crate::processor::Process::VERSION
+/// [`AssetProcessor::set_cache`]: crate::processor::AssetProcessor::set_cache
+pub trait ProcessedAssetCache: Send + Sync + 'static {
+    /// Returns the cached processed asset for `key`, or `None` if there is no entry.
+    fn get<'a>(
+        &'a self,
+        key: &'a AssetHash,
+    ) -> impl ConditionalSendFuture<
+        Output = Result<Option<CachedProcessedAsset>, ProcessedAssetCacheError>,
+    >;
+    /// Stores `asset` for `key`, replacing any existing entry.
+    fn put<'a>(
+        &'a self,
+        key: &'a AssetHash,
+        asset: &'a CachedProcessedAsset,
+    ) -> impl ConditionalSendFuture<Output = Result<(), ProcessedAssetCacheError>>;
+}
+
+/// Equivalent to a [`ProcessedAssetCache`] but using boxed futures, necessary eg. when using a `dyn ProcessedAssetCache`,
+/// as [`ProcessedAssetCache`] isn't currently object safe.
+pub trait ErasedProcessedAssetCache: Send + Sync + 'static {
+    /// Returns the cached processed asset for `key`, or `None` if there is no entry.
+    fn get<'a>(
+        &'a self,
+        key: &'a AssetHash,
+    ) -> BoxedFuture<'a, Result<Option<CachedProcessedAsset>, ProcessedAssetCacheError>>;
+    /// Stores `asset` for `key`, replacing any existing entry.
+    fn put<'a>(
+        &'a self,
+        key: &'a AssetHash,
+        asset: &'a CachedProcessedAsset,
+    ) -> BoxedFuture<'a, Result<(), ProcessedAssetCacheError>>;
+}
+
+impl<T: ProcessedAssetCache> ErasedProcessedAssetCache for T {
+    fn get<'a>(
+        &'a self,
+        key: &'a AssetHash,
+    ) -> BoxedFuture<'a, Result<Option<CachedProcessedAsset>, ProcessedAssetCacheError>> {
+        Box::pin(Self::get(self, key))
+    }
+    fn put<'a>(
+        &'a self,
+        key: &'a AssetHash,
+        asset: &'a CachedProcessedAsset,
+    ) -> BoxedFuture<'a, Result<(), ProcessedAssetCacheError>> {
+        Box::pin(Self::put(self, key, asset))
+    }
+}
+
+/// Returns the relative path of the cache entry for `key`. Entries are sharded into folders using the first byte of the key
+/// to keep directory sizes manageable, and use the `processed` extension so their meta files are named `<key>.processed.meta`.
+pub(crate) fn get_cache_entry_path(key: &AssetHash) -> PathBuf {
+    let mut name = String::with_capacity(key.len() * 2);
+    for byte in key {
+        write!(&mut name, "{byte:02x}").unwrap();
+    }
+    let folder = PathBuf::from(&name[..2]);
+    name.push_str(".processed");
+    folder.join(name)
+}
+
+/// A [`ProcessedAssetCache`] that stores entries in a directory on the local filesystem (or any mounted network share).
+///
+/// Each entry is stored as three files: the processed asset bytes, the references of the source asset and the `.meta`
+/// file. They are written to temporary files that are then renamed into place, meta last, so an entry is only visible
+/// to readers once it is complete, even if the process is interrupted while writing it.
+///
+/// This is not available on `wasm` targets.
+#[cfg(not(target_arch = "wasm32"))]
+pub struct FileProcessedAssetCache {
+    reader: FileAssetReader,
+    writer: FileAssetWriter,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl FileProcessedAssetCache {
+    /// Creates a new [`FileProcessedAssetCache`] at a path relative to the base asset path (see
+    /// [`FileAssetReader::get_base_path`]). Absolute paths are used as-is. The directory is created if it does not exist.
+    pub fn new<P: AsRef<Path> + core::fmt::Debug>(path: P) -> Self {
+        Self {
+            reader: FileAssetReader::new(&path),
+            writer: FileAssetWriter::new(&path, true),
+        }
+    }
+
+    /// Returns the root directory where cache entries are stored.
+    pub fn root_path(&self) -> &PathBuf {
+        self.reader.root_path()
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl ProcessedAssetCache for FileProcessedAssetCache {
+    async fn get<'a>(
+        &'a self,
+        key: &'a AssetHash,
+    ) -> Result<Option<CachedProcessedAsset>, ProcessedAssetCacheError> {
+        let path = get_cache_entry_path(key);
+        // The meta is written last, so its presence implies the asset bytes are complete.
+        let meta_bytes = match self.reader.read_meta_bytes(&path).await {
+            Ok(meta_bytes) => meta_bytes,
+            Err(AssetReaderError::NotFound(_)) => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        let mut reader = match self.reader.read(&path).await {
+            Ok(reader) => reader,
+            Err(AssetReaderError::NotFound(_)) => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        let mut asset_bytes = Vec::new();
+        reader
+            .read_to_end(&mut asset_bytes)
+            .await
+            .map_err(AssetReaderError::from)?;
+        let references = match self.reader.read(&get_references_path(&path)).await {
+            Ok(mut reader) => {
+                let mut bytes = Vec::new();
+                reader
+                    .read_to_end(&mut bytes)
+                    .await
+                    .map_err(AssetReaderError::from)?;
+                ron::de::from_bytes(&bytes)?
+            }
+            // Entries written before references were cached don't have them.
+            Err(AssetReaderError::NotFound(_)) => Vec::new(),
+            Err(err) => return Err(err.into()),
+        };
+        Ok(Some(CachedProcessedAsset {
+            asset_bytes,
+            meta_bytes,
+            references,
+        }))
+    }
+
+    async fn put<'a>(
+        &'a self,
+        key: &'a AssetHash,
+        asset: &'a CachedProcessedAsset,
+    ) -> Result<(), ProcessedAssetCacheError> {
+        let path = get_cache_entry_path(key);
+        let temp_path = get_temp_entry_path(&path);
+        self.writer
+            .write_bytes(&temp_path, &asset.asset_bytes)
+            .await?;
+        self.writer.rename(&temp_path, &path).await?;
+        let references = ron::ser::to_string(&asset.references)
+            .expect("asset paths can always be serialized to RON");
+        let references_path = get_references_path(&path);
+        self.writer
+            .write_bytes(&temp_path, references.as_bytes())
+            .await?;
+        self.writer.rename(&temp_path, &references_path).await?;
+        // The meta is renamed into place last, see `get`.
+        self.writer
+            .write_meta_bytes(&temp_path, &asset.meta_bytes)
+            .await?;
+        self.writer.rename_meta(&temp_path, &path).await?;
+        Ok(())
+    }
+}
+
+/// Returns the path of the file storing the references of the cache entry at `path`.
+#[cfg(not(target_arch = "wasm32"))]
+fn get_references_path(path: &Path) -> PathBuf {
+    path.with_extension("references")
+}
+
+/// Returns a unique path next to the cache entry at `path` to write it to before it is renamed into place.
+/// Cache directories can be shared by multiple processes, so the path includes the process id.
+#[cfg(not(target_arch = "wasm32"))]
+fn get_temp_entry_path(path: &Path) -> PathBuf {
+    static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+    let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+    let mut name = std::ffi::OsString::from(path.file_name().unwrap_or_default());
+    name.push(format!(".{}-{id}.tmp", std::process::id()));
+    path.with_file_name(name)
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::{
+        get_cache_entry_path, CachedProcessedAsset, FileProcessedAssetCache, ProcessedAssetCache,
+    };
+    use crate::AssetPath;
+    use alloc::{format, vec, vec::Vec};
+    use std::path::Path;
+
+    #[test]
+    fn cache_entry_paths_are_sharded() {
+        let mut key = [0u8; 32];
+        key[0] = 0xab;
+        key[31] = 0x01;
+        let path = get_cache_entry_path(&key);
+        assert_eq!(path.parent(), Some(Path::new("ab")));
+        let name = path.file_name().unwrap().to_str().unwrap();
+        assert_eq!(name.len(), 74);
+        assert!(name.starts_with("ab00"));
+        assert!(name.ends_with("01.processed"));
+    }
+
+    #[test]
+    fn file_cache_round_trip() {
+        let root = std::env::temp_dir().join(format!(
+            "bevy_asset_processed_cache_test_{}",
+            std::process::id()
+        ));
+        let cache = FileProcessedAssetCache::new(&root);
+        let key = [7u8; 32];
+
+        let missing = bevy_tasks::block_on(cache.get(&key)).unwrap();
+        assert!(missing.is_none());
+
+        let asset = CachedProcessedAsset {
+            asset_bytes: b"processed".to_vec(),
+            meta_bytes: b"(meta_format_version: \"1.0\")".to_vec(),
+            references: vec![AssetPath::from("textures/missing.png")],
+        };
+        bevy_tasks::block_on(cache.put(&key, &asset)).unwrap();
+        let cached = bevy_tasks::block_on(cache.get(&key)).unwrap().unwrap();
+        assert_eq!(cached.asset_bytes, asset.asset_bytes);
+        assert_eq!(cached.meta_bytes, asset.meta_bytes);
+        assert_eq!(cached.references, asset.references);
+
+        // Only the complete entry is left in the cache directory.
+        let entry_path = cache.root_path().join(get_cache_entry_path(&key));
+        let entries = std::fs::read_dir(entry_path.parent().unwrap())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect::<Vec<_>>();
+        assert_eq!(entries.len(), 3);
+        assert!(entries
+            .iter()
+            .all(|name| !name.to_string_lossy().ends_with(".tmp")));
+
+        let _ = std::fs::remove_dir_all(root);
+    }
+}
@@ -0,0 +1,304 @@
+use crate::{
+    processor::{AssetProcessResult, AssetProcessor, ProcessResult},
+    AssetMode, AssetPlugin,
+};
+use alloc::string::String;
+use bevy_app::{App, AppExit, Plugins, PluginsState, TaskPoolPlugin};
+use std::{eprintln, println};
+use thiserror::Error;
+
+/// A headless command-line entry point for the [`AssetProcessor`], which processes a source asset folder into a destination
+/// folder without running an app or opening a window. This is intended to be used from a small binary in your build pipeline:
+///
+/// ```no_run
+/// # use bevy_app::{App, AppExit, Plugin};
+/// # use bevy_asset::processor::AssetProcessorCli;
+/// # struct MyAssetProcessingPlugin;
+/// # impl Plugin for MyAssetProcessingPlugin { fn build(&self, _app: &mut App) {} }
+/// fn main() -> AppExit {
+///     let cli = match AssetProcessorCli::from_env() {
+///         Ok(cli) => cli,
+///         Err(err) => {
+///             eprintln!("{err}\n\n{}", AssetProcessorCli::USAGE);
+///             return AppExit::error();
+///         }
+///     };
+///     // Register your asset loaders and processors here.
+///     cli.run(MyAssetProcessingPlugin)
+/// }
+/// ```
+///
+/// Every processed asset is reported on stdout and every failure (along with its [`ProcessError`]) on stderr, as well as
+/// references to assets that do not exist. The returned [`AppExit`] is an error if any asset failed to process, which
+/// results in a non-zero exit code.
+///
+/// In watch mode, the processor keeps running after the initial pass and reports each asset as it is reprocessed.
+/// Failures while watching also count towards the returned [`AppExit`]. This requires the `file_watcher` feature.
+///
+/// [`ProcessError`]: crate::processor::ProcessError
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssetProcessorCli {
+    /// The folder containing the unprocessed source assets. See [`AssetPlugin::file_path`].
+    pub source: String,
+    /// The folder the processed assets are written to. See [`AssetPlugin::processed_file_path`].
+    pub destination: String,
+    /// Whether to keep watching the source folder for changes after the initial processing pass.
+    pub watch: bool,
+    /// The asset redirects file to apply while processing. See [`AssetPlugin::redirects_file_path`].
+    pub redirects: Option<String>,
+}
+
+/// An error that occurs when parsing [`AssetProcessorCli`] arguments.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum AssetProcessorCliError {
+    /// An argument was not recognized.
+    #[error("Unknown argument '{0}'")]
+    UnknownArgument(String),
+    /// An argument that requires a value was the last argument.
+    #[error("Missing value for argument '{0}'")]
+    MissingValue(String),
+}
+
+impl Default for AssetProcessorCli {
+    fn default() -> Self {
+        let plugin = AssetPlugin::default();
+        Self {
+            source: plugin.file_path,
+            destination: plugin.processed_file_path,
+            watch: false,
+            redirects: None,
+        }
+    }
+}
+
+impl AssetProcessorCli {
+    /// A description of the arguments accepted by [`AssetProcessorCli::from_args`].
+    pub const USAGE: &'static str = "\
+Arguments:
+  --source <PATH>       The folder containing the source assets [default: assets]
+  --destination <PATH>  The folder processed assets are written to [default: imported_assets/Default]
+  --watch               Keep running and reprocess assets when they change
+  --redirects <PATH>    An asset redirects file in the source folder used to rewrite moved asset paths";
+
+    /// Parses the arguments of the current process. See [`AssetProcessorCli::from_args`].
+    pub fn from_env() -> Result<Self, AssetProcessorCliError> {
+        Self::from_args(std::env::args().skip(1))
+    }
+
+    /// Parses the given arguments (excluding the program name). See [`AssetProcessorCli::USAGE`] for the accepted arguments.
+    pub fn from_args(
+        args: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Result<Self, AssetProcessorCliError> {
+        let mut cli = Self::default();
+        let mut args = args.into_iter().map(Into::into);
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--source" => {
+                    cli.source = args
+                        .next()
+                        .ok_or(AssetProcessorCliError::MissingValue(arg))?;
+                }
+                "--destination" => {
+                    cli.destination = args
+                        .next()
+                        .ok_or(AssetProcessorCliError::MissingValue(arg))?;
+                }
+                "--watch" => cli.watch = true,
+                "--redirects" => {
+                    cli.redirects = Some(
+                        args.next()
+                            .ok_or(AssetProcessorCliError::MissingValue(arg))?,
+                    );
+                }
+                _ => return Err(AssetProcessorCliError::UnknownArgument(arg)),
+            }
+        }
+        Ok(cli)
+    }
+
+    /// Returns the [`AssetPlugin`] configured to process [`AssetProcessorCli::source`] into [`AssetProcessorCli::destination`].
+    pub fn asset_plugin(&self) -> AssetPlugin {
+        AssetPlugin {
+            file_path: self.source.clone(),
+            processed_file_path: self.destination.clone(),
+            watch_for_changes_override: Some(self.watch),
+            mode: AssetMode::Processed,
+            redirects_file_path: self.redirects.clone(),
+            ..Default::default()
+        }
+    }
+
+    /// Builds a headless [`App`] with `plugins`, processes all assets, and reports the results.
+    ///
+    /// `plugins` should register the asset loaders and processors used by your assets. The [`TaskPoolPlugin`] and
+    /// [`AssetPlugin`] are added automatically, so they must not be part of `plugins`.
+    #[expect(
+        clippy::print_stdout,
+        clippy::print_stderr,
+        reason = "Reporting results on the terminal is the purpose of the CLI."
+    )]
+    pub fn run<M>(self, plugins: impl Plugins<M>) -> AppExit {
+        let mut app = App::new();
+        app.add_plugins((TaskPoolPlugin::default(), self.asset_plugin()))
+            .add_plugins(plugins);
+        while app.plugins_state() == PluginsState::Adding {
+            bevy_tasks::tick_global_task_pools_on_main_thread();
+        }
+        app.finish();
+        app.cleanup();
+
+        let processor = app.world().resource::<AssetProcessor>().clone();
+        let results = processor.subscribe_results();
+        processor.process_assets();
+
+        let mut failures = results.try_iter().filter(|result| !report(result)).count();
+        println!(
+            "Finished processing assets from '{}' into '{}'",
+            self.source, self.destination
+        );
+
+        if self.watch {
+            let watcher = processor.clone();
+            std::thread::spawn(move || {
+                bevy_tasks::block_on(watcher.listen_for_source_change_events());
+            });
+            println!("Watching '{}' for changes", self.source);
+            for result in results.iter() {
+                if !report(&result) {
+                    failures += 1;
+                }
+            }
+        }
+
+        if failures == 0 {
+            AppExit::Success
+        } else {
+            eprintln!("{failures} asset(s) failed to process");
+            AppExit::error()
+        }
+    }
+}
+
+/// Prints the given `result`, returning `false` if it is a failure.
+#[expect(
+    clippy::print_stdout,
+    clippy::print_stderr,
+    reason = "Reporting results on the terminal is the purpose of the CLI."
+)]
+fn report(result: &AssetProcessResult) -> bool {
+    if let Some(migration) = result.meta_migration {
+        println!(
+            "Migrated meta for '{}' from settings version {} to {}",
+            result.path, migration.from_version, migration.to_version
+        );
+    }
+    for reference in &result.dangling_references {
+        eprintln!(
+            "'{}' references '{reference}', which does not exist",
+            result.path
+        );
+    }
+    if let Some(err) = result.error() {
+        eprintln!("Failed to process '{}': {err}", result.path);
+        return false;
+    }
+    if let Ok(ProcessResult::Processed(_)) = &result.result {
+        println!("Processed '{}'", result.path);
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AssetProcessorCli, AssetProcessorCliError};
+    use crate::processor::AssetProcessor;
+    use alloc::{
+        format,
+        string::{String, ToString},
+        vec::Vec,
+    };
+    use bevy_app::{App, AppExit};
+
+    #[test]
+    fn parse_args() {
+        let cli = AssetProcessorCli::from_args([
+            "--source",
+            "art",
+            "--destination",
+            "out",
+            "--watch",
+            "--redirects",
+            "redirects.ron",
+        ])
+        .unwrap();
+        assert_eq!(
+            cli,
+            AssetProcessorCli {
+                source: "art".to_string(),
+                destination: "out".to_string(),
+                watch: true,
+                redirects: Some("redirects.ron".to_string()),
+            }
+        );
+        assert_eq!(
+            AssetProcessorCli::from_args(Vec::<String>::new()).unwrap(),
+            AssetProcessorCli::default()
+        );
+        assert_eq!(
+            AssetProcessorCli::from_args(["--source"]),
+            Err(AssetProcessorCliError::MissingValue("--source".to_string()))
+        );
+        assert_eq!(
+            AssetProcessorCli::from_args(["--verbose"]),
+            Err(AssetProcessorCliError::UnknownArgument(
+                "--verbose".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn skipped_assets_are_not_failures() {
+        let root = std::env::temp_dir().join(format!(
+            "bevy_asset_processor_cli_test_{}",
+            std::process::id()
+        ));
+        let source = root.join("source");
+        let destination = root.join("destination");
+        std::fs::create_dir_all(&source).unwrap();
+        // An asset that is explicitly ignored, and one without a loader.
+        std::fs::write(source.join("ignored.txt"), "ignored").unwrap();
+        std::fs::write(
+            source.join("ignored.txt.meta"),
+            "(meta_format_version: \"1.0\", asset: Ignore)",
+        )
+        .unwrap();
+        std::fs::write(source.join("no_loader.unknown"), "unknown").unwrap();
+
+        let cli = AssetProcessorCli {
+            source: source.to_string_lossy().into_owned(),
+            destination: destination.to_string_lossy().into_owned(),
+            ..Default::default()
+        };
+        // Keep the transaction log out of the base asset path, which is the crate directory in tests.
+        let log_path = root.join("log");
+        let set_log_path = move |app: &mut App| {
+            app.world()
+                .resource::<AssetProcessor>()
+                .set_log_path(log_path.clone());
+        };
+        assert_eq!(cli.clone().run(set_log_path.clone()), AppExit::Success);
+        assert!(!destination.join("ignored.txt").exists());
+
+        // An asset using a processor that does not exist is a failure.
+        std::fs::write(source.join("failed.txt"), "failed").unwrap();
+        std::fs::write(
+            source.join("failed.txt.meta"),
+            "(meta_format_version: \"1.0\", asset: Process(processor: \"Missing\", settings: ()))",
+        )
+        .unwrap();
+        assert_eq!(cli.run(set_log_path), AppExit::error());
+        assert!(root.join("log").exists());
+
+        let _ = std::fs::remove_dir_all(root);
+    }
+}
@@ -37,9 +37,23 @@
 //!
 //! In most cases, [`LoadTransformAndSave`] should be sufficient.
 
+mod cache;
+#[cfg(all(
+    feature = "asset_processor",
+    feature = "multi_threaded",
+    not(target_arch = "wasm32")
+))]
+mod cli;
 mod log;
 mod process;
 
+pub use cache::*;
+#[cfg(all(
+    feature = "asset_processor",
+    feature = "multi_threaded",
+    not(target_arch = "wasm32")
+))]
+pub use cli::*;
 pub use log::*;
 pub use process::*;
 
@@ -50,13 +64,16 @@ use crate::{
         MissingAssetSourceError,
     },
     meta::{
-        get_asset_hash, get_full_asset_hash, AssetAction, AssetActionMinimal, AssetHash, AssetMeta,
-        AssetMetaDyn, AssetMetaMinimal, ProcessedInfo, ProcessedInfoMinimal,
+        get_asset_hash, get_full_asset_hash, get_processed_asset_cache_key, AssetAction,
+        AssetActionMinimal, AssetHash, AssetMeta, AssetMetaDyn, AssetMetaMinimal, ProcessedInfo,
+        ProcessedInfoMinimal,
     },
     AssetLoadError, AssetMetaCheck, AssetPath, AssetServer, AssetServerMode, DeserializeMetaError,
     MissingAssetLoaderForExtensionError, UnapprovedPathMode, WriteDefaultMetaError,
 };
-use alloc::{borrow::ToOwned, boxed::Box, collections::VecDeque, sync::Arc, vec, vec::Vec};
+use alloc::{
+    borrow::ToOwned, boxed::Box, collections::VecDeque, string::ToString, sync::Arc, vec, vec::Vec,
+};
 use bevy_ecs::prelude::*;
 use bevy_platform::collections::{HashMap, HashSet};
 use bevy_tasks::IoTaskPool;
@@ -65,11 +82,10 @@ use futures_lite::{AsyncReadExt, AsyncWriteExt, StreamExt};
 use parking_lot::RwLock;
 use std::path::{Path, PathBuf};
 use thiserror::Error;
-use tracing::{debug, error, trace, warn};
+use tracing::{debug, error, info, trace, warn};
 
 #[cfg(feature = "trace")]
 use {
-    alloc::string::ToString,
     bevy_tasks::ConditionalSendFuture,
     tracing::{info_span, instrument::Instrument},
 };
@@ -88,6 +104,9 @@ use {
 /// A [`ProcessorTransactionLog`] is produced, which uses "write-ahead logging" to make the [`AssetProcessor`] crash and failure resistant. If a failed/unfinished
 /// transaction from a previous run is detected, the affected asset(s) will be re-processed.
 ///
+/// If a [`ProcessedAssetCache`] is configured with [`AssetProcessor::set_cache`], processed results are stored in (and reused from) it,
+/// which allows sharing processed assets across machines and clean checkouts.
+///
 /// [`AssetProcessor`] can be cloned. It is backed by an [`Arc`] so clones will share state. Clones can be freely used in parallel.
 #[derive(Resource, Clone)]
 pub struct AssetProcessor {
@@ -99,11 +118,14 @@ pub struct AssetProcessor {
 pub struct AssetProcessorData {
     pub(crate) asset_infos: async_lock::RwLock<ProcessorAssetInfos>,
     log: async_lock::RwLock<Option<ProcessorTransactionLog>>,
+    log_path: RwLock<PathBuf>,
     processors: RwLock<HashMap<&'static str, Arc<dyn ErasedProcessor>>>,
     /// Default processors for file extensions
     default_processors: RwLock<HashMap<Box<str>, &'static str>>,
     state: async_lock::RwLock<ProcessorState>,
     sources: AssetSources,
+    cache: RwLock<Option<Arc<dyn ErasedProcessedAssetCache>>>,
+    result_senders: RwLock<Vec<crossbeam_channel::Sender<AssetProcessResult>>>,
     initialized_sender: async_broadcast::Sender<()>,
     initialized_receiver: async_broadcast::Receiver<()>,
     finished_sender: async_broadcast::Sender<()>,
@@ -606,6 +628,87 @@ impl AssetProcessor {
         processors.get(processor_type_name).cloned()
     }
 
+    /// Sets the path of the [`ProcessorTransactionLog`] file. This should be set before the processor starts.
+    ///
+    /// Defaults to `imported_assets/log` in the base asset path.
+    pub fn set_log_path(&self, path: impl Into<PathBuf>) {
+        *self.data.log_path.write() = path.into();
+    }
+
+    /// Returns the path of the [`ProcessorTransactionLog`] file.
+    pub fn log_path(&self) -> PathBuf {
+        self.data.log_path.read().clone()
+    }
+
+    /// Sets the [`ProcessedAssetCache`] used to store and reuse processed assets. This should be set before the processor starts.
+    pub fn set_cache(&self, cache: impl ProcessedAssetCache) {
+        *self.data.cache.write() = Some(Arc::new(cache));
+    }
+
+    /// Returns the configured [`ProcessedAssetCache`], if it exists.
+    pub fn cache(&self) -> Option<Arc<dyn ErasedProcessedAssetCache>> {
+        self.data.cache.read().clone()
+    }
+
+    /// Returns a receiver that will be sent an [`AssetProcessResult`] every time the processor finishes an attempt to process an asset.
+    /// Results are only sent to receivers that exist at the time processing finishes.
+    pub fn subscribe_results(&self) -> crossbeam_channel::Receiver<AssetProcessResult> {
+        let (sender, receiver) = crossbeam_channel::unbounded();
+        self.data.result_senders.write().push(sender);
+        receiver
+    }
+
+    /// Returns the cached processed asset for `key` if it exists and all of its "process dependencies" match the current
+    /// processed versions of those dependencies. Cache errors are logged and treated as a cache miss.
+    async fn get_valid_cached_asset(
+        &self,
+        cache: &dyn ErasedProcessedAssetCache,
+        key: &AssetHash,
+        asset_hash: AssetHash,
+        asset_path: &AssetPath<'static>,
+    ) -> Option<(CachedProcessedAsset, ProcessedInfo)> {
+        let cached = match cache.get(key).await {
+            Ok(Some(cached)) => cached,
+            Ok(None) => return None,
+            Err(err) => {
+                warn!("Failed to read {asset_path} from the processed asset cache: {err}");
+                return None;
+            }
+        };
+        let processed_info = match ron::de::from_bytes::<ProcessedInfoMinimal>(&cached.meta_bytes) {
+            Ok(ProcessedInfoMinimal {
+                processed_info: Some(processed_info),
+            }) if processed_info.hash == asset_hash => processed_info,
+            Ok(_) => {
+                warn!("Ignoring processed asset cache entry for {asset_path} because it does not match the asset");
+                return None;
+            }
+            Err(err) => {
+                warn!("Ignoring processed asset cache entry for {asset_path} because its meta could not be parsed: {err}");
+                return None;
+            }
+        };
+        for dependency in &processed_info.process_dependencies {
+            // The dependency must be processed before its live hash can be compared to the cached one.
+            self.data
+                .wait_until_processed(dependency.path.clone())
+                .await;
+            let infos = self.data.asset_infos.read().await;
+            let live_hash = infos
+                .get(&dependency.path)
+                .and_then(|i| i.processed_info.as_ref())
+                .map(|i| i.full_hash);
+            if live_hash != Some(dependency.full_hash) {
+                trace!(
+                    "Processed asset cache entry for {asset_path} is stale because {} changed",
+                    dependency.path
+                );
+                return None;
+            }
+        }
+        Some((cached, processed_info))
+    }
+
     /// Populates the initial view of each asset by scanning the unprocessed and processed asset folders.
     /// This info will later be used to determine whether or not to re-process an asset
     ///
@@ -785,15 +888,45 @@ impl AssetProcessor {
     /// [`ProcessorGatedReader`]: crate::io::processor_gated::ProcessorGatedReader
     async fn process_asset(&self, source: &AssetSource, path: PathBuf) {
         let asset_path = AssetPath::from(path).with_source(source.id());
-        let result = self.process_asset_internal(source, &asset_path).await;
-        let mut infos = self.data.asset_infos.write().await;
-        infos.finish_processing(asset_path, result).await;
+        let mut meta_migration = None;
+        let mut dangling_references = Vec::new();
+        let result = self
+            .process_asset_internal(
+                source,
+                &asset_path,
+                &mut meta_migration,
+                &mut dangling_references,
+            )
+            .await
+            .map_err(Arc::new);
+        {
+            let mut infos = self.data.asset_infos.write().await;
+            infos
+                .finish_processing(asset_path.clone(), result.clone())
+                .await;
+        }
+        // Drop senders whose receivers no longer exist
+        self.data.result_senders.write().retain(|sender| {
+            sender
+                .send(AssetProcessResult {
+                    path: asset_path.clone(),
+                    result: result.clone(),
+                    meta_migration,
+                    dangling_references: dangling_references.clone(),
+                })
+                .is_ok()
+        });
     }
 
+    /// Processes the asset at `asset_path`. If its source meta had outdated settings, the meta is rewritten with the
+    /// migrated settings and `meta_migration` is populated. Any assets referenced by the processed asset that do not
+    /// exist are added to `dangling_references`.
     async fn process_asset_internal(
         &self,
         source: &AssetSource,
         asset_path: &AssetPath<'static>,
+        meta_migration: &mut Option<MetaMigration>,
+        dangling_references: &mut Vec<AssetPath<'static>>,
     ) -> Result<ProcessResult, ProcessError> {
         // TODO: The extension check was removed now that AssetPath is the input. is that ok?
         // TODO: check if already processing to protect against duplicate hot-reload events
@@ -836,6 +969,17 @@ impl AssetProcessor {
                         return Ok(ProcessResult::Ignored);
                     }
                 };
+                let meta_bytes = if meta.settings_version() == minimal.settings_version {
+                    meta_bytes
+                } else {
+                    let migration = MetaMigration {
+                        from_version: minimal.settings_version,
+                        to_version: meta.settings_version(),
+                    };
+                    *meta_migration = Some(migration);
+                    self.write_migrated_meta(source, asset_path, &*meta, migration)
+                        .await
+                };
                 (meta, meta_bytes, processor)
             }
             Err(AssetReaderError::NotFound(_path)) => {
@@ -911,6 +1055,19 @@ impl AssetProcessor {
                 }
             }
         }
+
+        let cache = processor.as_ref().and_then(|_| self.cache());
+        let cache_key = processor
+            .as_ref()
+            .map(|processor| get_processed_asset_cache_key(new_hash, processor.version()));
+        let cached = match (&cache, &cache_key) {
+            (Some(cache), Some(cache_key)) => {
+                self.get_valid_cached_asset(&**cache, cache_key, new_hash, asset_path)
+                    .await
+            }
+            _ => None,
+        };
+
         // Note: this lock must remain alive until all processed asset and meta writes have finished (or failed)
         // See ProcessedAssetInfo::file_transaction_lock docs for more info
         let _transaction_lock = {
@@ -923,15 +1080,33 @@ impl AssetProcessor {
         // Directly writing to the asset destination in the processor necessitates this behavior
         // TODO: this class of failure can be recovered via re-processing + smarter log validation that allows for duplicate transactions in the event of failures
         self.log_begin_processing(asset_path).await;
-        if let Some(processor) = processor {
+        if let Some((cached, processed_info)) = cached {
+            debug!("Using cached processed asset for {asset_path}");
+            processed_writer
+                .write_bytes(path, &cached.asset_bytes)
+                .await
+                .map_err(writer_err)?;
+            processed_writer
+                .write_meta_bytes(path, &cached.meta_bytes)
+                .await
+                .map_err(writer_err)?;
+            *dangling_references = self
+                .report_dangling_references(asset_path, cached.references.into_iter().collect())
+                .await;
+            new_processed_info = processed_info;
+        } else if let Some(processor) = processor {
             let mut writer = processed_writer.write(path).await.map_err(writer_err)?;
-            let mut processed_meta = {
+            let (mut processed_meta, references) = {
                 let mut context =
                     ProcessContext::new(self, asset_path, &asset_bytes, &mut new_processed_info);
-                processor
+                let processed_meta = processor
                     .process(&mut context, source_meta, &mut *writer)
-                    .await?
+                    .await?;
+                (processed_meta, context.references)
             };
+            *dangling_references = self
+                .report_dangling_references(asset_path, references.clone())
+                .await;
 
             writer
                 .flush()
@@ -955,6 +1130,18 @@ impl AssetProcessor {
                 .write_meta_bytes(path, &meta_bytes)
                 .await
                 .map_err(writer_err)?;
+
+            if let (Some(cache), Some(cache_key)) = (cache, cache_key) {
+                self.store_cached_asset(
+                    &*cache,
+                    &cache_key,
+                    source,
+                    asset_path,
+                    meta_bytes,
+                    references.into_iter().collect(),
+                )
+                .await;
+            }
         } else {
             processed_writer
                 .write_bytes(path, &asset_bytes)
@@ -972,8 +1159,111 @@ impl AssetProcessor {
         Ok(ProcessResult::Processed(new_processed_info))
     }
 
+    /// Logs a warning for each of the `references` of `asset_path` that does not exist, and returns them.
+    async fn report_dangling_references(
+        &self,
+        asset_path: &AssetPath<'static>,
+        references: HashSet<AssetPath<'static>>,
+    ) -> Vec<AssetPath<'static>> {
+        let dangling_references = self.get_dangling_references(references).await;
+        for reference in dangling_references.iter() {
+            warn!("{asset_path} references {reference}, which does not exist");
+        }
+        dangling_references
+    }
+
+    /// Returns the `references` that do not exist in their asset source. References to assets in sources that are not
+    /// processed are assumed to exist.
+    async fn get_dangling_references(
+        &self,
+        references: HashSet<AssetPath<'static>>,
+    ) -> Vec<AssetPath<'static>> {
+        let infos = self.data.asset_infos.read().await;
+        references
+            .into_iter()
+            .filter(|reference| match self.get_source(reference.source()) {
+                Ok(source) => {
+                    source.should_process()
+                        && infos.get(&reference.without_label().into_owned()).is_none()
+                }
+                Err(_) => true,
+            })
+            .collect()
+    }
+
+    /// Rewrites the source meta file of `asset_path` with its migrated settings, returning the new meta bytes.
+    /// Failing to write the meta is not fatal, as the settings will simply be migrated again next time.
+    async fn write_migrated_meta(
+        &self,
+        source: &AssetSource,
+        asset_path: &AssetPath<'static>,
+        meta: &dyn AssetMetaDyn,
+        migration: MetaMigration,
+    ) -> Vec<u8> {
+        let meta_bytes = meta.serialize();
+        let result = match source.writer() {
+            Ok(writer) => writer
+                .write_meta_bytes(asset_path.path(), &meta_bytes)
+                .await
+                .map_err(|err| err.to_string()),
+            Err(err) => Err(err.to_string()),
+        };
+        match result {
+            Ok(()) => info!(
+                "Migrated meta for {asset_path} from settings version {} to {}",
+                migration.from_version, migration.to_version
+            ),
+            Err(err) => warn!(
+                "Migrated settings of {asset_path} from version {} to {}, but failed to write the migrated meta: {err}",
+                migration.from_version, migration.to_version
+            ),
+        }
+        meta_bytes
+    }
+
+    /// Stores the freshly processed asset at `asset_path` in the given `cache`. Failures are logged, as the cache
+    /// is only an optimization.
+    async fn store_cached_asset(
+        &self,
+        cache: &dyn ErasedProcessedAssetCache,
+        key: &AssetHash,
+        source: &AssetSource,
+        asset_path: &AssetPath<'static>,
+        meta_bytes: Vec<u8>,
+        references: Vec<AssetPath<'static>>,
+    ) {
+        let mut asset_bytes = Vec::new();
+        let read_result = match source.processed_reader() {
+            Ok(processed_reader) => match processed_reader.read(asset_path.path()).await {
+                Ok(mut reader) => reader
+                    .read_to_end(&mut asset_bytes)
+                    .await
+                    .map(|_| ())
+                    .map_err(|err| AssetReaderError::Io(err.into())),
+                Err(err) => Err(err),
+            },
+            Err(err) => {
+                warn!("Failed to store {asset_path} in the processed asset cache: {err}");
+                return;
+            }
+        };
+        if let Err(err) = read_result {
+            warn!("Failed to store {asset_path} in the processed asset cache: {err}");
+            return;
+        }
+        let cached = CachedProcessedAsset {
+            asset_bytes,
+            meta_bytes,
+            references,
+        };
+        if let Err(err) = cache.put(key, &cached).await {
+            warn!("Failed to store {asset_path} in the processed asset cache: {err}");
+        }
+    }
+
     async fn validate_transaction_log_and_recover(&self) {
-        if let Err(err) = ProcessorTransactionLog::validate().await {
+        let log_path = self.log_path();
+        if let Err(err) = ProcessorTransactionLog::validate(&log_path).await {
             let state_is_valid = match err {
                 ValidateLogError::ReadLogError(err) => {
                     error!("Failed to read processor log file. Processed assets cannot be validated so they must be re-generated {err}");
@@ -1051,7 +1341,7 @@ impl AssetProcessor {
             }
         }
         let mut log = self.data.log.write().await;
-        *log = match ProcessorTransactionLog::new().await {
+        *log = match ProcessorTransactionLog::new(&log_path).await {
             Ok(log) => Some(log),
             Err(err) => panic!("Failed to initialize asset processor log. This cannot be recovered. Try restarting. If that doesn't work, try deleting processed asset folder. {}", err),
         };
@@ -1076,9 +1366,12 @@ impl AssetProcessorData {
             initialized_receiver,
             state: async_lock::RwLock::new(ProcessorState::Initializing),
             log: Default::default(),
+            log_path: RwLock::new(ProcessorTransactionLog::default_path()),
             processors: Default::default(),
             asset_infos: Default::default(),
             default_processors: Default::default(),
+            cache: Default::default(),
+            result_senders: Default::default(),
         }
     }
 
@@ -1144,6 +1437,11 @@ struct InstrumentedAssetProcessor<T>(T);
 impl<T: Process> Process for InstrumentedAssetProcessor<T> {
     type Settings = T::Settings;
     type OutputLoader = T::OutputLoader;
+    const VERSION: u32 = T::VERSION;
+
+    fn settings_migrations() -> crate::meta::SettingsMigrations<Self::Settings> {
+        T::settings_migrations()
+    }
 
     fn process(
         &self,
@@ -1156,6 +1454,7 @@ impl<T: Process> Process for InstrumentedAssetProcessor<T> {
         // Change the processor type for the `AssetMeta`, which works because we share the `Settings` type.
         let meta = AssetMeta {
             meta_format_version: meta.meta_format_version,
+            settings_version: meta.settings_version,
             processed_info: meta.processed_info,
             asset: meta.asset,
         };
@@ -1176,6 +1475,41 @@ pub enum ProcessResult {
     Ignored,
 }
 
+/// The outcome of a single attempt to process the asset at `path`. See [`AssetProcessor::subscribe_results`].
+#[derive(Debug, Clone)]
+pub struct AssetProcessResult {
+    /// The path of the asset that was processed.
+    pub path: AssetPath<'static>,
+    /// The result of processing the asset.
+    pub result: Result<ProcessResult, Arc<ProcessError>>,
+    /// Set if the settings in the asset's source meta were outdated and have been migrated.
+    pub meta_migration: Option<MetaMigration>,
+    /// The assets referenced by the processed asset that do not exist, for example because they were moved without
+    /// adding an [`AssetRedirects`](crate::AssetRedirects) entry.
+    pub dangling_references: Vec<AssetPath<'static>>,
+}
+
+/// Describes a source meta file whose settings were migrated to the latest settings version while processing.
+/// See [`SettingsMigrations`](crate::meta::SettingsMigrations).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MetaMigration {
+    /// The settings version the meta was written with.
+    pub from_version: u32,
+    /// The settings version the meta was migrated to.
+    pub to_version: u32,
+}
+
+impl AssetProcessResult {
+    /// Returns the error if processing this asset failed. Errors that only indicate that an asset cannot be
+    /// processed (such as a missing extension or loader), and are not reported as failures by the processor, return `None`.
+    pub fn error(&self) -> Option<&ProcessError> {
+        match &self.result {
+            Err(err) if !err.is_skip() => Some(err),
+            _ => None,
+        }
+    }
+}
+
 /// The final status of processing an asset
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum ProcessStatus {
@@ -1285,7 +1619,7 @@ impl ProcessorAssetInfos {
     async fn finish_processing(
         &mut self,
         asset_path: AssetPath<'static>,
-        result: Result<ProcessResult, ProcessError>,
+        result: Result<ProcessResult, Arc<ProcessError>>,
     ) {
         match result {
             Ok(ProcessResult::Processed(processed_info)) => {
@@ -1325,37 +1659,40 @@ impl ProcessorAssetInfos {
             Ok(ProcessResult::Ignored) => {
                 debug!("Skipping processing (ignored) \"{}\"", asset_path);
             }
-            Err(ProcessError::ExtensionRequired) => {
-                // Skip assets without extensions
-            }
-            Err(ProcessError::MissingAssetLoaderForExtension(_)) => {
-                trace!("No loader found for {asset_path}");
-            }
-            Err(ProcessError::AssetReaderError {
-                err: AssetReaderError::NotFound(_),
-                ..
-            }) => {
-                // if there is no asset source, no processing can be done
-                trace!("No need to process asset {asset_path} because it does not exist");
-            }
-            Err(err) => {
-                error!("Failed to process asset {asset_path}: {err}");
-                // if this failed because a dependency could not be loaded, make sure it is reprocessed if that dependency is reprocessed
-                if let ProcessError::AssetLoadError(AssetLoadError::AssetLoaderError(dependency)) =
-                    err
-                {
-                    let info = self.get_mut(&asset_path).expect("info should exist");
-                    info.processed_info = Some(ProcessedInfo {
-                        hash: AssetHash::default(),
-                        full_hash: AssetHash::default(),
-                        process_dependencies: vec![],
-                    });
-                    self.add_dependent(dependency.path(), asset_path.to_owned());
+            Err(err) => match &*err {
+                ProcessError::ExtensionRequired => {
+                    // Skip assets without extensions
+                }
+                ProcessError::MissingAssetLoaderForExtension(_) => {
+                    trace!("No loader found for {asset_path}");
+                }
+                ProcessError::AssetReaderError {
+                    err: AssetReaderError::NotFound(_),
+                    ..
+                } => {
+                    // if there is no asset source, no processing can be done
+                    trace!("No need to process asset {asset_path} because it does not exist");
                 }
+                err => {
+                    error!("Failed to process asset {asset_path}: {err}");
+                    // if this failed because a dependency could not be loaded, make sure it is reprocessed if that dependency is reprocessed
+                    if let ProcessError::AssetLoadError(AssetLoadError::AssetLoaderError(
+                        dependency,
+                    )) = err
+                    {
+                        let info = self.get_mut(&asset_path).expect("info should exist");
+                        info.processed_info = Some(ProcessedInfo {
+                            hash: AssetHash::default(),
+                            full_hash: AssetHash::default(),
+                            process_dependencies: vec![],
+                        });
+                        self.add_dependent(dependency.path(), asset_path.to_owned());
+                    }
 
-                let info = self.get_mut(&asset_path).expect("info should exist");
-                info.update_status(ProcessStatus::Failed).await;
-            }
+                    let info = self.get_mut(&asset_path).expect("info should exist");
+                    info.update_status(ProcessStatus::Failed).await;
+                }
+            },
         }
     }
 
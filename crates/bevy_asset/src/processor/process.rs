@@ -3,7 +3,10 @@ use crate::{
         AssetReaderError, AssetWriterError, MissingAssetWriterError,
         MissingProcessedAssetReaderError, MissingProcessedAssetWriterError, SliceReader, Writer,
     },
-    meta::{AssetAction, AssetMeta, AssetMetaDyn, ProcessDependencyInfo, ProcessedInfo, Settings},
+    meta::{
+        AssetAction, AssetMeta, AssetMetaDyn, ProcessDependencyInfo, ProcessedInfo, Settings,
+        SettingsMigrations,
+    },
     processor::AssetProcessor,
     saver::{AssetSaver, SavedAsset},
     transformer::{AssetTransformer, IdentityAssetTransformer, TransformedAsset},
@@ -15,6 +18,7 @@ use alloc::{
     boxed::Box,
     string::{String, ToString},
 };
+use bevy_platform::collections::HashSet;
 use bevy_tasks::{BoxedFuture, ConditionalSendFuture};
 use core::marker::PhantomData;
 use serde::{Deserialize, Serialize};
@@ -30,6 +34,16 @@ pub trait Process: Send + Sync + Sized + 'static {
     type Settings: Settings + Default + Serialize + for<'a> Deserialize<'a>;
     /// The [`AssetLoader`] that will be used to load the final processed asset.
     type OutputLoader: AssetLoader;
+    /// The version of this processor's output. Bump this whenever a change to the processing logic would produce
+    /// different output for the same input, so that stale entries in a [`ProcessedAssetCache`] are not reused.
+    ///
+    /// [`ProcessedAssetCache`]: crate::processor::ProcessedAssetCache
+    const VERSION: u32 = 0;
+    /// Returns the [`SettingsMigrations`] used to upgrade [`Process::Settings`] stored in `.meta` files written for
+    /// older versions of the settings. See [`AssetLoader::settings_migrations`].
+    fn settings_migrations() -> SettingsMigrations<Self::Settings> {
+        SettingsMigrations::new()
+    }
     /// Processes the asset stored on `context` in some way using the settings stored on `meta`. The results are written to `writer`. The
     /// final written processed asset is loadable using [`Process::OutputLoader`]. This load will use the returned [`AssetLoader::Settings`].
     fn process(
@@ -160,6 +174,22 @@ pub enum ProcessError {
     ExtensionRequired,
 }
 
+impl ProcessError {
+    /// Returns `true` if this error only indicates that the asset cannot be processed at all (it has no extension, no loader,
+    /// or no longer exists). The [`AssetProcessor`] skips these assets instead of treating them as failures.
+    pub fn is_skip(&self) -> bool {
+        matches!(
+            self,
+            ProcessError::ExtensionRequired
+                | ProcessError::MissingAssetLoaderForExtension(_)
+                | ProcessError::AssetReaderError {
+                    err: AssetReaderError::NotFound(_),
+                    ..
+                }
+        )
+    }
+}
+
 impl<Loader, Transformer, Saver> Process for LoadTransformAndSave<Loader, Transformer, Saver>
 where
     Loader: AssetLoader,
@@ -221,6 +251,12 @@ pub trait ErasedProcessor: Send + Sync {
     fn deserialize_meta(&self, meta: &[u8]) -> Result<Box<dyn AssetMetaDyn>, DeserializeMetaError>;
     /// Returns the default type-erased [`AssetMeta`] for the underlying [`Process`] impl.
     fn default_meta(&self) -> Box<dyn AssetMetaDyn>;
+    /// Returns the [`Process::VERSION`] of the underlying [`Process`] impl.
+    ///
+    /// Defaults to `0`, matching the default [`Process::VERSION`].
+    fn version(&self) -> u32 {
+        0
+    }
 }
 
 impl<P: Process> ErasedProcessor for P {
@@ -245,7 +281,7 @@ impl<P: Process> ErasedProcessor for P {
     }
 
     fn deserialize_meta(&self, meta: &[u8]) -> Result<Box<dyn AssetMetaDyn>, DeserializeMetaError> {
-        let meta: AssetMeta<(), P> = ron::de::from_bytes(meta)?;
+        let meta = AssetMeta::<(), P>::deserialize(meta)?;
         Ok(Box::new(meta))
     }
 
@@ -255,6 +291,10 @@ impl<P: Process> ErasedProcessor for P {
             settings: P::Settings::default(),
         }))
     }
+
+    fn version(&self) -> u32 {
+        P::VERSION
+    }
 }
 
 /// Provides scoped data access to the [`AssetProcessor`].
@@ -281,6 +321,8 @@ pub struct ProcessContext<'a> {
     processor: &'a AssetProcessor,
     path: &'a AssetPath<'static>,
     asset_bytes: &'a [u8],
+    /// The paths of the assets referenced by the loaded source asset, used to report dangling references.
+    pub(crate) references: HashSet<AssetPath<'static>>,
 }
 
 impl<'a> ProcessContext<'a> {
@@ -295,6 +337,7 @@ impl<'a> ProcessContext<'a> {
             path,
             asset_bytes,
             new_processed_info,
+            references: HashSet::default(),
         }
     }
 
@@ -307,6 +350,8 @@ impl<'a> ProcessContext<'a> {
         meta: AssetMeta<L, ()>,
     ) -> Result<ErasedLoadedAsset, AssetLoadError> {
         let server = &self.processor.server;
+        // Redirects must be known before loading, so that references to moved assets are resolved to their new paths.
+        server.wait_for_redirects().await;
         let loader_name = core::any::type_name::<L>();
         let loader = server.get_asset_loader_with_type_name(loader_name).await?;
         let mut reader = SliceReader::new(self.asset_bytes);
@@ -321,6 +366,17 @@ impl<'a> ProcessContext<'a> {
                     path: path.to_owned(),
                 });
         }
+        let dependencies = loaded_asset.dependencies.iter().chain(
+            loaded_asset
+                .labeled_assets
+                .values()
+                .flat_map(|labeled_asset| labeled_asset.asset.dependencies.iter()),
+        );
+        for id in dependencies {
+            if let Some(path) = server.get_path(*id) {
+                self.references.insert(path.into_owned());
+            }
+        }
         Ok(loaded_asset)
     }
 
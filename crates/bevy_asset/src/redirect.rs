@@ -0,0 +1,269 @@
+use crate::{
+    io::{AssetReaderError, MissingAssetSourceError, MissingProcessedAssetReaderError},
+    AssetPath, ParseAssetPathError,
+};
+use alloc::string::String;
+use bevy_platform::collections::HashMap;
+use bevy_reflect::{PartialReflect, ReflectMut};
+use thiserror::Error;
+use tracing::warn;
+
+/// A table that maps old [`AssetPath`]s to new ones, which the [`AssetServer`](crate::AssetServer) consults before loading
+/// an asset. This allows asset files to be renamed or moved without breaking the scenes, materials, and other assets that
+/// still reference the old path.
+///
+/// Redirects are usually read from a RON file in the asset source (see [`AssetPlugin::redirects_file_path`]), which maps
+/// old paths to new paths:
+///
+/// ```ron
+/// {
+///     "textures/rock.png": "textures/terrain/rock.png",
+///     "models/tree.gltf": "models/foliage/oak.gltf",
+/// }
+/// ```
+///
+/// Redirects apply to labeled sub-assets as well: with the table above, `models/tree.gltf#Mesh0` resolves to
+/// `models/foliage/oak.gltf#Mesh0`. A redirect for a specific labeled path takes precedence over a redirect for its base path.
+/// Redirects are followed transitively, so an asset can be moved more than once.
+///
+/// [`AssetPlugin::redirects_file_path`]: crate::AssetPlugin::redirects_file_path
+#[derive(Clone, Debug, Default)]
+pub struct AssetRedirects {
+    redirects: HashMap<AssetPath<'static>, AssetPath<'static>>,
+}
+
+/// An error that occurs when reading an [`AssetRedirects`] file.
+#[derive(Error, Debug)]
+pub enum AssetRedirectsError {
+    /// The asset source of the redirects file does not exist.
+    #[error(transparent)]
+    MissingAssetSource(#[from] MissingAssetSourceError),
+    /// The asset source of the redirects file does not have a processed reader.
+    #[error(transparent)]
+    MissingProcessedAssetReader(#[from] MissingProcessedAssetReaderError),
+    /// Failed to read the redirects file.
+    #[error("Failed to read asset redirects: {0}")]
+    Read(#[from] AssetReaderError),
+    /// The redirects file is not a valid RON map of paths.
+    #[error("Failed to parse asset redirects: {0}")]
+    Ron(#[from] ron::error::SpannedError),
+    /// The redirects file contains an invalid asset path.
+    #[error("Invalid asset path in asset redirects: {0}")]
+    InvalidPath(#[from] ParseAssetPathError),
+}
+
+impl AssetRedirects {
+    /// Parses the redirects in `bytes`, which were read from `path`. Relative paths in the file are resolved relative to
+    /// the folder containing `path` (see [`AssetPath::resolve_embed`]).
+    pub fn from_bytes(path: &AssetPath, bytes: &[u8]) -> Result<Self, AssetRedirectsError> {
+        let entries: HashMap<String, String> = ron::de::from_bytes(bytes)?;
+        let mut redirects = Self::default();
+        for (from, to) in entries {
+            redirects.insert(path.resolve_embed(&from)?, path.resolve_embed(&to)?);
+        }
+        Ok(redirects)
+    }
+
+    /// Redirects `from` to `to`, replacing any existing redirect for `from`.
+    pub fn insert<'a, 'b>(&mut self, from: impl Into<AssetPath<'a>>, to: impl Into<AssetPath<'b>>) {
+        self.redirects
+            .insert(from.into().into_owned(), to.into().into_owned());
+    }
+
+    /// Removes the redirect for `from`, returning the path it redirected to.
+    pub fn remove<'a>(&mut self, from: impl Into<AssetPath<'a>>) -> Option<AssetPath<'static>> {
+        self.redirects.remove(&from.into().into_owned())
+    }
+
+    /// Adds all redirects in `other`, replacing existing redirects for the same paths.
+    pub fn extend(&mut self, other: AssetRedirects) {
+        self.redirects.extend(other.redirects);
+    }
+
+    /// Returns the path that `path` is redirected to, following redirects transitively. Returns `None` if `path` is not
+    /// redirected, or if the redirects for `path` form a cycle.
+    pub fn get(&self, path: &AssetPath) -> Option<AssetPath<'static>> {
+        if self.redirects.is_empty() {
+            return None;
+        }
+        let mut current = self.get_direct(path)?;
+        // Every path can only be visited once without a cycle.
+        for _ in 0..self.redirects.len() {
+            match self.get_direct(&current) {
+                Some(next) => current = next,
+                None => return Some(current),
+            }
+        }
+        warn!("Asset redirects for {path} form a cycle and will be ignored");
+        None
+    }
+
+    fn get_direct(&self, path: &AssetPath) -> Option<AssetPath<'static>> {
+        let path = path.clone_owned();
+        if let Some(to) = self.redirects.get(&path) {
+            return Some(to.clone());
+        }
+        let label = path.label_cow()?;
+        self.redirects
+            .get(&path.without_label().into_owned())
+            .map(|to| to.clone().with_label(label))
+    }
+
+    /// Replaces every [`AssetPath`] stored in `value`, including paths nested in its fields, lists, maps and enum
+    /// variants, with the path it is redirected to. Elements of sets are left unchanged.
+    pub fn redirect_paths(&self, value: &mut dyn PartialReflect) {
+        if self.redirects.is_empty() {
+            return;
+        }
+        if let Some(path) = value.try_downcast_mut::<AssetPath<'static>>() {
+            if let Some(redirected) = self.get(path) {
+                *path = redirected;
+            }
+            return;
+        }
+        match value.reflect_mut() {
+            ReflectMut::Struct(value) => {
+                for index in 0..value.field_len() {
+                    self.redirect_paths(value.field_at_mut(index).unwrap());
+                }
+            }
+            ReflectMut::TupleStruct(value) => {
+                for index in 0..value.field_len() {
+                    self.redirect_paths(value.field_mut(index).unwrap());
+                }
+            }
+            ReflectMut::Tuple(value) => {
+                for index in 0..value.field_len() {
+                    self.redirect_paths(value.field_mut(index).unwrap());
+                }
+            }
+            ReflectMut::List(value) => {
+                for index in 0..value.len() {
+                    self.redirect_paths(value.get_mut(index).unwrap());
+                }
+            }
+            ReflectMut::Array(value) => {
+                for index in 0..value.len() {
+                    self.redirect_paths(value.get_mut(index).unwrap());
+                }
+            }
+            ReflectMut::Map(value) => {
+                for index in 0..value.len() {
+                    self.redirect_paths(value.get_at_mut(index).unwrap().1);
+                }
+            }
+            ReflectMut::Enum(value) => {
+                for index in 0..value.field_len() {
+                    self.redirect_paths(value.field_at_mut(index).unwrap());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns the number of redirects.
+    pub fn len(&self) -> usize {
+        self.redirects.len()
+    }
+
+    /// Returns `true` if there are no redirects.
+    pub fn is_empty(&self) -> bool {
+        self.redirects.is_empty()
+    }
+
+    /// Iterates over all redirects as `(from, to)` pairs.
+    pub fn iter(&self) -> impl Iterator<Item = (&AssetPath<'static>, &AssetPath<'static>)> {
+        self.redirects.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AssetRedirects;
+    use crate::AssetPath;
+    use alloc::{vec, vec::Vec};
+    use bevy_reflect::{FromReflect, PartialReflect, Reflect};
+
+    #[test]
+    fn redirects_follow_chains_and_labels() {
+        let mut redirects = AssetRedirects::default();
+        redirects.insert("a.png", "b.png");
+        redirects.insert("b.png", "c.png");
+        redirects.insert("scene.gltf", "models/scene.gltf");
+        redirects.insert("scene.gltf#Mesh0", "other.gltf#Mesh1");
+
+        assert_eq!(
+            redirects.get(&AssetPath::from("a.png")),
+            Some(AssetPath::from("c.png"))
+        );
+        assert_eq!(
+            redirects.get(&AssetPath::from("scene.gltf#Scene0")),
+            Some(AssetPath::from("models/scene.gltf#Scene0"))
+        );
+        assert_eq!(
+            redirects.get(&AssetPath::from("scene.gltf#Mesh0")),
+            Some(AssetPath::from("other.gltf#Mesh1"))
+        );
+        assert_eq!(redirects.get(&AssetPath::from("c.png")), None);
+
+        redirects.insert("c.png", "a.png");
+        assert_eq!(redirects.get(&AssetPath::from("a.png")), None);
+    }
+
+    #[test]
+    fn redirect_nested_paths() {
+        #[derive(Reflect)]
+        struct Paths {
+            path: AssetPath<'static>,
+            list: Vec<AssetPath<'static>>,
+            maybe: Option<AssetPath<'static>>,
+        }
+
+        let mut redirects = AssetRedirects::default();
+        redirects.insert("a.png", "b.png");
+        let mut paths = Paths {
+            path: "a.png".into(),
+            list: vec!["c.png".into(), "a.png#Label".into()],
+            maybe: Some("a.png".into()),
+        };
+        redirects.redirect_paths(&mut paths);
+        assert_eq!(paths.path, AssetPath::from("b.png"));
+        assert_eq!(
+            paths.list,
+            vec![AssetPath::from("c.png"), AssetPath::from("b.png#Label")]
+        );
+        assert_eq!(paths.maybe, Some(AssetPath::from("b.png")));
+
+        // Dynamic values, such as the components of a deserialized scene, are redirected as well.
+        let mut dynamic = Paths {
+            path: "a.png".into(),
+            list: Vec::new(),
+            maybe: None,
+        }
+        .to_dynamic();
+        redirects.redirect_paths(dynamic.as_mut());
+        let paths = Paths::from_reflect(dynamic.as_ref()).unwrap();
+        assert_eq!(paths.path, AssetPath::from("b.png"));
+    }
+
+    #[test]
+    fn parse_redirects_file() {
+        let redirects = AssetRedirects::from_bytes(
+            &AssetPath::from("redirects.ron"),
+            br#"{
+                "old/rock.png": "new/rock.png",
+                "other://tree.gltf": "other://foliage/tree.gltf",
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(redirects.len(), 2);
+        assert_eq!(
+            redirects.get(&AssetPath::from("old/rock.png")),
+            Some(AssetPath::from("new/rock.png"))
+        );
+        assert_eq!(
+            redirects.get(&AssetPath::from("other://tree.gltf")),
+            Some(AssetPath::from("other://foliage/tree.gltf"))
+        );
+    }
+}
@@ -0,0 +1,506 @@
+use crate::{
+    io::{Reader, Writer},
+    processor::LoadTransformAndSave,
+    saver::{AssetSaver, SavedAsset},
+    transformer::IdentityAssetTransformer,
+    Asset, AssetLoader, AsyncWriteExt, LoadContext, ReflectHandle,
+};
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use bevy_reflect::{
+    serde::{
+        ReflectDeserializerProcessor, ReflectSerializerProcessor, TypedReflectDeserializer,
+        TypedReflectSerializer,
+    },
+    FromReflect, PartialReflect, TypePath, TypeRegistration, TypeRegistry, TypeRegistryArc,
+};
+use core::marker::PhantomData;
+use serde::{de::DeserializeSeed, Deserialize, Serialize};
+use thiserror::Error;
+
+/// The text format used by a [`ReflectAssetLoader`] or [`ReflectAssetSaver`].
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ReflectAssetFormat {
+    /// [RON](https://github.com/ron-rs/ron).
+    #[default]
+    Ron,
+    /// JSON. Loading and saving JSON requires the `json` feature, and fails with an `UnsupportedFormat` error otherwise.
+    Json,
+}
+
+impl ReflectAssetFormat {
+    /// Returns the format matching the given file `extension`: [`ReflectAssetFormat::Json`] for `json`, and
+    /// [`ReflectAssetFormat::Ron`] otherwise.
+    pub fn from_extension(extension: &str) -> Self {
+        match extension {
+            "json" => Self::Json,
+            _ => Self::Ron,
+        }
+    }
+}
+
+/// Settings for a [`ReflectAssetLoader`].
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct ReflectAssetLoaderSettings {
+    /// The format of the asset. If `None`, the format is inferred from the extension of the asset path.
+    pub format: Option<ReflectAssetFormat>,
+}
+
+/// Settings for a [`ReflectAssetSaver`].
+#[derive(Serialize, Deserialize, Debug, Default, Clone)]
+pub struct ReflectAssetSaverSettings {
+    /// The format the asset is saved in.
+    pub format: ReflectAssetFormat,
+}
+
+/// An error that occurs when loading an asset with a [`ReflectAssetLoader`].
+#[derive(Error, Debug)]
+pub enum ReflectAssetLoaderError {
+    /// An [IO](std::io) Error
+    #[error("Could not read the asset: {0}")]
+    Io(#[from] std::io::Error),
+    /// A [RON](ron) Error
+    #[error("Could not parse RON: {0}")]
+    Ron(#[from] ron::error::SpannedError),
+    /// A JSON Error
+    #[cfg(feature = "json")]
+    #[error("Could not parse JSON: {0}")]
+    Json(#[from] serde_json::Error),
+    /// The asset type is not registered in the [`TypeRegistry`].
+    #[error("The type `{0}` is not registered in the type registry")]
+    UnregisteredType(&'static str),
+    /// The deserialized value could not be converted into the asset type.
+    #[error("Could not convert the deserialized value into `{0}`")]
+    FromReflect(&'static str),
+    /// The format is not supported because the feature it requires is disabled.
+    #[error("The {0:?} format is not supported, enable the `json` feature to support it")]
+    UnsupportedFormat(ReflectAssetFormat),
+}
+
+/// An error that occurs when saving an asset with a [`ReflectAssetSaver`].
+#[derive(Error, Debug)]
+pub enum ReflectAssetSaverError {
+    /// An [IO](std::io) Error
+    #[error("Could not write the asset: {0}")]
+    Io(#[from] std::io::Error),
+    /// A [RON](ron) Error
+    #[error("Could not serialize RON: {0}")]
+    Ron(#[from] ron::Error),
+    /// A JSON Error
+    #[cfg(feature = "json")]
+    #[error("Could not serialize JSON: {0}")]
+    Json(#[from] serde_json::Error),
+    /// The format is not supported because the feature it requires is disabled.
+    #[error("The {0:?} format is not supported, enable the `json` feature to support it")]
+    UnsupportedFormat(ReflectAssetFormat),
+}
+
+/// A generic [`AssetLoader`] for any [`Asset`] that implements [`FromReflect`], which deserializes the asset from RON or JSON
+/// using the [`TypeRegistry`].
+///
+/// [`Handle`](crate::Handle) fields are written as asset paths and loaded as dependencies of the asset. This requires the
+/// [`ReflectHandle`] type data to be registered for the handle type, which [`AssetApp::register_asset_reflect`] does.
+///
+/// The easiest way to use this loader is [`AssetApp::register_reflect_asset_loader`]:
+///
+/// ```
+/// # use bevy_app::App;
+/// # use bevy_asset::{Asset, AssetApp, Handle};
+/// # use bevy_reflect::Reflect;
+/// #[derive(Asset, Reflect)]
+/// struct Item {
+///     name: String,
+///     value: u32,
+///     #[dependency]
+///     upgrade: Option<Handle<Item>>,
+/// }
+///
+/// fn build(app: &mut App) {
+///     // Loads `.item.ron` files such as `(name: "Sword", value: 10, upgrade: Some("great_sword.item.ron"))`.
+///     app.init_asset::<Item>()
+///         .register_reflect_asset_loader::<Item>(&["item.ron"]);
+/// }
+/// ```
+///
+/// [`AssetApp::register_asset_reflect`]: crate::AssetApp::register_asset_reflect
+/// [`AssetApp::register_reflect_asset_loader`]: crate::AssetApp::register_reflect_asset_loader
+pub struct ReflectAssetLoader<A> {
+    type_registry: TypeRegistryArc,
+    extensions: Vec<&'static str>,
+    marker: PhantomData<fn() -> A>,
+}
+
+impl<A> ReflectAssetLoader<A> {
+    /// Creates a new [`ReflectAssetLoader`] that uses `type_registry` and loads assets with the given `extensions`.
+    pub fn new(type_registry: TypeRegistryArc, extensions: &[&'static str]) -> Self {
+        Self {
+            type_registry,
+            extensions: extensions.to_vec(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<A: Asset + FromReflect + TypePath> AssetLoader for ReflectAssetLoader<A> {
+    type Asset = A;
+    type Settings = ReflectAssetLoaderSettings;
+    type Error = ReflectAssetLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        settings: &ReflectAssetLoaderSettings,
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<A, ReflectAssetLoaderError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let format = settings.format.unwrap_or_else(|| {
+            ReflectAssetFormat::from_extension(
+                load_context
+                    .path()
+                    .extension()
+                    .and_then(|extension| extension.to_str())
+                    .unwrap_or_default(),
+            )
+        });
+
+        let type_registry = self.type_registry.read();
+        let registration = type_registry
+            .get(core::any::TypeId::of::<A>())
+            .ok_or(ReflectAssetLoaderError::UnregisteredType(A::type_path()))?;
+        let mut processor = HandleDeserializerProcessor { load_context };
+        let seed =
+            TypedReflectDeserializer::with_processor(registration, &type_registry, &mut processor);
+        let mut value = match format {
+            ReflectAssetFormat::Ron => {
+                let mut deserializer = ron::de::Deserializer::from_bytes(&bytes)?;
+                let value = seed
+                    .deserialize(&mut deserializer)
+                    .map_err(|err| deserializer.span_error(err))?;
+                deserializer
+                    .end()
+                    .map_err(|err| deserializer.span_error(err))?;
+                value
+            }
+            #[cfg(feature = "json")]
+            ReflectAssetFormat::Json => {
+                let mut deserializer = serde_json::Deserializer::from_slice(&bytes);
+                let value = seed.deserialize(&mut deserializer)?;
+                deserializer.end()?;
+                value
+            }
+            #[cfg(not(feature = "json"))]
+            ReflectAssetFormat::Json => {
+                return Err(ReflectAssetLoaderError::UnsupportedFormat(format));
+            }
+        };
+        // Handles are redirected as they are loaded, but plain asset paths have to be redirected here.
+        load_context.redirect_paths(&mut *value);
+        A::from_reflect(&*value).ok_or(ReflectAssetLoaderError::FromReflect(A::type_path()))
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &self.extensions
+    }
+}
+
+/// A generic [`AssetSaver`] for any [`Asset`] that implements [`FromReflect`], which serializes the asset to RON or JSON
+/// using the [`TypeRegistry`]. The saved asset can be loaded with a [`ReflectAssetLoader`].
+///
+/// [`Handle`](crate::Handle) fields are written as the path of the asset they point to. Saving fails if a handle does
+/// not have a path.
+pub struct ReflectAssetSaver<A> {
+    type_registry: TypeRegistryArc,
+    marker: PhantomData<fn() -> A>,
+}
+
+impl<A> ReflectAssetSaver<A> {
+    /// Creates a new [`ReflectAssetSaver`] that uses `type_registry`.
+    pub fn new(type_registry: TypeRegistryArc) -> Self {
+        Self {
+            type_registry,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<A: Asset + FromReflect + TypePath> AssetSaver for ReflectAssetSaver<A> {
+    type Asset = A;
+    type Settings = ReflectAssetSaverSettings;
+    type OutputLoader = ReflectAssetLoader<A>;
+    type Error = ReflectAssetSaverError;
+
+    async fn save(
+        &self,
+        writer: &mut Writer,
+        asset: SavedAsset<'_, A>,
+        settings: &ReflectAssetSaverSettings,
+    ) -> Result<ReflectAssetLoaderSettings, ReflectAssetSaverError> {
+        let bytes =
+            serialize_reflect_asset(asset.get(), &self.type_registry.read(), settings.format)?;
+        writer.write_all(&bytes).await?;
+        Ok(ReflectAssetLoaderSettings {
+            format: Some(settings.format),
+        })
+    }
+}
+
+/// A [`Process`](crate::processor::Process) that loads an asset with a [`ReflectAssetLoader`] and saves it again with a
+/// [`ReflectAssetSaver`], in the format of its [`ReflectAssetSaverSettings`].
+///
+/// [`Handle`](crate::Handle) fields are loaded through the [`AssetServer`](crate::AssetServer) of the
+/// [`AssetProcessor`](crate::processor::AssetProcessor), which applies [`AssetRedirects`](crate::AssetRedirects), so the
+/// processed asset references the new paths of moved assets. References to assets that do not exist are reported as
+/// dangling references.
+///
+/// The easiest way to use this processor is [`AssetApp::register_reflect_asset_processor`](crate::AssetApp::register_reflect_asset_processor).
+pub type ReflectAssetProcessor<A> =
+    LoadTransformAndSave<ReflectAssetLoader<A>, IdentityAssetTransformer<A>, ReflectAssetSaver<A>>;
+
+/// Serializes `asset` in the given `format`, writing [`Handle`](crate::Handle) fields as asset paths.
+fn serialize_reflect_asset(
+    asset: &dyn PartialReflect,
+    type_registry: &TypeRegistry,
+    format: ReflectAssetFormat,
+) -> Result<Vec<u8>, ReflectAssetSaverError> {
+    let serializer =
+        TypedReflectSerializer::with_processor(asset, type_registry, &HandleSerializerProcessor);
+    Ok(match format {
+        ReflectAssetFormat::Ron => {
+            ron::ser::to_string_pretty(&serializer, ron::ser::PrettyConfig::default())?.into_bytes()
+        }
+        #[cfg(feature = "json")]
+        ReflectAssetFormat::Json => serde_json::to_vec_pretty(&serializer)?,
+        #[cfg(not(feature = "json"))]
+        ReflectAssetFormat::Json => {
+            return Err(ReflectAssetSaverError::UnsupportedFormat(format));
+        }
+    })
+}
+
+/// Deserializes handles with [`ReflectHandle`] type data from asset paths, loading them as dependencies.
+struct HandleDeserializerProcessor<'a, 'ctx> {
+    load_context: &'a mut LoadContext<'ctx>,
+}
+
+impl ReflectDeserializerProcessor for HandleDeserializerProcessor<'_, '_> {
+    fn try_deserialize<'de, D>(
+        &mut self,
+        registration: &TypeRegistration,
+        _registry: &TypeRegistry,
+        deserializer: D,
+    ) -> Result<Result<Box<dyn PartialReflect>, D>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let Some(reflect_handle) = registration.data::<ReflectHandle>() else {
+            return Ok(Err(deserializer));
+        };
+        let path = String::deserialize(deserializer)?;
+        let handle = self
+            .load_context
+            .loader()
+            .with_dynamic_type(reflect_handle.asset_type_id())
+            .load(path);
+        Ok(Ok(reflect_handle.typed(handle).into_partial_reflect()))
+    }
+}
+
+/// Serializes handles with [`ReflectHandle`] type data as their asset path.
+struct HandleSerializerProcessor;
+
+impl ReflectSerializerProcessor for HandleSerializerProcessor {
+    fn try_serialize<S>(
+        &self,
+        value: &dyn PartialReflect,
+        registry: &TypeRegistry,
+        serializer: S,
+    ) -> Result<Result<S::Ok, S>, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let Some(value) = value.try_as_reflect() else {
+            return Ok(Err(serializer));
+        };
+        let Some(reflect_handle) =
+            registry.get_type_data::<ReflectHandle>(value.as_any().type_id())
+        else {
+            return Ok(Err(serializer));
+        };
+        let path = reflect_handle
+            .downcast_handle_untyped(value.as_any())
+            .and_then(|handle| handle.path().map(ToString::to_string))
+            .ok_or_else(|| {
+                <S::Error as serde::ser::Error>::custom(
+                    "cannot save a handle that does not have an asset path",
+                )
+            })?;
+        Ok(Ok(serializer.serialize_str(&path)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ReflectAssetFormat, ReflectAssetSaver, ReflectAssetSaverSettings};
+    use crate::{
+        io::{
+            memory::{Dir, MemoryAssetReader},
+            AssetSource, AssetSourceId,
+        },
+        saver::{AssetSaver, SavedAsset},
+        tests::run_app_until,
+        Asset, AssetApp, AssetPlugin, AssetServer, Assets, ErasedLoadedAsset, Handle, LoadedAsset,
+        RecursiveDependencyLoadState,
+    };
+    use alloc::{boxed::Box, string::String, vec::Vec};
+    use bevy_app::{App, TaskPoolPlugin};
+    use bevy_ecs::reflect::AppTypeRegistry;
+    use bevy_reflect::Reflect;
+    use std::path::Path;
+
+    #[derive(Asset, Reflect, Debug, Clone)]
+    struct Item {
+        name: String,
+        value: u32,
+        #[dependency]
+        upgrade: Option<Handle<Item>>,
+    }
+
+    fn setup(dir: &Dir) -> App {
+        dir.insert_asset_text(
+            Path::new("sword.item.ron"),
+            r#"(name: "Sword", value: 10, upgrade: Some("great_sword.item.ron"))"#,
+        );
+        dir.insert_asset_text(
+            Path::new("great_sword.item.ron"),
+            r#"(name: "Great Sword", value: 25, upgrade: None)"#,
+        );
+
+        let mut app = App::new();
+        let dir = dir.clone();
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSource::build()
+                .with_reader(move || Box::new(MemoryAssetReader { root: dir.clone() })),
+        )
+        .add_plugins((TaskPoolPlugin::default(), AssetPlugin::default()))
+        .init_asset::<Item>()
+        .register_reflect_asset_loader::<Item>(&["item.ron", "item.json"]);
+        app
+    }
+
+    fn load(app: &mut App, path: &'static str) -> Handle<Item> {
+        let handle: Handle<Item> = app.world().resource::<AssetServer>().load(path);
+        run_app_until(app, |world| {
+            let server = world.resource::<AssetServer>();
+            match server.get_recursive_dependency_load_state(&handle)? {
+                RecursiveDependencyLoadState::Loaded => Some(()),
+                RecursiveDependencyLoadState::Failed(err) => panic!("{err}"),
+                _ => None,
+            }
+        });
+        handle
+    }
+
+    /// Saves `item` with a [`ReflectAssetSaver`] in the given `format`.
+    fn save(app: &App, item: &Item, format: ReflectAssetFormat) -> String {
+        let saver =
+            ReflectAssetSaver::<Item>::new(app.world().resource::<AppTypeRegistry>().0.clone());
+        let loaded = ErasedLoadedAsset::from(LoadedAsset::from(item.clone()));
+        let mut bytes = Vec::new();
+        let settings = bevy_tasks::block_on(saver.save(
+            &mut bytes,
+            SavedAsset::from_loaded(&loaded).unwrap(),
+            &ReflectAssetSaverSettings { format },
+        ))
+        .unwrap();
+        assert_eq!(settings.format, Some(format));
+        String::from_utf8(bytes).unwrap()
+    }
+
+    #[test]
+    fn load_and_save_reflect_asset() {
+        let dir = Dir::default();
+        let mut app = setup(&dir);
+        let handle = load(&mut app, "sword.item.ron");
+
+        let items = app.world().resource::<Assets<Item>>();
+        let sword = items.get(&handle).unwrap();
+        assert_eq!(sword.name, "Sword");
+        assert_eq!(sword.value, 10);
+        let upgrade = items.get(sword.upgrade.as_ref().unwrap()).unwrap();
+        assert_eq!(upgrade.name, "Great Sword");
+        assert!(upgrade.upgrade.is_none());
+
+        let saved = save(&app, sword, ReflectAssetFormat::Ron);
+        assert!(saved.contains(r#"upgrade: Some("great_sword.item.ron")"#));
+    }
+
+    #[test]
+    fn saved_references_are_redirected() {
+        let dir = Dir::default();
+        dir.insert_asset_text(
+            Path::new("dagger.item.ron"),
+            r#"(name: "Dagger", value: 5, upgrade: Some("old/great_sword.item.ron"))"#,
+        );
+        let mut app = setup(&dir);
+        app.world()
+            .resource::<AssetServer>()
+            .add_redirect("old/great_sword.item.ron", "great_sword.item.ron");
+
+        // This is what a `ReflectAssetProcessor` does with a source asset.
+        let handle = load(&mut app, "dagger.item.ron");
+        let dagger = app.world().resource::<Assets<Item>>().get(&handle).unwrap();
+        let saved = save(&app, dagger, ReflectAssetFormat::Ron);
+        assert!(saved.contains(r#"upgrade: Some("great_sword.item.ron")"#));
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json_round_trip_through_saver() {
+        let dir = Dir::default();
+        let mut app = setup(&dir);
+        let handle = load(&mut app, "sword.item.ron");
+        let sword = app.world().resource::<Assets<Item>>().get(&handle).unwrap();
+        let saved = save(&app, sword, ReflectAssetFormat::Json);
+        dir.insert_asset_text(Path::new("sword_copy.item.json"), &saved);
+
+        let copy = load(&mut app, "sword_copy.item.json");
+        let items = app.world().resource::<Assets<Item>>();
+        let copy = items.get(&copy).unwrap();
+        assert_eq!(copy.name, "Sword");
+        assert_eq!(copy.value, 10);
+        let upgrade = items.get(copy.upgrade.as_ref().unwrap()).unwrap();
+        assert_eq!(upgrade.name, "Great Sword");
+    }
+
+    #[cfg(not(feature = "json"))]
+    #[test]
+    fn json_requires_feature() {
+        use super::ReflectAssetSaverError;
+
+        let dir = Dir::default();
+        let mut app = setup(&dir);
+        let handle = load(&mut app, "sword.item.ron");
+        let sword = app.world().resource::<Assets<Item>>().get(&handle).unwrap();
+        let saver =
+            ReflectAssetSaver::<Item>::new(app.world().resource::<AppTypeRegistry>().0.clone());
+        let loaded = ErasedLoadedAsset::from(LoadedAsset::from(sword.clone()));
+        let result = bevy_tasks::block_on(saver.save(
+            &mut Vec::new(),
+            SavedAsset::from_loaded(&loaded).unwrap(),
+            &ReflectAssetSaverSettings {
+                format: ReflectAssetFormat::Json,
+            },
+        ));
+        assert!(matches!(
+            result,
+            Err(ReflectAssetSaverError::UnsupportedFormat(
+                ReflectAssetFormat::Json
+            ))
+        ));
+    }
+}
@@ -1,8 +1,8 @@
 use crate::{
     meta::{AssetHash, MetaTransform},
-    Asset, AssetHandleProvider, AssetLoadError, AssetPath, DependencyLoadState, ErasedLoadedAsset,
-    Handle, InternalAssetEvent, LoadState, RecursiveDependencyLoadState, StrongHandle,
-    UntypedAssetId, UntypedHandle,
+    Asset, AssetHandleProvider, AssetLoadError, AssetPath, AssetRedirects, DependencyLoadState,
+    ErasedLoadedAsset, Handle, InternalAssetEvent, LoadState, RecursiveDependencyLoadState,
+    StrongHandle, UntypedAssetId, UntypedHandle,
 };
 use alloc::{
     borrow::ToOwned,
@@ -720,6 +720,41 @@ impl AssetInfos {
         true
     }
 
+    /// Moves the handles of paths that are redirected by `redirects` to the paths they are redirected to. This is used
+    /// for handles that were created before the redirects were known, so that later loads of either path return the
+    /// same handle. Handles are not moved if the redirected path already has a handle of the same type.
+    pub(crate) fn apply_redirects(&mut self, redirects: &AssetRedirects) {
+        let redirected = self
+            .path_to_id
+            .keys()
+            .filter_map(|path| Some((path.clone(), redirects.get(path)?)))
+            .collect::<Vec<_>>();
+        for (from, to) in redirected {
+            let Some(ids) = self.path_to_id.remove(&from) else {
+                continue;
+            };
+            let to_ids = self.path_to_id.entry(to.clone()).or_default();
+            let mut remaining = TypeIdMap::default();
+            for (type_id, id) in ids {
+                match to_ids.entry(type_id) {
+                    Entry::Vacant(entry) => {
+                        entry.insert(id);
+                        if let Some(info) = self.infos.get_mut(&id) {
+                            info.path = Some(to.clone());
+                        }
+                    }
+                    Entry::Occupied(_) => {
+                        warn!("{from} was loaded before it was redirected to {to}, which was also loaded. Loads of {from} will return a different handle than loads of {to}");
+                        remaining.insert(type_id, id);
+                    }
+                }
+            }
+            if !remaining.is_empty() {
+                self.path_to_id.insert(from, remaining);
+            }
+        }
+    }
+
     /// Consumes all current handle drop events. This will update information in [`AssetInfos`], but it
     /// will not affect [`Assets`] storages. For normal use cases, prefer `Assets::track_assets()`
     /// This should only be called if `Assets` storage isn't being used (such as in [`AssetProcessor`](crate::processor::AssetProcessor))
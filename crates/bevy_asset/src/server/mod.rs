@@ -14,6 +14,7 @@ use crate::{
         MetaTransform, Settings,
     },
     path::AssetPath,
+    redirect::{AssetRedirects, AssetRedirectsError},
     Asset, AssetEvent, AssetHandleProvider, AssetId, AssetLoadFailedEvent, AssetMetaCheck, Assets,
     DeserializeMetaError, ErasedLoadedAsset, Handle, LoadedUntypedAsset, UnapprovedPathMode,
     UntypedAssetId, UntypedAssetLoadFailedEvent, UntypedHandle,
@@ -27,6 +28,7 @@ use alloc::{
 use atomicow::CowArc;
 use bevy_ecs::prelude::*;
 use bevy_platform::collections::HashSet;
+use bevy_reflect::PartialReflect;
 use bevy_tasks::IoTaskPool;
 use core::{any::TypeId, future::Future, panic::AssertUnwindSafe, task::Poll};
 use crossbeam_channel::{Receiver, Sender};
@@ -69,6 +71,10 @@ pub(crate) struct AssetServerData {
     mode: AssetServerMode,
     meta_check: AssetMetaCheck,
     unapproved_path_mode: UnapprovedPathMode,
+    redirects: RwLock<AssetRedirects>,
+    /// One gate per [`AssetRedirects`] file that is being read, each held for writing until the file has been read,
+    /// so that loads can wait for the redirects.
+    redirects_gates: RwLock<Vec<Arc<async_lock::RwLock<()>>>>,
 }
 
 /// The "asset mode" the server is currently in.
@@ -139,6 +145,8 @@ impl AssetServer {
                 loaders,
                 infos: RwLock::new(infos),
                 unapproved_path_mode,
+                redirects: Default::default(),
+                redirects_gates: Default::default(),
             }),
         }
     }
@@ -461,7 +469,7 @@ impl AssetServer {
         guard: G,
         override_unapproved: bool,
     ) -> Handle<A> {
-        let path = path.into().into_owned();
+        let path = self.redirect(path.into()).into_owned();
 
         if path.is_unapproved() {
             match (&self.data.unapproved_path_mode, override_unapproved) {
@@ -494,7 +502,7 @@ impl AssetServer {
         meta_transform: Option<MetaTransform>,
         guard: G,
     ) -> UntypedHandle {
-        let path = path.into().into_owned();
+        let path = self.redirect(path.into()).into_owned();
         let mut infos = self.data.infos.write();
         let (handle, should_load) = infos.get_or_create_path_handle_erased(
             path.clone(),
@@ -561,7 +569,7 @@ impl AssetServer {
         path: impl Into<AssetPath<'a>>,
         meta_transform: Option<MetaTransform>,
     ) -> Handle<LoadedUntypedAsset> {
-        let path = path.into().into_owned();
+        let path = self.redirect(path.into()).into_owned();
         let untyped_source = AssetSourceId::Name(match path.source() {
             AssetSourceId::Default => CowArc::Static(UNTYPED_SOURCE_SUFFIX),
             AssetSourceId::Name(source) => {
@@ -654,7 +662,8 @@ impl AssetServer {
     ) -> Result<UntypedHandle, AssetLoadError> {
         let asset_type_id = input_handle.as_ref().map(UntypedHandle::type_id);
 
-        let path = path.into_owned();
+        self.wait_for_redirects().await;
+        let path = self.redirect(path).into_owned();
         let path_clone = path.clone();
         let (mut meta, loader, mut reader) = self
             .get_meta_loader_and_reader(&path_clone, asset_type_id)
@@ -1204,7 +1213,7 @@ impl AssetServer {
     /// [`get_path_ids`][Self::get_path_ids] for all handles.
     pub fn get_path_id<'a>(&self, path: impl Into<AssetPath<'a>>) -> Option<UntypedAssetId> {
         let infos = self.data.infos.read();
-        let path = path.into();
+        let path = self.redirect(path.into());
         let mut ids = infos.get_path_ids(&path);
         ids.next()
     }
@@ -1214,7 +1223,7 @@ impl AssetServer {
     /// Multiple IDs will be returned in the event that a single path is used by multiple [`AssetLoader`]'s.
     pub fn get_path_ids<'a>(&self, path: impl Into<AssetPath<'a>>) -> Vec<UntypedAssetId> {
         let infos = self.data.infos.read();
-        let path = path.into();
+        let path = self.redirect(path.into());
         infos.get_path_ids(&path).collect()
     }
 
@@ -1226,7 +1235,7 @@ impl AssetServer {
     /// [`get_handles_untyped`][Self::get_handles_untyped] for all handles.
     pub fn get_handle_untyped<'a>(&self, path: impl Into<AssetPath<'a>>) -> Option<UntypedHandle> {
         let infos = self.data.infos.read();
-        let path = path.into();
+        let path = self.redirect(path.into());
         let mut handles = infos.get_path_handles(&path);
         handles.next()
     }
@@ -1236,7 +1245,7 @@ impl AssetServer {
     /// Multiple handles will be returned in the event that a single path is used by multiple [`AssetLoader`]'s.
     pub fn get_handles_untyped<'a>(&self, path: impl Into<AssetPath<'a>>) -> Vec<UntypedHandle> {
         let infos = self.data.infos.read();
-        let path = path.into();
+        let path = self.redirect(path.into());
         infos.get_path_handles(&path).collect()
     }
 
@@ -1247,8 +1256,8 @@ impl AssetServer {
         path: &AssetPath,
         type_id: TypeId,
     ) -> Option<UntypedHandle> {
+        let path = self.redirect(path.clone());
         let infos = self.data.infos.read();
-        let path = path.into();
         infos.get_path_and_type_id_handle(&path, type_id)
     }
 
@@ -1264,6 +1273,113 @@ impl AssetServer {
         self.data.mode
     }
 
+    /// Redirects loads of `from` to `to`, replacing any existing redirect for `from`. See [`AssetRedirects`] for details.
+    ///
+    /// Redirects only apply to loads started after they are added.
+    pub fn add_redirect<'a, 'b>(
+        &self,
+        from: impl Into<AssetPath<'a>>,
+        to: impl Into<AssetPath<'b>>,
+    ) {
+        self.data.redirects.write().insert(from, to);
+    }
+
+    /// Adds all of the given `redirects`. See [`AssetServer::add_redirect`].
+    pub fn add_redirects(&self, redirects: AssetRedirects) {
+        self.data.redirects.write().extend(redirects);
+    }
+
+    /// Returns the path that `path` is redirected to, if it is redirected. See [`AssetRedirects::get`].
+    pub fn get_redirect<'a>(&self, path: impl Into<AssetPath<'a>>) -> Option<AssetPath<'static>> {
+        self.data.redirects.read().get(&path.into())
+    }
+
+    /// Redirects every [`AssetPath`] stored in `value`. See [`AssetRedirects::redirect_paths`].
+    pub(crate) fn redirect_paths(&self, value: &mut dyn PartialReflect) {
+        self.data.redirects.read().redirect_paths(value);
+    }
+
+    /// Reads the [`AssetRedirects`] file at `path` from its [`AssetSource`] in the background and adds its redirects.
+    /// Asset loads wait until the file has been read, so that they are redirected as well. Handles returned by loads
+    /// started in the meantime are moved to the redirected paths once the file has been read, so that later loads of
+    /// either path return the same handle.
+    ///
+    /// This is called automatically for [`AssetPlugin::redirects_file_path`](crate::AssetPlugin::redirects_file_path).
+    pub fn load_redirects<'a>(&self, path: impl Into<AssetPath<'a>>) {
+        self.load_shared_redirects(path.into().into_owned(), Vec::new());
+    }
+
+    /// Like [`AssetServer::load_redirects`], but also adds the redirects to the `others` servers, so that the file is only
+    /// read once. Loads of every server wait until the file has been read.
+    pub(crate) fn load_shared_redirects(&self, path: AssetPath<'static>, others: Vec<AssetServer>) {
+        let mut servers = others;
+        servers.insert(0, self.clone());
+        // Acquire the gate before spawning the task, so that loads started in the meantime wait for the redirects.
+        let gate = Arc::new(async_lock::RwLock::new(()));
+        let guard = gate
+            .try_write_arc()
+            .expect("a new lock can always be acquired");
+        for server in &servers {
+            server.data.redirects_gates.write().push(gate.clone());
+        }
+        IoTaskPool::get()
+            .spawn(async move {
+                match servers[0].read_redirects(&path).await {
+                    Ok(redirects) => {
+                        info!("Loaded {} asset redirect(s) from {path}", redirects.len());
+                        for server in &servers {
+                            server.add_redirects(redirects.clone());
+                            // Clone the redirects to avoid holding both locks at once.
+                            let redirects = server.data.redirects.read().clone();
+                            server.data.infos.write().apply_redirects(&redirects);
+                        }
+                    }
+                    Err(err) => error!("Failed to load asset redirects from {path}: {err}"),
+                }
+                for server in &servers {
+                    server
+                        .data
+                        .redirects_gates
+                        .write()
+                        .retain(|pending| !Arc::ptr_eq(pending, &gate));
+                }
+                drop(guard);
+            })
+            .detach();
+    }
+
+    async fn read_redirects(
+        &self,
+        path: &AssetPath<'static>,
+    ) -> Result<AssetRedirects, AssetRedirectsError> {
+        let source = self.get_source(path.source())?;
+        let reader = match self.data.mode {
+            AssetServerMode::Unprocessed => source.reader(),
+            AssetServerMode::Processed => source.processed_reader()?,
+        };
+        let mut bytes = Vec::new();
+        reader
+            .read(path.path())
+            .await?
+            .read_to_end(&mut bytes)
+            .await
+            .map_err(|err| AssetReaderError::Io(err.into()))?;
+        AssetRedirects::from_bytes(path, &bytes)
+    }
+
+    /// Waits until every [`AssetRedirects`] file started with [`AssetServer::load_redirects`] has been read.
+    pub(crate) async fn wait_for_redirects(&self) {
+        let gates = self.data.redirects_gates.read().clone();
+        for gate in gates {
+            let _gate = gate.read().await;
+        }
+    }
+
+    /// Returns the path that `path` is redirected to, or `path` itself if it is not redirected.
+    fn redirect<'a>(&self, path: AssetPath<'a>) -> AssetPath<'a> {
+        self.data.redirects.read().get(&path).unwrap_or(path)
+    }
+
     /// Pre-register a loader that will later be added.
     ///
     /// Assets loaded with matching extensions will be blocked until the
@@ -1278,13 +1394,10 @@ impl AssetServer {
         path: impl Into<AssetPath<'a>>,
         meta_transform: Option<MetaTransform>,
     ) -> Handle<A> {
+        let path = self.redirect(path.into()).into_owned();
         let mut infos = self.data.infos.write();
         infos
-            .get_or_create_path_handle::<A>(
-                path.into().into_owned(),
-                HandleLoadingMode::NotLoading,
-                meta_transform,
-            )
+            .get_or_create_path_handle::<A>(path, HandleLoadingMode::NotLoading, meta_transform)
             .0
     }
 
@@ -1298,10 +1411,11 @@ impl AssetServer {
         type_id: TypeId,
         meta_transform: Option<MetaTransform>,
     ) -> UntypedHandle {
+        let path = self.redirect(path.into()).into_owned();
         let mut infos = self.data.infos.write();
         infos
             .get_or_create_path_handle_erased(
-                path.into().into_owned(),
+                path,
                 type_id,
                 None,
                 HandleLoadingMode::NotLoading,
@@ -0,0 +1,588 @@
+//! The commands available in every console.
+
+use super::{
+    parse::{format_value, parse_entity, parse_value, resolve_type, split_path},
+    Console, ConsoleArg, ConsoleCommand, ConsoleCommands, ConsoleError,
+};
+use bevy_ecs::{
+    archetype::ArchetypeEntity,
+    component::ComponentInfo,
+    entity::Entity,
+    reflect::{AppTypeRegistry, ReflectComponent, ReflectFromWorld, ReflectResource},
+    world::World,
+};
+use bevy_reflect::{
+    prelude::ReflectDefault, PartialReflect, ReflectPath, TypeRegistration, TypeRegistry,
+};
+
+/// Adds the built-in commands to the given commands.
+pub(super) fn add_builtin_commands(commands: &mut ConsoleCommands) {
+    commands
+        .add(
+            "help",
+            ConsoleCommand::new(help, "help [command]", "Lists the commands or describes one")
+                .with_args([ConsoleArg::Command]),
+        )
+        .add(
+            "get",
+            ConsoleCommand::new(get, GET_USAGE, "Prints a resource or component, or one of its fields")
+                .with_args([ConsoleArg::Resource, ConsoleArg::Component]),
+        )
+        .add(
+            "set",
+            ConsoleCommand::new(set, SET_USAGE, "Sets a resource or component, or one of its fields, to a RON value")
+                .with_args([ConsoleArg::Resource, ConsoleArg::Component]),
+        )
+        .add(
+            "spawn",
+            ConsoleCommand::new(spawn, SPAWN_USAGE, "Spawns an entity with the given components, using their default value if no RON value is given")
+                .with_args([ConsoleArg::Components]),
+        )
+        .add(
+            "despawn",
+            ConsoleCommand::new(despawn, DESPAWN_USAGE, "Despawns an entity"),
+        )
+        .add(
+            "insert",
+            ConsoleCommand::new(insert, INSERT_USAGE, "Inserts a component into an entity, using its default value if no RON value is given")
+                .with_args([ConsoleArg::Value, ConsoleArg::Component]),
+        )
+        .add(
+            "remove",
+            ConsoleCommand::new(remove, REMOVE_USAGE, "Removes a component from an entity")
+                .with_args([ConsoleArg::Value, ConsoleArg::Component]),
+        )
+        .add(
+            "inspect",
+            ConsoleCommand::new(inspect, INSPECT_USAGE, "Prints the reflected components of an entity"),
+        )
+        .add(
+            "query",
+            ConsoleCommand::new(query, QUERY_USAGE, "Lists the entities with all the given components")
+                .with_args([ConsoleArg::Components]),
+        )
+        .add(
+            "list",
+            ConsoleCommand::new(list, LIST_USAGE, "Lists the registered resources, components, functions, systems or commands")
+                .with_args([ConsoleArg::Keyword(&["resources", "components", "functions", "systems", "commands"])]),
+        )
+        .add(
+            "call",
+            ConsoleCommand::new(call, CALL_USAGE, "Calls a function from the `AppFunctionRegistry` with RON arguments")
+                .with_args([ConsoleArg::Function]),
+        )
+        .add(
+            "run",
+            ConsoleCommand::new(run, RUN_USAGE, "Runs a system registered with `add_console_system`")
+                .with_args([ConsoleArg::System]),
+        )
+        .add(
+            "history",
+            ConsoleCommand::new(history, "history", "Lists the previous commands"),
+        )
+        .add(
+            "clear",
+            ConsoleCommand::new(clear, "clear", "Clears the console output"),
+        );
+}
+
+const GET_USAGE: &str = "get <resource>[.path] | get <entity> <component>[.path]";
+const SET_USAGE: &str =
+    "set <resource>[.path] [=] <value> | set <entity> <component>[.path] [=] <value>";
+const SPAWN_USAGE: &str = "spawn [<component> [value]]...";
+const DESPAWN_USAGE: &str = "despawn <entity>";
+const INSERT_USAGE: &str = "insert <entity> <component> [value]";
+const REMOVE_USAGE: &str = "remove <entity> <component>";
+const INSPECT_USAGE: &str = "inspect <entity>";
+const QUERY_USAGE: &str = "query <component>...";
+const LIST_USAGE: &str = "list resources|components|functions|systems|commands";
+const CALL_USAGE: &str = "call <function> [argument]...";
+const RUN_USAGE: &str = "run <system>";
+
+fn usage(usage: &str) -> ConsoleError {
+    ConsoleError::Usage(usage.to_string())
+}
+
+fn type_registry(world: &World) -> AppTypeRegistry {
+    world
+        .get_resource::<AppTypeRegistry>()
+        .cloned()
+        .unwrap_or_default()
+}
+
+fn short_path(registration: &TypeRegistration) -> String {
+    registration
+        .type_info()
+        .type_path_table()
+        .short_path()
+        .to_string()
+}
+
+/// A reflected resource or component of an entity, with a path to one of its fields.
+struct Target<'a> {
+    registration: &'a TypeRegistration,
+    entity: Option<Entity>,
+    path: &'a str,
+}
+
+impl<'a> Target<'a> {
+    /// Parses a target written as `<resource>[.path]` or `<entity> <component>[.path]`,
+    /// returning the remaining arguments.
+    fn parse(
+        world: &World,
+        registry: &'a TypeRegistry,
+        args: &'a [String],
+        usage_text: &str,
+    ) -> Result<(Self, &'a [String]), ConsoleError> {
+        let (entity, args) = match args.first() {
+            Some(first) if first.starts_with(|c: char| c.is_ascii_digit()) => {
+                (Some(parse_entity(world, first)?), &args[1..])
+            }
+            _ => (None, args),
+        };
+        let (word, rest) = args.split_first().ok_or_else(|| usage(usage_text))?;
+        let (type_name, path) = split_path(word);
+        let registration = resolve_type(registry, type_name)?;
+
+        match entity {
+            Some(_) if registration.data::<ReflectComponent>().is_none() => {
+                Err(ConsoleError::NotComponent(short_path(registration)))
+            }
+            None if registration.data::<ReflectResource>().is_none() => {
+                Err(ConsoleError::NotResource(short_path(registration)))
+            }
+            _ => Ok((
+                Self {
+                    registration,
+                    entity,
+                    path,
+                },
+                rest,
+            )),
+        }
+    }
+
+    fn invalid_path(&self, message: impl ToString) -> ConsoleError {
+        ConsoleError::InvalidPath {
+            path: self.path.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    fn missing(&self) -> ConsoleError {
+        match self.entity {
+            Some(entity) => ConsoleError::MissingComponent {
+                entity,
+                component: short_path(self.registration),
+            },
+            None => ConsoleError::MissingResource(short_path(self.registration)),
+        }
+    }
+
+    /// Returns the field of the target.
+    fn read<'w>(&self, world: &'w World) -> Result<&'w dyn PartialReflect, ConsoleError> {
+        let value = match self.entity {
+            Some(entity) => self
+                .registration
+                .data::<ReflectComponent>()
+                .and_then(|reflect_component| reflect_component.reflect(world.entity(entity))),
+            None => self
+                .registration
+                .data::<ReflectResource>()
+                .and_then(|reflect_resource| reflect_resource.reflect(world).ok()),
+        }
+        .ok_or_else(|| self.missing())?
+        .as_partial_reflect();
+
+        match self.path {
+            "" => Ok(value),
+            path => path
+                .reflect_element(value)
+                .map_err(|error| self.invalid_path(error)),
+        }
+    }
+
+    /// Calls `f` with the field of the target, triggering change detection.
+    fn modify<T>(
+        &self,
+        world: &mut World,
+        f: impl FnOnce(&mut dyn PartialReflect) -> Result<T, ConsoleError>,
+    ) -> Result<T, ConsoleError> {
+        let value = match self.entity {
+            Some(entity) => {
+                let mutable = world
+                    .components()
+                    .get_id(self.registration.type_id())
+                    .and_then(|id| world.components().get_info(id))
+                    .is_none_or(ComponentInfo::mutable);
+                if !mutable {
+                    return Err(ConsoleError::ImmutableComponent(short_path(
+                        self.registration,
+                    )));
+                }
+                self.registration
+                    .data::<ReflectComponent>()
+                    .and_then(|reflect_component| {
+                        reflect_component.reflect_mut(world.entity_mut(entity))
+                    })
+            }
+            None => self
+                .registration
+                .data::<ReflectResource>()
+                .and_then(|reflect_resource| reflect_resource.reflect_mut(world).ok()),
+        };
+        let mut value = value.ok_or_else(|| self.missing())?;
+        let value = value.as_partial_reflect_mut();
+
+        match self.path {
+            "" => f(value),
+            path => f(path
+                .reflect_element_mut(value)
+                .map_err(|error| self.invalid_path(error))?),
+        }
+    }
+}
+
+/// Returns the default value of a type, using [`ReflectDefault`] or [`ReflectFromWorld`].
+fn default_value(
+    world: &mut World,
+    registration: &TypeRegistration,
+) -> Result<Box<dyn PartialReflect>, ConsoleError> {
+    if let Some(reflect_default) = registration.data::<ReflectDefault>() {
+        Ok(reflect_default.default().into_partial_reflect())
+    } else if let Some(reflect_from_world) = registration.data::<ReflectFromWorld>() {
+        Ok(reflect_from_world.from_world(world).into_partial_reflect())
+    } else {
+        Err(ConsoleError::NoDefault(short_path(registration)))
+    }
+}
+
+/// Parses a reflected component.
+fn resolve_component<'a>(
+    registry: &'a TypeRegistry,
+    name: &str,
+) -> Result<(&'a TypeRegistration, &'a ReflectComponent), ConsoleError> {
+    let registration = resolve_type(registry, name)?;
+    let reflect_component = registration
+        .data::<ReflectComponent>()
+        .ok_or_else(|| ConsoleError::NotComponent(short_path(registration)))?;
+    Ok((registration, reflect_component))
+}
+
+fn help(world: &mut World, args: &[String]) -> Result<String, ConsoleError> {
+    let commands = world.resource::<ConsoleCommands>();
+    match args {
+        [] => Ok(commands
+            .iter()
+            .map(|(name, command)| format!("{name} - {}", command.description()))
+            .collect::<Vec<_>>()
+            .join("\n")),
+        [name] => {
+            let command = commands
+                .get(name)
+                .ok_or_else(|| ConsoleError::UnknownCommand(name.clone()))?;
+            Ok(format!(
+                "usage: {}\n{}",
+                command.usage(),
+                command.description()
+            ))
+        }
+        _ => Err(usage("help [command]")),
+    }
+}
+
+fn get(world: &mut World, args: &[String]) -> Result<String, ConsoleError> {
+    let registry = type_registry(world);
+    let registry = registry.read();
+    let (target, []) = Target::parse(world, &registry, args, GET_USAGE)? else {
+        return Err(usage(GET_USAGE));
+    };
+    Ok(format_value(target.read(world)?, &registry))
+}
+
+fn set(world: &mut World, args: &[String]) -> Result<String, ConsoleError> {
+    let registry = type_registry(world);
+    let registry = registry.read();
+    let (target, rest) = Target::parse(world, &registry, args, SET_USAGE)?;
+    let text = match rest {
+        [text] => text,
+        [equals, text] if equals == "=" => text,
+        _ => return Err(usage(SET_USAGE)),
+    };
+
+    target.modify(world, |field| {
+        let type_info = field
+            .get_represented_type_info()
+            .ok_or_else(|| ConsoleError::UnknownType(field.reflect_type_path().to_string()))?;
+        let registration = registry
+            .get(type_info.type_id())
+            .ok_or_else(|| ConsoleError::UnknownType(type_info.type_path().to_string()))?;
+        let value = parse_value(text, registration, &registry)?;
+        field
+            .try_apply(value.as_ref())
+            .map_err(|error| ConsoleError::InvalidValue {
+                value: text.clone(),
+                message: error.to_string(),
+            })?;
+        Ok(format_value(field, &registry))
+    })
+}
+
+fn spawn(world: &mut World, args: &[String]) -> Result<String, ConsoleError> {
+    let registry = type_registry(world);
+    let registry = registry.read();
+
+    // Words that aren't component names are the values of the preceding component
+    let mut components = Vec::<(&TypeRegistration, &ReflectComponent, Option<&str>)>::new();
+    for word in args {
+        match resolve_component(&registry, word) {
+            Ok((registration, reflect_component)) => {
+                components.push((registration, reflect_component, None));
+            }
+            Err(error) => match components.last_mut() {
+                Some((_, _, value @ None)) => *value = Some(word.as_str()),
+                _ => return Err(error),
+            },
+        }
+    }
+
+    let mut values = Vec::with_capacity(components.len());
+    for (registration, reflect_component, text) in components {
+        let value = match text {
+            Some(text) => parse_value(text, registration, &registry)?,
+            None => default_value(world, registration)?,
+        };
+        values.push((reflect_component, value));
+    }
+
+    let mut entity = world.spawn_empty();
+    for (reflect_component, value) in values {
+        reflect_component.insert(&mut entity, value.as_ref(), &registry);
+    }
+    Ok(entity.id().to_string())
+}
+
+fn despawn(world: &mut World, args: &[String]) -> Result<String, ConsoleError> {
+    let [entity] = args else {
+        return Err(usage(DESPAWN_USAGE));
+    };
+    let entity = parse_entity(world, entity)?;
+    world.despawn(entity);
+    Ok(String::new())
+}
+
+fn insert(world: &mut World, args: &[String]) -> Result<String, ConsoleError> {
+    let (entity, component, text) = match args {
+        [entity, component] => (entity, component, None),
+        [entity, component, text] => (entity, component, Some(text)),
+        _ => return Err(usage(INSERT_USAGE)),
+    };
+    let entity = parse_entity(world, entity)?;
+    let registry = type_registry(world);
+    let registry = registry.read();
+    let (registration, reflect_component) = resolve_component(&registry, component)?;
+
+    let value = match text {
+        Some(text) => parse_value(text, registration, &registry)?,
+        None => default_value(world, registration)?,
+    };
+    reflect_component.insert(&mut world.entity_mut(entity), value.as_ref(), &registry);
+    Ok(String::new())
+}
+
+fn remove(world: &mut World, args: &[String]) -> Result<String, ConsoleError> {
+    let [entity, component] = args else {
+        return Err(usage(REMOVE_USAGE));
+    };
+    let entity = parse_entity(world, entity)?;
+    let registry = type_registry(world);
+    let registry = registry.read();
+    let (_, reflect_component) = resolve_component(&registry, component)?;
+    reflect_component.remove(&mut world.entity_mut(entity));
+    Ok(String::new())
+}
+
+fn inspect(world: &mut World, args: &[String]) -> Result<String, ConsoleError> {
+    let [entity] = args else {
+        return Err(usage(INSPECT_USAGE));
+    };
+    let entity = world.entity(parse_entity(world, entity)?);
+    let registry = type_registry(world);
+    let registry = registry.read();
+
+    let mut lines = entity
+        .archetype()
+        .components()
+        .filter_map(|component_id| {
+            let info = world.components().get_info(component_id)?;
+            let Some(registration) = info.type_id().and_then(|type_id| registry.get(type_id))
+            else {
+                return Some(format!("{}: <not reflected>", info.name()));
+            };
+            let value = registration.data::<ReflectComponent>()?.reflect(entity)?;
+            Some(format!(
+                "{}: {}",
+                short_path(registration),
+                format_value(value.as_partial_reflect(), &registry)
+            ))
+        })
+        .collect::<Vec<_>>();
+    lines.sort();
+    Ok(lines.join("\n"))
+}
+
+fn query(world: &mut World, args: &[String]) -> Result<String, ConsoleError> {
+    if args.is_empty() {
+        return Err(usage(QUERY_USAGE));
+    }
+    let registry = type_registry(world);
+    let registry = registry.read();
+
+    let mut component_ids = Vec::with_capacity(args.len());
+    for component in args {
+        let (registration, _) = resolve_component(&registry, component)?;
+        match world.components().get_id(registration.type_id()) {
+            Some(component_id) => component_ids.push(component_id),
+            // The component has never been added to an entity
+            None => return Ok(String::new()),
+        }
+    }
+
+    let mut entities = world
+        .archetypes()
+        .iter()
+        .filter(|archetype| component_ids.iter().all(|id| archetype.contains(*id)))
+        .flat_map(|archetype| archetype.entities().iter().map(ArchetypeEntity::id))
+        .collect::<Vec<_>>();
+    entities.sort();
+    Ok(entities
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(" "))
+}
+
+fn list(world: &mut World, args: &[String]) -> Result<String, ConsoleError> {
+    let [kind] = args else {
+        return Err(usage(LIST_USAGE));
+    };
+    let registry = type_registry(world);
+    let registry = registry.read();
+    let names = match kind.as_str() {
+        "resources" => super::type_names::<ReflectResource>(&registry),
+        "components" => super::type_names::<ReflectComponent>(&registry),
+        "functions" => super::function_names(world)?,
+        "systems" => world
+            .resource::<ConsoleCommands>()
+            .systems()
+            .map(ToString::to_string)
+            .collect(),
+        "commands" => world
+            .resource::<ConsoleCommands>()
+            .iter()
+            .map(|(name, _)| name.to_string())
+            .collect(),
+        _ => return Err(usage(LIST_USAGE)),
+    };
+    Ok(names.join("\n"))
+}
+
+#[cfg(feature = "reflect_functions")]
+fn call(world: &mut World, args: &[String]) -> Result<String, ConsoleError> {
+    use bevy_ecs::reflect::AppFunctionRegistry;
+    use bevy_reflect::func::{args::Ownership, ArgList, Return};
+
+    let [name, args @ ..] = args else {
+        return Err(usage(CALL_USAGE));
+    };
+    let function = world
+        .get_resource::<AppFunctionRegistry>()
+        .and_then(|functions| functions.read().get(name).cloned())
+        .ok_or_else(|| ConsoleError::UnknownFunction(name.clone()))?;
+    let function_error = |message: String| ConsoleError::Function {
+        name: name.clone(),
+        message,
+    };
+    let signature = function
+        .info()
+        .signatures()
+        .iter()
+        .find(|signature| signature.arg_count() == args.len())
+        .ok_or_else(|| {
+            function_error(format!("expected `{:?}`", function.info().pretty_printer()))
+        })?;
+
+    let registry = type_registry(world);
+    let registry = registry.read();
+    let mut values = Vec::with_capacity(args.len());
+    for (text, info) in args.iter().zip(signature.args()) {
+        // Arguments taken by reference are registered as the referenced type
+        let type_path = info.type_path();
+        let type_path = match info.ownership() {
+            Ownership::Owned => type_path,
+            Ownership::Ref => type_path.trim_start_matches('&'),
+            Ownership::Mut => type_path.trim_start_matches("&mut "),
+        };
+        let registration = registry
+            .get_with_type_path(type_path)
+            .ok_or_else(|| ConsoleError::UnknownType(type_path.to_string()))?;
+        values.push(parse_value(text, registration, &registry)?);
+    }
+
+    let mut arg_list = ArgList::new();
+    for (value, info) in values.iter_mut().zip(signature.args()) {
+        match info.ownership() {
+            Ownership::Owned => arg_list.push_boxed(core::mem::replace(value, Box::new(()))),
+            Ownership::Ref => arg_list.push_ref(&**value),
+            Ownership::Mut => arg_list.push_mut(&mut **value),
+        }
+    }
+
+    match function
+        .call(arg_list)
+        .map_err(|error| function_error(error.to_string()))?
+    {
+        value if value.is_unit() => Ok(String::new()),
+        Return::Owned(value) => Ok(format_value(value.as_ref(), &registry)),
+        Return::Ref(value) => Ok(format_value(value, &registry)),
+        Return::Mut(value) => Ok(format_value(value, &registry)),
+    }
+}
+
+#[cfg(not(feature = "reflect_functions"))]
+fn call(_world: &mut World, _args: &[String]) -> Result<String, ConsoleError> {
+    Err(super::functions_disabled())
+}
+
+fn run(world: &mut World, args: &[String]) -> Result<String, ConsoleError> {
+    let [name] = args else {
+        return Err(usage(RUN_USAGE));
+    };
+    let system = world
+        .resource::<ConsoleCommands>()
+        .system(name)
+        .ok_or_else(|| ConsoleError::UnknownSystem(name.clone()))?;
+    world
+        .run_system(system)
+        .map_err(|error| ConsoleError::System {
+            name: name.clone(),
+            message: error.to_string(),
+        })?;
+    Ok(String::new())
+}
+
+fn history(world: &mut World, _args: &[String]) -> Result<String, ConsoleError> {
+    let console = world.resource::<Console>();
+    Ok(console
+        .history()
+        .iter()
+        .enumerate()
+        .map(|(index, line)| format!("{index:>4}  {line}"))
+        .collect::<Vec<_>>()
+        .join("\n"))
+}
+
+fn clear(world: &mut World, _args: &[String]) -> Result<String, ConsoleError> {
+    world.resource_mut::<Console>().clear_output();
+    Ok(String::new())
+}
@@ -0,0 +1,97 @@
+use bevy_ecs::entity::Entity;
+use thiserror::Error;
+
+/// An error that occurs when running a console command.
+#[derive(Error, Debug)]
+pub enum ConsoleError {
+    /// No command with the given name has been registered.
+    #[error("unknown command `{0}`, type `help` to list the available commands")]
+    UnknownCommand(String),
+    /// The arguments of a command don't match its usage.
+    #[error("usage: {0}")]
+    Usage(String),
+    /// A quoted string isn't closed.
+    #[error("unterminated string")]
+    UnterminatedString,
+    /// A bracket isn't opened or closed.
+    #[error("unmatched `{0}`")]
+    UnmatchedDelimiter(char),
+    /// No type with the given name is registered.
+    #[error("no registered type is named `{0}`")]
+    UnknownType(String),
+    /// Several registered types have the given short name.
+    #[error("`{0}` is ambiguous, use its full type path")]
+    AmbiguousType(String),
+    /// The type doesn't register [`ReflectComponent`](bevy_ecs::reflect::ReflectComponent).
+    #[error("`{0}` is not a reflected component")]
+    NotComponent(String),
+    /// The type doesn't register [`ReflectResource`](bevy_ecs::reflect::ReflectResource).
+    #[error("`{0}` is not a reflected resource")]
+    NotResource(String),
+    /// The resource doesn't exist in the world.
+    #[error("the `{0}` resource does not exist")]
+    MissingResource(String),
+    /// The text isn't an entity, such as `12v1` or `12`.
+    #[error("`{0}` is not an entity")]
+    InvalidEntity(String),
+    /// The entity doesn't exist.
+    #[error("entity {0} does not exist")]
+    MissingEntity(Entity),
+    /// The entity doesn't have the component.
+    #[error("entity {entity} does not have a `{component}` component")]
+    MissingComponent {
+        /// The entity.
+        entity: Entity,
+        /// The name of the component.
+        component: String,
+    },
+    /// The component is immutable, so it can't be modified in place.
+    #[error("the `{0}` component is immutable")]
+    ImmutableComponent(String),
+    /// The path doesn't lead to a field of the value.
+    #[error("invalid path `{path}`: {message}")]
+    InvalidPath {
+        /// The path.
+        path: String,
+        /// Why the path is invalid.
+        message: String,
+    },
+    /// The value couldn't be parsed or applied.
+    #[error("invalid value `{value}`: {message}")]
+    InvalidValue {
+        /// The text of the value.
+        value: String,
+        /// Why the value is invalid.
+        message: String,
+    },
+    /// A value was omitted, but the type has no default value.
+    #[error("`{0}` has no default value, a value must be given")]
+    NoDefault(String),
+    /// No function with the given name is registered in the
+    /// [`AppFunctionRegistry`](bevy_ecs::reflect::AppFunctionRegistry).
+    #[error("no function is named `{0}`")]
+    UnknownFunction(String),
+    /// The function couldn't be called.
+    #[error("failed to call `{name}`: {message}")]
+    Function {
+        /// The name of the function.
+        name: String,
+        /// Why the call failed.
+        message: String,
+    },
+    /// No system with the given name has been registered with
+    /// [`ConsoleAppExt::add_console_system`](super::ConsoleAppExt::add_console_system).
+    #[error("no system is named `{0}`")]
+    UnknownSystem(String),
+    /// The system couldn't be run.
+    #[error("failed to run `{name}`: {message}")]
+    System {
+        /// The name of the system.
+        name: String,
+        /// Why the system couldn't be run.
+        message: String,
+    },
+    /// An error reported by a custom command.
+    #[error("{0}")]
+    Custom(String),
+}
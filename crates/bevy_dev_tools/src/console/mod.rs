@@ -0,0 +1,650 @@
+//! A developer console for inspecting and modifying the world with text commands.
+//!
+//! The [`ConsolePlugin`] adds the [`ConsoleCommands`] and the [`Console`] history and output.
+//! Commands can then be run from:
+//! - an in-game overlay displayed with `bevy_ui`, added by the [`ConsoleOverlayPlugin`],
+//! - the standard input of the app, with the [`ConsoleStdinPlugin`], which is useful for headless apps,
+//! - code, with [`Console::submit`].
+//!
+//! # Commands
+//!
+//! A command is a name followed by arguments separated by whitespace.
+//! Quoted strings and values in brackets, such as `(x: 1.0, y: 2.0)`, form a single argument.
+//! Values are written in [RON] and parsed through reflection, so any registered type can be used.
+//!
+//! The built-in commands are:
+//! - `help [command]`: lists the commands, or describes one.
+//! - `get <resource>[.path]` and `get <entity> <component>[.path]`: prints a reflected
+//!   resource or component, or one of its fields using a [reflection path].
+//! - `set <resource>[.path] [=] <value>` and `set <entity> <component>[.path] [=] <value>`:
+//!   modifies a resource, component or field.
+//! - `spawn [<component> [value]]...`: spawns an entity, using the default value of the
+//!   components without a value.
+//! - `despawn <entity>`, `insert <entity> <component> [value]` and `remove <entity> <component>`.
+//! - `inspect <entity>`: prints all the reflected components of an entity.
+//! - `query <component>...`: lists the entities with all the given components.
+//! - `list resources|components|functions|systems|commands`.
+//! - `call <function> [argument]...`: calls a function from the [`AppFunctionRegistry`],
+//!   which requires the `reflect_functions` feature.
+//! - `run <system>`: runs a one-shot system registered with [`ConsoleAppExt::add_console_system`].
+//! - `history` and `clear`.
+//!
+//! Entities are written like they are displayed, such as `12v1`, or with their index only, such as `12`.
+//! Types can be written with their short type path if it isn't ambiguous, such as `Transform`.
+//!
+//! Custom commands can be added with [`ConsoleAppExt::add_console_command`].
+//!
+//! ```
+//! # use bevy_app::prelude::*;
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_reflect::Reflect;
+//! use bevy_dev_tools::console::{Console, ConsoleAppExt, ConsoleCommand, ConsolePlugin};
+//!
+//! #[derive(Resource, Reflect, Default)]
+//! #[reflect(Resource)]
+//! struct Gravity(f32);
+//!
+//! fn reset_gravity(mut gravity: ResMut<Gravity>) {
+//!     gravity.0 = 9.81;
+//! }
+//!
+//! let mut app = App::new();
+//! app.add_plugins(ConsolePlugin)
+//!     .init_resource::<Gravity>()
+//!     .register_type::<Gravity>()
+//!     .add_console_system("reset_gravity", reset_gravity)
+//!     .add_console_command(
+//!         "greet",
+//!         ConsoleCommand::new(
+//!             |_world, args| Ok(format!("Hello, {}!", args.join(" "))),
+//!             "greet <name>",
+//!             "Greets someone",
+//!         ),
+//!     );
+//!
+//! let world = app.world_mut();
+//! Console::submit(world, "set Gravity.0 = 1.62").unwrap();
+//! assert_eq!(world.resource::<Gravity>().0, 1.62);
+//! Console::submit(world, "run reset_gravity").unwrap();
+//! assert_eq!(Console::submit(world, "get Gravity").unwrap(), "(9.81)");
+//! assert_eq!(Console::submit(world, "greet Bevy").unwrap(), "Hello, Bevy!");
+//! ```
+//!
+//! [RON]: https://github.com/ron-rs/ron
+//! [reflection path]: bevy_reflect::GetPath
+//! [`AppFunctionRegistry`]: bevy_ecs::reflect::AppFunctionRegistry
+
+mod builtin;
+mod error;
+mod overlay;
+mod parse;
+#[cfg(not(target_family = "wasm"))]
+mod stdin;
+
+pub use error::ConsoleError;
+pub use overlay::{ConsoleOverlayConfig, ConsoleOverlayPlugin, ConsolePrompt, CONSOLE_ZINDEX};
+pub use parse::tokenize;
+#[cfg(not(target_family = "wasm"))]
+pub use stdin::ConsoleStdinPlugin;
+
+use alloc::{borrow::Cow, collections::BTreeMap, collections::VecDeque, sync::Arc};
+use bevy_app::{App, Plugin};
+use bevy_ecs::{
+    reflect::{AppTypeRegistry, ReflectComponent, ReflectResource},
+    resource::Resource,
+    system::{IntoSystem, SystemId},
+    world::World,
+};
+use bevy_reflect::{TypeData, TypeInfo, TypeRegistry};
+use parse::{resolve_type, split_path};
+
+/// A plugin that adds the [`Console`] and the built-in [`ConsoleCommands`].
+///
+/// This plugin doesn't read any input, add the [`ConsoleOverlayPlugin`] or the
+/// [`ConsoleStdinPlugin`] to run commands.
+#[derive(Default)]
+pub struct ConsolePlugin;
+
+impl Plugin for ConsolePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Console>()
+            .init_resource::<ConsoleCommands>();
+    }
+}
+
+/// The history and output of the console.
+#[derive(Resource, Default)]
+pub struct Console {
+    history: Vec<String>,
+    output: VecDeque<String>,
+}
+
+impl Console {
+    /// The maximum number of lines kept in the output.
+    pub const MAX_OUTPUT_LINES: usize = 1000;
+
+    /// Runs a command line, recording it in the history and its result in the output.
+    ///
+    /// Returns the output of the command.
+    pub fn submit(world: &mut World, line: &str) -> Result<String, ConsoleError> {
+        let line = line.trim();
+        if line.is_empty() {
+            return Ok(String::new());
+        }
+        let mut console = world.get_resource_or_init::<Console>();
+        if console.history.last().is_none_or(|last| last != line) {
+            console.history.push(line.to_string());
+        }
+        console.print(format!("> {line}"));
+
+        let result = ConsoleCommands::execute(world, line);
+        let mut console = world.resource_mut::<Console>();
+        match &result {
+            Ok(output) => console.print(output),
+            Err(error) => console.print(format!("error: {error}")),
+        }
+        result
+    }
+
+    /// Adds text to the output.
+    pub fn print(&mut self, text: impl AsRef<str>) {
+        for line in text.as_ref().lines() {
+            if self.output.len() == Self::MAX_OUTPUT_LINES {
+                self.output.pop_front();
+            }
+            self.output.push_back(line.to_string());
+        }
+    }
+
+    /// Returns the lines of the output, from oldest to newest.
+    pub fn output(&self) -> impl DoubleEndedIterator<Item = &str> + ExactSizeIterator {
+        self.output.iter().map(String::as_str)
+    }
+
+    /// Clears the output.
+    pub fn clear_output(&mut self) {
+        self.output.clear();
+    }
+
+    /// Returns the previously submitted command lines, from oldest to newest.
+    pub fn history(&self) -> &[String] {
+        &self.history
+    }
+}
+
+/// The function run by a [`ConsoleCommand`], taking the arguments of the command
+/// and returning its output.
+pub type ConsoleCommandFn =
+    dyn Fn(&mut World, &[String]) -> Result<String, ConsoleError> + Send + Sync;
+
+/// What an argument of a [`ConsoleCommand`] is, used to suggest completions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConsoleArg {
+    /// A value that isn't completed.
+    Value,
+    /// The name of a command.
+    Command,
+    /// A reflected resource, optionally followed by a path to one of its fields.
+    Resource,
+    /// A reflected component, optionally followed by a path to one of its fields.
+    Component,
+    /// Reflected components, for this argument and all the following ones.
+    Components,
+    /// A function from the [`AppFunctionRegistry`](bevy_ecs::reflect::AppFunctionRegistry).
+    Function,
+    /// A system registered with [`ConsoleAppExt::add_console_system`].
+    System,
+    /// One of the given keywords.
+    Keyword(&'static [&'static str]),
+}
+
+/// A command that can be run from the console.
+#[derive(Clone)]
+pub struct ConsoleCommand {
+    run: Arc<ConsoleCommandFn>,
+    usage: Cow<'static, str>,
+    description: Cow<'static, str>,
+    args: Vec<ConsoleArg>,
+}
+
+impl ConsoleCommand {
+    /// Creates a command running the given function.
+    ///
+    /// The usage is displayed when the arguments are invalid, such as `greet <name>`.
+    pub fn new(
+        run: impl Fn(&mut World, &[String]) -> Result<String, ConsoleError> + Send + Sync + 'static,
+        usage: impl Into<Cow<'static, str>>,
+        description: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        Self {
+            run: Arc::new(run),
+            usage: usage.into(),
+            description: description.into(),
+            args: Vec::new(),
+        }
+    }
+
+    /// Sets what the arguments of the command are, so that they can be completed.
+    pub fn with_args(mut self, args: impl IntoIterator<Item = ConsoleArg>) -> Self {
+        self.args = args.into_iter().collect();
+        self
+    }
+
+    /// Returns how the command is used.
+    pub fn usage(&self) -> &str {
+        &self.usage
+    }
+
+    /// Returns what the command does.
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Returns what the arguments of the command are.
+    pub fn args(&self) -> &[ConsoleArg] {
+        &self.args
+    }
+
+    /// Runs the command with the given arguments.
+    pub fn run(&self, world: &mut World, args: &[String]) -> Result<String, ConsoleError> {
+        (self.run)(world, args)
+    }
+}
+
+/// The commands and systems that can be run from the console.
+///
+/// The built-in commands are added by default.
+#[derive(Resource)]
+pub struct ConsoleCommands {
+    commands: BTreeMap<String, ConsoleCommand>,
+    systems: BTreeMap<String, SystemId>,
+}
+
+impl Default for ConsoleCommands {
+    fn default() -> Self {
+        let mut commands = Self {
+            commands: BTreeMap::new(),
+            systems: BTreeMap::new(),
+        };
+        builtin::add_builtin_commands(&mut commands);
+        commands
+    }
+}
+
+impl ConsoleCommands {
+    /// Adds a command, replacing any command with the same name.
+    pub fn add(&mut self, name: impl Into<String>, command: ConsoleCommand) -> &mut Self {
+        self.commands.insert(name.into(), command);
+        self
+    }
+
+    /// Returns the command with the given name.
+    pub fn get(&self, name: &str) -> Option<&ConsoleCommand> {
+        self.commands.get(name)
+    }
+
+    /// Returns the commands and their names, sorted by name.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &ConsoleCommand)> {
+        self.commands
+            .iter()
+            .map(|(name, command)| (name.as_str(), command))
+    }
+
+    /// Makes a registered one-shot system runnable with the `run` command.
+    pub fn add_system(&mut self, name: impl Into<String>, system: SystemId) -> &mut Self {
+        self.systems.insert(name.into(), system);
+        self
+    }
+
+    /// Returns the system with the given name.
+    pub fn system(&self, name: &str) -> Option<SystemId> {
+        self.systems.get(name).copied()
+    }
+
+    /// Returns the names of the systems, sorted.
+    pub fn systems(&self) -> impl Iterator<Item = &str> {
+        self.systems.keys().map(String::as_str)
+    }
+
+    /// Runs a command line, without recording it in the [`Console`].
+    pub fn execute(world: &mut World, line: &str) -> Result<String, ConsoleError> {
+        let words = tokenize(line)?;
+        let Some((name, args)) = words.split_first() else {
+            return Ok(String::new());
+        };
+        let command = world
+            .get_resource_or_init::<ConsoleCommands>()
+            .get(name)
+            .cloned()
+            .ok_or_else(|| ConsoleError::UnknownCommand(name.clone()))?;
+        command.run(world, args)
+    }
+
+    /// Returns the possible completions of the last word of a command line,
+    /// as complete command lines.
+    pub fn complete(world: &World, line: &str) -> Vec<String> {
+        let Some(commands) = world.get_resource::<ConsoleCommands>() else {
+            return Vec::new();
+        };
+        let start = line
+            .char_indices()
+            .rfind(|(_, c)| c.is_whitespace())
+            .map_or(0, |(index, c)| index + c.len_utf8());
+        let (head, word) = line.split_at(start);
+        let Ok(words) = tokenize(head) else {
+            return Vec::new();
+        };
+
+        let registry = world
+            .get_resource::<AppTypeRegistry>()
+            .cloned()
+            .unwrap_or_default();
+        let registry = registry.read();
+        let candidates =
+            match words.split_first() {
+                None => commands.commands.keys().cloned().collect(),
+                Some((name, args)) => {
+                    let arg = commands.get(name).and_then(|command| {
+                        command.args.get(args.len()).or_else(|| {
+                            command
+                                .args
+                                .last()
+                                .filter(|arg| **arg == ConsoleArg::Components)
+                        })
+                    });
+                    match arg {
+                        None | Some(ConsoleArg::Value) => Vec::new(),
+                        Some(ConsoleArg::Command) => commands.commands.keys().cloned().collect(),
+                        Some(
+                            ConsoleArg::Resource | ConsoleArg::Component | ConsoleArg::Components,
+                        ) if word.contains(['.', '[']) => complete_path(&registry, word),
+                        Some(ConsoleArg::Resource) => type_names::<ReflectResource>(&registry),
+                        Some(ConsoleArg::Component | ConsoleArg::Components) => {
+                            type_names::<ReflectComponent>(&registry)
+                        }
+                        Some(ConsoleArg::Function) => function_names(world).unwrap_or_default(),
+                        Some(ConsoleArg::System) => {
+                            commands.systems().map(ToString::to_string).collect()
+                        }
+                        Some(ConsoleArg::Keyword(keywords)) => {
+                            keywords.iter().map(ToString::to_string).collect()
+                        }
+                    }
+                }
+            };
+
+        candidates
+            .into_iter()
+            .filter(|candidate| candidate.starts_with(word))
+            .map(|candidate| format!("{head}{candidate}"))
+            .collect()
+    }
+}
+
+/// Returns the names of the registered types with the given type data, sorted.
+///
+/// Types are named with their short type path, or their full type path if it is ambiguous.
+fn type_names<T: TypeData>(registry: &TypeRegistry) -> Vec<String> {
+    let mut names = registry
+        .iter_with_data::<T>()
+        .map(|(registration, _)| {
+            let table = registration.type_info().type_path_table();
+            match registry.is_ambiguous(table.short_path()) {
+                true => table.path().to_string(),
+                false => table.short_path().to_string(),
+            }
+        })
+        .collect::<Vec<_>>();
+    names.sort();
+    names
+}
+
+/// Returns the names of the functions of the
+/// [`AppFunctionRegistry`](bevy_ecs::reflect::AppFunctionRegistry), sorted.
+fn function_names(world: &World) -> Result<Vec<String>, ConsoleError> {
+    #[cfg(feature = "reflect_functions")]
+    {
+        let mut names = world
+            .get_resource::<bevy_ecs::reflect::AppFunctionRegistry>()
+            .map(|functions| {
+                functions
+                    .read()
+                    .iter()
+                    .filter_map(|function| function.name().map(ToString::to_string))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        names.sort();
+        Ok(names)
+    }
+
+    #[cfg(not(feature = "reflect_functions"))]
+    {
+        let _ = world;
+        Err(functions_disabled())
+    }
+}
+
+#[cfg(not(feature = "reflect_functions"))]
+fn functions_disabled() -> ConsoleError {
+    ConsoleError::Custom(
+        "calling functions requires the `reflect_functions` feature of `bevy_dev_tools`"
+            .to_string(),
+    )
+}
+
+/// Completes the field names of a word such as `Transform.transl`.
+fn complete_path(registry: &TypeRegistry, word: &str) -> Vec<String> {
+    let (type_name, path) = split_path(word);
+    let Ok(registration) = resolve_type(registry, type_name) else {
+        return Vec::new();
+    };
+    // Only named and indexed fields are completed
+    let Some(parents) = path.strip_prefix('.').filter(|path| !path.contains('[')) else {
+        return Vec::new();
+    };
+    let (parents, _) = parents.rsplit_once('.').unwrap_or(("", parents));
+
+    let mut type_info = Some(registration.type_info());
+    for name in parents.split('.').filter(|name| !name.is_empty()) {
+        type_info = type_info.and_then(|info| {
+            fields(info)
+                .into_iter()
+                .find(|(field, _)| field == name)
+                .and_then(|(_, info)| info)
+        });
+    }
+    let Some(type_info) = type_info else {
+        return Vec::new();
+    };
+
+    let prefix = match parents {
+        "" => type_name.to_string(),
+        parents => format!("{type_name}.{parents}"),
+    };
+    fields(type_info)
+        .into_iter()
+        .map(|(field, _)| format!("{prefix}.{field}"))
+        .collect()
+}
+
+/// Returns the names and types of the fields of a type.
+fn fields(type_info: &TypeInfo) -> Vec<(String, Option<&'static TypeInfo>)> {
+    match type_info {
+        TypeInfo::Struct(info) => info
+            .iter()
+            .map(|field| (field.name().to_string(), field.type_info()))
+            .collect(),
+        TypeInfo::TupleStruct(info) => info
+            .iter()
+            .map(|field| (field.index().to_string(), field.type_info()))
+            .collect(),
+        TypeInfo::Tuple(info) => info
+            .iter()
+            .map(|field| (field.index().to_string(), field.type_info()))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Extension trait for adding console commands and systems to an [`App`].
+pub trait ConsoleAppExt {
+    /// Adds a command that can be run from the console.
+    fn add_console_command(
+        &mut self,
+        name: impl Into<String>,
+        command: ConsoleCommand,
+    ) -> &mut Self;
+
+    /// Registers a one-shot system that can be run from the console with `run <name>`.
+    fn add_console_system<M>(
+        &mut self,
+        name: impl Into<String>,
+        system: impl IntoSystem<(), (), M> + 'static,
+    ) -> &mut Self;
+}
+
+impl ConsoleAppExt for App {
+    fn add_console_command(
+        &mut self,
+        name: impl Into<String>,
+        command: ConsoleCommand,
+    ) -> &mut Self {
+        self.world_mut()
+            .get_resource_or_init::<ConsoleCommands>()
+            .add(name, command);
+        self
+    }
+
+    fn add_console_system<M>(
+        &mut self,
+        name: impl Into<String>,
+        system: impl IntoSystem<(), (), M> + 'static,
+    ) -> &mut Self {
+        let world = self.world_mut();
+        let system = world.register_system(system);
+        world
+            .get_resource_or_init::<ConsoleCommands>()
+            .add_system(name, system);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::{component::Component, system::ResMut};
+    use bevy_reflect::{prelude::ReflectDefault, Reflect};
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component, Default)]
+    struct Health {
+        current: f32,
+        max: f32,
+    }
+
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    struct Score(u32);
+
+    fn setup() -> App {
+        let mut app = App::new();
+        app.add_plugins(ConsolePlugin)
+            .init_resource::<Score>()
+            .register_type::<Health>()
+            .register_type::<Score>()
+            .add_console_system("reset_score", |mut score: ResMut<Score>| score.0 = 0);
+        app
+    }
+
+    #[test]
+    fn tokenize_command_lines() {
+        assert_eq!(
+            tokenize(r#"spawn Health (current: 1.0, max: 2.0) Name "a b\" c""#).unwrap(),
+            [
+                "spawn",
+                "Health",
+                "(current: 1.0, max: 2.0)",
+                "Name",
+                r#""a b\" c""#
+            ]
+        );
+        assert!(matches!(
+            tokenize("set Foo (a: [1, 2)"),
+            Err(ConsoleError::UnmatchedDelimiter(')'))
+        ));
+        assert!(matches!(
+            tokenize("set Foo (a: 1"),
+            Err(ConsoleError::UnmatchedDelimiter('('))
+        ));
+        assert!(matches!(
+            tokenize(r#"greet "Bevy"#),
+            Err(ConsoleError::UnterminatedString)
+        ));
+    }
+
+    #[test]
+    fn run_builtin_commands() {
+        let mut app = setup();
+        let world = app.world_mut();
+
+        let entity = Console::submit(world, "spawn Health (current: 5.0, max: 10.0)").unwrap();
+        Console::submit(world, &format!("set {entity} Health.current = 7.5")).unwrap();
+        assert_eq!(
+            Console::submit(world, &format!("get {entity} Health")).unwrap(),
+            "(current:7.5,max:10.0)"
+        );
+        assert_eq!(Console::submit(world, "query Health").unwrap(), entity);
+
+        let other = Console::submit(world, "spawn").unwrap();
+        Console::submit(world, &format!("insert {other} Health")).unwrap();
+        let other = parse::parse_entity(world, &other).unwrap();
+        assert_eq!(world.get::<Health>(other), Some(&Health::default()));
+
+        Console::submit(world, "set Score (12)").unwrap();
+        assert_eq!(world.resource::<Score>().0, 12);
+        Console::submit(world, "run reset_score").unwrap();
+        assert_eq!(world.resource::<Score>().0, 0);
+
+        assert!(matches!(
+            Console::submit(world, "get Score.1"),
+            Err(ConsoleError::InvalidPath { .. })
+        ));
+        assert!(matches!(
+            Console::submit(world, "get Health"),
+            Err(ConsoleError::NotResource(_))
+        ));
+        assert!(matches!(
+            Console::submit(world, "unknown"),
+            Err(ConsoleError::UnknownCommand(_))
+        ));
+
+        let console = world.resource::<Console>();
+        assert_eq!(console.history().len(), 11);
+        assert_eq!(
+            console.output().last(),
+            Some("error: unknown command `unknown`, type `help` to list the available commands")
+        );
+    }
+
+    #[test]
+    fn complete_command_lines() {
+        let app = setup();
+        let world = app.world();
+
+        assert_eq!(ConsoleCommands::complete(world, "he"), ["help"]);
+        assert_eq!(ConsoleCommands::complete(world, "get Sc"), ["get Score"]);
+        assert_eq!(
+            ConsoleCommands::complete(world, "get 0v1 Health."),
+            ["get 0v1 Health.current", "get 0v1 Health.max"]
+        );
+        assert_eq!(
+            ConsoleCommands::complete(world, "list sys"),
+            ["list systems"]
+        );
+        assert_eq!(
+            ConsoleCommands::complete(world, "run "),
+            ["run reset_score"]
+        );
+    }
+}
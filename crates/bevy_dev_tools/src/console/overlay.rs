@@ -0,0 +1,261 @@
+use super::{Console, ConsoleCommands, ConsolePlugin};
+use bevy_app::{App, Plugin, Startup, Update};
+use bevy_color::Color;
+use bevy_ecs::{
+    component::Component,
+    event::EventReader,
+    query::With,
+    resource::Resource,
+    schedule::{common_conditions::resource_changed, Condition, IntoScheduleConfigs},
+    system::{Commands, Query, Res, SystemState},
+    world::World,
+};
+use bevy_input::{
+    keyboard::{Key, KeyCode, KeyboardInput},
+    ButtonState,
+};
+use bevy_text::{TextColor, TextFont};
+use bevy_ui::{
+    widget::Text, BackgroundColor, Display, GlobalZIndex, Node, PositionType, UiRect, Val,
+};
+
+/// [`GlobalZIndex`] used to render the console overlay.
+///
+/// This is below the [`FPS_OVERLAY_ZINDEX`](crate::fps_overlay::FPS_OVERLAY_ZINDEX).
+pub const CONSOLE_ZINDEX: i32 = i32::MAX - 64;
+
+/// A plugin that adds a console overlay, opened with a key, to run commands with the keyboard.
+///
+/// Pressing tab completes the last word of the prompt, and the up and down arrows
+/// browse the history. This plugin will add the [`ConsolePlugin`] if it wasn't added before.
+#[derive(Default)]
+pub struct ConsoleOverlayPlugin {
+    /// Starting configuration of the overlay, this can be later be changed through the [`ConsoleOverlayConfig`] resource.
+    pub config: ConsoleOverlayConfig,
+}
+
+impl Plugin for ConsoleOverlayPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<ConsolePlugin>() {
+            app.add_plugins(ConsolePlugin);
+        }
+        app.insert_resource(self.config.clone())
+            .init_resource::<ConsolePrompt>()
+            .add_systems(Startup, setup)
+            .add_systems(
+                Update,
+                (
+                    handle_keyboard,
+                    update_overlay.run_if(
+                        resource_changed::<Console>
+                            .or(resource_changed::<ConsolePrompt>)
+                            .or(resource_changed::<ConsoleOverlayConfig>),
+                    ),
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Configuration options for the console overlay.
+#[derive(Resource, Clone)]
+pub struct ConsoleOverlayConfig {
+    /// Configuration of text in the overlay.
+    pub text_font: TextFont,
+    /// Color of text in the overlay.
+    pub text_color: Color,
+    /// Background color of the overlay.
+    pub background_color: Color,
+    /// The key opening and closing the overlay.
+    pub toggle_key: KeyCode,
+    /// The number of output lines displayed above the prompt.
+    pub visible_lines: usize,
+}
+
+impl Default for ConsoleOverlayConfig {
+    fn default() -> Self {
+        ConsoleOverlayConfig {
+            text_font: TextFont {
+                font_size: 14.0,
+                ..Default::default()
+            },
+            text_color: Color::WHITE,
+            background_color: Color::srgba(0.05, 0.05, 0.08, 0.9),
+            toggle_key: KeyCode::Backquote,
+            visible_lines: 20,
+        }
+    }
+}
+
+/// The state of the prompt of the console overlay.
+#[derive(Resource, Default)]
+pub struct ConsolePrompt {
+    /// Whether the overlay is open.
+    ///
+    /// Other systems can check this to ignore keyboard input while the console is used.
+    pub open: bool,
+    /// The command line being typed.
+    pub input: String,
+    /// The position in the history when browsing it with the arrow keys.
+    history_index: Option<usize>,
+}
+
+#[derive(Component)]
+struct ConsoleOverlay;
+
+#[derive(Component)]
+struct ConsoleOverlayText;
+
+fn setup(mut commands: Commands, config: Res<ConsoleOverlayConfig>) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                padding: UiRect::all(Val::Px(6.0)),
+                display: Display::None,
+                ..Default::default()
+            },
+            BackgroundColor(config.background_color),
+            // Render the overlay on top of everything else
+            GlobalZIndex(CONSOLE_ZINDEX),
+            ConsoleOverlay,
+        ))
+        .with_child((
+            Text::default(),
+            config.text_font.clone(),
+            TextColor(config.text_color),
+            ConsoleOverlayText,
+        ));
+}
+
+fn handle_keyboard(world: &mut World, keyboard: &mut SystemState<EventReader<KeyboardInput>>) {
+    let inputs = keyboard
+        .get_mut(world)
+        .read()
+        .filter(|input| input.state == ButtonState::Pressed)
+        .cloned()
+        .collect::<Vec<_>>();
+    let toggle_key = world.resource::<ConsoleOverlayConfig>().toggle_key;
+
+    for input in inputs {
+        let mut prompt = world.resource_mut::<ConsolePrompt>();
+        if input.key_code == toggle_key {
+            if !input.repeat {
+                prompt.open = !prompt.open;
+            }
+            continue;
+        }
+        if !prompt.open {
+            continue;
+        }
+
+        match &input.logical_key {
+            Key::Enter => {
+                let line = core::mem::take(&mut prompt.input);
+                prompt.history_index = None;
+                // The error is displayed in the output
+                let _ = Console::submit(world, &line);
+            }
+            Key::Escape => prompt.open = false,
+            Key::Backspace => {
+                prompt.input.pop();
+            }
+            Key::Tab => {
+                let line = prompt.input.clone();
+                complete(world, &line);
+            }
+            Key::ArrowUp | Key::ArrowDown => {
+                let up = input.logical_key == Key::ArrowUp;
+                let history_index = prompt.history_index;
+                let history_len = world.resource::<Console>().history().len();
+                let history_index = match (history_index, up) {
+                    (None, true) => history_len.checked_sub(1),
+                    (Some(index), true) => Some(index.saturating_sub(1)),
+                    (Some(index), false) => Some(index + 1).filter(|index| *index < history_len),
+                    (None, false) => None,
+                };
+                let line = history_index
+                    .map(|index| world.resource::<Console>().history()[index].clone())
+                    .unwrap_or_default();
+                let mut prompt = world.resource_mut::<ConsolePrompt>();
+                prompt.history_index = history_index;
+                prompt.input = line;
+            }
+            _ => {
+                if let Some(text) = &input.text {
+                    prompt
+                        .input
+                        .extend(text.chars().filter(|c| !c.is_control()));
+                }
+            }
+        }
+    }
+}
+
+/// Completes the prompt with the longest common prefix of its completions,
+/// printing the completions if there are several.
+fn complete(world: &mut World, line: &str) {
+    let completions = ConsoleCommands::complete(world, line);
+    let Some(first) = completions.first() else {
+        return;
+    };
+    let common_len = completions.iter().fold(first.len(), |len, completion| {
+        first
+            .char_indices()
+            .zip(completion.chars())
+            .take_while(|((_, a), b)| a == b)
+            .last()
+            .map_or(0, |((index, a), _)| index + a.len_utf8())
+            .min(len)
+    });
+
+    if completions.len() > 1 {
+        let start = line
+            .char_indices()
+            .rfind(|(_, c)| c.is_whitespace())
+            .map_or(0, |(index, c)| index + c.len_utf8());
+        let words = completions
+            .iter()
+            .map(|completion| &completion[start..])
+            .collect::<Vec<_>>();
+        world.resource_mut::<Console>().print(words.join("  "));
+    }
+    let mut input = first[..common_len].to_string();
+    if completions.len() == 1 {
+        input.push(' ');
+    }
+    world.resource_mut::<ConsolePrompt>().input = input;
+}
+
+fn update_overlay(
+    console: Res<Console>,
+    prompt: Res<ConsolePrompt>,
+    config: Res<ConsoleOverlayConfig>,
+    mut overlay: Query<(&mut Node, &mut BackgroundColor), With<ConsoleOverlay>>,
+    mut text: Query<(&mut Text, &mut TextFont, &mut TextColor), With<ConsoleOverlayText>>,
+) {
+    for (mut node, mut background_color) in &mut overlay {
+        node.display = match prompt.open {
+            true => Display::Flex,
+            false => Display::None,
+        };
+        background_color.0 = config.background_color;
+    }
+
+    let output = console.output();
+    let skip = output.len().saturating_sub(config.visible_lines);
+    let mut lines = output.skip(skip).collect::<Vec<_>>().join("\n");
+    if !lines.is_empty() {
+        lines.push('\n');
+    }
+    lines.push_str("> ");
+    lines.push_str(&prompt.input);
+    lines.push('_');
+
+    for (mut text, mut text_font, mut text_color) in &mut text {
+        text.0.clone_from(&lines);
+        *text_font = config.text_font.clone();
+        text_color.0 = config.text_color;
+    }
+}
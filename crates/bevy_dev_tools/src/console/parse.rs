@@ -0,0 +1,154 @@
+use super::ConsoleError;
+use bevy_ecs::{entity::Entity, world::World};
+use bevy_reflect::{
+    serde::{TypedReflectDeserializer, TypedReflectSerializer},
+    PartialReflect, ReflectFromReflect, TypeRegistration, TypeRegistry,
+};
+use serde::de::DeserializeSeed;
+
+/// Splits a command line into words separated by whitespace.
+///
+/// Quoted strings and values in brackets are kept in a single word, along with their quotes
+/// and brackets, so that they can be parsed as RON.
+pub fn tokenize(line: &str) -> Result<Vec<String>, ConsoleError> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    // The opening and closing brackets that haven't been closed yet
+    let mut brackets = Vec::new();
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for c in line.chars() {
+        if in_string {
+            word.push(c);
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match c {
+            '"' => in_string = true,
+            '(' => brackets.push(('(', ')')),
+            '[' => brackets.push(('[', ']')),
+            '{' => brackets.push(('{', '}')),
+            ')' | ']' | '}' => match brackets.pop() {
+                Some((_, close)) if close == c => {}
+                _ => return Err(ConsoleError::UnmatchedDelimiter(c)),
+            },
+            c if c.is_whitespace() && brackets.is_empty() => {
+                if !word.is_empty() {
+                    words.push(core::mem::take(&mut word));
+                }
+                continue;
+            }
+            _ => {}
+        }
+        word.push(c);
+    }
+
+    if in_string {
+        return Err(ConsoleError::UnterminatedString);
+    }
+    if let Some((open, _)) = brackets.pop() {
+        return Err(ConsoleError::UnmatchedDelimiter(open));
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    Ok(words)
+}
+
+/// Parses an entity written as `index` or `indexvgeneration`, such as `12` or `12v1`.
+pub(crate) fn parse_entity(world: &World, text: &str) -> Result<Entity, ConsoleError> {
+    let invalid = || ConsoleError::InvalidEntity(text.to_string());
+    let (index, generation) = match text.split_once('v') {
+        Some((index, generation)) => (index, Some(generation)),
+        None => (text, None),
+    };
+    let index = index.parse::<u32>().map_err(|_| invalid())?;
+    let generation = generation
+        .map(|generation| generation.parse::<u32>().map_err(|_| invalid()))
+        .transpose()?;
+
+    let entity = world
+        .entities()
+        .resolve_from_id(index)
+        .ok_or_else(invalid)?;
+    let entity = match generation {
+        Some(generation) if generation != entity.generation() => {
+            return Err(ConsoleError::MissingEntity(entity));
+        }
+        _ => entity,
+    };
+    if world.get_entity(entity).is_err() {
+        return Err(ConsoleError::MissingEntity(entity));
+    }
+    Ok(entity)
+}
+
+/// Splits a word such as `Transform.translation.x` into a type name and a reflection path.
+pub(crate) fn split_path(text: &str) -> (&str, &str) {
+    let mut depth = 0_usize;
+    for (index, c) in text.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth = depth.saturating_sub(1),
+            '.' | '[' if depth == 0 => return text.split_at(index),
+            _ => {}
+        }
+    }
+    (text, "")
+}
+
+/// Finds the registration of a type from its full or short type path.
+pub(crate) fn resolve_type<'a>(
+    registry: &'a TypeRegistry,
+    name: &str,
+) -> Result<&'a TypeRegistration, ConsoleError> {
+    registry
+        .get_with_type_path(name)
+        .or_else(|| registry.get_with_short_type_path(name))
+        .ok_or_else(|| match registry.is_ambiguous(name) {
+            true => ConsoleError::AmbiguousType(name.to_string()),
+            false => ConsoleError::UnknownType(name.to_string()),
+        })
+}
+
+/// Parses a RON value of the given type.
+///
+/// The value is converted to its concrete type if the type registers [`ReflectFromReflect`].
+pub(crate) fn parse_value(
+    text: &str,
+    registration: &TypeRegistration,
+    registry: &TypeRegistry,
+) -> Result<Box<dyn PartialReflect>, ConsoleError> {
+    let invalid = |message: String| ConsoleError::InvalidValue {
+        value: text.to_string(),
+        message,
+    };
+
+    let mut deserializer =
+        ron::Deserializer::from_str(text).map_err(|error| invalid(error.to_string()))?;
+    let value = TypedReflectDeserializer::new(registration, registry)
+        .deserialize(&mut deserializer)
+        .map_err(|error| invalid(error.to_string()))?;
+    deserializer
+        .end()
+        .map_err(|error| invalid(error.to_string()))?;
+
+    Ok(registration
+        .data::<ReflectFromReflect>()
+        .and_then(|from_reflect| from_reflect.from_reflect(value.as_ref()))
+        .map_or(value, |value| value.into_partial_reflect()))
+}
+
+/// Formats a value as RON, falling back to its debug representation
+/// if it can't be serialized.
+pub(crate) fn format_value(value: &dyn PartialReflect, registry: &TypeRegistry) -> String {
+    ron::ser::to_string(&TypedReflectSerializer::new(value, registry))
+        .unwrap_or_else(|_| format!("{value:?}"))
+}
@@ -0,0 +1,82 @@
+use super::{Console, ConsolePlugin};
+use bevy_app::{App, Plugin, Startup, Update};
+use bevy_ecs::{
+    resource::Resource,
+    schedule::{common_conditions::resource_exists, IntoScheduleConfigs},
+    system::Commands,
+    world::World,
+};
+use std::{
+    io::{BufRead, Write},
+    sync::{
+        mpsc::{self, Receiver},
+        Mutex,
+    },
+};
+
+/// A plugin that runs every line written to the standard input of the app as a console command,
+/// writing its output to the standard output.
+///
+/// This lets headless apps be controlled from a terminal or by a parent process.
+/// This plugin will add the [`ConsolePlugin`] if it wasn't added before.
+///
+/// This plugin cannot be used when targeting WASM.
+#[derive(Default)]
+pub struct ConsoleStdinPlugin;
+
+impl Plugin for ConsoleStdinPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<ConsolePlugin>() {
+            app.add_plugins(ConsolePlugin);
+        }
+        app.add_systems(Startup, start_reading_stdin).add_systems(
+            Update,
+            run_stdin_commands.run_if(resource_exists::<ConsoleStdin>),
+        );
+    }
+}
+
+/// The lines read from stdin.
+#[derive(Resource)]
+struct ConsoleStdin(Mutex<Receiver<String>>);
+
+/// A system that starts the thread reading lines from stdin.
+fn start_reading_stdin(mut commands: Commands) {
+    let (sender, receiver) = mpsc::channel();
+    std::thread::Builder::new()
+        .name("console stdin".to_string())
+        .spawn(move || {
+            for line in std::io::stdin().lock().lines() {
+                let Ok(line) = line else {
+                    break;
+                };
+                if sender.send(line).is_err() {
+                    break;
+                }
+            }
+        })
+        .expect("failed to spawn the console stdin thread");
+    commands.insert_resource(ConsoleStdin(Mutex::new(receiver)));
+}
+
+/// A system that runs the lines read from stdin since the last frame.
+fn run_stdin_commands(world: &mut World) {
+    let lines = world
+        .resource::<ConsoleStdin>()
+        .0
+        .lock()
+        .map(|receiver| receiver.try_iter().collect::<Vec<_>>())
+        .unwrap_or_default();
+
+    for line in lines {
+        let result = Console::submit(world, &line);
+        let mut stdout = std::io::stdout().lock();
+        // Stdout may be closed, in which case there is nowhere to report the output
+        let _ = match result {
+            Ok(output) if output.is_empty() => Ok(()),
+            Ok(output) => writeln!(stdout, "{output}"),
+            Err(error) => writeln!(stdout, "error: {error}"),
+        }
+        .and_then(|()| stdout.flush());
+    }
+}
@@ -0,0 +1,1437 @@
+//! A reflection-based inspector for viewing and editing entities and resources in-game.
+//!
+//! Spawn an [`Inspector`] UI node targeting an entity or a resource, and the [`InspectorPlugin`]
+//! fills it with a row for each reflected field of the target:
+//! - numbers can be incremented and decremented, honoring the [`Range`] and [`Step`] attributes,
+//! - booleans can be toggled,
+//! - strings can be edited with the keyboard after clicking them,
+//! - enums can be switched to their previous or next variant,
+//! - colors show a preview next to their components,
+//! - list elements can be pushed and removed,
+//! - map values can be edited and map entries can be removed, or added with the default key.
+//!
+//! Structs, tuples and enum variants are displayed as nested rows, so vectors and other
+//! math types are edited through their components.
+//! Changes are written back to the target through reflection, which triggers change detection.
+//!
+//! Only types registered in the [`AppTypeRegistry`] are displayed, and only components
+//! registering [`ReflectComponent`] and resources registering [`ReflectResource`] can be inspected.
+//! Adding a variant, a list element or a map entry requires the types of its fields to register
+//! [`ReflectDefault`].
+//!
+//! ```no_run
+//! # use bevy_app::prelude::*;
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_dev_tools::inspector::{Inspector, InspectorPlugin};
+//! # use bevy_reflect::Reflect;
+//! # use bevy_ui::prelude::*;
+//! #[derive(Component, Reflect, Default)]
+//! #[reflect(Component)]
+//! struct Player {
+//!     name: String,
+//!     health: f32,
+//! }
+//!
+//! fn setup(mut commands: Commands) {
+//!     let player = commands.spawn(Player::default()).id();
+//!     commands.spawn((
+//!         Node {
+//!             flex_direction: FlexDirection::Column,
+//!             ..Default::default()
+//!         },
+//!         Inspector::entity(player),
+//!     ));
+//! }
+//! # App::new()
+//! #     .register_type::<Player>()
+//! #     .add_plugins(InspectorPlugin::default())
+//! #     .add_systems(Startup, setup);
+//! ```
+
+use alloc::sync::Arc;
+use bevy_app::{App, Plugin, Update};
+use bevy_color::Color;
+use bevy_ecs::{
+    change_detection::{DetectChanges, DetectChangesMut},
+    component::Component,
+    entity::Entity,
+    event::EventReader,
+    hierarchy::{ChildSpawner, Children},
+    query::{Changed, QueryState, With},
+    reflect::{AppTypeRegistry, ReflectComponent, ReflectResource},
+    resource::Resource,
+    schedule::IntoScheduleConfigs,
+    system::{Local, SystemState},
+    world::World,
+};
+use bevy_input::{
+    keyboard::{Key, KeyboardInput},
+    ButtonState,
+};
+use bevy_reflect::{
+    attributes::CustomAttributes,
+    prelude::ReflectDefault,
+    validation::{Range, Step},
+    DynamicEnum, DynamicStruct, DynamicTuple, DynamicVariant, FromReflect, Map, NamedField,
+    PartialReflect, Reflect, ReflectMut, ReflectPath, ReflectRef, TypeInfo, TypeRegistry,
+    UnnamedField, VariantInfo,
+};
+use bevy_text::{TextColor, TextFont};
+use bevy_time::Time;
+use bevy_ui::{
+    widget::{Button, Text},
+    AlignItems, BackgroundColor, FlexDirection, Interaction, Node, UiRect, Val,
+};
+use core::{any::TypeId, time::Duration};
+
+/// A plugin that fills [`Inspector`] nodes with the reflected fields of their target
+/// and applies the edits made through them.
+///
+/// The appearance of inspectors can be changed through the [`InspectorConfig`] resource.
+#[derive(Default)]
+pub struct InspectorPlugin {
+    /// Starting configuration of inspectors, this can be later be changed through the [`InspectorConfig`] resource.
+    pub config: InspectorConfig,
+}
+
+impl Plugin for InspectorPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(self.config.clone())
+            .init_resource::<InspectorFocus>()
+            .add_systems(
+                Update,
+                (apply_edits, edit_focused_text, update_inspectors).chain(),
+            );
+    }
+}
+
+/// Configuration options for [`Inspector`] nodes.
+#[derive(Resource, Clone)]
+pub struct InspectorConfig {
+    /// Configuration of text in inspectors.
+    pub text_font: TextFont,
+    /// Color of field names.
+    pub label_color: Color,
+    /// Color of field values.
+    pub value_color: Color,
+    /// Background color of buttons.
+    pub button_color: Color,
+    /// Indentation of each level of nested fields.
+    pub indent: Val,
+    /// Fields nested deeper than this are not displayed.
+    pub max_depth: usize,
+    /// The period after which inspectors are refreshed with the current values of their target.
+    ///
+    /// Defaults to once every 100 ms.
+    pub refresh_interval: Duration,
+}
+
+impl Default for InspectorConfig {
+    fn default() -> Self {
+        InspectorConfig {
+            text_font: TextFont {
+                font_size: 14.0,
+                ..Default::default()
+            },
+            label_color: Color::srgb(0.7, 0.7, 0.7),
+            value_color: Color::WHITE,
+            button_color: Color::srgb(0.25, 0.25, 0.3),
+            indent: Val::Px(12.0),
+            max_depth: 8,
+            refresh_interval: Duration::from_millis(100),
+        }
+    }
+}
+
+/// What an [`Inspector`] displays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InspectorTarget {
+    /// The reflected components of an entity.
+    Entity(Entity),
+    /// The reflected resource with the given [`TypeId`].
+    Resource(TypeId),
+}
+
+/// A UI node displaying the reflected fields of an [`InspectorTarget`] as editable rows.
+///
+/// The rows are spawned as children of this node by the [`InspectorPlugin`],
+/// so it is usually given a [`Node`] with a column [`FlexDirection`].
+#[derive(Component)]
+#[require(Node)]
+pub struct Inspector {
+    /// What this inspector displays.
+    pub target: InspectorTarget,
+    /// The rows currently displayed, used to only update values when the layout doesn't change.
+    rows: Vec<Row>,
+    /// The entities displaying the value of each row.
+    value_nodes: Vec<Option<Entity>>,
+}
+
+impl Inspector {
+    /// Creates an inspector for the given target.
+    pub fn new(target: InspectorTarget) -> Self {
+        Self {
+            target,
+            rows: Vec::new(),
+            value_nodes: Vec::new(),
+        }
+    }
+
+    /// Creates an inspector for the components of the given entity.
+    pub fn entity(entity: Entity) -> Self {
+        Self::new(InspectorTarget::Entity(entity))
+    }
+
+    /// Creates an inspector for the resource `R`.
+    pub fn resource<R: Resource>() -> Self {
+        Self::new(InspectorTarget::Resource(TypeId::of::<R>()))
+    }
+}
+
+/// The string field currently being edited with the keyboard, if any.
+#[derive(Resource, Default)]
+pub struct InspectorFocus(Option<(Entity, FieldRef)>);
+
+impl InspectorFocus {
+    /// Stops editing the focused string field.
+    pub fn clear(&mut self) {
+        self.0 = None;
+    }
+
+    /// Returns `true` if a string field is being edited.
+    ///
+    /// This can be used to ignore keyboard input meant for the inspector in other systems.
+    pub fn is_focused(&self) -> bool {
+        self.0.is_some()
+    }
+}
+
+/// A step from a value to one of its fields.
+enum Access {
+    /// A [reflection path](bevy_reflect::ReflectPath), such as `.field` or `[3]`.
+    Path(String),
+    /// The value of a map entry.
+    Key(Box<dyn PartialReflect>),
+}
+
+/// The location of a field within a component or resource.
+#[derive(Clone)]
+struct FieldRef {
+    /// The [`TypeId`] of the component or resource.
+    root: TypeId,
+    accesses: Arc<[Access]>,
+    /// A readable version of the accesses, used to compare field locations.
+    display: Arc<str>,
+}
+
+impl PartialEq for FieldRef {
+    fn eq(&self, other: &Self) -> bool {
+        self.root == other.root && self.display == other.display
+    }
+}
+
+/// A row of an [`Inspector`].
+#[derive(PartialEq)]
+struct Row {
+    depth: usize,
+    label: String,
+    widget: Widget,
+    /// The location of the value, or `None` if the value can't be edited.
+    field: Option<FieldRef>,
+    /// The location of the list or map containing this element, if it can be removed.
+    removable: Option<(FieldRef, Element)>,
+}
+
+impl Row {
+    /// Returns `true` if both rows display their values with the same UI nodes.
+    fn same_layout(&self, other: &Row) -> bool {
+        self.depth == other.depth
+            && self.label == other.label
+            && self.field == other.field
+            && self.removable == other.removable
+            && core::mem::discriminant(&self.widget) == core::mem::discriminant(&other.widget)
+            && match (&self.widget, &other.widget) {
+                (
+                    Widget::Number { step, min, max, .. },
+                    Widget::Number {
+                        step: other_step,
+                        min: other_min,
+                        max: other_max,
+                        ..
+                    },
+                ) => step == other_step && min == other_min && max == other_max,
+                (
+                    Widget::Collection { can_push, .. },
+                    Widget::Collection {
+                        can_push: other_can_push,
+                        ..
+                    },
+                ) => can_push == other_can_push,
+                _ => true,
+            }
+    }
+}
+
+/// An element of a list or a map.
+#[derive(Clone)]
+enum Element {
+    /// The index of a list element.
+    Index(usize),
+    /// The key of a map entry.
+    Key(Arc<dyn PartialReflect>),
+}
+
+impl PartialEq for Element {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Element::Index(index), Element::Index(other)) => index == other,
+            (Element::Key(key), Element::Key(other)) => {
+                key.reflect_partial_eq(other.as_ref()).unwrap_or(false)
+            }
+            _ => false,
+        }
+    }
+}
+
+#[derive(PartialEq, Debug)]
+enum Widget {
+    /// A label for nested rows.
+    Header,
+    Number {
+        value: String,
+        step: f64,
+        min: Option<f64>,
+        max: Option<f64>,
+    },
+    Bool(bool),
+    Text {
+        value: String,
+        focused: bool,
+    },
+    Enum(String),
+    Color(Color),
+    Collection {
+        len: usize,
+        can_push: bool,
+    },
+    ReadOnly(String),
+}
+
+impl Widget {
+    /// The text displayed as the value of the row.
+    fn text(&self) -> Option<String> {
+        match self {
+            Widget::Header | Widget::Color(_) => None,
+            Widget::Number { value, .. } | Widget::Enum(value) | Widget::ReadOnly(value) => {
+                Some(value.clone())
+            }
+            Widget::Bool(value) => Some(value.to_string()),
+            Widget::Text { value, focused } => Some(match focused {
+                true => format!("\"{value}_\""),
+                false => format!("\"{value}\""),
+            }),
+            Widget::Collection { len, .. } => Some(match len {
+                1 => "1 item".to_string(),
+                len => format!("{len} items"),
+            }),
+        }
+    }
+}
+
+/// An edit made by pressing an inspector button.
+#[derive(Clone)]
+enum Edit {
+    Add {
+        delta: f64,
+        min: Option<f64>,
+        max: Option<f64>,
+    },
+    Toggle,
+    CycleVariant(isize),
+    Push,
+    Remove(Element),
+    Focus,
+}
+
+/// A button of an [`Inspector`] that edits a field when pressed.
+#[derive(Component, Clone)]
+struct InspectorEdit {
+    inspector: Entity,
+    field: FieldRef,
+    edit: Edit,
+}
+
+/// Builds the rows of an inspector by walking through reflected values.
+struct RowBuilder<'a> {
+    registry: &'a TypeRegistry,
+    max_depth: usize,
+    focus: Option<&'a FieldRef>,
+    root: TypeId,
+    accesses: Vec<Access>,
+    display: String,
+    editable: bool,
+    rows: Vec<Row>,
+}
+
+impl RowBuilder<'_> {
+    fn field(&self) -> Option<FieldRef> {
+        self.editable.then(|| FieldRef {
+            root: self.root,
+            accesses: self
+                .accesses
+                .iter()
+                .map(|access| match access {
+                    Access::Path(path) => Access::Path(path.clone()),
+                    Access::Key(key) => Access::Key(key.to_dynamic()),
+                })
+                .collect(),
+            display: self.display.as_str().into(),
+        })
+    }
+
+    fn push(&mut self, depth: usize, label: String, widget: Widget) {
+        let field = self.field();
+        self.rows.push(Row {
+            depth,
+            label,
+            widget,
+            field,
+            removable: None,
+        });
+    }
+
+    /// Walks into the field reached by `access`, which is displayed as `display`.
+    fn child(&mut self, access: Access, display: &str, f: impl FnOnce(&mut Self)) {
+        let len = self.display.len();
+        self.display.push_str(display);
+        self.accesses.push(access);
+        f(self);
+        self.accesses.pop();
+        self.display.truncate(len);
+    }
+
+    fn walk(
+        &mut self,
+        value: &dyn PartialReflect,
+        label: String,
+        depth: usize,
+        attributes: Option<&CustomAttributes>,
+    ) {
+        if depth > self.max_depth {
+            return;
+        }
+
+        if let Some(widget) = self.primitive_widget(value, attributes) {
+            self.push(depth, label, widget);
+            return;
+        }
+
+        let widget = match value.reflect_ref() {
+            _ if value.represents::<Color>() => {
+                Widget::Color(Color::from_reflect(value).unwrap_or_default())
+            }
+            ReflectRef::Struct(_) | ReflectRef::TupleStruct(_) | ReflectRef::Tuple(_) => {
+                Widget::Header
+            }
+            ReflectRef::List(list) => Widget::Collection {
+                len: list.len(),
+                can_push: list
+                    .get_represented_list_info()
+                    .is_some_and(|info| self.has_default(info.item_ty().id())),
+            },
+            ReflectRef::Array(array) => Widget::Collection {
+                len: array.len(),
+                can_push: false,
+            },
+            ReflectRef::Map(map) => Widget::Collection {
+                len: map.len(),
+                can_push: default_entry(map, self.registry)
+                    .is_some_and(|(key, _)| map.get(key.as_partial_reflect()).is_none()),
+            },
+            ReflectRef::Set(set) => Widget::Collection {
+                len: set.len(),
+                can_push: false,
+            },
+            ReflectRef::Enum(value) => Widget::Enum(value.variant_name().to_string()),
+            _ => Widget::ReadOnly(truncate(format!("{value:?}"))),
+        };
+        self.push(depth, label, widget);
+
+        let depth = depth + 1;
+        let type_info = value.get_represented_type_info();
+        match value.reflect_ref() {
+            ReflectRef::Struct(value) => {
+                for (index, field) in value.iter_fields().enumerate() {
+                    let name = value.name_at(index).unwrap_or_default();
+                    let attributes = match type_info {
+                        Some(TypeInfo::Struct(info)) => {
+                            info.field_at(index).map(NamedField::custom_attributes)
+                        }
+                        _ => None,
+                    };
+                    let path = format!(".{name}");
+                    self.child(Access::Path(path.clone()), &path, |builder| {
+                        builder.walk(field, name.to_string(), depth, attributes);
+                    });
+                }
+            }
+            ReflectRef::TupleStruct(value) => {
+                for (index, field) in value.iter_fields().enumerate() {
+                    let attributes = match type_info {
+                        Some(TypeInfo::TupleStruct(info)) => {
+                            info.field_at(index).map(UnnamedField::custom_attributes)
+                        }
+                        _ => None,
+                    };
+                    let path = format!(".{index}");
+                    self.child(Access::Path(path.clone()), &path, |builder| {
+                        builder.walk(field, index.to_string(), depth, attributes);
+                    });
+                }
+            }
+            ReflectRef::Tuple(value) => {
+                for (index, field) in value.iter_fields().enumerate() {
+                    let path = format!(".{index}");
+                    self.child(Access::Path(path.clone()), &path, |builder| {
+                        builder.walk(field, index.to_string(), depth, None);
+                    });
+                }
+            }
+            ReflectRef::List(value) => {
+                let list = self.field();
+                for (index, element) in value.iter().enumerate() {
+                    let path = format!("[{index}]");
+                    let row = self.rows.len();
+                    self.child(Access::Path(path.clone()), &path, |builder| {
+                        builder.walk(element, path.clone(), depth, None);
+                    });
+                    if let (Some(list), Some(row)) = (&list, self.rows.get_mut(row)) {
+                        row.removable = Some((list.clone(), Element::Index(index)));
+                    }
+                }
+            }
+            ReflectRef::Array(value) => {
+                for (index, element) in value.iter().enumerate() {
+                    let path = format!("[{index}]");
+                    self.child(Access::Path(path.clone()), &path, |builder| {
+                        builder.walk(element, path.clone(), depth, None);
+                    });
+                }
+            }
+            ReflectRef::Map(value) => {
+                let map = self.field();
+                for (key, value) in value.iter() {
+                    let label = truncate(format!("{key:?}"));
+                    let display = format!("[{label}]");
+                    let row = self.rows.len();
+                    self.child(Access::Key(key.to_dynamic()), &display, |builder| {
+                        builder.walk(value, label, depth, None);
+                    });
+                    if let (Some(map), Some(row)) = (&map, self.rows.get_mut(row)) {
+                        row.removable = Some((map.clone(), Element::Key(key.to_dynamic().into())));
+                    }
+                }
+            }
+            ReflectRef::Set(value) => {
+                // Set elements can't be addressed, so they are read-only
+                let editable = core::mem::replace(&mut self.editable, false);
+                for element in value.iter() {
+                    self.walk(element, "-".to_string(), depth, None);
+                }
+                self.editable = editable;
+            }
+            ReflectRef::Enum(value) => {
+                for (index, field) in value.iter_fields().enumerate() {
+                    let (path, label) = match field.name() {
+                        Some(name) => (format!(".{name}"), name.to_string()),
+                        None => (format!(".{index}"), index.to_string()),
+                    };
+                    self.child(Access::Path(path.clone()), &path, |builder| {
+                        builder.walk(field.value(), label, depth, None);
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns the widget for numbers, booleans and strings.
+    fn primitive_widget(
+        &self,
+        value: &dyn PartialReflect,
+        attributes: Option<&CustomAttributes>,
+    ) -> Option<Widget> {
+        macro_rules! number {
+            ($step:expr, $($ty:ty),*) => {
+                $(
+                    if let Some(number) = value.try_downcast_ref::<$ty>() {
+                        let range = attributes.and_then(CustomAttributes::get::<Range>);
+                        let step = attributes
+                            .and_then(CustomAttributes::get::<Step>)
+                            .map_or($step, |step| step.0);
+                        return Some(Widget::Number {
+                            value: number.to_string(),
+                            step,
+                            min: range.and_then(|range| range.min),
+                            max: range.and_then(|range| range.max),
+                        });
+                    }
+                )*
+            };
+        }
+
+        number!(1.0, u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+        number!(0.1, f32, f64);
+
+        if let Some(value) = value.try_downcast_ref::<bool>() {
+            Some(Widget::Bool(*value))
+        } else if let Some(value) = value.try_downcast_ref::<String>() {
+            let focused = self
+                .focus
+                .is_some_and(|focus| focus.root == self.root && *focus.display == *self.display);
+            Some(Widget::Text {
+                value: value.clone(),
+                focused,
+            })
+        } else {
+            None
+        }
+    }
+
+    fn has_default(&self, type_id: TypeId) -> bool {
+        self.registry
+            .get_type_data::<ReflectDefault>(type_id)
+            .is_some()
+    }
+}
+
+fn truncate(mut text: String) -> String {
+    const MAX_LEN: usize = 48;
+    if let Some((index, _)) = text.char_indices().nth(MAX_LEN) {
+        text.truncate(index);
+        text.push('…');
+    }
+    text
+}
+
+/// Builds the rows of the given inspector target.
+fn build_rows(
+    world: &World,
+    registry: &TypeRegistry,
+    target: InspectorTarget,
+    focus: Option<&FieldRef>,
+    max_depth: usize,
+) -> Vec<Row> {
+    let mut builder = RowBuilder {
+        registry,
+        max_depth,
+        focus,
+        root: TypeId::of::<()>(),
+        accesses: Vec::new(),
+        display: String::new(),
+        editable: true,
+        rows: Vec::new(),
+    };
+
+    match target {
+        InspectorTarget::Entity(entity) => {
+            let Ok(entity_ref) = world.get_entity(entity) else {
+                return Vec::new();
+            };
+            let mut components = entity_ref
+                .archetype()
+                .components()
+                .filter_map(|component_id| {
+                    let info = world.components().get_info(component_id)?;
+                    let registration = registry.get(info.type_id()?)?;
+                    let value = registration
+                        .data::<ReflectComponent>()?
+                        .reflect(entity_ref)?;
+                    let name = registration.type_info().type_path_table().short_path();
+                    Some((name, registration.type_id(), value, info.mutable()))
+                })
+                .collect::<Vec<_>>();
+            components.sort_by_key(|(name, ..)| *name);
+
+            for (name, type_id, value, mutable) in components {
+                builder.root = type_id;
+                builder.display = name.to_string();
+                builder.editable = mutable;
+                builder.walk(value.as_partial_reflect(), name.to_string(), 0, None);
+            }
+        }
+        InspectorTarget::Resource(type_id) => {
+            let Some(registration) = registry.get(type_id) else {
+                return Vec::new();
+            };
+            let Some(value) = registration
+                .data::<ReflectResource>()
+                .and_then(|reflect_resource| reflect_resource.reflect(world).ok())
+            else {
+                return Vec::new();
+            };
+            let name = registration.type_info().type_path_table().short_path();
+            builder.root = type_id;
+            builder.display = name.to_string();
+            builder.walk(value.as_partial_reflect(), name.to_string(), 0, None);
+        }
+    }
+
+    builder.rows
+}
+
+/// Refreshes the rows of all inspectors.
+fn update_inspectors(
+    world: &mut World,
+    pressed: &mut QueryState<&Interaction, (Changed<Interaction>, With<InspectorEdit>)>,
+    new_inspectors: &mut QueryState<(), Changed<Inspector>>,
+    inspectors: &mut QueryState<Entity, With<Inspector>>,
+    mut time_since_refresh: Local<Duration>,
+) {
+    let Some(config) = world.get_resource::<InspectorConfig>().cloned() else {
+        return;
+    };
+    let Some(registry) = world.get_resource::<AppTypeRegistry>().cloned() else {
+        return;
+    };
+
+    // Refresh immediately after an edit or when a new inspector is spawned
+    *time_since_refresh += world
+        .get_resource::<Time>()
+        .map(Time::delta)
+        .unwrap_or_default();
+    let edited = world.resource_ref::<InspectorFocus>().is_changed()
+        || pressed
+            .iter(world)
+            .any(|interaction| *interaction == Interaction::Pressed);
+    if *time_since_refresh < config.refresh_interval
+        && !edited
+        && new_inspectors.iter(world).next().is_none()
+    {
+        return;
+    }
+    *time_since_refresh = Duration::ZERO;
+
+    let registry = registry.read();
+    let inspectors = inspectors.iter(world).collect::<Vec<_>>();
+    for inspector in inspectors {
+        let focus = world.resource::<InspectorFocus>().0.as_ref();
+        let focus = focus
+            .filter(|(focused, _)| *focused == inspector)
+            .map(|(_, field)| field);
+        let inspector_ref = world.get::<Inspector>(inspector).unwrap();
+        let rows = build_rows(
+            world,
+            &registry,
+            inspector_ref.target,
+            focus,
+            config.max_depth,
+        );
+
+        let same_layout = inspector_ref.rows.len() == rows.len()
+            && inspector_ref
+                .rows
+                .iter()
+                .zip(&rows)
+                .all(|(old, new)| old.same_layout(new));
+
+        if same_layout {
+            let changed = inspector_ref
+                .rows
+                .iter()
+                .zip(&rows)
+                .zip(&inspector_ref.value_nodes)
+                .filter(|((old, new), _)| old != new)
+                .filter_map(|((_, new), node)| Some((*node)?).zip(Some(new)))
+                .map(|(node, row)| match row.widget {
+                    Widget::Color(color) => (node, Err(color)),
+                    ref widget => (node, Ok(widget.text().unwrap_or_default())),
+                })
+                .collect::<Vec<_>>();
+            for (node, value) in changed {
+                match value {
+                    Ok(text) => {
+                        if let Some(mut node_text) = world.get_mut::<Text>(node) {
+                            node_text.0 = text;
+                        }
+                    }
+                    Err(color) => {
+                        if let Some(mut background) = world.get_mut::<BackgroundColor>(node) {
+                            background.set_if_neq(BackgroundColor(color));
+                        }
+                    }
+                }
+            }
+            let mut inspector = world.get_mut::<Inspector>(inspector).unwrap();
+            inspector.bypass_change_detection().rows = rows;
+        } else {
+            let mut value_nodes = Vec::with_capacity(rows.len());
+            let mut entity = world.entity_mut(inspector);
+            entity.despawn_related::<Children>();
+            entity.with_children(|parent| {
+                for row in &rows {
+                    value_nodes.push(spawn_row(parent, inspector, row, &config));
+                }
+            });
+            let mut inspector = world.get_mut::<Inspector>(inspector).unwrap();
+            let inspector = inspector.bypass_change_detection();
+            inspector.rows = rows;
+            inspector.value_nodes = value_nodes;
+        }
+    }
+}
+
+/// Spawns the UI nodes of a row, returning the node displaying its value.
+fn spawn_row(
+    parent: &mut ChildSpawner,
+    inspector: Entity,
+    row: &Row,
+    config: &InspectorConfig,
+) -> Option<Entity> {
+    let text =
+        |text: String, color: Color| (Text::new(text), config.text_font.clone(), TextColor(color));
+    // Returns the entity of the text of the button
+    let button = |parent: &mut ChildSpawner, label: String, field: &FieldRef, edit: Edit| {
+        let mut label_node = Entity::PLACEHOLDER;
+        parent
+            .spawn((
+                Button,
+                Node {
+                    padding: UiRect::horizontal(Val::Px(4.0)),
+                    ..Default::default()
+                },
+                BackgroundColor(config.button_color),
+                InspectorEdit {
+                    inspector,
+                    field: field.clone(),
+                    edit,
+                },
+            ))
+            .with_children(|parent| {
+                label_node = parent.spawn(text(label, config.value_color)).id();
+            });
+        label_node
+    };
+
+    let indent = match config.indent {
+        Val::Px(indent) => Val::Px(indent * row.depth as f32),
+        Val::Percent(indent) => Val::Percent(indent * row.depth as f32),
+        indent => indent,
+    };
+    let mut value_node = None;
+    parent
+        .spawn(Node {
+            flex_direction: FlexDirection::Row,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(6.0),
+            padding: UiRect::left(indent),
+            ..Default::default()
+        })
+        .with_children(|parent| {
+            parent.spawn(text(row.label.clone(), config.label_color));
+
+            let field = row.field.as_ref();
+            match (&row.widget, field) {
+                (Widget::Header, _) => {}
+                (Widget::Color(color), _) => {
+                    value_node = Some(
+                        parent
+                            .spawn((
+                                Node {
+                                    width: Val::Px(24.0),
+                                    height: Val::Px(12.0),
+                                    ..Default::default()
+                                },
+                                BackgroundColor(*color),
+                            ))
+                            .id(),
+                    );
+                }
+                (Widget::Number { step, min, max, .. }, Some(field)) => {
+                    button(
+                        parent,
+                        "-".to_string(),
+                        field,
+                        Edit::Add {
+                            delta: -step,
+                            min: *min,
+                            max: *max,
+                        },
+                    );
+                    value_node = Some(
+                        parent
+                            .spawn(text(
+                                row.widget.text().unwrap_or_default(),
+                                config.value_color,
+                            ))
+                            .id(),
+                    );
+                    button(
+                        parent,
+                        "+".to_string(),
+                        field,
+                        Edit::Add {
+                            delta: *step,
+                            min: *min,
+                            max: *max,
+                        },
+                    );
+                }
+                (Widget::Bool(_), Some(field)) | (Widget::Text { .. }, Some(field)) => {
+                    let edit = match row.widget {
+                        Widget::Bool(_) => Edit::Toggle,
+                        _ => Edit::Focus,
+                    };
+                    value_node = Some(button(
+                        parent,
+                        row.widget.text().unwrap_or_default(),
+                        field,
+                        edit,
+                    ));
+                }
+                (Widget::Enum(_), Some(field)) => {
+                    button(parent, "<".to_string(), field, Edit::CycleVariant(-1));
+                    value_node = Some(
+                        parent
+                            .spawn(text(
+                                row.widget.text().unwrap_or_default(),
+                                config.value_color,
+                            ))
+                            .id(),
+                    );
+                    button(parent, ">".to_string(), field, Edit::CycleVariant(1));
+                }
+                (Widget::Collection { can_push, .. }, field) => {
+                    value_node = Some(
+                        parent
+                            .spawn(text(
+                                row.widget.text().unwrap_or_default(),
+                                config.label_color,
+                            ))
+                            .id(),
+                    );
+                    if let (true, Some(field)) = (can_push, field) {
+                        button(parent, "+".to_string(), field, Edit::Push);
+                    }
+                }
+                (widget, _) => {
+                    value_node = Some(
+                        parent
+                            .spawn(text(widget.text().unwrap_or_default(), config.value_color))
+                            .id(),
+                    );
+                }
+            }
+
+            if let Some((collection, element)) = &row.removable {
+                button(
+                    parent,
+                    "x".to_string(),
+                    collection,
+                    Edit::Remove(element.clone()),
+                );
+            }
+        });
+
+    value_node
+}
+
+/// Applies the edits of the inspector buttons pressed this frame.
+fn apply_edits(
+    world: &mut World,
+    pressed: &mut QueryState<(&Interaction, &InspectorEdit), Changed<Interaction>>,
+) {
+    let edits = pressed
+        .iter(world)
+        .filter(|(interaction, _)| **interaction == Interaction::Pressed)
+        .map(|(_, edit)| edit.clone())
+        .collect::<Vec<_>>();
+
+    for InspectorEdit {
+        inspector,
+        field,
+        edit,
+    } in edits
+    {
+        if let Edit::Focus = edit {
+            world.resource_mut::<InspectorFocus>().0 = Some((inspector, field));
+            continue;
+        }
+        let Some(registry) = world.get_resource::<AppTypeRegistry>().cloned() else {
+            return;
+        };
+        let registry = registry.read();
+        edit_field(world, inspector, &field, |value| {
+            apply_edit(value, &edit, &registry);
+        });
+    }
+}
+
+/// Edits the focused string field with the keyboard.
+fn edit_focused_text(world: &mut World, keyboard: &mut SystemState<EventReader<KeyboardInput>>) {
+    let inputs = keyboard
+        .get_mut(world)
+        .read()
+        .filter(|input| input.state == ButtonState::Pressed)
+        .cloned()
+        .collect::<Vec<_>>();
+    let Some((inspector, field)) = world.resource::<InspectorFocus>().0.clone() else {
+        return;
+    };
+    if inputs.is_empty() {
+        return;
+    }
+
+    let mut unfocus = false;
+    let found = edit_field(world, inspector, &field, |value| {
+        let Some(text) = value.try_downcast_mut::<String>() else {
+            unfocus = true;
+            return;
+        };
+        for input in &inputs {
+            match &input.logical_key {
+                Key::Backspace => {
+                    text.pop();
+                }
+                Key::Enter | Key::Escape => unfocus = true,
+                _ => {
+                    if let Some(input_text) = &input.text {
+                        text.extend(input_text.chars().filter(|c| !c.is_control()));
+                    }
+                }
+            }
+        }
+    });
+
+    let mut focus = world.resource_mut::<InspectorFocus>();
+    if unfocus || !found {
+        focus.clear();
+    } else {
+        // Refresh the inspector to display the new text
+        focus.set_changed();
+    }
+}
+
+/// Calls `f` with the given field of the target of an inspector.
+///
+/// Returns `false` if the field doesn't exist anymore.
+fn edit_field(
+    world: &mut World,
+    inspector: Entity,
+    field: &FieldRef,
+    f: impl FnOnce(&mut dyn PartialReflect),
+) -> bool {
+    let Some(target) = world
+        .get::<Inspector>(inspector)
+        .map(|inspector| inspector.target)
+    else {
+        return false;
+    };
+    let Some(registry) = world.get_resource::<AppTypeRegistry>().cloned() else {
+        return false;
+    };
+    let registry = registry.read();
+    let Some(registration) = registry.get(field.root) else {
+        return false;
+    };
+
+    let root = match target {
+        InspectorTarget::Entity(entity) => {
+            let (Some(reflect_component), Ok(entity)) = (
+                registration.data::<ReflectComponent>(),
+                world.get_entity_mut(entity),
+            ) else {
+                return false;
+            };
+            reflect_component.reflect_mut(entity)
+        }
+        InspectorTarget::Resource(_) => registration
+            .data::<ReflectResource>()
+            .and_then(|reflect_resource| reflect_resource.reflect_mut(world).ok()),
+    };
+    let Some(mut root) = root else {
+        return false;
+    };
+
+    match resolve(root.as_partial_reflect_mut(), &field.accesses) {
+        Some(value) => {
+            f(value);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Follows the given accesses from `value`.
+fn resolve<'a>(
+    mut value: &'a mut dyn PartialReflect,
+    accesses: &[Access],
+) -> Option<&'a mut dyn PartialReflect> {
+    for access in accesses {
+        value = match access {
+            Access::Path(path) => path.as_str().reflect_element_mut(value).ok()?,
+            Access::Key(key) => match value.reflect_mut() {
+                ReflectMut::Map(map) => map.get_mut(key.as_ref())?,
+                _ => return None,
+            },
+        };
+    }
+    Some(value)
+}
+
+/// Applies an edit to a value.
+fn apply_edit(value: &mut dyn PartialReflect, edit: &Edit, registry: &TypeRegistry) {
+    match *edit {
+        Edit::Add { delta, min, max } => add(value, delta, min, max),
+        Edit::Toggle => {
+            if let Some(value) = value.try_downcast_mut::<bool>() {
+                *value = !*value;
+            }
+        }
+        Edit::CycleVariant(offset) => {
+            if let Some(variant) = next_variant(value, offset, registry) {
+                // The new variant has the same type, so this can't fail
+                let _ = value.try_apply(&variant);
+            }
+        }
+        Edit::Push => match value.reflect_mut() {
+            ReflectMut::List(list) => {
+                let element = list
+                    .get_represented_list_info()
+                    .and_then(|info| registry.get_type_data::<ReflectDefault>(info.item_ty().id()))
+                    .map(ReflectDefault::default);
+                if let Some(element) = element {
+                    list.push(element.into_partial_reflect());
+                }
+            }
+            ReflectMut::Map(map) => {
+                if let Some((key, value)) = default_entry(map, registry) {
+                    // Don't reset the value of an existing entry
+                    if map.get(key.as_partial_reflect()).is_none() {
+                        map.insert_boxed(key.into_partial_reflect(), value.into_partial_reflect());
+                    }
+                }
+            }
+            _ => {}
+        },
+        Edit::Remove(ref element) => match (value.reflect_mut(), element) {
+            (ReflectMut::List(list), Element::Index(index)) if *index < list.len() => {
+                list.remove(*index);
+            }
+            (ReflectMut::Map(map), Element::Key(key)) => {
+                map.remove(key.as_ref());
+            }
+            _ => {}
+        },
+        Edit::Focus => {}
+    }
+}
+
+/// Adds `delta` to a number, clamping it to the given range and to the bounds of its type.
+fn add(value: &mut dyn PartialReflect, delta: f64, min: Option<f64>, max: Option<f64>) {
+    macro_rules! float {
+        ($($ty:ty),*) => {
+            $(
+                if let Some(value) = value.try_downcast_mut::<$ty>() {
+                    let mut new = *value as f64 + delta;
+                    if let Some(min) = min {
+                        new = new.max(min);
+                    }
+                    if let Some(max) = max {
+                        new = new.min(max);
+                    }
+                    *value = new as $ty;
+                    return;
+                }
+            )*
+        };
+    }
+    macro_rules! int {
+        ($($ty:ty),*) => {
+            $(
+                if let Some(value) = value.try_downcast_mut::<$ty>() {
+                    let (type_min, type_max) = (<$ty>::MIN as i128, <$ty>::MAX as i128);
+                    let min = min.map_or(type_min, |min| (min.ceil() as i128).clamp(type_min, type_max));
+                    let max = max.map_or(type_max, |max| (max.floor() as i128).clamp(type_min, type_max));
+                    // No integer lies within inverted bounds, such as those of `Range::new(0.2, 0.8)`
+                    if min <= max {
+                        let new = (*value as i128)
+                            .saturating_add(delta.round() as i128)
+                            .clamp(min, max);
+                        *value = new as $ty;
+                    }
+                    return;
+                }
+            )*
+        };
+    }
+
+    float!(f32, f64);
+    int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+}
+
+/// Returns the default key and value of a map, if both types register [`ReflectDefault`].
+fn default_entry(
+    map: &dyn Map,
+    registry: &TypeRegistry,
+) -> Option<(Box<dyn Reflect>, Box<dyn Reflect>)> {
+    let info = map.get_represented_map_info()?;
+    let default = |type_id| {
+        registry
+            .get_type_data::<ReflectDefault>(type_id)
+            .map(ReflectDefault::default)
+    };
+    Some((default(info.key_ty().id())?, default(info.value_ty().id())?))
+}
+
+/// Returns the variant `offset` variants away from the current variant of an enum,
+/// with the default values of its fields.
+///
+/// Returns `None` if a field doesn't register [`ReflectDefault`].
+fn next_variant(
+    value: &dyn PartialReflect,
+    offset: isize,
+    registry: &TypeRegistry,
+) -> Option<DynamicEnum> {
+    let (ReflectRef::Enum(value), Some(TypeInfo::Enum(info))) =
+        (value.reflect_ref(), value.get_represented_type_info())
+    else {
+        return None;
+    };
+    let index = (value.variant_index() as isize + offset).rem_euclid(info.variant_len() as isize);
+    let default = |type_id| {
+        registry
+            .get_type_data::<ReflectDefault>(type_id)
+            .map(|default| default.default().into_partial_reflect())
+    };
+
+    let variant = match info.variant_at(index as usize)? {
+        VariantInfo::Unit(_) => DynamicVariant::Unit,
+        VariantInfo::Tuple(variant) => {
+            let mut fields = DynamicTuple::default();
+            for field in variant.iter() {
+                fields.insert_boxed(default(field.type_id())?);
+            }
+            DynamicVariant::Tuple(fields)
+        }
+        VariantInfo::Struct(variant) => {
+            let mut fields = DynamicStruct::default();
+            for field in variant.iter() {
+                fields.insert_boxed(field.name(), default(field.type_id())?);
+            }
+            DynamicVariant::Struct(fields)
+        }
+    };
+    let mut variant = DynamicEnum::new_with_index(
+        index as usize,
+        info.variant_names()[index as usize],
+        variant,
+    );
+    variant.set_represented_type(Some(value.get_represented_type_info()?));
+    Some(variant)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::collections::BTreeMap;
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Player {
+        name: String,
+        #[reflect(@Range::new(0.0, 100.0), @Step(10.0))]
+        health: f32,
+        alive: bool,
+        state: State,
+        inventory: Vec<u32>,
+        scores: BTreeMap<String, u32>,
+    }
+
+    #[derive(Reflect, Default, PartialEq, Debug)]
+    #[reflect(Default)]
+    enum State {
+        #[default]
+        Idle,
+        Walking(f32),
+    }
+
+    fn setup() -> (World, Entity, Entity) {
+        let mut world = World::new();
+        let registry = AppTypeRegistry::default();
+        registry.write().register::<Player>();
+        world.insert_resource(registry);
+        let player = world
+            .spawn(Player {
+                health: 95.0,
+                ..Default::default()
+            })
+            .id();
+        let inspector = world.spawn(Inspector::entity(player)).id();
+        (world, player, inspector)
+    }
+
+    fn edit(world: &mut World, inspector: Entity, label: &str, edit: Edit) {
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+        let target = world.get::<Inspector>(inspector).unwrap().target;
+        let rows = build_rows(world, &registry, target, None, 8);
+        let row = rows.iter().find(|row| row.label == label).unwrap();
+        let field = match edit {
+            Edit::Remove(_) => row.removable.as_ref().unwrap().0.clone(),
+            _ => row.field.clone().unwrap(),
+        };
+        assert!(edit_field(world, inspector, &field, |value| {
+            apply_edit(value, &edit, &registry);
+        }));
+    }
+
+    #[test]
+    fn build_rows_for_entity() {
+        let (world, player, _) = setup();
+        let registry = world.resource::<AppTypeRegistry>().read();
+        let rows = build_rows(&world, &registry, InspectorTarget::Entity(player), None, 8);
+
+        let labels = rows
+            .iter()
+            .map(|row| (row.depth, row.label.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            labels,
+            [
+                (0, "Player"),
+                (1, "name"),
+                (1, "health"),
+                (1, "alive"),
+                (1, "state"),
+                (1, "inventory"),
+                (1, "scores"),
+            ]
+        );
+        assert!(matches!(
+            rows[2].widget,
+            Widget::Number {
+                step: 10.0,
+                min: Some(0.0),
+                max: Some(100.0),
+                ..
+            }
+        ));
+        assert_eq!(rows[4].widget, Widget::Enum("Idle".to_string()));
+    }
+
+    #[test]
+    fn apply_edits_through_reflection() {
+        let (mut world, player, inspector) = setup();
+
+        edit(
+            &mut world,
+            inspector,
+            "health",
+            Edit::Add {
+                delta: 10.0,
+                min: Some(0.0),
+                max: Some(100.0),
+            },
+        );
+        edit(&mut world, inspector, "alive", Edit::Toggle);
+        edit(&mut world, inspector, "state", Edit::CycleVariant(1));
+        edit(&mut world, inspector, "inventory", Edit::Push);
+        edit(&mut world, inspector, "inventory", Edit::Push);
+        edit(
+            &mut world,
+            inspector,
+            "[0]",
+            Edit::Add {
+                delta: -1.0,
+                min: None,
+                max: None,
+            },
+        );
+        edit(
+            &mut world,
+            inspector,
+            "[1]",
+            Edit::Remove(Element::Index(1)),
+        );
+        edit(&mut world, inspector, "scores", Edit::Push);
+        edit(
+            &mut world,
+            inspector,
+            "\"\"",
+            Edit::Add {
+                delta: 1.0,
+                min: None,
+                max: None,
+            },
+        );
+
+        let player_ref = world.get::<Player>(player).unwrap();
+        assert_eq!(player_ref.health, 100.0);
+        assert!(player_ref.alive);
+        assert_eq!(player_ref.state, State::Walking(0.0));
+        // Unsigned values saturate at zero
+        assert_eq!(player_ref.inventory, [0]);
+        assert_eq!(player_ref.scores, BTreeMap::from([(String::new(), 1)]));
+
+        let key: Arc<dyn PartialReflect> = Arc::new(String::new());
+        edit(
+            &mut world,
+            inspector,
+            "\"\"",
+            Edit::Remove(Element::Key(key)),
+        );
+        assert!(world.get::<Player>(player).unwrap().scores.is_empty());
+    }
+
+    #[test]
+    fn add_clamps_to_the_bounds_of_the_type() {
+        let mut value = 5_u8;
+        // No integer lies within the range, so the value is kept
+        add(&mut value, 1.0, Some(0.2), Some(0.8));
+        assert_eq!(value, 5);
+        add(&mut value, 1.0, Some(300.0), None);
+        assert_eq!(value, u8::MAX);
+        add(&mut value, -1.0, None, Some(-5.0));
+        assert_eq!(value, 0);
+    }
+
+    #[test]
+    fn inspector_applies_pressed_buttons() {
+        let mut app = App::new();
+        app.add_event::<KeyboardInput>()
+            .register_type::<Player>()
+            .add_plugins(InspectorPlugin::default());
+        let player = app
+            .world_mut()
+            .spawn(Player {
+                health: 95.0,
+                ..Default::default()
+            })
+            .id();
+        let inspector = app.world_mut().spawn(Inspector::entity(player)).id();
+        app.update();
+
+        let world = app.world_mut();
+        let rows = world.get::<Inspector>(inspector).unwrap().rows.len();
+        assert_eq!(world.get::<Children>(inspector).unwrap().len(), rows);
+
+        // Press the "+" button of the health row
+        let button = world
+            .query::<(Entity, &InspectorEdit)>()
+            .iter(world)
+            .find(|(_, button)| {
+                &*button.field.display == "Player.health"
+                    && matches!(button.edit, Edit::Add { delta, .. } if delta > 0.0)
+            })
+            .map(|(entity, _)| entity)
+            .unwrap();
+        *world.get_mut::<Interaction>(button).unwrap() = Interaction::Pressed;
+        app.update();
+
+        let world = app.world();
+        assert_eq!(world.get::<Player>(player).unwrap().health, 100.0);
+        let value_node = world.get::<Inspector>(inspector).unwrap().value_nodes[2].unwrap();
+        assert_eq!(world.get::<Text>(value_node).unwrap().0, "100");
+    }
+}
@@ -8,13 +8,20 @@
 //! This crate provides additional utilities for the [Bevy game engine](https://bevyengine.org),
 //! focused on improving developer experience.
 
+extern crate alloc;
+
 use bevy_app::prelude::*;
 
 #[cfg(feature = "bevy_ci_testing")]
 pub mod ci_testing;
 
+#[cfg(feature = "console")]
+pub mod console;
+
 pub mod fps_overlay;
 
+pub mod inspector;
+
 pub mod picking_debug;
 
 pub mod states;
@@ -0,0 +1,236 @@
+//! Definitions for [`Event`] reflection.
+//!
+//! This allows sending, triggering and reading events whose types are only known at runtime,
+//! for example by tools that connect to a running app.
+//!
+//! Add `#[reflect(Event)]` to your `#[derive(Reflect)]` to make the [`ReflectEvent`] type data
+//! available in the [`TypeRegistry`].
+
+use crate::{
+    entity::Entity,
+    event::{Event, Events},
+    world::World,
+};
+use alloc::vec::Vec;
+use bevy_reflect::{FromReflect, FromType, PartialReflect, Reflect, TypePath, TypeRegistry};
+
+use super::from_reflect_with_fallback;
+
+/// A struct used to operate on reflected [`Event`]s of a type.
+///
+/// A [`ReflectEvent`] for type `T` can be obtained via
+/// [`bevy_reflect::TypeRegistration::data`].
+#[derive(Clone)]
+pub struct ReflectEvent(ReflectEventFns);
+
+/// The raw function pointers needed to make up a [`ReflectEvent`].
+///
+/// This is used when creating custom implementations of [`ReflectEvent`] with
+/// [`ReflectEvent::new()`].
+///
+/// > **Note:**
+/// > Creating custom implementations of [`ReflectEvent`] is an advanced feature that most users
+/// > will not need.
+/// > Usually a [`ReflectEvent`] is created for a type by deriving [`Reflect`]
+/// > and adding the `#[reflect(Event)]` attribute.
+/// > After adding the event to the [`TypeRegistry`],
+/// > its [`ReflectEvent`] can then be retrieved when needed.
+#[derive(Clone)]
+pub struct ReflectEventFns {
+    /// Function pointer implementing [`ReflectEvent::send()`].
+    pub send: fn(&mut World, &dyn PartialReflect, &TypeRegistry) -> bool,
+    /// Function pointer implementing [`ReflectEvent::trigger()`].
+    pub trigger: fn(&mut World, &dyn PartialReflect, &[Entity], &TypeRegistry),
+    /// Function pointer implementing [`ReflectEvent::event_count()`].
+    pub event_count: fn(&World) -> Option<usize>,
+    /// Function pointer implementing [`ReflectEvent::get()`].
+    pub get: for<'w> fn(&'w World, usize) -> Option<&'w dyn Reflect>,
+}
+
+impl ReflectEventFns {
+    /// Get the default set of [`ReflectEventFns`] for a specific event type using its
+    /// [`FromType`] implementation.
+    ///
+    /// This is useful if you want to start with the default implementation before overriding some
+    /// of the functions to create a custom implementation.
+    pub fn new<T: Event + FromReflect + TypePath>() -> Self {
+        <ReflectEvent as FromType<T>>::from_type().0
+    }
+}
+
+impl ReflectEvent {
+    /// Sends a reflected [`Event`] like [`send_event()`](World::send_event), to be read by
+    /// [`EventReader`](crate::event::EventReader)s.
+    ///
+    /// Returns `false` if the event couldn't be sent because the [`Events`] resource of the event
+    /// type doesn't exist.
+    pub fn send(
+        &self,
+        world: &mut World,
+        event: &dyn PartialReflect,
+        registry: &TypeRegistry,
+    ) -> bool {
+        (self.0.send)(world, event, registry)
+    }
+
+    /// Triggers a reflected [`Event`] like [`trigger_targets()`](World::trigger_targets), running
+    /// the observers watching for it.
+    ///
+    /// If `targets` is empty, the event is triggered without targets like
+    /// [`trigger()`](World::trigger).
+    pub fn trigger(
+        &self,
+        world: &mut World,
+        event: &dyn PartialReflect,
+        targets: &[Entity],
+        registry: &TypeRegistry,
+    ) {
+        (self.0.trigger)(world, event, targets, registry);
+    }
+
+    /// Returns the total number of events of this type that were sent to the world, or `None` if
+    /// the [`Events`] resource of the event type doesn't exist.
+    ///
+    /// The count is also the id of the next event that is sent, see [`ReflectEvent::get`].
+    pub fn event_count(&self, world: &World) -> Option<usize> {
+        (self.0.event_count)(world)
+    }
+
+    /// Gets the event with the given id as a reflected reference, if it's still in the
+    /// [`Events`] buffer.
+    ///
+    /// Events are numbered in the order they are sent, starting at `0`.
+    pub fn get<'w>(&self, world: &'w World, id: usize) -> Option<&'w dyn Reflect> {
+        (self.0.get)(world, id)
+    }
+
+    /// Create a custom implementation of [`ReflectEvent`].
+    ///
+    /// This is an advanced feature,
+    /// useful for scripting implementations,
+    /// that should not be used by most users
+    /// unless you know what you are doing.
+    ///
+    /// Usually you should derive [`Reflect`] and add the `#[reflect(Event)]` attribute
+    /// to generate a [`ReflectEvent`] implementation automatically.
+    ///
+    /// See [`ReflectEventFns`] for more information.
+    pub fn new(fns: ReflectEventFns) -> Self {
+        Self(fns)
+    }
+
+    /// The underlying function pointers implementing methods on `ReflectEvent`.
+    ///
+    /// This is useful when you want to keep track locally of an individual
+    /// function pointer.
+    pub fn fn_pointers(&self) -> &ReflectEventFns {
+        &self.0
+    }
+}
+
+impl<E: Event + FromReflect + TypePath> FromType<E> for ReflectEvent {
+    fn from_type() -> Self {
+        ReflectEvent(ReflectEventFns {
+            send: |world, reflected_event, registry| {
+                let event = from_reflect_with_fallback::<E>(reflected_event, world, registry);
+                world.send_event(event).is_some()
+            },
+            trigger: |world, reflected_event, targets, registry| {
+                let event = from_reflect_with_fallback::<E>(reflected_event, world, registry);
+                if targets.is_empty() {
+                    world.trigger(event);
+                } else {
+                    world.trigger_targets(event, Vec::from(targets));
+                }
+            },
+            event_count: |world| {
+                world
+                    .get_resource::<Events<E>>()
+                    .map(|events| events.event_count)
+            },
+            get: |world, id| {
+                let (event, _) = world.get_resource::<Events<E>>()?.get_event(id)?;
+                Some(event)
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        event::{Event, Events},
+        observer::Trigger,
+        prelude::Resource,
+        reflect::{AppTypeRegistry, ReflectEvent},
+        system::ResMut,
+        world::World,
+    };
+    use bevy_reflect::{DynamicStruct, Reflect};
+
+    #[derive(Event, Reflect, Debug, PartialEq)]
+    #[reflect(Event)]
+    struct Damage {
+        amount: u32,
+    }
+
+    #[derive(Resource, Default)]
+    struct TotalDamage(u32);
+
+    fn reflect_event(world: &World) -> ReflectEvent {
+        let registry = world.resource::<AppTypeRegistry>().read();
+        registry
+            .get_type_data::<ReflectEvent>(core::any::TypeId::of::<Damage>())
+            .unwrap()
+            .clone()
+    }
+
+    fn create_world() -> World {
+        let mut world = World::new();
+        let registry = AppTypeRegistry::default();
+        registry.write().register::<Damage>();
+        world.insert_resource(registry);
+        world
+    }
+
+    #[test]
+    fn send_and_read_reflected_events() {
+        let mut world = create_world();
+        let reflect_event = reflect_event(&world);
+        let registry = world.resource::<AppTypeRegistry>().clone();
+
+        let mut value = DynamicStruct::default();
+        value.insert("amount", 3u32);
+        assert!(!reflect_event.send(&mut world, &value, &registry.read()));
+
+        world.init_resource::<Events<Damage>>();
+        assert_eq!(reflect_event.event_count(&world), Some(0));
+        assert!(reflect_event.send(&mut world, &value, &registry.read()));
+        assert_eq!(reflect_event.event_count(&world), Some(1));
+
+        let event = reflect_event.get(&world, 0).unwrap();
+        assert_eq!(event.downcast_ref::<Damage>(), Some(&Damage { amount: 3 }));
+        assert!(reflect_event.get(&world, 1).is_none());
+    }
+
+    #[test]
+    fn trigger_reflected_events() {
+        let mut world = create_world();
+        let reflect_event = reflect_event(&world);
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        world.init_resource::<TotalDamage>();
+        world.add_observer(|trigger: Trigger<Damage>, mut total: ResMut<TotalDamage>| {
+            total.0 += trigger.event().amount;
+        });
+
+        let target = world.spawn_empty().id();
+        reflect_event.trigger(&mut world, &Damage { amount: 2 }, &[], &registry.read());
+        reflect_event.trigger(
+            &mut world,
+            &Damage { amount: 5 },
+            &[target],
+            &registry.read(),
+        );
+        assert_eq!(world.resource::<TotalDamage>().0, 7);
+    }
+}
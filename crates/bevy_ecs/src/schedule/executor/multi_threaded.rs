@@ -181,7 +181,7 @@ impl SystemExecutor for MultiThreadedExecutor {
         &mut self,
         schedule: &mut SystemSchedule,
         world: &mut World,
-        _skip_systems: Option<&FixedBitSet>,
+        skip_systems: Option<&FixedBitSet>,
         error_handler: fn(BevyError, ErrorContext),
     ) {
         let state = self.state.get_mut().unwrap();
@@ -195,10 +195,9 @@ impl SystemExecutor for MultiThreadedExecutor {
             .clone_from(&schedule.system_dependencies);
         state.ready_systems.clone_from(&self.starting_systems);
 
-        // If stepping is enabled, make sure we skip those systems that should
-        // not be run.
-        #[cfg(feature = "bevy_debug_stepping")]
-        if let Some(skipped_systems) = _skip_systems {
+        // Skip the systems that were disabled or that should not be run while
+        // stepping.
+        if let Some(skipped_systems) = skip_systems {
             debug_assert_eq!(skipped_systems.len(), state.completed_systems.len());
             // mark skipped systems as completed
             state.completed_systems |= skipped_systems;
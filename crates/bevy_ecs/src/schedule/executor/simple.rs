@@ -43,13 +43,12 @@ impl SystemExecutor for SimpleExecutor {
         &mut self,
         schedule: &mut SystemSchedule,
         world: &mut World,
-        _skip_systems: Option<&FixedBitSet>,
+        skip_systems: Option<&FixedBitSet>,
         error_handler: fn(BevyError, ErrorContext),
     ) {
-        // If stepping is enabled, make sure we skip those systems that should
-        // not be run.
-        #[cfg(feature = "bevy_debug_stepping")]
-        if let Some(skipped_systems) = _skip_systems {
+        // Skip the systems that were disabled or that should not be run while
+        // stepping.
+        if let Some(skipped_systems) = skip_systems {
             // mark skipped systems as completed
             self.completed_systems |= skipped_systems;
         }
@@ -297,6 +297,7 @@ pub struct Schedule {
     executable: SystemSchedule,
     executor: Box<dyn SystemExecutor>,
     executor_initialized: bool,
+    disabled_systems: HashSet<NodeId>,
 }
 
 #[derive(ScheduleLabel, Hash, PartialEq, Eq, Debug, Clone)]
@@ -321,6 +322,7 @@ impl Schedule {
             executable: SystemSchedule::new(),
             executor: make_executor(ExecutorKind::default()),
             executor_initialized: false,
+            disabled_systems: HashSet::default(),
         };
         // Call `set_build_settings` to add any default build passes
         this.set_build_settings(Default::default());
@@ -443,24 +445,62 @@ impl Schedule {
 
         let error_handler = default_error_handler();
 
-        #[cfg(not(feature = "bevy_debug_stepping"))]
-        self.executor
-            .run(&mut self.executable, world, None, error_handler);
+        #[cfg_attr(
+            not(feature = "bevy_debug_stepping"),
+            expect(unused_mut, reason = "only stepping adds to the skipped systems")
+        )]
+        let mut skip_systems = self.disabled_system_indices();
 
         #[cfg(feature = "bevy_debug_stepping")]
+        if let Some(stepping_skipped) = world
+            .get_resource_mut::<Stepping>()
+            .and_then(|mut stepping| stepping.skipped_systems(self))
         {
-            let skip_systems = match world.get_resource_mut::<Stepping>() {
-                None => None,
-                Some(mut stepping) => stepping.skipped_systems(self),
-            };
+            match &mut skip_systems {
+                Some(skip_systems) => skip_systems.union_with(&stepping_skipped),
+                None => skip_systems = Some(stepping_skipped),
+            }
+        }
 
-            self.executor.run(
-                &mut self.executable,
-                world,
-                skip_systems.as_ref(),
-                error_handler,
-            );
+        self.executor.run(
+            &mut self.executable,
+            world,
+            skip_systems.as_ref(),
+            error_handler,
+        );
+    }
+
+    /// Enables or disables the system with the given [`NodeId`].
+    ///
+    /// Disabled systems are skipped when the schedule runs, as if their run conditions were not met.
+    /// The ids of the systems in the schedule can be found with [`Schedule::systems`].
+    pub fn set_system_enabled(&mut self, system: NodeId, enabled: bool) -> &mut Self {
+        if enabled {
+            self.disabled_systems.remove(&system);
+        } else {
+            self.disabled_systems.insert(system);
+        }
+        self
+    }
+
+    /// Returns `false` if the system with the given [`NodeId`] was disabled with
+    /// [`Schedule::set_system_enabled`].
+    pub fn is_system_enabled(&self, system: NodeId) -> bool {
+        !self.disabled_systems.contains(&system)
+    }
+
+    /// Returns the indices of the disabled systems in the executable schedule.
+    fn disabled_system_indices(&self) -> Option<FixedBitSet> {
+        if self.disabled_systems.is_empty() {
+            return None;
+        }
+        let mut indices = FixedBitSet::with_capacity(self.executable.system_ids.len());
+        for (index, id) in self.executable.system_ids.iter().enumerate() {
+            if self.disabled_systems.contains(id) {
+                indices.insert(index);
+            }
         }
+        Some(indices)
     }
 
     /// Initializes any newly-added systems and conditions, rebuilds the executable schedule,
@@ -2094,6 +2134,35 @@ mod tests {
         schedule.run(&mut world);
     }
 
+    #[test]
+    fn disabled_systems_are_skipped() {
+        #[derive(Resource, Default)]
+        struct Counter(u32);
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(
+            (
+                |mut counter: ResMut<Counter>| counter.0 += 1,
+                |mut counter: ResMut<Counter>| counter.0 += 10,
+            )
+                .chain(),
+        );
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 11);
+
+        let (first, _) = schedule.systems().unwrap().next().unwrap();
+        schedule.set_system_enabled(first, false);
+        assert!(!schedule.is_system_enabled(first));
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 21);
+
+        schedule.set_system_enabled(first, true);
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 32);
+    }
+
     #[test]
     fn inserts_a_sync_point() {
         let mut schedule = Schedule::default();
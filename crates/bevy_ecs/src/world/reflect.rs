@@ -4,10 +4,13 @@ use core::any::TypeId;
 
 use thiserror::Error;
 
-use alloc::string::{String, ToString};
-use bevy_reflect::{Reflect, ReflectFromPtr};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use bevy_reflect::{Reflect, ReflectFromPtr, ReflectTrait};
 
-use crate::{prelude::*, world::ComponentId};
+use crate::{archetype::ArchetypeEntity, prelude::*, world::ComponentId};
 
 impl World {
     /// Retrieves a reference to the given `entity`'s [`Component`] of the given `type_id` using
@@ -196,9 +199,173 @@ impl World {
 
         Ok(comp_mut_typed)
     }
+
+    /// Returns an iterator over all components implementing the trait reflected by `T`,
+    /// along with the entity they belong to.
+    ///
+    /// `T` is the type data generated by [`#[reflect_trait]`](bevy_reflect::reflect_trait),
+    /// such as `ReflectDamageable` for a `Damageable` trait.
+    /// Only components whose type registers `T` are returned, which allows operating over
+    /// an open set of component types without knowing them in advance.
+    ///
+    /// The entities are visited lazily, archetype by archetype, as the iterator advances.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GetComponentReflectError::MissingAppTypeRegistry`] if the [`World`]
+    /// is missing the [`AppTypeRegistry`] resource.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_ecs::prelude::*;
+    /// use bevy_reflect::{reflect_trait, Reflect};
+    ///
+    /// #[reflect_trait]
+    /// trait Damageable {
+    ///     fn health(&self) -> u32;
+    ///     fn damage(&mut self, amount: u32);
+    /// }
+    ///
+    /// #[derive(Component, Reflect)]
+    /// #[reflect(Damageable)]
+    /// struct Wall(u32);
+    ///
+    /// impl Damageable for Wall {
+    ///     fn health(&self) -> u32 {
+    ///         self.0
+    ///     }
+    ///
+    ///     fn damage(&mut self, amount: u32) {
+    ///         self.0 = self.0.saturating_sub(amount);
+    ///     }
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// world.init_resource::<AppTypeRegistry>();
+    /// world.resource::<AppTypeRegistry>().write().register::<Wall>();
+    /// world.spawn(Wall(10));
+    ///
+    /// world
+    ///     .for_each_reflect_trait_mut::<ReflectDamageable>(|_, mut damageable| damageable.damage(3))
+    ///     .unwrap();
+    ///
+    /// let total: u32 = world
+    ///     .iter_reflect_trait::<ReflectDamageable>()
+    ///     .unwrap()
+    ///     .map(|(_, damageable)| damageable.health())
+    ///     .sum();
+    /// assert_eq!(total, 7);
+    /// ```
+    ///
+    /// # Note
+    /// Requires the `bevy_reflect` feature (included in the default features).
+    pub fn iter_reflect_trait<T: ReflectTrait>(
+        &self,
+    ) -> Result<impl Iterator<Item = (Entity, &T::Target)>, GetComponentReflectError> {
+        let Some(type_registry) = self.get_resource::<AppTypeRegistry>().map(|atr| atr.read())
+        else {
+            return Err(GetComponentReflectError::MissingAppTypeRegistry);
+        };
+
+        // Clone the type data so that the iterator doesn't hold the registry lock.
+        let components: Vec<_> = self
+            .reflect_trait_components::<T>(&type_registry)
+            .into_iter()
+            .filter_map(|(component_id, reflect_trait, reflect_from_ptr)| {
+                let reflect_trait = reflect_trait.clone_type_data().downcast::<T>().ok()?;
+                Some((component_id, reflect_trait, reflect_from_ptr.clone()))
+            })
+            .collect();
+
+        Ok(components.into_iter().flat_map(
+            move |(component_id, reflect_trait, reflect_from_ptr)| {
+                self.entities_with(component_id).filter_map(move |entity| {
+                    let ptr = self.get_by_id(entity, component_id)?;
+                    // SAFETY: `ptr` points to a component with the type id of `reflect_from_ptr`,
+                    // as both were looked up from the same registration.
+                    let reflect = unsafe { reflect_from_ptr.as_reflect(ptr) };
+                    Some((entity, reflect_trait.get_trait(reflect)?))
+                })
+            },
+        ))
+    }
+
+    /// Calls `f` for all components implementing the trait reflected by `T`,
+    /// along with the entity they belong to.
+    ///
+    /// This is the mutable version of [`World::iter_reflect_trait`], see its docs for more
+    /// information and an example.
+    /// Change detection is only triggered for the components that are mutably dereferenced.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GetComponentReflectError::MissingAppTypeRegistry`] if the [`World`]
+    /// is missing the [`AppTypeRegistry`] resource.
+    ///
+    /// # Note
+    /// Requires the `bevy_reflect` feature (included in the default features).
+    pub fn for_each_reflect_trait_mut<T: ReflectTrait>(
+        &mut self,
+        mut f: impl FnMut(Entity, Mut<'_, T::Target>),
+    ) -> Result<(), GetComponentReflectError> {
+        // Clone the registry so that it doesn't borrow `self`, like in `get_reflect_mut`
+        let Some(app_type_registry) = self.get_resource::<AppTypeRegistry>().cloned() else {
+            return Err(GetComponentReflectError::MissingAppTypeRegistry);
+        };
+        let type_registry = app_type_registry.read();
+
+        let components = self.reflect_trait_components::<T>(&type_registry);
+        for (component_id, reflect_trait, reflect_from_ptr) in components {
+            let entities = self.entities_with(component_id).collect::<Vec<_>>();
+            for entity in entities {
+                let Some(component) = self.get_mut_by_id(entity, component_id) else {
+                    continue;
+                };
+                // SAFETY: `ptr_mut` points to a component with the type id of `reflect_from_ptr`,
+                // as both were looked up from the same registration.
+                let reflect = component
+                    .map_unchanged(|ptr_mut| unsafe { reflect_from_ptr.as_reflect_mut(ptr_mut) });
+                if reflect_trait.get_trait(&*reflect).is_some() {
+                    f(
+                        entity,
+                        reflect
+                            .map_unchanged(|reflect| reflect_trait.get_trait_mut(reflect).unwrap()),
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the component ids of the registered components implementing the trait reflected by `T`,
+    /// along with their `T` and [`ReflectFromPtr`] type data.
+    fn reflect_trait_components<'r, T: ReflectTrait>(
+        &self,
+        type_registry: &'r bevy_reflect::TypeRegistry,
+    ) -> Vec<(ComponentId, &'r T, &'r ReflectFromPtr)> {
+        type_registry
+            .iter_implementing::<T>()
+            .filter_map(|(registration, reflect_trait)| {
+                let component_id = self.components().get_id(registration.type_id())?;
+                let reflect_from_ptr = registration.data::<ReflectFromPtr>()?;
+                Some((component_id, reflect_trait, reflect_from_ptr))
+            })
+            .collect()
+    }
+
+    /// Returns an iterator over the entities with the given component.
+    fn entities_with(&self, component_id: ComponentId) -> impl Iterator<Item = Entity> + '_ {
+        self.archetypes()
+            .iter()
+            .filter(move |archetype| archetype.contains(component_id))
+            .flat_map(|archetype| archetype.entities().iter().map(ArchetypeEntity::id))
+    }
 }
 
-/// The error type returned by [`World::get_reflect`] and [`World::get_reflect_mut`].
+/// The error type returned by [`World::get_reflect`], [`World::get_reflect_mut`],
+/// [`World::iter_reflect_trait`] and [`World::for_each_reflect_trait_mut`].
 #[derive(Error, Debug)]
 pub enum GetComponentReflectError {
     /// There is no [`ComponentId`] corresponding to the given [`TypeId`].
@@ -249,7 +416,8 @@ pub enum GetComponentReflectError {
 mod tests {
     use core::any::TypeId;
 
-    use bevy_reflect::Reflect;
+    use alloc::vec::Vec;
+    use bevy_reflect::{reflect_trait, Reflect};
 
     use crate::prelude::{AppTypeRegistry, Component, DetectChanges, World};
 
@@ -330,4 +498,88 @@ mod tests {
             assert!(reflect_opt.is_err());
         }
     }
+
+    #[reflect_trait]
+    trait Named {
+        fn name(&self) -> &str;
+        fn rename(&mut self, name: &str);
+    }
+
+    #[derive(Component, Reflect)]
+    #[reflect(Named)]
+    struct Player(&'static str);
+
+    #[derive(Component, Reflect)]
+    #[reflect(Named)]
+    struct Npc(&'static str);
+
+    impl Named for Player {
+        fn name(&self) -> &str {
+            self.0
+        }
+
+        fn rename(&mut self, _name: &str) {
+            self.0 = "renamed player";
+        }
+    }
+
+    impl Named for Npc {
+        fn name(&self) -> &str {
+            self.0
+        }
+
+        fn rename(&mut self, _name: &str) {
+            self.0 = "renamed npc";
+        }
+    }
+
+    #[test]
+    fn iter_components_implementing_reflected_trait() {
+        let mut world = World::new();
+        assert!(world.iter_reflect_trait::<ReflectNamed>().is_err());
+
+        world.init_resource::<AppTypeRegistry>();
+        {
+            let mut registry = world.resource::<AppTypeRegistry>().write();
+            registry.register::<Player>();
+            registry.register::<Npc>();
+            registry.register::<RFoo>();
+        }
+
+        let player = world.spawn(Player("player")).id();
+        let npc = world.spawn((Npc("npc"), RFoo(1))).id();
+        let both = world.spawn((Player("both player"), Npc("both npc"))).id();
+        world.spawn((RFoo(2), Bar));
+
+        let mut names = world
+            .iter_reflect_trait::<ReflectNamed>()
+            .unwrap()
+            .map(|(entity, named)| (entity, named.name()))
+            .collect::<Vec<_>>();
+        names.sort();
+        let mut expected = [
+            (player, "player"),
+            (npc, "npc"),
+            (both, "both player"),
+            (both, "both npc"),
+        ];
+        expected.sort();
+        assert_eq!(names, expected);
+
+        world.clear_trackers();
+        world
+            .for_each_reflect_trait_mut::<ReflectNamed>(|entity, mut named| {
+                if entity == both {
+                    named.rename("renamed");
+                }
+            })
+            .unwrap();
+
+        let player_ref = world.entity(player).get_ref::<Player>().unwrap();
+        assert!(!player_ref.is_changed());
+        let both_ref = world.entity(both).get_ref::<Npc>().unwrap();
+        assert!(both_ref.is_changed());
+        assert_eq!(both_ref.0, "renamed npc");
+        assert_eq!(world.get::<Player>(both).unwrap().0, "renamed player");
+    }
 }
@@ -26,6 +26,7 @@ mod kw {
     syn::custom_keyword!(Clone);
     syn::custom_keyword!(no_field_bounds);
     syn::custom_keyword!(opaque);
+    syn::custom_keyword!(methods);
 }
 
 // The "special" trait idents that are used internally for reflection.
@@ -47,6 +48,9 @@ const TYPE_PATH_ATTR: &str = "type_path";
 // The error message to show when a trait/type is specified multiple times
 const CONFLICTING_TYPE_DATA_MESSAGE: &str = "conflicting type data registration";
 
+// The error message to show when a method is registered multiple times
+const CONFLICTING_METHOD_MESSAGE: &str = "conflicting method registration";
+
 /// A marker for trait implementations registered via the `Reflect` derive macro.
 #[derive(Clone, Default)]
 pub(crate) enum TraitImpl {
@@ -114,6 +118,22 @@ impl TypePathAttrs {
     }
 }
 
+/// A method registered via the `#[reflect(methods(...))]` attribute.
+#[derive(Clone)]
+pub(crate) struct ReflectMethod {
+    /// The name the method is registered under.
+    name: Ident,
+    /// The function to register in place of the method of the same name, if any.
+    #[cfg_attr(
+        not(feature = "functions"),
+        expect(
+            dead_code,
+            reason = "methods are only registered with the `functions` feature"
+        )
+    )]
+    function: Option<Expr>,
+}
+
 /// A collection of traits that have been registered for a reflected type.
 ///
 /// This keeps track of a few traits that are utilized internally for reflection
@@ -186,6 +206,7 @@ pub(crate) struct ContainerAttributes {
     no_field_bounds: bool,
     custom_attributes: CustomAttributes,
     is_opaque: bool,
+    methods: Vec<ReflectMethod>,
     idents: Vec<Ident>,
 }
 
@@ -238,6 +259,8 @@ impl ContainerAttributes {
             self.parse_opaque(input)
         } else if lookahead.peek(kw::no_field_bounds) {
             self.parse_no_field_bounds(input)
+        } else if lookahead.peek(kw::methods) {
+            self.parse_methods(input)
         } else if lookahead.peek(kw::Clone) {
             self.parse_clone(input)
         } else if lookahead.peek(kw::Debug) {
@@ -378,6 +401,42 @@ impl ContainerAttributes {
         Ok(())
     }
 
+    /// Parse `methods` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(methods(reset, pause))]`
+    /// - `#[reflect(methods(look_at = |t: &mut Transform, target: Vec3| t.look_at(target, Vec3::Y)))]`
+    fn parse_methods(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<kw::methods>()?;
+
+        let content;
+        parenthesized!(content in input);
+
+        terminated_parser(Token![,], |stream| {
+            let name = stream.call(Ident::parse_any)?;
+            let function = if stream.peek(Token![=]) {
+                stream.parse::<Token![=]>()?;
+                Some(stream.parse::<Expr>()?)
+            } else {
+                None
+            };
+
+            let name_str = name.unraw().to_string();
+            if self
+                .methods
+                .iter()
+                .any(|method| method.name.unraw() == name_str)
+            {
+                return Err(syn::Error::new(name.span(), CONFLICTING_METHOD_MESSAGE));
+            }
+
+            self.methods.push(ReflectMethod { name, function });
+            Ok(())
+        })(&content)?;
+
+        Ok(())
+    }
+
     /// Parse `where` attribute.
     ///
     /// Examples:
@@ -569,6 +628,35 @@ impl ContainerAttributes {
         }
     }
 
+    /// Returns the registration of the `ReflectMethods` type data
+    /// for the methods found within `#[reflect(methods(...))]` attributes, if any.
+    #[cfg(feature = "functions")]
+    pub fn get_methods_registration(
+        &self,
+        bevy_reflect_path: &Path,
+    ) -> Option<proc_macro2::TokenStream> {
+        if self.methods.is_empty() {
+            return None;
+        }
+
+        let methods = self.methods.iter().map(|method| {
+            let name = method.name.unraw().to_string();
+            match &method.function {
+                Some(function) => quote::quote! { .with_method(#name, #function) },
+                None => {
+                    let ident = &method.name;
+                    quote::quote! { .with_method(#name, Self::#ident) }
+                }
+            }
+        });
+
+        Some(quote::quote! {
+            registration.insert::<#bevy_reflect_path::func::ReflectMethods>(
+                #bevy_reflect_path::func::ReflectMethods::new()#(#methods)*
+            );
+        })
+    }
+
     pub fn custom_attributes(&self) -> &CustomAttributes {
         &self.custom_attributes
     }
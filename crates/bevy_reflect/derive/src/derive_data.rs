@@ -2,8 +2,8 @@ use core::fmt;
 use proc_macro2::Span;
 
 use crate::{
-    container_attributes::{ContainerAttributes, FromReflectAttrs, TypePathAttrs},
-    field_attributes::FieldAttributes,
+    container_attributes::{ContainerAttributes, FromReflectAttrs, TypePathAttrs, REFLECT_DEFAULT},
+    field_attributes::{DefaultBehavior, FieldAttributes},
     remote::RemoteType,
     result_sifter::ResultSifter,
     serialization::SerializationDataDef,
@@ -18,7 +18,7 @@ use syn::token::Comma;
 use crate::enum_utility::{EnumVariantOutputData, ReflectCloneVariantBuilder, VariantBuilder};
 use crate::field_attributes::CloneBehavior;
 use crate::generics::generate_generics;
-use bevy_macro_utils::fq_std::{FQClone, FQOption, FQResult};
+use bevy_macro_utils::fq_std::{FQBox, FQClone, FQDefault, FQOption, FQResult};
 use syn::{
     parse_str, punctuated::Punctuated, spanned::Spanned, Data, DeriveInput, Field, Fields,
     GenericParam, Generics, Ident, LitStr, Member, Meta, Path, PathSegment, Type, TypeParam,
@@ -497,7 +497,14 @@ impl<'a> ReflectMeta<'a> {
 
 impl<'a> StructField<'a> {
     /// Generates a `TokenStream` for `NamedField` or `UnnamedField` construction.
-    pub fn to_info_tokens(&self, bevy_reflect_path: &Path) -> proc_macro2::TokenStream {
+    ///
+    /// `container_default` is an expression for the default value of the containing type,
+    /// used as the default value of fields without a `#[reflect(default)]` attribute.
+    pub fn to_info_tokens(
+        &self,
+        bevy_reflect_path: &Path,
+        container_default: Option<&proc_macro2::TokenStream>,
+    ) -> proc_macro2::TokenStream {
         let name = match &self.data.ident {
             Some(ident) => ident.to_string().to_token_stream(),
             None => self.reflection_index.to_token_stream(),
@@ -527,6 +534,14 @@ impl<'a> StructField<'a> {
             #field_info::new::<#ty>(#name).with_custom_attributes(#custom_attributes)
         };
 
+        if let Some(default) = self.default_tokens(container_default) {
+            info.extend(quote! {
+                .with_default(|| -> #FQBox<dyn #bevy_reflect_path::PartialReflect> {
+                    #FQBox::<#ty>::new(#default)
+                })
+            });
+        }
+
         #[cfg(feature = "documentation")]
         {
             let docs = &self.doc;
@@ -538,6 +553,30 @@ impl<'a> StructField<'a> {
         info
     }
 
+    /// Returns an expression for the default value of this field, if it is known.
+    ///
+    /// These are the values used by `FromReflect` for missing fields.
+    /// Fields with a remote wrapper type don't have a default value,
+    /// since their default value would be of the remote type instead.
+    fn default_tokens(
+        &self,
+        container_default: Option<&proc_macro2::TokenStream>,
+    ) -> Option<proc_macro2::TokenStream> {
+        if self.attrs.remote.is_some() {
+            return None;
+        }
+
+        let ty = &self.data.ty;
+        match &self.attrs.default {
+            DefaultBehavior::Func(path) => Some(quote!(#path())),
+            DefaultBehavior::Default => Some(quote!(<#ty as #FQDefault>::default())),
+            DefaultBehavior::Required => {
+                let member = self.to_member();
+                container_default.map(|container_default| quote!(#container_default.#member))
+            }
+        }
+    }
+
     /// Returns the reflected type of this field.
     ///
     /// Normally this is just the field's defined type.
@@ -649,9 +688,17 @@ impl<'a> ReflectStruct<'a> {
             )
         };
 
+        let container_default = self.meta().attrs().contains(REFLECT_DEFAULT).then(|| {
+            let reflect_ty = match self.meta().remote_ty() {
+                Some(remote_ty) => remote_ty.type_path().to_token_stream(),
+                None => quote!(Self),
+            };
+            quote!(<#reflect_ty as #FQDefault>::default())
+        });
+
         let field_infos = self
             .active_fields()
-            .map(|field| field.to_info_tokens(bevy_reflect_path));
+            .map(|field| field.to_info_tokens(bevy_reflect_path, container_default.as_ref()));
 
         let custom_attributes = self
             .meta
@@ -999,7 +1046,7 @@ impl<'a> EnumVariant<'a> {
 
         let fields = self
             .active_fields()
-            .map(|field| field.to_info_tokens(bevy_reflect_path));
+            .map(|field| field.to_info_tokens(bevy_reflect_path, None));
 
         let args = match &self.fields {
             EnumVariantFields::Unit => quote!(#variant_name),
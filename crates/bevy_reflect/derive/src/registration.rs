@@ -45,6 +45,11 @@ pub(crate) fn impl_get_type_registration<'a>(
         }
     });
 
+    #[cfg(feature = "functions")]
+    let methods_data = meta.attrs().get_methods_registration(bevy_reflect_path);
+    #[cfg(not(feature = "functions"))]
+    let methods_data: Option<proc_macro2::TokenStream> = None;
+
     quote! {
         #[allow(unused_mut)]
         impl #impl_generics #bevy_reflect_path::GetTypeRegistration for #type_path #ty_generics #where_reflect_clause {
@@ -53,6 +58,7 @@ pub(crate) fn impl_get_type_registration<'a>(
                 registration.insert::<#bevy_reflect_path::ReflectFromPtr>(#bevy_reflect_path::FromType::<Self>::from_type());
                 #from_reflect_data
                 #serialization_data
+                #methods_data
                 #(registration.insert::<#registration_data>(#bevy_reflect_path::FromType::<Self>::from_type());)*
                 registration
             }
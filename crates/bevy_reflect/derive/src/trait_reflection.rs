@@ -52,8 +52,8 @@ pub(crate) fn reflect_trait(_args: &TokenStream, input: TokenStream) -> TokenStr
         #[doc = #struct_doc]
         #[derive(#FQClone)]
         #trait_vis struct #reflect_trait_ident {
-            get_func: fn(&dyn #bevy_reflect_path::Reflect) -> #FQOption<&dyn #trait_ident>,
-            get_mut_func: fn(&mut dyn #bevy_reflect_path::Reflect) -> #FQOption<&mut dyn #trait_ident>,
+            get_func: fn(&dyn #bevy_reflect_path::Reflect) -> #FQOption<&(dyn #trait_ident + 'static)>,
+            get_mut_func: fn(&mut dyn #bevy_reflect_path::Reflect) -> #FQOption<&mut (dyn #trait_ident + 'static)>,
             get_boxed_func: fn(#bevy_reflect_path::__macro_exports::alloc_utils::Box<dyn #bevy_reflect_path::Reflect>) -> #FQResult<#bevy_reflect_path::__macro_exports::alloc_utils::Box<dyn #trait_ident>, #bevy_reflect_path::__macro_exports::alloc_utils::Box<dyn #bevy_reflect_path::Reflect>>,
         }
 
@@ -65,7 +65,7 @@ pub(crate) fn reflect_trait(_args: &TokenStream, input: TokenStream) -> TokenStr
 
             #[doc = #get_mut_doc]
             pub fn get_mut<'a>(&self, reflect_value: &'a mut dyn #bevy_reflect_path::Reflect) -> #FQOption<&'a mut dyn #trait_ident> {
-                (self.get_mut_func)(reflect_value)
+                (self.get_mut_func)(reflect_value).map(|value| value as &mut dyn #trait_ident)
             }
 
             #[doc = #get_box_doc]
@@ -74,14 +74,30 @@ pub(crate) fn reflect_trait(_args: &TokenStream, input: TokenStream) -> TokenStr
             }
         }
 
+        impl #bevy_reflect_path::ReflectTrait for #reflect_trait_ident {
+            type Target = dyn #trait_ident;
+
+            fn get_trait<'a>(&self, reflect_value: &'a dyn #bevy_reflect_path::Reflect) -> #FQOption<&'a Self::Target> {
+                (self.get_func)(reflect_value)
+            }
+
+            fn get_trait_mut<'a>(&self, reflect_value: &'a mut dyn #bevy_reflect_path::Reflect) -> #FQOption<&'a mut Self::Target> {
+                (self.get_mut_func)(reflect_value)
+            }
+
+            fn get_trait_boxed(&self, reflect_value: #bevy_reflect_path::__macro_exports::alloc_utils::Box<dyn #bevy_reflect_path::Reflect>) -> #FQResult<#bevy_reflect_path::__macro_exports::alloc_utils::Box<Self::Target>, #bevy_reflect_path::__macro_exports::alloc_utils::Box<dyn #bevy_reflect_path::Reflect>> {
+                (self.get_boxed_func)(reflect_value)
+            }
+        }
+
         impl<T: #trait_ident + #bevy_reflect_path::Reflect> #bevy_reflect_path::FromType<T> for #reflect_trait_ident {
             fn from_type() -> Self {
                 Self {
                     get_func: |reflect_value| {
-                        <dyn #bevy_reflect_path::Reflect>::downcast_ref::<T>(reflect_value).map(|value| value as &dyn #trait_ident)
+                        <dyn #bevy_reflect_path::Reflect>::downcast_ref::<T>(reflect_value).map(|value| value as &(dyn #trait_ident + 'static))
                     },
                     get_mut_func: |reflect_value| {
-                        <dyn #bevy_reflect_path::Reflect>::downcast_mut::<T>(reflect_value).map(|value| value as &mut dyn #trait_ident)
+                        <dyn #bevy_reflect_path::Reflect>::downcast_mut::<T>(reflect_value).map(|value| value as &mut (dyn #trait_ident + 'static))
                     },
                     get_boxed_func: |reflect_value| {
                         <dyn #bevy_reflect_path::Reflect>::downcast::<T>(reflect_value).map(|value| value as #bevy_reflect_path::__macro_exports::alloc_utils::Box<dyn #trait_ident>)
@@ -0,0 +1,393 @@
+use alloc::{boxed::Box, string::ToString, vec::Vec};
+
+use crate::{
+    binary::{
+        builtin_with_id, kind_from_u8, BinaryError, BinaryReader, ReflectBinaryValue, MAGIC,
+        MAX_DEPTH, VARIANT_STRUCT, VARIANT_TUPLE, VARIANT_UNIT, VERSION,
+    },
+    std_traits::ReflectDefault,
+    DynamicArray, DynamicEnum, DynamicList, DynamicMap, DynamicSet, DynamicStruct, DynamicTuple,
+    DynamicTupleStruct, DynamicVariant, Map, PartialReflect, ReflectFromReflect, ReflectKind, Set,
+    StableTypeId, TypeInfo, TypeRegistration, TypeRegistry, VariantInfo,
+};
+
+/// An entry of the type table of an encoded value.
+#[derive(Clone, Copy)]
+struct DecodedType<'a> {
+    kind: ReflectKind,
+    id: StableTypeId,
+    /// The registration of the type, if it is registered with the same kind.
+    registration: Option<&'a TypeRegistration>,
+    /// For opaque types, their [`ReflectBinaryValue`] and whether they are built-in.
+    codec: Option<(ReflectBinaryValue, bool)>,
+}
+
+impl DecodedType<'_> {
+    fn type_info(&self) -> Option<&'static TypeInfo> {
+        self.registration.map(TypeRegistration::type_info)
+    }
+}
+
+/// Decodes a value from the binary format, using the type and name tables of its header.
+pub(super) struct Decoder<'a> {
+    reader: BinaryReader<'a>,
+    types: Vec<DecodedType<'a>>,
+    names: Vec<&'a str>,
+    /// The number of values currently being decoded or skipped, see [`MAX_DEPTH`].
+    depth: usize,
+}
+
+impl<'a> Decoder<'a> {
+    /// Reads the header of the given bytes, resolving its types in the registry.
+    pub fn new(bytes: &'a [u8], registry: &'a TypeRegistry) -> Result<Self, BinaryError> {
+        let mut reader = BinaryReader::new(bytes);
+        if reader.read_bytes(MAGIC.len()) != Ok(MAGIC) {
+            return Err(BinaryError::InvalidMagic);
+        }
+        let version = reader.read_u8()?;
+        if version != VERSION {
+            return Err(BinaryError::UnsupportedVersion(version));
+        }
+
+        let mut types = Vec::new();
+        for _ in 0..reader.read_len()? {
+            let kind = kind_from_u8(reader.read_u8()?)?;
+            let id = StableTypeId::from_bits(u64::from_le_bytes(reader.read_array()?));
+            let registration = registry
+                .get_with_stable_id(id)
+                .filter(|registration| registration.type_info().kind() == kind);
+            let codec = if kind == ReflectKind::Opaque {
+                builtin_with_id(id).map(|codec| (codec, true)).or_else(|| {
+                    registration
+                        .and_then(TypeRegistration::data::<ReflectBinaryValue>)
+                        .map(|codec| (*codec, false))
+                })
+            } else {
+                None
+            };
+            types.push(DecodedType {
+                kind,
+                id,
+                registration,
+                codec,
+            });
+        }
+
+        let mut names = Vec::new();
+        for _ in 0..reader.read_len()? {
+            names.push(reader.read_str()?);
+        }
+
+        Ok(Self {
+            reader,
+            types,
+            names,
+            depth: 0,
+        })
+    }
+
+    /// Checks that the whole input has been decoded.
+    pub fn finish(self) -> Result<(), BinaryError> {
+        match self.reader.remaining() {
+            0 => Ok(()),
+            remaining => Err(BinaryError::TrailingBytes(remaining)),
+        }
+    }
+
+    fn read_type(&mut self) -> Result<DecodedType<'a>, BinaryError> {
+        let id = self.reader.read_varint()?;
+        usize::try_from(id)
+            .ok()
+            .and_then(|id| self.types.get(id))
+            .copied()
+            .ok_or(BinaryError::InvalidTypeId(id))
+    }
+
+    fn read_name(&mut self) -> Result<&'a str, BinaryError> {
+        let id = self.reader.read_varint()?;
+        usize::try_from(id)
+            .ok()
+            .and_then(|id| self.names.get(id))
+            .copied()
+            .ok_or(BinaryError::InvalidNameId(id))
+    }
+
+    /// Enters a nested value, returning an error if values are nested too deeply.
+    fn enter(&mut self) -> Result<(), BinaryError> {
+        self.depth += 1;
+        if self.depth > MAX_DEPTH {
+            return Err(BinaryError::DepthLimitExceeded(MAX_DEPTH));
+        }
+        Ok(())
+    }
+
+    /// Decodes the next value.
+    ///
+    /// Values of registered types are converted to their concrete type when possible,
+    /// using [`ReflectFromReflect`] or falling back to applying the decoded value to
+    /// the [default](ReflectDefault) value of the type.
+    /// Fields that no longer exist on the registered type are skipped.
+    pub fn decode(&mut self) -> Result<Box<dyn PartialReflect>, BinaryError> {
+        self.enter()?;
+        let value = self.decode_value();
+        self.depth -= 1;
+        value
+    }
+
+    fn decode_value(&mut self) -> Result<Box<dyn PartialReflect>, BinaryError> {
+        let ty = self.read_type()?;
+        let type_info = ty.type_info();
+
+        let dynamic: Box<dyn PartialReflect> = match ty.kind {
+            ReflectKind::Struct => {
+                let mut value = DynamicStruct::default();
+                value.set_represented_type(type_info);
+                let fields = match type_info {
+                    Some(TypeInfo::Struct(info)) => Some(|name: &str| info.field(name).is_some()),
+                    _ => None,
+                };
+                for _ in 0..self.reader.read_len()? {
+                    let name = self.read_name()?;
+                    if fields.is_some_and(|has_field| !has_field(name)) {
+                        self.skip()?;
+                    } else {
+                        value.insert_boxed(name, self.decode()?);
+                    }
+                }
+                Box::new(value)
+            }
+            ReflectKind::TupleStruct => {
+                let mut value = DynamicTupleStruct::default();
+                value.set_represented_type(type_info);
+                let field_len = match type_info {
+                    Some(TypeInfo::TupleStruct(info)) => info.field_len(),
+                    _ => usize::MAX,
+                };
+                for index in 0..self.reader.read_len()? {
+                    if index < field_len {
+                        value.insert_boxed(self.decode()?);
+                    } else {
+                        self.skip()?;
+                    }
+                }
+                Box::new(value)
+            }
+            ReflectKind::Tuple => {
+                let mut value = DynamicTuple::default();
+                value.set_represented_type(type_info);
+                for _ in 0..self.reader.read_len()? {
+                    value.insert_boxed(self.decode()?);
+                }
+                Box::new(value)
+            }
+            ReflectKind::List => {
+                let mut value = DynamicList::default();
+                value.set_represented_type(type_info);
+                for _ in 0..self.reader.read_len()? {
+                    value.push_box(self.decode()?);
+                }
+                Box::new(value)
+            }
+            ReflectKind::Array => {
+                let mut values = Vec::new();
+                for _ in 0..self.reader.read_len()? {
+                    values.push(self.decode()?);
+                }
+                let mut value = DynamicArray::new(values.into_boxed_slice());
+                value.set_represented_type(type_info);
+                Box::new(value)
+            }
+            ReflectKind::Map => {
+                let mut value = DynamicMap::default();
+                value.set_represented_type(type_info);
+                for _ in 0..self.reader.read_len()? {
+                    let key = Self::check_key(self.decode()?)?;
+                    value.insert_boxed(key, self.decode()?);
+                }
+                Box::new(value)
+            }
+            ReflectKind::Set => {
+                let mut value = DynamicSet::default();
+                value.set_represented_type(type_info);
+                for _ in 0..self.reader.read_len()? {
+                    value.insert_boxed(Self::check_key(self.decode()?)?);
+                }
+                Box::new(value)
+            }
+            ReflectKind::Enum => {
+                let variant_name = self.read_name()?;
+                let variant_info = match type_info {
+                    Some(TypeInfo::Enum(info)) => {
+                        Some(info.variant(variant_name).ok_or_else(|| {
+                            BinaryError::UnknownVariant {
+                                type_path: info.type_path().to_string(),
+                                variant: variant_name.to_string(),
+                            }
+                        })?)
+                    }
+                    _ => None,
+                };
+                let variant = match self.reader.read_u8()? {
+                    VARIANT_UNIT => DynamicVariant::Unit,
+                    VARIANT_TUPLE => {
+                        let field_len = match variant_info {
+                            Some(VariantInfo::Tuple(info)) => info.field_len(),
+                            _ => usize::MAX,
+                        };
+                        let mut fields = DynamicTuple::default();
+                        for index in 0..self.reader.read_len()? {
+                            if index < field_len {
+                                fields.insert_boxed(self.decode()?);
+                            } else {
+                                self.skip()?;
+                            }
+                        }
+                        DynamicVariant::Tuple(fields)
+                    }
+                    VARIANT_STRUCT => {
+                        let has_field = match variant_info {
+                            Some(VariantInfo::Struct(info)) => {
+                                Some(|name: &str| info.field(name).is_some())
+                            }
+                            _ => None,
+                        };
+                        let mut fields = DynamicStruct::default();
+                        for _ in 0..self.reader.read_len()? {
+                            let name = self.read_name()?;
+                            if has_field.is_some_and(|has_field| !has_field(name)) {
+                                self.skip()?;
+                            } else {
+                                fields.insert_boxed(name, self.decode()?);
+                            }
+                        }
+                        DynamicVariant::Struct(fields)
+                    }
+                    kind => return Err(BinaryError::InvalidVariantKind(kind)),
+                };
+                let mut value = DynamicEnum::new(variant_name, variant);
+                value.set_represented_type(type_info);
+                Box::new(value)
+            }
+            ReflectKind::Opaque => {
+                return match ty.codec {
+                    Some((codec, true)) => Ok(codec.read(&mut self.reader)?.into_partial_reflect()),
+                    Some((codec, false)) => {
+                        let len = self.reader.read_len()?;
+                        let mut reader = BinaryReader::new(self.reader.read_bytes(len)?);
+                        Ok(codec.read(&mut reader)?.into_partial_reflect())
+                    }
+                    None => Err(match ty.registration {
+                        Some(registration) => BinaryError::UnsupportedType {
+                            type_path: registration.type_info().type_path().to_string(),
+                        },
+                        None => BinaryError::UnknownType(ty.id),
+                    }),
+                };
+            }
+            #[cfg(feature = "functions")]
+            ReflectKind::Function => unreachable!("functions are rejected by the type table"),
+        };
+
+        Ok(Self::convert(ty, dynamic))
+    }
+
+    /// Checks that a decoded map key or set element can be hashed and is equal to itself,
+    /// which [`DynamicMap`] and [`DynamicSet`] require.
+    fn check_key(key: Box<dyn PartialReflect>) -> Result<Box<dyn PartialReflect>, BinaryError> {
+        if key.reflect_hash().is_some() && key.reflect_partial_eq(&*key) == Some(true) {
+            Ok(key)
+        } else {
+            Err(BinaryError::InvalidKey {
+                type_path: key.reflect_type_path().to_string(),
+            })
+        }
+    }
+
+    /// Converts a decoded dynamic value to its concrete type if possible.
+    fn convert(ty: DecodedType, dynamic: Box<dyn PartialReflect>) -> Box<dyn PartialReflect> {
+        let Some(registration) = ty.registration else {
+            return dynamic;
+        };
+
+        if let Some(value) = registration
+            .data::<ReflectFromReflect>()
+            .and_then(|from_reflect| from_reflect.from_reflect(dynamic.as_ref()))
+        {
+            return value.into_partial_reflect();
+        }
+
+        if let Some(default) = registration.data::<ReflectDefault>() {
+            let mut value = default.default();
+            if value.try_apply(dynamic.as_ref()).is_ok() {
+                return value.into_partial_reflect();
+            }
+        }
+
+        dynamic
+    }
+
+    /// Skips the next value, such as the value of a field that no longer exists.
+    fn skip(&mut self) -> Result<(), BinaryError> {
+        self.enter()?;
+        self.skip_value()?;
+        self.depth -= 1;
+        Ok(())
+    }
+
+    fn skip_value(&mut self) -> Result<(), BinaryError> {
+        let ty = self.read_type()?;
+        match ty.kind {
+            ReflectKind::Struct => {
+                for _ in 0..self.reader.read_len()? {
+                    self.read_name()?;
+                    self.skip()?;
+                }
+            }
+            ReflectKind::TupleStruct
+            | ReflectKind::Tuple
+            | ReflectKind::List
+            | ReflectKind::Array
+            | ReflectKind::Set => {
+                for _ in 0..self.reader.read_len()? {
+                    self.skip()?;
+                }
+            }
+            ReflectKind::Map => {
+                for _ in 0..self.reader.read_len()? {
+                    self.skip()?;
+                    self.skip()?;
+                }
+            }
+            ReflectKind::Enum => {
+                self.read_name()?;
+                match self.reader.read_u8()? {
+                    VARIANT_UNIT => {}
+                    VARIANT_TUPLE => {
+                        for _ in 0..self.reader.read_len()? {
+                            self.skip()?;
+                        }
+                    }
+                    VARIANT_STRUCT => {
+                        for _ in 0..self.reader.read_len()? {
+                            self.read_name()?;
+                            self.skip()?;
+                        }
+                    }
+                    kind => return Err(BinaryError::InvalidVariantKind(kind)),
+                }
+            }
+            ReflectKind::Opaque => match ty.codec {
+                Some((codec, true)) => {
+                    codec.read(&mut self.reader)?;
+                }
+                _ => {
+                    let len = self.reader.read_len()?;
+                    self.reader.read_bytes(len)?;
+                }
+            },
+            #[cfg(feature = "functions")]
+            ReflectKind::Function => unreachable!("functions are rejected by the type table"),
+        }
+        Ok(())
+    }
+}
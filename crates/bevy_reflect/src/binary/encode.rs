@@ -0,0 +1,210 @@
+use alloc::{string::ToString, vec::Vec};
+use bevy_platform::collections::HashMap;
+
+use crate::{
+    binary::{
+        builtin, kind_to_u8, BinaryError, BinaryWriter, ReflectBinaryValue, MAGIC, VARIANT_STRUCT,
+        VARIANT_TUPLE, VARIANT_UNIT, VERSION,
+    },
+    PartialReflect, ReflectKind, ReflectRef, StableTypeId, TypeInfo, TypeRegistry, VariantType,
+};
+
+/// An entry of the type table of an encoded value.
+struct EncodedType {
+    kind: ReflectKind,
+    id: StableTypeId,
+}
+
+/// Encodes a value into the binary format, building the type and name tables along the way.
+pub(super) struct Encoder<'a> {
+    registry: &'a TypeRegistry,
+    type_ids: HashMap<&'a str, (u64, Option<(ReflectBinaryValue, bool)>)>,
+    types: Vec<EncodedType>,
+    name_ids: HashMap<&'a str, u64>,
+    names: Vec<&'a str>,
+    body: BinaryWriter,
+}
+
+impl<'a> Encoder<'a> {
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self {
+            registry,
+            type_ids: HashMap::default(),
+            types: Vec::new(),
+            name_ids: HashMap::default(),
+            names: Vec::new(),
+            body: BinaryWriter::new(),
+        }
+    }
+
+    /// Returns the encoded header followed by the encoded body.
+    pub fn finish(self) -> Vec<u8> {
+        let mut writer = BinaryWriter::new();
+        writer.write_bytes(MAGIC);
+        writer.write_u8(VERSION);
+        writer.write_varint(self.types.len() as u64);
+        for ty in &self.types {
+            writer.write_u8(kind_to_u8(ty.kind));
+            writer.write_bytes(&ty.id.to_bits().to_le_bytes());
+        }
+        writer.write_varint(self.names.len() as u64);
+        for name in &self.names {
+            writer.write_str(name);
+        }
+        writer.write_bytes(self.body.as_bytes());
+        writer.into_bytes()
+    }
+
+    pub fn encode(&mut self, value: &'a dyn PartialReflect) -> Result<(), BinaryError> {
+        #[cfg(feature = "functions")]
+        if let ReflectRef::Function(function) = value.reflect_ref() {
+            return Err(BinaryError::Function {
+                name: function
+                    .name()
+                    .map(ToString::to_string)
+                    .unwrap_or_else(|| "<anonymous>".to_string()),
+            });
+        }
+
+        let codec = self.write_type_id(value, value.reflect_kind())?;
+
+        match value.reflect_ref() {
+            ReflectRef::Struct(value) => {
+                self.body.write_varint(value.field_len() as u64);
+                for (index, field) in value.iter_fields().enumerate() {
+                    self.write_name_id(value.name_at(index).unwrap_or_default());
+                    self.encode(field)?;
+                }
+            }
+            ReflectRef::TupleStruct(value) => {
+                self.body.write_varint(value.field_len() as u64);
+                for field in value.iter_fields() {
+                    self.encode(field)?;
+                }
+            }
+            ReflectRef::Tuple(value) => {
+                self.body.write_varint(value.field_len() as u64);
+                for field in value.iter_fields() {
+                    self.encode(field)?;
+                }
+            }
+            ReflectRef::List(value) => {
+                self.body.write_varint(value.len() as u64);
+                for element in value.iter() {
+                    self.encode(element)?;
+                }
+            }
+            ReflectRef::Array(value) => {
+                self.body.write_varint(value.len() as u64);
+                for element in value.iter() {
+                    self.encode(element)?;
+                }
+            }
+            ReflectRef::Map(value) => {
+                self.body.write_varint(value.len() as u64);
+                for (key, value) in value.iter() {
+                    self.encode(key)?;
+                    self.encode(value)?;
+                }
+            }
+            ReflectRef::Set(value) => {
+                self.body.write_varint(value.len() as u64);
+                for element in value.iter() {
+                    self.encode(element)?;
+                }
+            }
+            ReflectRef::Enum(value) => {
+                self.write_name_id(value.variant_name());
+                match value.variant_type() {
+                    VariantType::Unit => self.body.write_u8(VARIANT_UNIT),
+                    VariantType::Tuple => {
+                        self.body.write_u8(VARIANT_TUPLE);
+                        self.body.write_varint(value.field_len() as u64);
+                        for field in value.iter_fields() {
+                            self.encode(field.value())?;
+                        }
+                    }
+                    VariantType::Struct => {
+                        self.body.write_u8(VARIANT_STRUCT);
+                        self.body.write_varint(value.field_len() as u64);
+                        for field in value.iter_fields() {
+                            self.write_name_id(field.name().unwrap_or_default());
+                            self.encode(field.value())?;
+                        }
+                    }
+                }
+            }
+            ReflectRef::Opaque(value) => match codec {
+                Some((codec, true)) => codec.write(value, &mut self.body)?,
+                Some((codec, false)) => {
+                    let mut writer = BinaryWriter::new();
+                    codec.write(value, &mut writer)?;
+                    self.body.write_varint(writer.len() as u64);
+                    self.body.write_bytes(writer.as_bytes());
+                }
+                None => unreachable!(
+                    "opaque types without a codec are rejected when their type id is assigned"
+                ),
+            },
+            #[cfg(feature = "functions")]
+            ReflectRef::Function(_) => unreachable!(),
+        }
+
+        Ok(())
+    }
+
+    /// Writes the index of the type of the given value, adding the type to the type table if needed.
+    ///
+    /// For opaque types, returns their [`ReflectBinaryValue`] and whether they are built-in.
+    fn write_type_id(
+        &mut self,
+        value: &'a dyn PartialReflect,
+        kind: ReflectKind,
+    ) -> Result<Option<(ReflectBinaryValue, bool)>, BinaryError> {
+        // Dynamic types are encoded as the type they represent
+        let type_path = value
+            .get_represented_type_info()
+            .map(TypeInfo::type_path)
+            .unwrap_or_else(|| value.reflect_type_path());
+        if let Some(&(index, codec)) = self.type_ids.get(type_path) {
+            self.body.write_varint(index);
+            return Ok(codec);
+        }
+
+        let codec = if kind == ReflectKind::Opaque {
+            let codec = builtin(type_path).map(|codec| (codec, true)).or_else(|| {
+                value
+                    .get_represented_type_info()
+                    .and_then(|info| self.registry.get(info.type_id()))
+                    .and_then(|registration| registration.data::<ReflectBinaryValue>())
+                    .map(|codec| (*codec, false))
+            });
+            if codec.is_none() {
+                return Err(BinaryError::UnsupportedType {
+                    type_path: type_path.to_string(),
+                });
+            }
+            codec
+        } else {
+            None
+        };
+
+        let index = self.types.len() as u64;
+        self.types.push(EncodedType {
+            kind,
+            id: StableTypeId::of_type_path(type_path),
+        });
+        self.type_ids.insert(type_path, (index, codec));
+        self.body.write_varint(index);
+        Ok(codec)
+    }
+
+    /// Writes the id of the given field or variant name, adding it to the name table if needed.
+    fn write_name_id(&mut self, name: &'a str) {
+        let id = *self.name_ids.entry(name).or_insert_with(|| {
+            self.names.push(name);
+            self.names.len() as u64 - 1
+        });
+        self.body.write_varint(id);
+    }
+}
@@ -0,0 +1,92 @@
+use alloc::string::String;
+use thiserror::Error;
+
+use crate::StableTypeId;
+
+/// An error that occurs when encoding or decoding a reflected value in the [binary format].
+///
+/// [binary format]: crate::binary
+#[derive(Error, Debug, PartialEq, Eq, Clone)]
+pub enum BinaryError {
+    /// The input does not start with the magic bytes of the binary format.
+    #[error("expected the magic bytes of the binary reflection format")]
+    InvalidMagic,
+    /// The input was encoded with an unsupported version of the binary format.
+    #[error("unsupported binary reflection format version {0}")]
+    UnsupportedVersion(u8),
+    /// The input ended before the value was fully decoded.
+    #[error("unexpected end of input")]
+    UnexpectedEnd,
+    /// The input contains bytes after the encoded value.
+    #[error("found {0} trailing bytes after the encoded value")]
+    TrailingBytes(usize),
+    /// A variable-length integer does not fit in the expected number of bits.
+    #[error("variable-length integer is too large")]
+    VarintOverflow,
+    /// A string is not valid UTF-8.
+    #[error("string is not valid UTF-8")]
+    InvalidUtf8,
+    /// A value refers to a type index that is missing from the type table.
+    #[error("type index {0} is missing from the type table")]
+    InvalidTypeId(u64),
+    /// A value refers to a name index that is missing from the name table.
+    #[error("name index {0} is missing from the name table")]
+    InvalidNameId(u64),
+    /// The values are nested deeper than the decoder allows.
+    #[error("values are nested more than {0} levels deep")]
+    DepthLimitExceeded(usize),
+    /// A decoded map key or set element can't be hashed or isn't equal to itself,
+    /// such as a float or a type that doesn't reflect `Hash`.
+    #[error("a value of type `{type_path}` cannot be used as a map key or set element")]
+    InvalidKey {
+        /// The [type path] of the value.
+        ///
+        /// [type path]: crate::TypePath::type_path
+        type_path: String,
+    },
+    /// The type table contains an unknown kind of type.
+    #[error("invalid type kind {0}")]
+    InvalidKind(u8),
+    /// An enum value contains an unknown kind of variant.
+    #[error("invalid variant kind {0}")]
+    InvalidVariantKind(u8),
+    /// An enum value has a variant that doesn't exist on its registered type.
+    #[error("variant `{variant}` does not exist on enum `{type_path}`")]
+    UnknownVariant {
+        /// The [type path] of the enum.
+        ///
+        /// [type path]: crate::TypePath::type_path
+        type_path: String,
+        /// The name of the variant.
+        variant: String,
+    },
+    /// The bytes of an opaque value don't represent a valid value of its type.
+    #[error("invalid value for type `{type_path}`")]
+    InvalidValue {
+        /// The [type path] of the value.
+        ///
+        /// [type path]: crate::TypePath::type_path
+        type_path: String,
+    },
+    /// An opaque type has no binary representation.
+    ///
+    /// Register [`ReflectBinaryValue`] for the type to give it one.
+    ///
+    /// [`ReflectBinaryValue`]: crate::binary::ReflectBinaryValue
+    #[error("opaque type `{type_path}` does not register `ReflectBinaryValue`")]
+    UnsupportedType {
+        /// The [type path] of the type.
+        ///
+        /// [type path]: crate::TypePath::type_path
+        type_path: String,
+    },
+    /// An opaque value has a type that isn't registered, so it can't be decoded.
+    #[error("no type with the stable id {:#018x} is registered", .0.to_bits())]
+    UnknownType(StableTypeId),
+    /// The value is a function, which can't be encoded.
+    #[error("cannot encode function `{name}`")]
+    Function {
+        /// The name of the function, or `"<anonymous>"` if it has none.
+        name: String,
+    },
+}
@@ -0,0 +1,626 @@
+//! A compact binary format for reflected values.
+//!
+//! Unlike the [`serde`](crate::serde) integration, this format doesn't go through a serde
+//! data format and doesn't write type paths or repeat field names for every value.
+//! Types are identified by the [`StableTypeId`] the [`TypeRegistry`] assigns them,
+//! which stays the same across builds, platforms and registration orders.
+//! [`to_bytes`] gives each type and name a small integer index the first time it's
+//! encountered, and writes a table mapping these indices to stable type ids and names
+//! in the header.
+//!
+//! This makes it well suited for networking and save files.
+//!
+//! Since payloads may come from untrusted sources, decoding them never panics:
+//! invalid input, such as map keys that can't be hashed or values nested deeper than
+//! [`MAX_DEPTH`], results in a [`BinaryError`].
+//!
+//! # Schema evolution
+//!
+//! Every value is self-describing, so [`from_bytes`] can decode payloads written by an
+//! older or newer version of a type:
+//! - Fields that no longer exist on the registered type are skipped.
+//! - Fields that are missing from the payload are filled in with their default value,
+//!   as long as the type (or the field) has a default through [`FromReflect`] or
+//!   registers [`ReflectDefault`].
+//!
+//! Types that were renamed or moved can still be decoded by [registering an alias] for
+//! their old type path.
+//! Changing the type of a field or the kind of a type is not supported,
+//! and neither is decoding a variant that was removed from an enum.
+//!
+//! # Opaque types
+//!
+//! Opaque types are encoded using [`BinaryValue`], which is implemented for the primitive types,
+//! [`String`](alloc::string::String) and [`Duration`](core::time::Duration).
+//! Other opaque types need to implement it and register [`ReflectBinaryValue`].
+//!
+//! # Layout
+//!
+//! A payload consists of:
+//! - the magic bytes `BRFL` and a version byte,
+//! - the type table: a count followed by the kind and [`StableTypeId`] of each type,
+//!   the latter as 8 little-endian bytes,
+//! - the name table: a count followed by each field and variant name,
+//! - the root value.
+//!
+//! Each value starts with the index of its type, followed by:
+//! - for structs, the number of fields and the name index and value of each field,
+//! - for tuples, tuple structs, lists, arrays and sets, the number of elements and each element,
+//! - for maps, the number of entries and the key and value of each entry,
+//! - for enums, the name index of the variant, its kind and its fields,
+//! - for opaque values, their [`BinaryValue`] representation, prefixed by its length
+//!   unless the type is built-in.
+//!
+//! # Example
+//!
+//! ```
+//! # use bevy_reflect::{binary, prelude::*, TypeRegistry};
+//! #[derive(Reflect, Debug, PartialEq)]
+//! struct Player {
+//!     name: String,
+//!     health: u32,
+//! }
+//!
+//! let mut registry = TypeRegistry::default();
+//! registry.register::<Player>();
+//!
+//! let player = Player { name: "Alice".into(), health: 100 };
+//! let bytes = binary::to_bytes(&player, &registry).unwrap();
+//!
+//! let decoded = binary::from_bytes(&bytes, &registry).unwrap();
+//! assert_eq!(decoded.try_downcast_ref::<Player>(), Some(&player));
+//! ```
+//!
+//! [`StableTypeId`]: crate::StableTypeId
+//! [registering an alias]: TypeRegistry::register_alias
+//! [`FromReflect`]: crate::FromReflect
+//! [`ReflectDefault`]: crate::std_traits::ReflectDefault
+
+mod decode;
+mod encode;
+mod error;
+mod value;
+
+pub use error::*;
+pub use value::*;
+
+use alloc::{boxed::Box, vec::Vec};
+
+use crate::{PartialReflect, ReflectKind, TypeRegistry};
+use decode::Decoder;
+use encode::Encoder;
+
+const MAGIC: &[u8] = b"BRFL";
+const VERSION: u8 = 2;
+
+/// The maximum number of values nested in each other that [`from_bytes`] decodes,
+/// which keeps malicious payloads from overflowing the stack.
+pub const MAX_DEPTH: usize = 128;
+
+const VARIANT_UNIT: u8 = 0;
+const VARIANT_TUPLE: u8 = 1;
+const VARIANT_STRUCT: u8 = 2;
+
+/// Encodes the given value in the binary format.
+///
+/// The registry is used to look up the [`ReflectBinaryValue`] of opaque types
+/// that are not built-in.
+pub fn to_bytes(
+    value: &dyn PartialReflect,
+    registry: &TypeRegistry,
+) -> Result<Vec<u8>, BinaryError> {
+    let mut encoder = Encoder::new(registry);
+    encoder.encode(value)?;
+    Ok(encoder.finish())
+}
+
+/// Decodes a value from the binary format.
+///
+/// Values whose type is registered are converted to that type when possible,
+/// otherwise they are returned as dynamic types representing it.
+/// See the [module-level documentation](self) for how changes to a type are handled.
+pub fn from_bytes(
+    bytes: &[u8],
+    registry: &TypeRegistry,
+) -> Result<Box<dyn PartialReflect>, BinaryError> {
+    let mut decoder = Decoder::new(bytes, registry)?;
+    let value = decoder.decode()?;
+    decoder.finish()?;
+    Ok(value)
+}
+
+fn kind_to_u8(kind: ReflectKind) -> u8 {
+    match kind {
+        ReflectKind::Struct => 0,
+        ReflectKind::TupleStruct => 1,
+        ReflectKind::Tuple => 2,
+        ReflectKind::List => 3,
+        ReflectKind::Array => 4,
+        ReflectKind::Map => 5,
+        ReflectKind::Set => 6,
+        ReflectKind::Enum => 7,
+        ReflectKind::Opaque => 8,
+        #[cfg(feature = "functions")]
+        ReflectKind::Function => unreachable!("functions cannot be encoded"),
+    }
+}
+
+fn kind_from_u8(kind: u8) -> Result<ReflectKind, BinaryError> {
+    Ok(match kind {
+        0 => ReflectKind::Struct,
+        1 => ReflectKind::TupleStruct,
+        2 => ReflectKind::Tuple,
+        3 => ReflectKind::List,
+        4 => ReflectKind::Array,
+        5 => ReflectKind::Map,
+        6 => ReflectKind::Set,
+        7 => ReflectKind::Enum,
+        8 => ReflectKind::Opaque,
+        _ => return Err(BinaryError::InvalidKind(kind)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{prelude::*, DynamicStruct, StableTypeId};
+    use alloc::{
+        string::{String, ToString},
+        vec,
+    };
+    use bevy_platform::collections::{HashMap, HashSet};
+    use core::time::Duration;
+
+    #[derive(Reflect, Debug, PartialEq, Clone)]
+    enum Shape {
+        Circle { radius: f32 },
+        Rect(f32, f32),
+        Point,
+    }
+
+    #[derive(Reflect, Debug, PartialEq, Clone)]
+    struct Level(u8);
+
+    #[derive(Reflect, Debug, PartialEq, Clone)]
+    struct Scene {
+        name: String,
+        shapes: Vec<Shape>,
+        layers: HashMap<String, i64>,
+        tags: HashSet<String>,
+        origin: (f32, f64),
+        corners: [u16; 2],
+        selected: Option<usize>,
+        level: Level,
+        symbol: char,
+        big: u128,
+        delay: Duration,
+    }
+
+    fn scene() -> Scene {
+        Scene {
+            name: "sketch".to_string(),
+            shapes: vec![
+                Shape::Circle { radius: 1.5 },
+                Shape::Rect(1.0, 2.0),
+                Shape::Point,
+            ],
+            layers: HashMap::from_iter([("background".to_string(), -3), ("front".to_string(), 1)]),
+            tags: HashSet::from_iter(["draft".to_string()]),
+            origin: (0.5, -0.25),
+            corners: [300, 7],
+            selected: Some(2),
+            level: Level(4),
+            symbol: 'λ',
+            big: u128::MAX,
+            delay: Duration::new(3, 250),
+        }
+    }
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Scene>();
+        registry
+    }
+
+    #[test]
+    fn round_trip() {
+        let registry = registry();
+        let bytes = to_bytes(&scene(), &registry).unwrap();
+        let decoded = from_bytes(&bytes, &registry).unwrap();
+        assert_eq!(decoded.try_downcast_ref::<Scene>(), Some(&scene()));
+    }
+
+    #[test]
+    fn round_trip_dynamic() {
+        let registry = TypeRegistry::default();
+        let mut value = DynamicStruct::default();
+        value.insert("count", 3_u32);
+        value.insert("label", "three".to_string());
+
+        let bytes = to_bytes(&value, &registry).unwrap();
+        let decoded = from_bytes(&bytes, &registry).unwrap();
+        assert!(decoded.reflect_partial_eq(&value).unwrap());
+    }
+
+    #[test]
+    fn should_be_smaller_than_ron() {
+        #[derive(Reflect, Clone)]
+        struct Stats {
+            level: u32,
+            experience: u64,
+            alive: bool,
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Vec<Stats>>();
+        let value = (0..32)
+            .map(|level| Stats {
+                level,
+                experience: u64::from(level) * 250,
+                alive: level % 2 == 0,
+            })
+            .collect::<Vec<_>>();
+        let bytes = to_bytes(&value, &registry).unwrap();
+
+        let serializer = crate::serde::ReflectSerializer::new(&value, &registry);
+        let ron = ron::to_string(&serializer).unwrap();
+        assert!(bytes.len() * 2 < ron.len());
+    }
+
+    #[test]
+    fn schema_evolution() {
+        #[derive(Reflect)]
+        #[type_path = "game"]
+        #[type_name = "Player"]
+        struct PlayerV1 {
+            name: String,
+            mana: u32,
+            inventory: Vec<Item>,
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        #[type_path = "game"]
+        #[type_name = "Player"]
+        struct PlayerV2 {
+            name: String,
+            #[reflect(default = "default_health")]
+            health: u32,
+        }
+
+        #[derive(Reflect, Debug, PartialEq, Default)]
+        #[reflect(Default)]
+        #[type_path = "game"]
+        #[type_name = "Settings"]
+        struct Settings {
+            volume: f32,
+            muted: bool,
+        }
+
+        // Opaque types of removed fields don't need to be decodable.
+        #[derive(Reflect, Clone)]
+        #[reflect(opaque, BinaryValue)]
+        struct Item(u8);
+
+        impl BinaryValue for Item {
+            fn write(&self, writer: &mut BinaryWriter) {
+                writer.write_u8(self.0);
+            }
+
+            fn read(reader: &mut BinaryReader) -> Result<Self, BinaryError> {
+                reader.read_u8().map(Item)
+            }
+        }
+
+        fn default_health() -> u32 {
+            100
+        }
+
+        let mut old_registry = TypeRegistry::default();
+        old_registry.register::<PlayerV1>();
+        old_registry.register::<Item>();
+        let mut new_registry = TypeRegistry::default();
+        new_registry.register::<PlayerV2>();
+        new_registry.register::<Settings>();
+
+        let old = PlayerV1 {
+            name: "Alice".to_string(),
+            mana: 30,
+            inventory: vec![Item(1), Item(2)],
+        };
+        let bytes = to_bytes(&old, &old_registry).unwrap();
+        let new = from_bytes(&bytes, &new_registry).unwrap();
+        assert_eq!(
+            new.try_downcast_ref::<PlayerV2>(),
+            Some(&PlayerV2 {
+                name: "Alice".to_string(),
+                health: 100,
+            })
+        );
+
+        // Types registering `ReflectDefault` start from their default value.
+        let mut settings = DynamicStruct::default();
+        settings.set_represented_type(Some(<Settings as crate::Typed>::type_info()));
+        settings.insert("volume", 0.5_f32);
+        let bytes = to_bytes(&settings, &new_registry).unwrap();
+        let settings = from_bytes(&bytes, &new_registry).unwrap();
+        assert_eq!(
+            settings.try_downcast_ref::<Settings>(),
+            Some(&Settings {
+                volume: 0.5,
+                muted: false,
+            })
+        );
+
+        // Types that can't be converted are returned as dynamic values.
+        let bytes = to_bytes(&old, &old_registry).unwrap();
+        let dynamic = from_bytes(&bytes, &TypeRegistry::empty());
+        assert_eq!(
+            dynamic.err(),
+            Some(BinaryError::UnknownType(StableTypeId::of::<Item>()))
+        );
+        let mut registry = TypeRegistry::empty();
+        registry.register::<Item>();
+        let dynamic = from_bytes(&bytes, &registry).unwrap();
+        let dynamic = dynamic.reflect_ref().as_struct().unwrap();
+        assert_eq!(dynamic.field_len(), 3);
+        assert_eq!(dynamic.name_at(1), Some("mana"));
+    }
+
+    #[test]
+    fn types_are_written_as_stable_ids() {
+        let registry = registry();
+        let bytes = to_bytes(&scene(), &registry).unwrap();
+        for type_path in [
+            Scene::type_path(),
+            Shape::type_path(),
+            "alloc::string::String",
+        ] {
+            assert!(!bytes
+                .windows(type_path.len())
+                .any(|window| window == type_path.as_bytes()));
+        }
+    }
+
+    #[test]
+    fn renamed_types_are_decoded_through_aliases() {
+        #[derive(Reflect)]
+        #[type_path = "game::old"]
+        #[type_name = "Door"]
+        struct OldDoor {
+            open: bool,
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        #[type_path = "game::new"]
+        #[type_name = "Door"]
+        struct NewDoor {
+            open: bool,
+        }
+
+        let mut old_registry = TypeRegistry::default();
+        old_registry.register::<OldDoor>();
+        let bytes = to_bytes(&OldDoor { open: true }, &old_registry).unwrap();
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<NewDoor>();
+        registry.register_alias::<NewDoor>(OldDoor::type_path());
+        let decoded = from_bytes(&bytes, &registry).unwrap();
+        assert_eq!(
+            decoded.try_downcast_ref::<NewDoor>(),
+            Some(&NewDoor { open: true })
+        );
+    }
+
+    /// Builds a payload with the given type table, no names and the given body.
+    fn payload(
+        types: &[(ReflectKind, StableTypeId)],
+        body: impl FnOnce(&mut BinaryWriter),
+    ) -> Vec<u8> {
+        let mut writer = BinaryWriter::new();
+        writer.write_bytes(MAGIC);
+        writer.write_u8(VERSION);
+        writer.write_varint(types.len() as u64);
+        for (kind, id) in types {
+            writer.write_u8(kind_to_u8(*kind));
+            writer.write_bytes(&id.to_bits().to_le_bytes());
+        }
+        writer.write_varint(0);
+        body(&mut writer);
+        writer.into_bytes()
+    }
+
+    #[test]
+    fn invalid_keys_are_errors() {
+        let registry = registry();
+        let map = StableTypeId::of_type_path("test::Map");
+        let set = StableTypeId::of_type_path("test::Set");
+        let unknown_struct = StableTypeId::of_type_path("test::Struct");
+
+        for key in [1.5_f32, f32::NAN] {
+            let bytes = payload(
+                &[
+                    (ReflectKind::Map, map),
+                    (ReflectKind::Opaque, StableTypeId::of::<f32>()),
+                ],
+                |writer| {
+                    writer.write_varint(0);
+                    writer.write_varint(1);
+                    writer.write_varint(1);
+                    key.write(writer);
+                    writer.write_varint(1);
+                    key.write(writer);
+                },
+            );
+            assert_eq!(
+                from_bytes(&bytes, &registry).err(),
+                Some(BinaryError::InvalidKey {
+                    type_path: "f32".to_string(),
+                })
+            );
+        }
+
+        let bytes = payload(
+            &[
+                (ReflectKind::Set, set),
+                (ReflectKind::Struct, unknown_struct),
+            ],
+            |writer| {
+                writer.write_varint(0);
+                writer.write_varint(1);
+                writer.write_varint(1);
+                writer.write_varint(0);
+            },
+        );
+        assert!(matches!(
+            from_bytes(&bytes, &registry),
+            Err(BinaryError::InvalidKey { .. })
+        ));
+    }
+
+    #[test]
+    fn deeply_nested_values_are_errors() {
+        let registry = registry();
+        let list = StableTypeId::of_type_path("test::List");
+        let nested = |writer: &mut BinaryWriter| {
+            for _ in 0..10_000 {
+                writer.write_varint(1);
+                writer.write_varint(1);
+            }
+            writer.write_varint(1);
+            writer.write_varint(0);
+        };
+
+        let bytes = payload(
+            &[
+                (ReflectKind::List, StableTypeId::of_type_path("test::Root")),
+                (ReflectKind::List, list),
+            ],
+            |writer| {
+                writer.write_varint(0);
+                writer.write_varint(1);
+                nested(writer);
+            },
+        );
+        assert_eq!(
+            from_bytes(&bytes, &registry).err(),
+            Some(BinaryError::DepthLimitExceeded(MAX_DEPTH))
+        );
+
+        // Skipped values are limited too.
+        let mut registry = TypeRegistry::default();
+        registry.register::<Level>();
+        let bytes = payload(
+            &[
+                (ReflectKind::TupleStruct, StableTypeId::of::<Level>()),
+                (ReflectKind::List, list),
+            ],
+            |writer| {
+                // `Level` has a single field, so the second one is skipped.
+                writer.write_varint(0);
+                writer.write_varint(2);
+                writer.write_varint(1);
+                writer.write_varint(0);
+                nested(writer);
+            },
+        );
+        assert_eq!(
+            from_bytes(&bytes, &registry).err(),
+            Some(BinaryError::DepthLimitExceeded(MAX_DEPTH))
+        );
+    }
+
+    #[test]
+    fn corrupted_payloads_are_errors() {
+        let registry = registry();
+        let bytes = to_bytes(&scene(), &registry).unwrap();
+
+        // Every truncated payload fails to decode.
+        for len in 0..bytes.len() {
+            assert!(from_bytes(&bytes[..len], &registry).is_err());
+        }
+
+        // Randomly corrupted payloads either fail to decode or decode to some value,
+        // but never panic.
+        let mut state = 0x9E37_79B9_7F4A_7C15_u64;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+        for _ in 0..2_000 {
+            let mut corrupted = bytes.clone();
+            for _ in 0..=next() % 4 {
+                let index = (next() % corrupted.len() as u64) as usize;
+                corrupted[index] = next() as u8;
+            }
+            let _ = from_bytes(&corrupted, &registry);
+        }
+    }
+
+    #[test]
+    fn errors() {
+        let registry = registry();
+
+        #[derive(Reflect, Clone)]
+        #[reflect(opaque)]
+        struct Secret;
+
+        assert_eq!(
+            to_bytes(&Secret, &registry),
+            Err(BinaryError::UnsupportedType {
+                type_path: Secret::type_path().to_string(),
+            })
+        );
+
+        let bytes = to_bytes(&scene(), &registry).unwrap();
+        assert_eq!(
+            from_bytes(b"JSON", &registry).err(),
+            Some(BinaryError::InvalidMagic)
+        );
+        assert_eq!(
+            from_bytes(&bytes[..bytes.len() - 1], &registry).err(),
+            Some(BinaryError::UnexpectedEnd)
+        );
+
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert_eq!(
+            from_bytes(&trailing, &registry).err(),
+            Some(BinaryError::TrailingBytes(1))
+        );
+
+        let mut version = bytes;
+        version[MAGIC.len()] = VERSION + 1;
+        assert_eq!(
+            from_bytes(&version, &registry).err(),
+            Some(BinaryError::UnsupportedVersion(VERSION + 1))
+        );
+    }
+
+    #[test]
+    fn varints() {
+        let mut writer = BinaryWriter::new();
+        for value in [0, 1, 127, 128, 300, u64::MAX] {
+            writer.write_varint(value);
+        }
+        for value in [0, -1, 1, i64::MIN, i64::MAX] {
+            writer.write_zigzag(value);
+        }
+        writer.write_zigzag_i128(i128::MIN);
+
+        let bytes = writer.into_bytes();
+        let mut reader = BinaryReader::new(&bytes);
+        for value in [0, 1, 127, 128, 300, u64::MAX] {
+            assert_eq!(reader.read_varint(), Ok(value));
+        }
+        for value in [0, -1, 1, i64::MIN, i64::MAX] {
+            assert_eq!(reader.read_zigzag(), Ok(value));
+        }
+        assert_eq!(reader.read_zigzag_i128(), Ok(i128::MIN));
+        assert_eq!(reader.remaining(), 0);
+
+        let mut reader = BinaryReader::new(&[0xFF; 11]);
+        assert_eq!(reader.read_varint(), Err(BinaryError::VarintOverflow));
+    }
+}
@@ -0,0 +1,464 @@
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::time::Duration;
+
+use crate::{binary::BinaryError, FromType, PartialReflect, Reflect, StableTypeId, TypePath};
+
+/// A buffer that values are written to in the [binary format].
+///
+/// Integers are written as variable-length [LEB128] integers, with signed integers being
+/// [zigzag encoded] first, so that small values take up a single byte.
+///
+/// [binary format]: crate::binary
+/// [LEB128]: https://en.wikipedia.org/wiki/LEB128
+/// [zigzag encoded]: https://en.wikipedia.org/wiki/Variable-length_quantity#Zigzag_encoding
+#[derive(Default, Debug, Clone)]
+pub struct BinaryWriter {
+    bytes: Vec<u8>,
+}
+
+impl BinaryWriter {
+    /// Creates an empty writer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Writes a single byte.
+    #[inline]
+    pub fn write_u8(&mut self, value: u8) {
+        self.bytes.push(value);
+    }
+
+    /// Writes the given bytes as-is.
+    #[inline]
+    pub fn write_bytes(&mut self, bytes: &[u8]) {
+        self.bytes.extend_from_slice(bytes);
+    }
+
+    /// Writes an unsigned variable-length integer.
+    pub fn write_varint(&mut self, mut value: u64) {
+        while value >= 0x80 {
+            self.bytes.push(value as u8 | 0x80);
+            value >>= 7;
+        }
+        self.bytes.push(value as u8);
+    }
+
+    /// Writes an unsigned variable-length 128-bit integer.
+    pub fn write_varint_u128(&mut self, mut value: u128) {
+        while value >= 0x80 {
+            self.bytes.push(value as u8 | 0x80);
+            value >>= 7;
+        }
+        self.bytes.push(value as u8);
+    }
+
+    /// Writes a signed variable-length integer.
+    #[inline]
+    pub fn write_zigzag(&mut self, value: i64) {
+        self.write_varint(((value << 1) ^ (value >> 63)) as u64);
+    }
+
+    /// Writes a signed variable-length 128-bit integer.
+    #[inline]
+    pub fn write_zigzag_i128(&mut self, value: i128) {
+        self.write_varint_u128(((value << 1) ^ (value >> 127)) as u128);
+    }
+
+    /// Writes a length-prefixed string.
+    pub fn write_str(&mut self, value: &str) {
+        self.write_varint(value.len() as u64);
+        self.write_bytes(value.as_bytes());
+    }
+
+    /// Returns the number of bytes written so far.
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Returns `true` if no bytes have been written.
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Returns the bytes written so far.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Returns the written bytes.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+/// A cursor over bytes that values are read from in the [binary format].
+///
+/// This is the counterpart to [`BinaryWriter`].
+///
+/// [binary format]: crate::binary
+#[derive(Debug, Clone)]
+pub struct BinaryReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> BinaryReader<'a> {
+    /// Creates a reader over the given bytes.
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    /// Reads a single byte.
+    #[inline]
+    pub fn read_u8(&mut self) -> Result<u8, BinaryError> {
+        let (&byte, rest) = self.bytes.split_first().ok_or(BinaryError::UnexpectedEnd)?;
+        self.bytes = rest;
+        Ok(byte)
+    }
+
+    /// Reads the given number of bytes as-is.
+    pub fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], BinaryError> {
+        if len > self.bytes.len() {
+            return Err(BinaryError::UnexpectedEnd);
+        }
+        let (bytes, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(bytes)
+    }
+
+    /// Reads a fixed number of bytes as an array.
+    pub fn read_array<const N: usize>(&mut self) -> Result<[u8; N], BinaryError> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.read_bytes(N)?);
+        Ok(array)
+    }
+
+    /// Reads an unsigned variable-length integer.
+    pub fn read_varint(&mut self) -> Result<u64, BinaryError> {
+        let mut value = 0_u64;
+        let mut shift = 0;
+        loop {
+            let byte = self.read_u8()?;
+            if shift == 63 && byte > 1 {
+                return Err(BinaryError::VarintOverflow);
+            }
+            value |= u64::from(byte & 0x7F) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+            shift += 7;
+        }
+    }
+
+    /// Reads an unsigned variable-length 128-bit integer.
+    pub fn read_varint_u128(&mut self) -> Result<u128, BinaryError> {
+        let mut value = 0_u128;
+        let mut shift = 0;
+        loop {
+            let byte = self.read_u8()?;
+            if shift == 126 && byte > 3 {
+                return Err(BinaryError::VarintOverflow);
+            }
+            value |= u128::from(byte & 0x7F) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+            shift += 7;
+        }
+    }
+
+    /// Reads a signed variable-length integer.
+    #[inline]
+    pub fn read_zigzag(&mut self) -> Result<i64, BinaryError> {
+        let value = self.read_varint()?;
+        Ok((value >> 1) as i64 ^ -((value & 1) as i64))
+    }
+
+    /// Reads a signed variable-length 128-bit integer.
+    #[inline]
+    pub fn read_zigzag_i128(&mut self) -> Result<i128, BinaryError> {
+        let value = self.read_varint_u128()?;
+        Ok((value >> 1) as i128 ^ -((value & 1) as i128))
+    }
+
+    /// Reads a variable-length integer as a length or count.
+    pub fn read_len(&mut self) -> Result<usize, BinaryError> {
+        usize::try_from(self.read_varint()?).map_err(|_| BinaryError::VarintOverflow)
+    }
+
+    /// Reads a length-prefixed string.
+    pub fn read_str(&mut self) -> Result<&'a str, BinaryError> {
+        let len = self.read_len()?;
+        core::str::from_utf8(self.read_bytes(len)?).map_err(|_| BinaryError::InvalidUtf8)
+    }
+
+    /// Returns the number of bytes left to read.
+    pub fn remaining(&self) -> usize {
+        self.bytes.len()
+    }
+}
+
+/// A type with a representation in the [binary format].
+///
+/// Opaque types are encoded using this trait, which is implemented for the primitive types,
+/// [`String`] and [`Duration`].
+/// Other opaque types need to implement it and register [`ReflectBinaryValue`]
+/// with `#[reflect(BinaryValue)]` in order to be encoded.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{binary::{BinaryError, BinaryReader, BinaryValue, BinaryWriter, ReflectBinaryValue}, Reflect};
+/// #[derive(Reflect, Clone, Copy)]
+/// #[reflect(opaque, BinaryValue)]
+/// struct Rgb(u8, u8, u8);
+///
+/// impl BinaryValue for Rgb {
+///     fn write(&self, writer: &mut BinaryWriter) {
+///         writer.write_bytes(&[self.0, self.1, self.2]);
+///     }
+///
+///     fn read(reader: &mut BinaryReader) -> Result<Self, BinaryError> {
+///         let [r, g, b] = reader.read_array()?;
+///         Ok(Rgb(r, g, b))
+///     }
+/// }
+/// ```
+///
+/// [binary format]: crate::binary
+pub trait BinaryValue: Reflect + TypePath + Sized {
+    /// Writes this value to the given writer.
+    fn write(&self, writer: &mut BinaryWriter);
+
+    /// Reads a value from the given reader.
+    fn read(reader: &mut BinaryReader) -> Result<Self, BinaryError>;
+}
+
+/// Type data for encoding opaque types in the [binary format].
+///
+/// This is created from types implementing [`BinaryValue`].
+///
+/// [binary format]: crate::binary
+#[derive(Clone, Copy)]
+pub struct ReflectBinaryValue {
+    write: fn(&dyn PartialReflect, &mut BinaryWriter) -> Result<(), BinaryError>,
+    read: fn(&mut BinaryReader) -> Result<Box<dyn Reflect>, BinaryError>,
+}
+
+impl ReflectBinaryValue {
+    /// Writes the given value, which must be of the type this type data was created for.
+    pub fn write(
+        &self,
+        value: &dyn PartialReflect,
+        writer: &mut BinaryWriter,
+    ) -> Result<(), BinaryError> {
+        (self.write)(value, writer)
+    }
+
+    /// Reads a value of the type this type data was created for.
+    pub fn read(&self, reader: &mut BinaryReader) -> Result<Box<dyn Reflect>, BinaryError> {
+        (self.read)(reader)
+    }
+}
+
+impl<T: BinaryValue> FromType<T> for ReflectBinaryValue {
+    fn from_type() -> Self {
+        Self {
+            write: |value, writer| {
+                let value =
+                    value
+                        .try_downcast_ref::<T>()
+                        .ok_or_else(|| BinaryError::InvalidValue {
+                            type_path: value.reflect_type_path().to_string(),
+                        })?;
+                value.write(writer);
+                Ok(())
+            },
+            read: |reader| Ok(Box::new(T::read(reader)?)),
+        }
+    }
+}
+
+fn invalid<T: TypePath>() -> BinaryError {
+    BinaryError::InvalidValue {
+        type_path: T::type_path().to_string(),
+    }
+}
+
+impl BinaryValue for bool {
+    fn write(&self, writer: &mut BinaryWriter) {
+        writer.write_u8(u8::from(*self));
+    }
+
+    fn read(reader: &mut BinaryReader) -> Result<Self, BinaryError> {
+        match reader.read_u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(invalid::<bool>()),
+        }
+    }
+}
+
+impl BinaryValue for u8 {
+    fn write(&self, writer: &mut BinaryWriter) {
+        writer.write_u8(*self);
+    }
+
+    fn read(reader: &mut BinaryReader) -> Result<Self, BinaryError> {
+        reader.read_u8()
+    }
+}
+
+impl BinaryValue for i8 {
+    fn write(&self, writer: &mut BinaryWriter) {
+        writer.write_u8(*self as u8);
+    }
+
+    fn read(reader: &mut BinaryReader) -> Result<Self, BinaryError> {
+        Ok(reader.read_u8()? as i8)
+    }
+}
+
+macro_rules! impl_varint {
+    ($write:ident, $read:ident, $wide:ty: $($ty:ty),*) => {
+        $(
+            impl BinaryValue for $ty {
+                fn write(&self, writer: &mut BinaryWriter) {
+                    writer.$write(*self as $wide);
+                }
+
+                fn read(reader: &mut BinaryReader) -> Result<Self, BinaryError> {
+                    <$ty>::try_from(reader.$read()?).map_err(|_| invalid::<$ty>())
+                }
+            }
+        )*
+    };
+}
+
+impl_varint!(write_varint, read_varint, u64: u16, u32, u64, usize);
+impl_varint!(write_varint_u128, read_varint_u128, u128: u128);
+impl_varint!(write_zigzag, read_zigzag, i64: i16, i32, i64, isize);
+impl_varint!(write_zigzag_i128, read_zigzag_i128, i128: i128);
+
+impl BinaryValue for f32 {
+    fn write(&self, writer: &mut BinaryWriter) {
+        writer.write_bytes(&self.to_le_bytes());
+    }
+
+    fn read(reader: &mut BinaryReader) -> Result<Self, BinaryError> {
+        Ok(f32::from_le_bytes(reader.read_array()?))
+    }
+}
+
+impl BinaryValue for f64 {
+    fn write(&self, writer: &mut BinaryWriter) {
+        writer.write_bytes(&self.to_le_bytes());
+    }
+
+    fn read(reader: &mut BinaryReader) -> Result<Self, BinaryError> {
+        Ok(f64::from_le_bytes(reader.read_array()?))
+    }
+}
+
+impl BinaryValue for char {
+    fn write(&self, writer: &mut BinaryWriter) {
+        writer.write_varint(u64::from(u32::from(*self)));
+    }
+
+    fn read(reader: &mut BinaryReader) -> Result<Self, BinaryError> {
+        u32::try_from(reader.read_varint()?)
+            .ok()
+            .and_then(char::from_u32)
+            .ok_or_else(invalid::<char>)
+    }
+}
+
+impl BinaryValue for String {
+    fn write(&self, writer: &mut BinaryWriter) {
+        writer.write_str(self);
+    }
+
+    fn read(reader: &mut BinaryReader) -> Result<Self, BinaryError> {
+        reader.read_str().map(String::from)
+    }
+}
+
+impl BinaryValue for Duration {
+    fn write(&self, writer: &mut BinaryWriter) {
+        writer.write_varint(self.as_secs());
+        writer.write_varint(u64::from(self.subsec_nanos()));
+    }
+
+    fn read(reader: &mut BinaryReader) -> Result<Self, BinaryError> {
+        let secs = reader.read_varint()?;
+        let nanos = u32::try_from(reader.read_varint()?)
+            .ok()
+            .filter(|nanos| *nanos < 1_000_000_000)
+            .ok_or_else(invalid::<Duration>)?;
+        Ok(Duration::new(secs, nanos))
+    }
+}
+
+/// The type paths of the built-in types, see [`builtin`].
+const BUILTIN_TYPE_PATHS: [&str; 18] = [
+    "bool",
+    "u8",
+    "u16",
+    "u32",
+    "u64",
+    "u128",
+    "usize",
+    "i8",
+    "i16",
+    "i32",
+    "i64",
+    "i128",
+    "isize",
+    "f32",
+    "f64",
+    "char",
+    "alloc::string::String",
+    "core::time::Duration",
+];
+
+/// Returns the [`ReflectBinaryValue`] of the built-in type with the given [`StableTypeId`].
+pub(super) fn builtin_with_id(id: StableTypeId) -> Option<ReflectBinaryValue> {
+    BUILTIN_TYPE_PATHS
+        .into_iter()
+        .find(|type_path| StableTypeId::of_type_path(type_path) == id)
+        .and_then(builtin)
+}
+
+/// Returns the [`ReflectBinaryValue`] of the built-in type with the given type path.
+///
+/// Built-in types are encoded without a length prefix and don't need to be registered.
+pub(super) fn builtin(type_path: &str) -> Option<ReflectBinaryValue> {
+    fn of<T: BinaryValue>() -> Option<ReflectBinaryValue> {
+        Some(<ReflectBinaryValue as FromType<T>>::from_type())
+    }
+
+    match type_path {
+        "bool" => of::<bool>(),
+        "u8" => of::<u8>(),
+        "u16" => of::<u16>(),
+        "u32" => of::<u32>(),
+        "u64" => of::<u64>(),
+        "u128" => of::<u128>(),
+        "usize" => of::<usize>(),
+        "i8" => of::<i8>(),
+        "i16" => of::<i16>(),
+        "i32" => of::<i32>(),
+        "i64" => of::<i64>(),
+        "i128" => of::<i128>(),
+        "isize" => of::<isize>(),
+        "f32" => of::<f32>(),
+        "f64" => of::<f64>(),
+        "char" => of::<char>(),
+        "alloc::string::String" => of::<String>(),
+        "core::time::Duration" => of::<Duration>(),
+        _ => None,
+    }
+}
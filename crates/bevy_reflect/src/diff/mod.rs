@@ -0,0 +1,217 @@
+//! Computing and applying the differences between reflected values.
+//!
+//! [`Patch::diff`] compares two values of the same type and returns a [`Patch`]: a list of
+//! [changes](PatchChange) that each modify the value at a [`ParsedPath`](crate::ParsedPath),
+//! such as setting a field, inserting into or removing from a list, changing a map entry or
+//! switching an enum variant. Applying the patch to the old value with [`Patch::apply`] turns
+//! it into the new one.
+//!
+//! Since a patch only contains what changed, it's well suited for undo stacks, for editors that
+//! track modifications, and for sending the changes of a value over the network. Patches can be
+//! serialized with a [`PatchSerializer`] and deserialized with a [`PatchDeserializer`].
+
+mod patch;
+mod serde;
+
+pub use patch::*;
+pub use serde::*;
+
+#[cfg(test)]
+mod tests {
+    use super::{ApplyPatchError, Patch, PatchDeserializer, PatchOp, PatchSerializer};
+    use crate::{DynamicStruct, PartialReflect, Reflect, Struct, TypeRegistry};
+    use alloc::{
+        string::{String, ToString},
+        vec,
+        vec::Vec,
+    };
+    use bevy_platform::collections::{HashMap, HashSet};
+    use serde::de::DeserializeSeed;
+
+    #[derive(Reflect, Debug, PartialEq, Clone)]
+    enum Shape {
+        Circle { radius: f32 },
+        Rect(f32, f32),
+        Point,
+    }
+
+    #[derive(Reflect, Debug, PartialEq, Clone)]
+    struct Drawing {
+        name: String,
+        shapes: Vec<Shape>,
+        layers: HashMap<String, u32>,
+        tags: HashSet<String>,
+        origin: (f32, f32),
+        selected: Option<usize>,
+    }
+
+    fn drawing() -> Drawing {
+        Drawing {
+            name: "sketch".to_string(),
+            shapes: vec![
+                Shape::Circle { radius: 1.0 },
+                Shape::Rect(1.0, 2.0),
+                Shape::Point,
+            ],
+            layers: HashMap::from_iter([("background".to_string(), 0), ("front".to_string(), 1)]),
+            tags: HashSet::from_iter(["draft".to_string()]),
+            origin: (0.0, 0.0),
+            selected: None,
+        }
+    }
+
+    fn paths(patch: &Patch) -> Vec<(String, &'static str)> {
+        patch
+            .changes()
+            .iter()
+            .map(|change| (change.path.to_string(), change.op.name()))
+            .collect()
+    }
+
+    #[test]
+    fn equal_values_have_an_empty_patch() {
+        assert!(Patch::diff(&drawing(), &drawing()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn diff_and_apply() {
+        let old = drawing();
+        let mut new = drawing();
+        new.shapes[0] = Shape::Circle { radius: 2.0 };
+        new.shapes[1] = Shape::Point;
+        new.shapes.insert(2, Shape::Rect(3.0, 4.0));
+        new.layers.remove("background");
+        new.layers.insert("front".to_string(), 2);
+        new.layers.insert("overlay".to_string(), 3);
+        new.tags = HashSet::from_iter(["final".to_string()]);
+        new.origin.1 = 5.0;
+        new.selected = Some(1);
+
+        let patch = Patch::diff(&old, &new).unwrap();
+        let mut paths = paths(&patch);
+        // Map and set changes come in an unspecified order.
+        paths[3..6].sort();
+        paths[6..8].sort();
+        assert_eq!(
+            paths,
+            [
+                (".shapes[0].radius", "replace"),
+                (".shapes[1]", "variant"),
+                (".shapes", "list_insert"),
+                (".layers", "map_insert"),
+                (".layers", "map_insert"),
+                (".layers", "map_remove"),
+                (".tags", "set_insert"),
+                (".tags", "set_remove"),
+                (".origin.1", "replace"),
+                (".selected", "variant"),
+            ]
+            .map(|(path, op)| (path.to_string(), op))
+        );
+
+        let mut value = old.clone();
+        patch.apply(&mut value).unwrap();
+        assert_eq!(value, new);
+
+        // Removing from the middle of a list doesn't touch the other elements.
+        let mut new = old.clone();
+        new.shapes.remove(1);
+        let patch = Patch::diff(&old, &new).unwrap();
+        assert!(matches!(
+            patch.changes(),
+            [change] if matches!(change.op, PatchOp::ListRemove { index: 1 })
+        ));
+        let mut value = old;
+        patch.apply(&mut value).unwrap();
+        assert_eq!(value, new);
+    }
+
+    #[test]
+    fn diff_dynamic_values() {
+        let mut old = DynamicStruct::default();
+        old.insert("a", 1_u32);
+        old.insert("b", 2_u32);
+        let mut new = old.to_dynamic_struct();
+        new.insert("b", 3_u32);
+
+        let patch = Patch::diff(&old, &new).unwrap();
+        assert_eq!(paths(&patch), [(".b".to_string(), "replace")]);
+        patch.apply(&mut old).unwrap();
+        assert_eq!(old.reflect_partial_eq(&new), Some(true));
+    }
+
+    #[test]
+    fn mismatched_values_are_errors() {
+        assert!(Patch::diff(&1_u32, &1_i32).is_err());
+
+        let patch = Patch::diff(&vec![1_u32], &vec![1_u32, 2]).unwrap();
+        let mut value = 1_u32;
+        assert!(patch.apply(&mut value).is_err());
+        let mut value: Vec<u32> = Vec::new();
+        assert!(patch.apply(&mut value).is_err());
+
+        // Elements, keys and values of the wrong type are errors instead of panics.
+        let mut value: Vec<u32> = vec![1];
+        assert!(matches!(
+            Patch::diff(&vec![1_i32], &vec![1_i32, 2])
+                .unwrap()
+                .apply(&mut value),
+            Err(ApplyPatchError::MismatchedTypes { .. })
+        ));
+        let old: HashMap<String, u32> = HashMap::default();
+        let new: HashMap<String, u32> = HashMap::from_iter([("a".to_string(), 1)]);
+        let mut value: HashMap<String, i32> = HashMap::default();
+        assert!(matches!(
+            Patch::diff(&old, &new).unwrap().apply(&mut value),
+            Err(ApplyPatchError::MismatchedTypes { .. })
+        ));
+        let mut value: HashMap<u32, u32> = HashMap::default();
+        assert!(matches!(
+            Patch::diff(&old, &new).unwrap().apply(&mut value),
+            Err(ApplyPatchError::MismatchedTypes { .. })
+        ));
+        let mut value: HashSet<u32> = HashSet::default();
+        assert!(matches!(
+            Patch::diff(
+                &HashSet::<String>::default(),
+                &HashSet::<String>::from_iter(["a".to_string()])
+            )
+            .unwrap()
+            .apply(&mut value),
+            Err(ApplyPatchError::MismatchedTypes { .. })
+        ));
+        assert!(value.is_empty());
+    }
+
+    #[test]
+    fn serialize_patches() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Drawing>();
+
+        let old = drawing();
+        let mut new = drawing();
+        new.name = "final".to_string();
+        new.shapes.push(Shape::Rect(5.0, 6.0));
+        new.layers.remove("front");
+        new.tags.insert("final".to_string());
+        new.selected = Some(2);
+        let patch = Patch::diff(&old, &new).unwrap();
+
+        let serialized = ron::to_string(&PatchSerializer::new(&patch, &registry)).unwrap();
+        let mut deserializer = ron::Deserializer::from_str(&serialized).unwrap();
+        let patch = PatchDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        let mut value = old.clone();
+        patch.apply(&mut value).unwrap();
+        assert_eq!(value, new);
+
+        let serialized = serde_json::to_string(&PatchSerializer::new(&patch, &registry)).unwrap();
+        let patch = PatchDeserializer::new(&registry)
+            .deserialize(&mut serde_json::Deserializer::from_str(&serialized))
+            .unwrap();
+        let mut value = old;
+        patch.apply(&mut value).unwrap();
+        assert_eq!(value, new);
+    }
+}
@@ -0,0 +1,633 @@
+use crate::{access::Access, ParsedPath};
+use crate::{
+    ApplyError, Map, PartialReflect, ReflectCloneError, ReflectKind, ReflectMut, ReflectPath,
+    ReflectRef, Set, Type, TypeInfo,
+};
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use thiserror::Error;
+
+/// A list of changes that turns one reflected value into another.
+///
+/// Patches are computed with [`Patch::diff`] and applied with [`Patch::apply`].
+/// Every [change](PatchChange) addresses the value it modifies with a [`ParsedPath`],
+/// so a patch only contains the parts of a value that actually changed.
+///
+/// Patches can be serialized with a [`PatchSerializer`] and deserialized with a
+/// [`PatchDeserializer`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{diff::{Patch, PatchOp}, Reflect};
+/// #[derive(Reflect, Debug, PartialEq)]
+/// struct Player {
+///     name: String,
+///     health: u32,
+///     items: Vec<String>,
+/// }
+///
+/// let old = Player {
+///     name: "Bevy".to_string(),
+///     health: 100,
+///     items: vec!["sword".to_string()],
+/// };
+/// let new = Player {
+///     name: "Bevy".to_string(),
+///     health: 80,
+///     items: vec!["sword".to_string(), "shield".to_string()],
+/// };
+///
+/// let patch = Patch::diff(&old, &new).unwrap();
+/// assert_eq!(patch.changes().len(), 2);
+/// assert_eq!(patch.changes()[0].path.to_string(), ".health");
+/// assert!(matches!(patch.changes()[1].op, PatchOp::ListInsert { index: 1, .. }));
+///
+/// let mut value = old;
+/// patch.apply(&mut value).unwrap();
+/// assert_eq!(value, new);
+/// ```
+///
+/// [`PatchSerializer`]: crate::diff::PatchSerializer
+/// [`PatchDeserializer`]: crate::diff::PatchDeserializer
+#[derive(Debug, Default)]
+pub struct Patch {
+    changes: Vec<PatchChange>,
+}
+
+/// A single change of a [`Patch`].
+#[derive(Debug)]
+pub struct PatchChange {
+    /// The path to the value that is changed, relative to the value the patch is applied to.
+    pub path: ParsedPath,
+    /// The change to make to the value at [`path`](Self::path).
+    pub op: PatchOp,
+}
+
+/// The operation of a [`PatchChange`].
+#[derive(Debug)]
+pub enum PatchOp {
+    /// Replaces the value with the given one.
+    ///
+    /// This is used for values that can't be diffed any further, such as [opaque] values.
+    ///
+    /// [opaque]: ReflectKind::Opaque
+    Replace(Box<dyn PartialReflect>),
+    /// Replaces the enum value with the given one, which has a different variant.
+    Variant(Box<dyn PartialReflect>),
+    /// Inserts an element into the list at the given index.
+    ListInsert {
+        /// The index of the new element.
+        index: usize,
+        /// The new element.
+        value: Box<dyn PartialReflect>,
+    },
+    /// Removes the element at the given index from the list.
+    ListRemove {
+        /// The index of the removed element.
+        index: usize,
+    },
+    /// Inserts an entry into the map, replacing the value of an existing entry with the same key.
+    MapInsert {
+        /// The key of the entry.
+        key: Box<dyn PartialReflect>,
+        /// The new value of the entry.
+        value: Box<dyn PartialReflect>,
+    },
+    /// Removes the entry with the given key from the map.
+    MapRemove {
+        /// The key of the removed entry.
+        key: Box<dyn PartialReflect>,
+    },
+    /// Inserts the given value into the set.
+    SetInsert(Box<dyn PartialReflect>),
+    /// Removes the given value from the set.
+    SetRemove(Box<dyn PartialReflect>),
+}
+
+impl PatchOp {
+    /// Returns the name of the operation, as used when serializing it.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Replace(_) => "replace",
+            Self::Variant(_) => "variant",
+            Self::ListInsert { .. } => "list_insert",
+            Self::ListRemove { .. } => "list_remove",
+            Self::MapInsert { .. } => "map_insert",
+            Self::MapRemove { .. } => "map_remove",
+            Self::SetInsert(_) => "set_insert",
+            Self::SetRemove(_) => "set_remove",
+        }
+    }
+}
+
+/// An error returned by [`Patch::diff`].
+#[derive(Error, Debug)]
+pub enum DiffError {
+    /// The two values are of different types.
+    #[error("cannot diff `{old}` with `{new}`")]
+    MismatchedTypes {
+        /// The type path of the old value.
+        old: Box<str>,
+        /// The type path of the new value.
+        new: Box<str>,
+    },
+    /// A changed value couldn't be cloned into the patch.
+    #[error(transparent)]
+    Clone(#[from] ReflectCloneError),
+}
+
+/// An error returned by [`Patch::apply`].
+#[derive(Error, Debug)]
+pub enum ApplyPatchError {
+    /// The path of a change doesn't lead to a value.
+    #[error("cannot access `{path}`: {message}")]
+    InvalidPath {
+        /// The path of the change.
+        path: String,
+        /// A description of why the path is invalid.
+        message: String,
+    },
+    /// The value at the path of a change is of the wrong kind for its operation,
+    /// e.g. a [`PatchOp::ListInsert`] on a struct.
+    #[error("expected a {expected} at `{path}`, found a {actual}")]
+    MismatchedKinds {
+        /// The path of the change.
+        path: String,
+        /// The kind that the operation expected.
+        expected: ReflectKind,
+        /// The kind of the value at the path.
+        actual: ReflectKind,
+    },
+    /// The index of a list operation is out of bounds.
+    #[error("index {index} is out of bounds for the list at `{path}` of length {len}")]
+    IndexOutOfBounds {
+        /// The path of the change.
+        path: String,
+        /// The index of the operation.
+        index: usize,
+        /// The length of the list.
+        len: usize,
+    },
+    /// A new element, key or value of a change isn't of the type stored by the list, map or set
+    /// at the path of the change.
+    #[error("expected a value of type `{expected}` at `{path}`, found `{actual}`")]
+    MismatchedTypes {
+        /// The path of the change.
+        path: String,
+        /// The type path of the type stored by the list, map or set.
+        expected: Box<str>,
+        /// The type path of the value of the change.
+        actual: Box<str>,
+    },
+    /// The new value of a change couldn't be applied.
+    #[error("cannot apply the value at `{path}`: {error}")]
+    Apply {
+        /// The path of the change.
+        path: String,
+        /// The error returned by [`PartialReflect::try_apply`].
+        #[source]
+        error: ApplyError,
+    },
+}
+
+impl Patch {
+    /// Creates an empty patch.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Computes the patch that turns `old` into `new`.
+    ///
+    /// The values must be of the same type, or represent the same type if they are dynamic.
+    /// Changed values are cloned into the patch with [`PartialReflect::reflect_clone`], falling
+    /// back to [`PartialReflect::to_dynamic`] for types that can't be cloned directly.
+    ///
+    /// - Structs, tuples, tuple structs and arrays are diffed field by field.
+    /// - Lists are diffed element by element. Elements added or removed around a common prefix
+    ///   and suffix become [`PatchOp::ListInsert`] and [`PatchOp::ListRemove`] changes.
+    /// - Maps and sets are diffed by key, producing [`PatchOp::MapInsert`],
+    ///   [`PatchOp::MapRemove`], [`PatchOp::SetInsert`] and [`PatchOp::SetRemove`] changes.
+    /// - Enums of the same variant are diffed field by field, otherwise a
+    ///   [`PatchOp::Variant`] change replaces the whole value.
+    /// - Opaque values are compared with [`PartialReflect::reflect_partial_eq`] and replaced if
+    ///   they aren't known to be equal.
+    pub fn diff(old: &dyn PartialReflect, new: &dyn PartialReflect) -> Result<Self, DiffError> {
+        if !is_same_type(old, new) {
+            return Err(DiffError::MismatchedTypes {
+                old: old.reflect_type_path().into(),
+                new: new.reflect_type_path().into(),
+            });
+        }
+        let mut patch = Self::new();
+        diff_value(old, new, &mut Vec::new(), &mut patch.changes)?;
+        Ok(patch)
+    }
+
+    /// Returns the changes of the patch, in the order they are applied.
+    pub fn changes(&self) -> &[PatchChange] {
+        &self.changes
+    }
+
+    /// Adds a change to the end of the patch.
+    pub fn push(&mut self, change: PatchChange) {
+        self.changes.push(change);
+    }
+
+    /// Returns `true` if the patch has no changes.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Applies the changes of the patch to the given value, in order.
+    ///
+    /// If a change fails, the error is returned and the changes before it remain applied.
+    /// Elements, keys and values that are inserted into or removed from a list, map or set must
+    /// represent the type it stores, otherwise [`ApplyPatchError::MismatchedTypes`] is returned.
+    pub fn apply(&self, target: &mut dyn PartialReflect) -> Result<(), ApplyPatchError> {
+        for PatchChange { path, op } in &self.changes {
+            let value =
+                path.reflect_element_mut(target)
+                    .map_err(|err| ApplyPatchError::InvalidPath {
+                        path: path.to_string(),
+                        message: format!("{err}"),
+                    })?;
+            apply_op(path, op, value)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromIterator<PatchChange> for Patch {
+    fn from_iter<T: IntoIterator<Item = PatchChange>>(iter: T) -> Self {
+        Self {
+            changes: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl IntoIterator for Patch {
+    type Item = PatchChange;
+    type IntoIter = alloc::vec::IntoIter<PatchChange>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.changes.into_iter()
+    }
+}
+
+fn apply_op(
+    path: &ParsedPath,
+    op: &PatchOp,
+    target: &mut dyn PartialReflect,
+) -> Result<(), ApplyPatchError> {
+    let mismatched_kinds = |expected, actual| ApplyPatchError::MismatchedKinds {
+        path: path.to_string(),
+        expected,
+        actual,
+    };
+    match op {
+        PatchOp::Replace(value) | PatchOp::Variant(value) => target
+            .try_apply(value.as_ref())
+            .map_err(|error| ApplyPatchError::Apply {
+                path: path.to_string(),
+                error,
+            }),
+        PatchOp::ListInsert { index, value } => {
+            let ReflectMut::List(list) = target.reflect_mut() else {
+                return Err(mismatched_kinds(ReflectKind::List, target.reflect_kind()));
+            };
+            if *index > list.len() {
+                return Err(ApplyPatchError::IndexOutOfBounds {
+                    path: path.to_string(),
+                    index: *index,
+                    len: list.len(),
+                });
+            }
+            if let Some(info) = list.get_represented_list_info() {
+                check_type(path, info.item_ty(), value.as_ref())?;
+            }
+            list.insert(*index, clone_value(value.as_ref()));
+            Ok(())
+        }
+        PatchOp::ListRemove { index } => {
+            let ReflectMut::List(list) = target.reflect_mut() else {
+                return Err(mismatched_kinds(ReflectKind::List, target.reflect_kind()));
+            };
+            if *index >= list.len() {
+                return Err(ApplyPatchError::IndexOutOfBounds {
+                    path: path.to_string(),
+                    index: *index,
+                    len: list.len(),
+                });
+            }
+            list.remove(*index);
+            Ok(())
+        }
+        PatchOp::MapInsert { key, value } => {
+            let ReflectMut::Map(map) = target.reflect_mut() else {
+                return Err(mismatched_kinds(ReflectKind::Map, target.reflect_kind()));
+            };
+            if let Some(info) = map.get_represented_map_info() {
+                check_type(path, info.key_ty(), key.as_ref())?;
+                check_type(path, info.value_ty(), value.as_ref())?;
+            }
+            map.insert_boxed(clone_value(key.as_ref()), clone_value(value.as_ref()));
+            Ok(())
+        }
+        PatchOp::MapRemove { key } => {
+            let ReflectMut::Map(map) = target.reflect_mut() else {
+                return Err(mismatched_kinds(ReflectKind::Map, target.reflect_kind()));
+            };
+            if let Some(info) = map.get_represented_map_info() {
+                check_type(path, info.key_ty(), key.as_ref())?;
+            }
+            map.remove(key.as_ref());
+            Ok(())
+        }
+        PatchOp::SetInsert(value) => {
+            let ReflectMut::Set(set) = target.reflect_mut() else {
+                return Err(mismatched_kinds(ReflectKind::Set, target.reflect_kind()));
+            };
+            check_set_value(path, set, value.as_ref())?;
+            set.insert_boxed(clone_value(value.as_ref()));
+            Ok(())
+        }
+        PatchOp::SetRemove(value) => {
+            let ReflectMut::Set(set) = target.reflect_mut() else {
+                return Err(mismatched_kinds(ReflectKind::Set, target.reflect_kind()));
+            };
+            check_set_value(path, set, value.as_ref())?;
+            set.remove(value.as_ref());
+            Ok(())
+        }
+    }
+}
+
+/// Checks that the value of a change represents the type stored by a list, map or set.
+fn check_type(
+    path: &ParsedPath,
+    expected: Type,
+    value: &dyn PartialReflect,
+) -> Result<(), ApplyPatchError> {
+    match value.get_represented_type_info() {
+        Some(info) if info.type_id() == expected.id() => Ok(()),
+        _ => Err(ApplyPatchError::MismatchedTypes {
+            path: path.to_string(),
+            expected: expected.path().into(),
+            actual: value.reflect_type_path().into(),
+        }),
+    }
+}
+
+fn check_set_value(
+    path: &ParsedPath,
+    set: &dyn Set,
+    value: &dyn PartialReflect,
+) -> Result<(), ApplyPatchError> {
+    match set.get_represented_type_info() {
+        Some(TypeInfo::Set(info)) => check_type(path, info.value_ty(), value),
+        _ => Ok(()),
+    }
+}
+
+/// Returns `true` if both values are, or represent, the same type.
+fn is_same_type(a: &dyn PartialReflect, b: &dyn PartialReflect) -> bool {
+    match (a.get_represented_type_info(), b.get_represented_type_info()) {
+        (Some(a), Some(b)) => a.type_id() == b.type_id(),
+        _ => a.reflect_kind() == b.reflect_kind(),
+    }
+}
+
+/// Clones a value that is stored in a patch, preferring a concrete clone over a dynamic one.
+fn clone_value(value: &dyn PartialReflect) -> Box<dyn PartialReflect> {
+    try_clone_value(value).unwrap_or_else(|_| value.to_dynamic())
+}
+
+fn try_clone_value(
+    value: &dyn PartialReflect,
+) -> Result<Box<dyn PartialReflect>, ReflectCloneError> {
+    match value.reflect_clone() {
+        Ok(value) => Ok(value.into_partial_reflect()),
+        // Opaque values have no dynamic representation to fall back to.
+        Err(err) if value.reflect_kind() == ReflectKind::Opaque => Err(err),
+        Err(_) => Ok(value.to_dynamic()),
+    }
+}
+
+fn diff_value(
+    old: &dyn PartialReflect,
+    new: &dyn PartialReflect,
+    path: &mut Vec<Access<'static>>,
+    changes: &mut Vec<PatchChange>,
+) -> Result<(), DiffError> {
+    let push = |changes: &mut Vec<PatchChange>, path: &[Access<'static>], op| {
+        changes.push(PatchChange {
+            path: ParsedPath::from(path.to_vec()),
+            op,
+        });
+    };
+    if !is_same_type(old, new) {
+        push(changes, path, PatchOp::Replace(try_clone_value(new)?));
+        return Ok(());
+    }
+
+    match (old.reflect_ref(), new.reflect_ref()) {
+        (ReflectRef::Struct(old_struct), ReflectRef::Struct(new_struct)) => {
+            let same_fields = old_struct.field_len() == new_struct.field_len()
+                && (0..old_struct.field_len())
+                    .all(|i| old_struct.name_at(i) == new_struct.name_at(i));
+            if !same_fields {
+                push(changes, path, PatchOp::Replace(try_clone_value(new)?));
+                return Ok(());
+            }
+            for (i, old_field) in old_struct.iter_fields().enumerate() {
+                let name = old_struct.name_at(i).unwrap_or_default();
+                let new_field = new_struct.field_at(i).unwrap();
+                path.push(Access::Field(name.to_string().into()));
+                diff_value(old_field, new_field, path, changes)?;
+                path.pop();
+            }
+        }
+        (ReflectRef::TupleStruct(old_tuple), ReflectRef::TupleStruct(new_tuple)) => {
+            if old_tuple.field_len() != new_tuple.field_len() {
+                push(changes, path, PatchOp::Replace(try_clone_value(new)?));
+                return Ok(());
+            }
+            for (i, (old_field, new_field)) in old_tuple
+                .iter_fields()
+                .zip(new_tuple.iter_fields())
+                .enumerate()
+            {
+                path.push(Access::TupleIndex(i));
+                diff_value(old_field, new_field, path, changes)?;
+                path.pop();
+            }
+        }
+        (ReflectRef::Tuple(old_tuple), ReflectRef::Tuple(new_tuple)) => {
+            if old_tuple.field_len() != new_tuple.field_len() {
+                push(changes, path, PatchOp::Replace(try_clone_value(new)?));
+                return Ok(());
+            }
+            for (i, (old_field, new_field)) in old_tuple
+                .iter_fields()
+                .zip(new_tuple.iter_fields())
+                .enumerate()
+            {
+                path.push(Access::TupleIndex(i));
+                diff_value(old_field, new_field, path, changes)?;
+                path.pop();
+            }
+        }
+        (ReflectRef::Array(old_array), ReflectRef::Array(new_array)) => {
+            if old_array.len() != new_array.len() {
+                push(changes, path, PatchOp::Replace(try_clone_value(new)?));
+                return Ok(());
+            }
+            for (i, (old_item, new_item)) in old_array.iter().zip(new_array.iter()).enumerate() {
+                path.push(Access::ListIndex(i));
+                diff_value(old_item, new_item, path, changes)?;
+                path.pop();
+            }
+        }
+        (ReflectRef::List(old_list), ReflectRef::List(new_list)) => {
+            let is_equal = |a: usize, b: usize| {
+                let (a, b) = (old_list.get(a).unwrap(), new_list.get(b).unwrap());
+                a.reflect_partial_eq(b) == Some(true)
+            };
+            let (old_len, new_len) = (old_list.len(), new_list.len());
+            let prefix = (0..old_len.min(new_len))
+                .take_while(|&i| is_equal(i, i))
+                .count();
+            let suffix = (0..old_len.min(new_len) - prefix)
+                .take_while(|&i| is_equal(old_len - 1 - i, new_len - 1 - i))
+                .count();
+
+            // Diff the elements in between that kept their index, then insert or remove the rest.
+            let (old_end, new_end) = (old_len - suffix, new_len - suffix);
+            let kept = (old_end - prefix).min(new_end - prefix);
+            for i in prefix..prefix + kept {
+                path.push(Access::ListIndex(i));
+                diff_value(
+                    old_list.get(i).unwrap(),
+                    new_list.get(i).unwrap(),
+                    path,
+                    changes,
+                )?;
+                path.pop();
+            }
+            for index in prefix + kept..new_end {
+                let value = try_clone_value(new_list.get(index).unwrap())?;
+                push(changes, path, PatchOp::ListInsert { index, value });
+            }
+            for index in (prefix + kept..old_end).rev() {
+                push(changes, path, PatchOp::ListRemove { index });
+            }
+        }
+        (ReflectRef::Map(old_map), ReflectRef::Map(new_map)) => {
+            diff_map(old_map, new_map, path, changes)?;
+        }
+        (ReflectRef::Set(old_set), ReflectRef::Set(new_set)) => {
+            diff_set(old_set, new_set, path, changes)?;
+        }
+        (ReflectRef::Enum(old_enum), ReflectRef::Enum(new_enum)) => {
+            if old_enum.variant_name() != new_enum.variant_name()
+                || old_enum.field_len() != new_enum.field_len()
+            {
+                push(changes, path, PatchOp::Variant(try_clone_value(new)?));
+                return Ok(());
+            }
+            for (i, (old_field, new_field)) in old_enum
+                .iter_fields()
+                .zip(new_enum.iter_fields())
+                .enumerate()
+            {
+                path.push(match old_field.name() {
+                    Some(name) => Access::Field(name.to_string().into()),
+                    None => Access::TupleIndex(i),
+                });
+                diff_value(old_field.value(), new_field.value(), path, changes)?;
+                path.pop();
+            }
+        }
+        _ => {
+            if old.reflect_partial_eq(new) != Some(true) {
+                push(changes, path, PatchOp::Replace(try_clone_value(new)?));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn diff_map(
+    old_map: &dyn Map,
+    new_map: &dyn Map,
+    path: &[Access<'static>],
+    changes: &mut Vec<PatchChange>,
+) -> Result<(), DiffError> {
+    let path = ParsedPath::from(path.to_vec());
+    for (key, old_value) in old_map.iter() {
+        let op = match new_map.get(key) {
+            None => PatchOp::MapRemove {
+                key: try_clone_value(key)?,
+            },
+            Some(new_value) => {
+                // Map entries can't be addressed by paths, so a changed entry is replaced.
+                let mut entry_changes = Vec::new();
+                diff_value(old_value, new_value, &mut Vec::new(), &mut entry_changes)?;
+                if entry_changes.is_empty() {
+                    continue;
+                }
+                PatchOp::MapInsert {
+                    key: try_clone_value(key)?,
+                    value: try_clone_value(new_value)?,
+                }
+            }
+        };
+        changes.push(PatchChange {
+            path: path.clone(),
+            op,
+        });
+    }
+    for (key, new_value) in new_map.iter() {
+        if old_map.get(key).is_none() {
+            changes.push(PatchChange {
+                path: path.clone(),
+                op: PatchOp::MapInsert {
+                    key: try_clone_value(key)?,
+                    value: try_clone_value(new_value)?,
+                },
+            });
+        }
+    }
+    Ok(())
+}
+
+fn diff_set(
+    old_set: &dyn Set,
+    new_set: &dyn Set,
+    path: &[Access<'static>],
+    changes: &mut Vec<PatchChange>,
+) -> Result<(), DiffError> {
+    let path = ParsedPath::from(path.to_vec());
+    for value in old_set.iter() {
+        if !new_set.contains(value) {
+            changes.push(PatchChange {
+                path: path.clone(),
+                op: PatchOp::SetRemove(try_clone_value(value)?),
+            });
+        }
+    }
+    for value in new_set.iter() {
+        if !old_set.contains(value) {
+            changes.push(PatchChange {
+                path: path.clone(),
+                op: PatchOp::SetInsert(try_clone_value(value)?),
+            });
+        }
+    }
+    Ok(())
+}
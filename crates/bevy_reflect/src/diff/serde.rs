@@ -0,0 +1,253 @@
+use super::{Patch, PatchChange, PatchOp};
+use crate::{
+    serde::{ReflectDeserializer, ReflectSerializer},
+    ParsedPath, PartialReflect, TypeRegistry,
+};
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+};
+use core::fmt::{self, Formatter};
+use serde::{
+    de::{self, DeserializeSeed, MapAccess, SeqAccess, Visitor},
+    ser::{SerializeMap, SerializeSeq},
+    Serialize, Serializer,
+};
+
+const PATH: &str = "path";
+const OP: &str = "op";
+const INDEX: &str = "index";
+const KEY: &str = "key";
+const VALUE: &str = "value";
+
+/// A serializer for a [`Patch`].
+///
+/// The patch is serialized as a sequence of changes. Each change is a map with the `path` of the
+/// change, the [name](PatchOp::name) of its `op` and, depending on the operation, an `index`,
+/// a `key` and a `value`. Keys and values are serialized with a [`ReflectSerializer`], so their
+/// types must be registered in the [`TypeRegistry`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{diff::{Patch, PatchDeserializer, PatchSerializer}, Reflect, TypeRegistry};
+/// # use serde::de::DeserializeSeed;
+/// #[derive(Reflect, Debug, PartialEq)]
+/// struct Position {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Position>();
+///
+/// let patch = Patch::diff(&Position { x: 0.0, y: 0.0 }, &Position { x: 0.0, y: 1.0 }).unwrap();
+/// let serialized = ron::to_string(&PatchSerializer::new(&patch, &registry)).unwrap();
+/// assert_eq!(serialized, r#"[{"path":".y","op":"replace","value":{"f32":1.0}}]"#);
+///
+/// let mut deserializer = ron::Deserializer::from_str(&serialized).unwrap();
+/// let patch = PatchDeserializer::new(&registry).deserialize(&mut deserializer).unwrap();
+///
+/// let mut position = Position { x: 0.0, y: 0.0 };
+/// patch.apply(&mut position).unwrap();
+/// assert_eq!(position, Position { x: 0.0, y: 1.0 });
+/// ```
+pub struct PatchSerializer<'a> {
+    patch: &'a Patch,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> PatchSerializer<'a> {
+    /// Creates a serializer for the given patch.
+    pub fn new(patch: &'a Patch, registry: &'a TypeRegistry) -> Self {
+        Self { patch, registry }
+    }
+}
+
+impl Serialize for PatchSerializer<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let changes = self.patch.changes();
+        let mut seq = serializer.serialize_seq(Some(changes.len()))?;
+        for change in changes {
+            seq.serialize_element(&ChangeSerializer {
+                change,
+                registry: self.registry,
+            })?;
+        }
+        seq.end()
+    }
+}
+
+struct ChangeSerializer<'a> {
+    change: &'a PatchChange,
+    registry: &'a TypeRegistry,
+}
+
+impl Serialize for ChangeSerializer<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (index, key, value) = match &self.change.op {
+            PatchOp::Replace(value)
+            | PatchOp::Variant(value)
+            | PatchOp::SetInsert(value)
+            | PatchOp::SetRemove(value) => (None, None, Some(value)),
+            PatchOp::ListInsert { index, value } => (Some(index), None, Some(value)),
+            PatchOp::ListRemove { index } => (Some(index), None, None),
+            PatchOp::MapInsert { key, value } => (None, Some(key), Some(value)),
+            PatchOp::MapRemove { key } => (None, Some(key), None),
+        };
+
+        let len = 2 + [index.is_some(), key.is_some(), value.is_some()]
+            .into_iter()
+            .filter(|is_some| *is_some)
+            .count();
+        let mut map = serializer.serialize_map(Some(len))?;
+        map.serialize_entry(PATH, &self.change.path.to_string())?;
+        map.serialize_entry(OP, self.change.op.name())?;
+        if let Some(index) = index {
+            map.serialize_entry(INDEX, index)?;
+        }
+        if let Some(key) = key {
+            map.serialize_entry(KEY, &ReflectSerializer::new(key.as_ref(), self.registry))?;
+        }
+        if let Some(value) = value {
+            map.serialize_entry(
+                VALUE,
+                &ReflectSerializer::new(value.as_ref(), self.registry),
+            )?;
+        }
+        map.end()
+    }
+}
+
+/// A deserializer for a [`Patch`] that was serialized with a [`PatchSerializer`].
+///
+/// The types of the keys and values of the patch must be registered in the [`TypeRegistry`].
+pub struct PatchDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> PatchDeserializer<'a> {
+    /// Creates a deserializer that looks up the types of keys and values in the given registry.
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self { registry }
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for PatchDeserializer<'_> {
+    type Value = Patch;
+
+    fn deserialize<D: de::Deserializer<'de>>(self, deserializer: D) -> Result<Patch, D::Error> {
+        struct PatchVisitor<'a> {
+            registry: &'a TypeRegistry,
+        }
+
+        impl<'de> Visitor<'de> for PatchVisitor<'_> {
+            type Value = Patch;
+
+            fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                formatter.write_str("a sequence of patch changes")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Patch, A::Error> {
+                let mut patch = Patch::new();
+                while let Some(change) = seq.next_element_seed(ChangeDeserializer {
+                    registry: self.registry,
+                })? {
+                    patch.push(change);
+                }
+                Ok(patch)
+            }
+        }
+
+        deserializer.deserialize_seq(PatchVisitor {
+            registry: self.registry,
+        })
+    }
+}
+
+struct ChangeDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'de> DeserializeSeed<'de> for ChangeDeserializer<'_> {
+    type Value = PatchChange;
+
+    fn deserialize<D: de::Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<PatchChange, D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de> Visitor<'de> for ChangeDeserializer<'_> {
+    type Value = PatchChange;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a patch change")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<PatchChange, A::Error> {
+        let mut path: Option<String> = None;
+        let mut op: Option<String> = None;
+        let mut index: Option<usize> = None;
+        let mut key: Option<Box<dyn PartialReflect>> = None;
+        let mut value: Option<Box<dyn PartialReflect>> = None;
+        while let Some(name) = map.next_key::<String>()? {
+            match name.as_str() {
+                PATH => path = Some(map.next_value()?),
+                OP => op = Some(map.next_value()?),
+                INDEX => index = Some(map.next_value()?),
+                KEY => key = Some(map.next_value_seed(ReflectDeserializer::new(self.registry))?),
+                VALUE => {
+                    value = Some(map.next_value_seed(ReflectDeserializer::new(self.registry))?);
+                }
+                _ => {
+                    return Err(de::Error::unknown_field(
+                        &name,
+                        &[PATH, OP, INDEX, KEY, VALUE],
+                    ))
+                }
+            }
+        }
+
+        let path = path.ok_or_else(|| de::Error::missing_field(PATH))?;
+        let path = ParsedPath::parse(&path).map_err(de::Error::custom)?;
+        let op = op.ok_or_else(|| de::Error::missing_field(OP))?;
+        let index = || index.ok_or_else(|| de::Error::missing_field(INDEX));
+        let key = || key.ok_or_else(|| de::Error::missing_field(KEY));
+        let value = || value.ok_or_else(|| de::Error::missing_field(VALUE));
+        let op = match op.as_str() {
+            "replace" => PatchOp::Replace(value()?),
+            "variant" => PatchOp::Variant(value()?),
+            "list_insert" => PatchOp::ListInsert {
+                index: index()?,
+                value: value()?,
+            },
+            "list_remove" => PatchOp::ListRemove { index: index()? },
+            "map_insert" => PatchOp::MapInsert {
+                key: key()?,
+                value: value()?,
+            },
+            "map_remove" => PatchOp::MapRemove { key: key()? },
+            "set_insert" => PatchOp::SetInsert(value()?),
+            "set_remove" => PatchOp::SetRemove(value()?),
+            _ => {
+                return Err(de::Error::unknown_variant(
+                    &op,
+                    &[
+                        "replace",
+                        "variant",
+                        "list_insert",
+                        "list_remove",
+                        "map_insert",
+                        "map_remove",
+                        "set_insert",
+                        "set_remove",
+                    ],
+                ))
+            }
+        };
+        Ok(PatchChange { path, op })
+    }
+}
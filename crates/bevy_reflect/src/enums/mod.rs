@@ -349,6 +349,13 @@ mod tests {
         assert_eq!(target, MyEnumAnalogue::C { foo: 1.0, bar: 1 });
     }
 
+    #[test]
+    fn from_reflect_should_reject_unknown_variants() {
+        let value = DynamicEnum::new("D", DynamicVariant::Unit);
+        assert_eq!(MyEnum::from_reflect(&value), None);
+        assert_eq!(Option::<u8>::from_reflect(&value), None);
+    }
+
     #[test]
     fn should_skip_ignored_fields() {
         #[derive(Reflect, Debug, PartialEq)]
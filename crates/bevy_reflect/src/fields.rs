@@ -3,7 +3,7 @@ use crate::{
     type_info::impl_type_methods,
     MaybeTyped, PartialReflect, Type, TypeInfo, TypePath,
 };
-use alloc::borrow::Cow;
+use alloc::{borrow::Cow, boxed::Box};
 use bevy_platform::sync::Arc;
 use core::fmt::{Display, Formatter};
 
@@ -14,6 +14,7 @@ pub struct NamedField {
     type_info: fn() -> Option<&'static TypeInfo>,
     ty: Type,
     custom_attributes: Arc<CustomAttributes>,
+    default: Option<fn() -> Box<dyn PartialReflect>>,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
@@ -26,6 +27,7 @@ impl NamedField {
             type_info: T::maybe_type_info,
             ty: Type::of::<T>(),
             custom_attributes: Arc::new(CustomAttributes::default()),
+            default: None,
             #[cfg(feature = "documentation")]
             docs: None,
         }
@@ -45,6 +47,16 @@ impl NamedField {
         }
     }
 
+    /// Sets the function returning the default value of this field.
+    ///
+    /// The returned value must be of the type of this field.
+    pub fn with_default(self, default: fn() -> Box<dyn PartialReflect>) -> Self {
+        Self {
+            default: Some(default),
+            ..self
+        }
+    }
+
     /// The name of the field.
     pub fn name(&self) -> &'static str {
         self.name
@@ -67,6 +79,32 @@ impl NamedField {
         self.docs
     }
 
+    /// Returns the default value of this field, if it is known.
+    ///
+    /// When derived, this is the value given by `#[reflect(default)]` on the field,
+    /// or else the value of the field in the `Default` implementation of the container
+    /// if it registers `#[reflect(Default)]`.
+    /// These are the values [`FromReflect`] uses for missing fields.
+    ///
+    /// [`FromReflect`]: crate::FromReflect
+    pub fn default_value(&self) -> Option<Box<dyn PartialReflect>> {
+        self.default.map(|default| default())
+    }
+
+    /// Returns `true` if the default value of this field is known.
+    pub fn has_default(&self) -> bool {
+        self.default.is_some()
+    }
+
+    /// Returns whether `value` is equal to the [default value] of this field.
+    ///
+    /// Returns `None` if the default value isn't known or if the values can't be compared.
+    ///
+    /// [default value]: Self::default_value
+    pub fn is_default(&self, value: &dyn PartialReflect) -> Option<bool> {
+        self.default_value()?.reflect_partial_eq(value)
+    }
+
     impl_custom_attribute_methods!(self.custom_attributes, "field");
 }
 
@@ -77,6 +115,7 @@ pub struct UnnamedField {
     type_info: fn() -> Option<&'static TypeInfo>,
     ty: Type,
     custom_attributes: Arc<CustomAttributes>,
+    default: Option<fn() -> Box<dyn PartialReflect>>,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
@@ -88,6 +127,7 @@ impl UnnamedField {
             type_info: T::maybe_type_info,
             ty: Type::of::<T>(),
             custom_attributes: Arc::new(CustomAttributes::default()),
+            default: None,
             #[cfg(feature = "documentation")]
             docs: None,
         }
@@ -107,6 +147,16 @@ impl UnnamedField {
         }
     }
 
+    /// Sets the function returning the default value of this field.
+    ///
+    /// The returned value must be of the type of this field.
+    pub fn with_default(self, default: fn() -> Box<dyn PartialReflect>) -> Self {
+        Self {
+            default: Some(default),
+            ..self
+        }
+    }
+
     /// Returns the index of the field.
     pub fn index(&self) -> usize {
         self.index
@@ -129,6 +179,32 @@ impl UnnamedField {
         self.docs
     }
 
+    /// Returns the default value of this field, if it is known.
+    ///
+    /// When derived, this is the value given by `#[reflect(default)]` on the field,
+    /// or else the value of the field in the `Default` implementation of the container
+    /// if it registers `#[reflect(Default)]`.
+    /// These are the values [`FromReflect`] uses for missing fields.
+    ///
+    /// [`FromReflect`]: crate::FromReflect
+    pub fn default_value(&self) -> Option<Box<dyn PartialReflect>> {
+        self.default.map(|default| default())
+    }
+
+    /// Returns `true` if the default value of this field is known.
+    pub fn has_default(&self) -> bool {
+        self.default.is_some()
+    }
+
+    /// Returns whether `value` is equal to the [default value] of this field.
+    ///
+    /// Returns `None` if the default value isn't known or if the values can't be compared.
+    ///
+    /// [default value]: Self::default_value
+    pub fn is_default(&self, value: &dyn PartialReflect) -> Option<bool> {
+        self.default_value()?.reflect_partial_eq(value)
+    }
+
     impl_custom_attribute_methods!(self.custom_attributes, "field");
 }
 
@@ -0,0 +1,256 @@
+use alloc::borrow::Cow;
+use bevy_platform::collections::HashMap;
+use core::fmt::{self, Debug, Formatter};
+
+use crate::func::{ArgList, DynamicFunction, FunctionResult, IntoFunction};
+
+/// Type data holding the reflected methods of a type.
+///
+/// Methods are [`DynamicFunction`]s mapped by name, whose first argument is the receiver:
+/// a `&Self`, a `&mut Self` or an owned `Self`.
+/// This allows scripting layers and remote tools to call methods on a reflected value
+/// without knowing its type at compile time.
+///
+/// This type data is usually registered with the `#[reflect(methods(...))]` attribute,
+/// which takes a list of method names, optionally followed by `= function` to register
+/// a function or closure under that name instead of the method itself.
+/// Methods that don't conform to the [valid signatures] of reflected functions,
+/// such as generic methods, can be wrapped in a closure that does.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{func::{ArgList, ReflectMethods}, GetTypeRegistration, Reflect};
+/// #[derive(Reflect, Default)]
+/// #[reflect(methods(
+///     reset,
+///     add,
+///     double = |counter: &mut Counter| counter.add(counter.count),
+/// ))]
+/// struct Counter {
+///     count: u32,
+/// }
+///
+/// impl Counter {
+///     fn reset(&mut self) {
+///         self.count = 0;
+///     }
+///
+///     fn add(&mut self, amount: u32) {
+///         self.count += amount;
+///     }
+/// }
+///
+/// let registration = Counter::get_type_registration();
+/// let methods = registration.data::<ReflectMethods>().unwrap();
+///
+/// let mut counter = Counter::default();
+/// methods
+///     .call("add", ArgList::new().with_mut(&mut counter).with_owned(3_u32))
+///     .unwrap()
+///     .unwrap();
+/// registration
+///     .method("double")
+///     .unwrap()
+///     .call(ArgList::new().with_mut(&mut counter))
+///     .unwrap();
+/// assert_eq!(counter.count, 6);
+/// ```
+///
+/// [valid signatures]: crate::func#valid-signatures
+#[derive(Clone, Default)]
+pub struct ReflectMethods {
+    methods: HashMap<Cow<'static, str>, DynamicFunction<'static>>,
+}
+
+impl ReflectMethods {
+    /// Creates an empty set of methods.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the given function as the method with the given name,
+    /// overwriting any existing method with the same name.
+    ///
+    /// Functions without a [name] are given the name of the method.
+    ///
+    /// [name]: DynamicFunction::name
+    pub fn register<F, Marker>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        method: F,
+    ) -> &mut Self
+    where
+        F: IntoFunction<'static, Marker> + 'static,
+    {
+        let name = name.into();
+        let mut method = method.into_function();
+        if method.name().is_none() {
+            method = method.with_name(name.clone());
+        }
+        self.methods.insert(name, method);
+        self
+    }
+
+    /// Registers the given function as the method with the given name,
+    /// overwriting any existing method with the same name, and returns `self`.
+    ///
+    /// See [`register`](Self::register) for more details.
+    pub fn with_method<F, Marker>(mut self, name: impl Into<Cow<'static, str>>, method: F) -> Self
+    where
+        F: IntoFunction<'static, Marker> + 'static,
+    {
+        self.register(name, method);
+        self
+    }
+
+    /// Calls the method with the given name, passing the receiver as the first of the `args`.
+    ///
+    /// Returns `None` if there is no method with the given name.
+    /// Otherwise, returns the result of the method call.
+    pub fn call<'a>(&self, name: &str, args: ArgList<'a>) -> Option<FunctionResult<'a>> {
+        Some(self.get(name)?.call(args))
+    }
+
+    /// Returns the method with the given name.
+    pub fn get(&self, name: &str) -> Option<&DynamicFunction<'static>> {
+        self.methods.get(name)
+    }
+
+    /// Returns `true` if there is a method with the given name.
+    pub fn contains(&self, name: &str) -> bool {
+        self.methods.contains_key(name)
+    }
+
+    /// Returns an iterator over the names of the methods and the methods themselves.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (&str, &DynamicFunction<'static>)> {
+        self.methods
+            .iter()
+            .map(|(name, method)| (name.as_ref(), method))
+    }
+
+    /// Returns the number of methods.
+    pub fn len(&self) -> usize {
+        self.methods.len()
+    }
+
+    /// Returns `true` if there are no methods.
+    pub fn is_empty(&self) -> bool {
+        self.methods.is_empty()
+    }
+}
+
+impl Debug for ReflectMethods {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.methods.keys()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{func::FunctionError, GetTypeRegistration, Reflect};
+    use alloc::{string::String, vec, vec::Vec};
+
+    #[derive(Reflect, Default, Debug, PartialEq)]
+    #[reflect(methods(
+        len,
+        push,
+        name,
+        clear = |list: &mut Playlist| list.tracks.clear(),
+        r#take,
+    ))]
+    struct Playlist {
+        name: String,
+        tracks: Vec<u32>,
+    }
+
+    impl Playlist {
+        fn len(&self) -> usize {
+            self.tracks.len()
+        }
+
+        fn push(&mut self, track: u32) {
+            self.tracks.push(track);
+        }
+
+        fn name(&self) -> &String {
+            &self.name
+        }
+
+        fn take(self) -> Vec<u32> {
+            self.tracks
+        }
+    }
+
+    #[test]
+    fn should_register_methods() {
+        let registration = Playlist::get_type_registration();
+        let methods = registration.data::<ReflectMethods>().unwrap();
+
+        let mut names = methods.iter().map(|(name, _)| name).collect::<Vec<_>>();
+        names.sort();
+        assert_eq!(names, ["clear", "len", "name", "push", "take"]);
+        assert!(registration.method("push").is_some());
+        assert!(registration.method("missing").is_none());
+    }
+
+    #[test]
+    fn should_call_methods_with_receiver() {
+        let registration = Playlist::get_type_registration();
+        let methods = registration.data::<ReflectMethods>().unwrap();
+        let mut playlist = Playlist {
+            name: String::from("favorites"),
+            tracks: Vec::new(),
+        };
+
+        methods
+            .call(
+                "push",
+                ArgList::new().with_mut(&mut playlist).with_owned(7_u32),
+            )
+            .unwrap()
+            .unwrap();
+        methods
+            .call(
+                "push",
+                ArgList::new().with_mut(&mut playlist).with_owned(8_u32),
+            )
+            .unwrap()
+            .unwrap();
+
+        let len = methods
+            .call("len", ArgList::new().with_ref(&playlist))
+            .unwrap()
+            .unwrap()
+            .unwrap_owned();
+        assert_eq!(len.try_downcast_ref::<usize>(), Some(&2));
+
+        let name = methods
+            .call("name", ArgList::new().with_ref(&playlist))
+            .unwrap()
+            .unwrap()
+            .unwrap_ref();
+        assert_eq!(name.try_downcast_ref::<String>().unwrap(), "favorites");
+
+        methods
+            .call("clear", ArgList::new().with_mut(&mut playlist))
+            .unwrap()
+            .unwrap();
+        assert!(playlist.tracks.is_empty());
+
+        playlist.push(1);
+        let tracks = methods
+            .call("take", ArgList::new().with_owned(playlist))
+            .unwrap()
+            .unwrap()
+            .unwrap_owned();
+        assert_eq!(tracks.try_downcast_ref::<Vec<u32>>(), Some(&vec![1]));
+
+        assert!(methods.call("missing", ArgList::new()).is_none());
+        assert!(matches!(
+            methods.call("len", ArgList::new()),
+            Some(Err(FunctionError::ArgCountMismatch { .. }))
+        ));
+    }
+}
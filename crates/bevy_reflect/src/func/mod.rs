@@ -149,6 +149,12 @@
 //! assert_eq!(value.unwrap_owned().try_downcast_ref::<i32>(), Some(&50));
 //! ```
 //!
+//! # Methods
+//!
+//! Functions can also be registered as methods of a type with the `#[reflect(methods(...))]` attribute,
+//! which stores them in the type's [`ReflectMethods`] type data.
+//! Their first argument is the receiver, so they can be called on a reflected value by name.
+//!
 //! [`PartialReflect`]: crate::PartialReflect
 //! [`Reflect`]: crate::Reflect
 //! [lack of variadic generics]: https://poignardazur.github.io/2024/05/25/report-on-rustnl-variadics/
@@ -166,6 +172,7 @@ pub use function::*;
 pub use info::*;
 pub use into_function::*;
 pub use into_function_mut::*;
+pub use methods::*;
 pub use reflect_fn::*;
 pub use reflect_fn_mut::*;
 pub use registry::*;
@@ -181,6 +188,7 @@ mod info;
 mod into_function;
 mod into_function_mut;
 pub(crate) mod macros;
+mod methods;
 mod reflect_fn;
 mod reflect_fn_mut;
 mod registry;
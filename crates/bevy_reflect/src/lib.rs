@@ -237,6 +237,10 @@
 //! assert_eq!(None, value);
 //! ```
 //!
+//! To find out what changed between two values instead, the [`diff`] module computes a
+//! [`Patch`](diff::Patch) of path-addressed changes that can be applied to another value
+//! and serialized, for example to send only the changes of a value over the network.
+//!
 //! ## `FromReflect`
 //!
 //! It's important to remember that dynamic types are _not_ the concrete type they may be representing.
@@ -445,6 +449,9 @@
 //! assert_eq!(original_value, converted_value);
 //! ```
 //!
+//! For networking and save files, the [`binary`] module offers a compact binary format
+//! that doesn't go through serde and tolerates fields being added to or removed from a type.
+//!
 //! # Limitations
 //!
 //! While this crate offers a lot in terms of adding reflection to Rust,
@@ -520,6 +527,19 @@
 //! which enables capturing the type stack when serializing or deserializing a type
 //! and displaying it in error messages.
 //!
+//! ## `regex`
+//!
+//! | Default | Dependencies  |
+//! | :-----: | :-----------: |
+//! | ❌      | [`regex`]     |
+//!
+//! This feature enables checking strings against [`Pattern`] constraints
+//! when [validating] reflected values.
+//!
+//! [`regex`]: https://docs.rs/regex/latest/regex/
+//! [`Pattern`]: validation::Pattern
+//! [validating]: validation::validate
+//!
 //! [Reflection]: https://en.wikipedia.org/wiki/Reflective_programming
 //! [Bevy]: https://bevyengine.org/
 //! [limitations]: #limitations
@@ -607,6 +627,8 @@ mod impls {
 }
 
 pub mod attributes;
+pub mod binary;
+pub mod diff;
 mod enums;
 mod generics;
 pub mod serde;
@@ -614,6 +636,7 @@ pub mod std_traits;
 #[cfg(feature = "debug_stack")]
 mod type_info_stack;
 pub mod utility;
+pub mod validation;
 
 /// The reflect prelude.
 ///
@@ -885,6 +908,50 @@ mod tests {
         );
     }
 
+    #[test]
+    fn reflect_field_defaults() {
+        #[derive(Reflect)]
+        #[reflect(Default, where T: Default + From<u8>)]
+        struct Foo<T> {
+            a: u32,
+            #[reflect(default = "default_b")]
+            b: T,
+        }
+
+        impl<T: Default> Default for Foo<T> {
+            fn default() -> Self {
+                Self {
+                    a: 1,
+                    b: T::default(),
+                }
+            }
+        }
+
+        fn default_b<T: From<u8>>() -> T {
+            T::from(2)
+        }
+
+        #[derive(Reflect)]
+        struct Bar(u32, #[reflect(default)] u32);
+
+        let TypeInfo::Struct(info) = Foo::<u64>::type_info() else {
+            panic!("expected struct info");
+        };
+        let a = info.field_default("a").unwrap();
+        assert_eq!(a.try_downcast_ref::<u32>(), Some(&1));
+        let b = info.field_default("b").unwrap();
+        assert_eq!(b.try_downcast_ref::<u64>(), Some(&2));
+        assert_eq!(info.field("a").unwrap().is_default(&1_u32), Some(true));
+        assert_eq!(info.field("b").unwrap().is_default(&3_u64), Some(false));
+
+        let TypeInfo::TupleStruct(info) = Bar::type_info() else {
+            panic!("expected tuple struct info");
+        };
+        assert!(!info.field_at(0).unwrap().has_default());
+        assert!(info.field_default(0).is_none());
+        assert_eq!(info.field_at(1).unwrap().is_default(&0_u32), Some(true));
+    }
+
     #[test]
     fn reflect_unit_struct() {
         #[derive(Reflect)]
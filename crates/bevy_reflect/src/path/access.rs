@@ -1,6 +1,6 @@
 //! Representation for individual element accesses within a path.
 
-use alloc::borrow::Cow;
+use alloc::{borrow::Cow, string::ToString, vec::Vec};
 use core::fmt;
 
 use super::error::AccessErrorKind;
@@ -22,6 +22,14 @@ pub enum Access<'a> {
     TupleIndex(usize),
     /// An index-based access on a list.
     ListIndex(usize),
+    /// A wildcard access on every field of a struct, tuple, tuple struct or enum variant
+    /// whose name matches a pattern.
+    ///
+    /// A `*` in the pattern matches any sequence of characters, so `*` alone matches every field.
+    /// The fields of tuples, tuple structs and tuple variants are matched by their index.
+    FieldPattern(Cow<'a, str>),
+    /// A wildcard access on every element of a list or array, or every value of a map.
+    AllElements,
 }
 
 impl fmt::Display for Access<'_> {
@@ -31,6 +39,8 @@ impl fmt::Display for Access<'_> {
             Access::FieldIndex(index) => write!(f, "#{index}"),
             Access::TupleIndex(index) => write!(f, ".{index}"),
             Access::ListIndex(index) => write!(f, "[{index}]"),
+            Access::FieldPattern(pattern) => write!(f, ".{pattern}"),
+            Access::AllElements => f.write_str("[*]"),
         }
     }
 }
@@ -47,9 +57,16 @@ impl<'a> Access<'a> {
             Self::FieldIndex(value) => Access::FieldIndex(value),
             Self::TupleIndex(value) => Access::TupleIndex(value),
             Self::ListIndex(value) => Access::ListIndex(value),
+            Self::FieldPattern(value) => Access::FieldPattern(Cow::Owned(value.into_owned())),
+            Self::AllElements => Access::AllElements,
         }
     }
 
+    /// Returns `true` if this is a wildcard access that can match more than one element.
+    pub fn is_wildcard(&self) -> bool {
+        matches!(self, Self::FieldPattern(_) | Self::AllElements)
+    }
+
     pub(super) fn element<'r>(
         &self,
         base: &'r dyn PartialReflect,
@@ -104,6 +121,8 @@ impl<'a> Access<'a> {
                 expected: ReflectKind::List,
                 actual: actual.into(),
             }),
+
+            (Self::FieldPattern(_) | Self::AllElements, _) => Err(AccessErrorKind::MultipleTargets),
         }
     }
 
@@ -163,14 +182,119 @@ impl<'a> Access<'a> {
                 expected: ReflectKind::List,
                 actual: actual.into(),
             }),
+
+            (Self::FieldPattern(_) | Self::AllElements, _) => Err(AccessErrorKind::MultipleTargets),
+        }
+    }
+
+    /// Calls `f` with every element matched by this access.
+    ///
+    /// Non-wildcard accesses match exactly one element.
+    pub(super) fn for_each_element<'r>(
+        &self,
+        base: &'r dyn PartialReflect,
+        offset: Option<usize>,
+        f: &mut dyn FnMut(&'r dyn PartialReflect) -> Result<(), AccessError<'a>>,
+    ) -> Result<(), AccessError<'a>> {
+        if !self.is_wildcard() {
+            return f(self.element(base, offset)?);
+        }
+
+        let indices = self
+            .wildcard_indices(base)
+            .map_err(|err| err.with_access(self.clone(), offset))?;
+        for index in indices {
+            if let Some(element) = child_at(base, index) {
+                f(element)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Calls `f` with a mutable reference to every element matched by this access, one at a time.
+    ///
+    /// Non-wildcard accesses match exactly one element.
+    pub(super) fn for_each_element_mut(
+        &self,
+        base: &mut dyn PartialReflect,
+        offset: Option<usize>,
+        f: &mut dyn FnMut(&mut dyn PartialReflect) -> Result<(), AccessError<'a>>,
+    ) -> Result<(), AccessError<'a>> {
+        if !self.is_wildcard() {
+            return f(self.element_mut(base, offset)?);
+        }
+
+        let indices = self
+            .wildcard_indices(base)
+            .map_err(|err| err.with_access(self.clone(), offset))?;
+        for index in indices {
+            if let Some(element) = child_at_mut(base, index) {
+                f(element)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the indices of the children of `base` matched by this wildcard access,
+    /// as understood by [`child_at`] and [`child_at_mut`].
+    fn wildcard_indices(&self, base: &dyn PartialReflect) -> InnerResult<Vec<usize>> {
+        use ReflectRef::*;
+
+        let matching_indices = |pattern: &str, len: usize| {
+            (0..len)
+                .filter(|index| matches_pattern(pattern, &index.to_string()))
+                .collect()
+        };
+
+        match (self, base.reflect_ref()) {
+            (Self::FieldPattern(pattern), Struct(struct_ref)) => Ok((0..struct_ref.field_len())
+                .filter(|&index| {
+                    struct_ref
+                        .name_at(index)
+                        .is_some_and(|name| matches_pattern(pattern, name))
+                })
+                .collect()),
+            (Self::FieldPattern(pattern), TupleStruct(tuple)) => {
+                Ok(matching_indices(pattern, tuple.field_len()))
+            }
+            (Self::FieldPattern(pattern), Tuple(tuple)) => {
+                Ok(matching_indices(pattern, tuple.field_len()))
+            }
+            (Self::FieldPattern(pattern), Enum(enum_ref)) => match enum_ref.variant_type() {
+                VariantType::Struct => Ok((0..enum_ref.field_len())
+                    .filter(|&index| {
+                        enum_ref
+                            .name_at(index)
+                            .is_some_and(|name| matches_pattern(pattern, name))
+                    })
+                    .collect()),
+                VariantType::Tuple => Ok(matching_indices(pattern, enum_ref.field_len())),
+                VariantType::Unit => Ok(Vec::new()),
+            },
+            (Self::FieldPattern(_), actual) => Err(AccessErrorKind::IncompatibleTypes {
+                expected: ReflectKind::Struct,
+                actual: actual.into(),
+            }),
+
+            (Self::AllElements, List(list)) => Ok((0..list.len()).collect()),
+            (Self::AllElements, Array(array)) => Ok((0..array.len()).collect()),
+            (Self::AllElements, Map(map)) => Ok((0..map.len()).collect()),
+            (Self::AllElements, actual) => Err(AccessErrorKind::IncompatibleTypes {
+                expected: ReflectKind::List,
+                actual: actual.into(),
+            }),
+
+            // Other accesses aren't wildcards and always match a single element.
+            _ => Ok(Vec::new()),
         }
     }
 
     /// Returns a reference to this [`Access`]'s inner value as a [`&dyn Display`](fmt::Display).
     pub fn display_value(&self) -> &dyn fmt::Display {
         match self {
-            Self::Field(value) => value,
+            Self::Field(value) | Self::FieldPattern(value) => value,
             Self::FieldIndex(value) | Self::TupleIndex(value) | Self::ListIndex(value) => value,
+            Self::AllElements => &"*",
         }
     }
 
@@ -179,6 +303,58 @@ impl<'a> Access<'a> {
             Self::Field(_) => "field",
             Self::FieldIndex(_) => "field index",
             Self::TupleIndex(_) | Self::ListIndex(_) => "index",
+            Self::FieldPattern(_) => "field pattern",
+            Self::AllElements => "wildcard index",
+        }
+    }
+}
+
+/// Returns the child of `base` at `index`: a field, a list or array element, or a map value.
+fn child_at(base: &dyn PartialReflect, index: usize) -> Option<&dyn PartialReflect> {
+    match base.reflect_ref() {
+        ReflectRef::Struct(struct_ref) => struct_ref.field_at(index),
+        ReflectRef::TupleStruct(tuple) => tuple.field(index),
+        ReflectRef::Tuple(tuple) => tuple.field(index),
+        ReflectRef::Enum(enum_ref) => enum_ref.field_at(index),
+        ReflectRef::List(list) => list.get(index),
+        ReflectRef::Array(array) => array.get(index),
+        ReflectRef::Map(map) => map.get_at(index).map(|(_, value)| value),
+        _ => None,
+    }
+}
+
+/// The mutable counterpart of [`child_at`].
+fn child_at_mut(base: &mut dyn PartialReflect, index: usize) -> Option<&mut dyn PartialReflect> {
+    match base.reflect_mut() {
+        ReflectMut::Struct(struct_mut) => struct_mut.field_at_mut(index),
+        ReflectMut::TupleStruct(tuple) => tuple.field_mut(index),
+        ReflectMut::Tuple(tuple) => tuple.field_mut(index),
+        ReflectMut::Enum(enum_mut) => enum_mut.field_at_mut(index),
+        ReflectMut::List(list) => list.get_mut(index),
+        ReflectMut::Array(array) => array.get_mut(index),
+        ReflectMut::Map(map) => map.get_at_mut(index).map(|(_, value)| value),
+        _ => None,
+    }
+}
+
+/// Returns `true` if `name` matches `pattern`, where a `*` in the pattern matches
+/// any sequence of characters.
+fn matches_pattern(pattern: &str, name: &str) -> bool {
+    let Some((prefix, rest)) = pattern.split_once('*') else {
+        return pattern == name;
+    };
+    let (middle, suffix) = rest.rsplit_once('*').unwrap_or(("", rest));
+    let Some(mut name) = name
+        .strip_prefix(prefix)
+        .and_then(|name| name.strip_suffix(suffix))
+    else {
+        return false;
+    };
+    for part in middle.split('*') {
+        match name.find(part) {
+            Some(index) => name = &name[index + part.len()..],
+            None => return false,
         }
     }
+    true
 }
@@ -27,6 +27,10 @@ pub enum AccessErrorKind {
         /// The actual [`VariantType`] that was found.
         actual: VariantType,
     },
+
+    /// An error that occurs when a wildcard [`Access`] is used where a single element is expected.
+    /// (i.e. a [`FieldPattern`](Access::FieldPattern) in [`GetPath::reflect_path`](crate::GetPath::reflect_path))
+    MultipleTargets,
 }
 
 impl AccessErrorKind {
@@ -110,7 +114,12 @@ impl fmt::Display for AccessError<'_> {
                         f,
                         "The {type_accessed} accessed doesn't have index `{}`",
                         access.display_value()
-                    )
+                    ),
+                    Access::FieldPattern(_) | Access::AllElements => write!(
+                        f,
+                        "The {type_accessed} accessed doesn't have any element matching `{}`",
+                        access.display_value()
+                    ),
                 }
             }
             AccessErrorKind::IncompatibleTypes { expected, actual } => write!(
@@ -123,6 +132,11 @@ impl fmt::Display for AccessError<'_> {
                 "Expected variant {} access to access a {expected:?} variant, found a {actual:?} variant instead.",
                 access.kind()
             ),
+            AccessErrorKind::MultipleTargets => write!(
+                f,
+                "A {} access can match several elements, so it can't be used to get a single element.",
+                access.kind()
+            ),
         }
     }
 }
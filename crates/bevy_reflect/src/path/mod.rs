@@ -8,8 +8,8 @@ mod parse;
 pub use parse::ParseError;
 use parse::PathParser;
 
-use crate::{PartialReflect, Reflect};
-use alloc::vec::Vec;
+use crate::{PartialReflect, Reflect, ReflectRef, TypeInfo, VariantInfo};
+use alloc::{boxed::Box, vec::Vec};
 use core::fmt;
 use derive_more::derive::From;
 use thiserror::Error;
@@ -28,6 +28,11 @@ pub enum ReflectPathError<'a> {
     #[error("Can't downcast result of access to the given type")]
     InvalidDowncast,
 
+    /// An error caused by trying to reset an element that isn't a field with a known
+    /// default value, or whose default value can't be applied to it.
+    #[error("Can't reset the accessed element to a default value")]
+    NoDefault,
+
     /// An error caused by an invalid path string that couldn't be parsed.
     #[error("Encountered an error at offset {offset} while parsing `{path}`: {error}")]
     ParseError {
@@ -62,6 +67,42 @@ pub trait ReflectPath<'a>: Sized {
         root: &mut dyn PartialReflect,
     ) -> PathResult<'a, &mut dyn PartialReflect>;
 
+    /// Gets references to every element matched by the path on the given [`Reflect`] object.
+    ///
+    /// See [`GetPath::reflect_path_all`] for more details.
+    /// The default implementation treats the path as matching exactly one element.
+    fn reflect_elements(
+        self,
+        root: &dyn PartialReflect,
+    ) -> PathResult<'a, Vec<&dyn PartialReflect>> {
+        Ok(alloc::vec![self.reflect_element(root)?])
+    }
+
+    /// Calls `f` with a mutable reference to every element matched by the path
+    /// on the given [`Reflect`] object.
+    ///
+    /// See [`GetPath::reflect_path_for_each_mut`] for more details.
+    /// The default implementation treats the path as matching exactly one element.
+    fn for_each_element_mut(
+        self,
+        root: &mut dyn PartialReflect,
+        mut f: impl FnMut(&mut dyn PartialReflect),
+    ) -> PathResult<'a, ()> {
+        f(self.reflect_element_mut(root)?);
+        Ok(())
+    }
+
+    /// Resets every element matched by the path on the given [`Reflect`] object
+    /// to the default value of its field.
+    ///
+    /// See [`GetPath::reset_path`] for more details.
+    /// The default implementation can't know the default value of the element,
+    /// so it fails with [`ReflectPathError::NoDefault`] if the element can be accessed.
+    fn reset_element(self, root: &mut dyn PartialReflect) -> PathResult<'a, ()> {
+        self.reflect_element_mut(root)?;
+        Err(ReflectPathError::NoDefault)
+    }
+
     /// Gets a `&T` to the specified element on the given [`Reflect`] object.
     ///
     /// See [`GetPath::path`] for more details.
@@ -99,6 +140,29 @@ impl<'a> ReflectPath<'a> for &'a str {
         }
         Ok(root)
     }
+    fn reflect_elements(
+        self,
+        root: &dyn PartialReflect,
+    ) -> PathResult<'a, Vec<&dyn PartialReflect>> {
+        let mut elements = Vec::new();
+        for_each_element(&ParsedPath::parse(self)?.0, root, &mut |element| {
+            elements.push(element);
+            Ok(())
+        })?;
+        Ok(elements)
+    }
+    fn for_each_element_mut(
+        self,
+        root: &mut dyn PartialReflect,
+        f: impl FnMut(&mut dyn PartialReflect),
+    ) -> PathResult<'a, ()> {
+        let path = ParsedPath::parse(self)?;
+        Ok(visit_elements_mut(&path.0, root, f)?)
+    }
+    fn reset_element(self, root: &mut dyn PartialReflect) -> PathResult<'a, ()> {
+        let path = ParsedPath::parse(self)?;
+        reset_elements(&path.0, root)
+    }
 }
 /// A trait which allows nested [`Reflect`] values to be retrieved with path strings.
 ///
@@ -237,11 +301,54 @@ impl<'a> ReflectPath<'a> for &'a str {
 /// );
 /// ```
 ///
+/// # Wildcards
+///
+/// A path can match several elements at once with wildcards:
+/// - `[*]` matches every element of a [`List`] or [`Array`], or every value of a [`Map`]
+/// - `.*` matches every field of a struct, tuple, tuple struct or enum variant
+/// - `.prefix*` matches every field whose name matches the pattern,
+///   where `*` stands for any sequence of characters
+///
+/// Paths with wildcards can only be used with [`reflect_path_all`](GetPath::reflect_path_all)
+/// and [`reflect_path_for_each_mut`](GetPath::reflect_path_for_each_mut).
+/// The other methods, which return a single element, fail with
+/// [`AccessErrorKind::MultipleTargets`].
+///
+/// ## Example
+/// ```
+/// # use bevy_reflect::{GetPath, Reflect};
+/// #[derive(Reflect)]
+/// struct Item {
+///   weight: f32,
+///   max_speed: f32,
+///   min_speed: f32,
+/// }
+///
+/// let mut items = vec![
+///   Item { weight: 1.0, max_speed: 2.0, min_speed: 0.5 },
+///   Item { weight: 3.0, max_speed: 4.0, min_speed: 1.5 },
+/// ];
+///
+/// let weights = items.reflect_path_all("[*].weight").unwrap();
+/// assert_eq!(weights.len(), 2);
+/// assert_eq!(weights[1].try_downcast_ref::<f32>(), Some(&3.0));
+///
+/// // Double every speed of every item.
+/// items
+///   .reflect_path_for_each_mut("[*].*_speed", |speed| {
+///     *speed.try_downcast_mut::<f32>().unwrap() *= 2.0;
+///   })
+///   .unwrap();
+/// assert_eq!(items[0].max_speed, 4.0);
+/// assert_eq!(items[1].min_speed, 3.0);
+/// ```
+///
 /// [`Struct`]: crate::Struct
 /// [`Tuple`]: crate::Tuple
 /// [`TupleStruct`]: crate::TupleStruct
 /// [`List`]: crate::List
 /// [`Array`]: crate::Array
+/// [`Map`]: crate::Map
 /// [`Enum`]: crate::Enum
 #[diagnostic::on_unimplemented(
     message = "`{Self}` does not implement `GetPath` so cannot be accessed by reflection path",
@@ -288,6 +395,79 @@ pub trait GetPath: PartialReflect {
     fn path_mut<'p, T: Reflect>(&mut self, path: impl ReflectPath<'p>) -> PathResult<'p, &mut T> {
         path.element_mut(self.as_partial_reflect_mut())
     }
+
+    /// Returns references to every value matched by `path`, which may contain
+    /// [wildcards](GetPath#wildcards).
+    ///
+    /// A path without wildcards matches exactly one value.
+    /// A wildcard over an empty list or a unit enum variant matches no values.
+    ///
+    /// Fails if any of the matched values can't be accessed by the rest of the path,
+    /// i.e. if one of the elements of `[*].field` isn't a struct with a `field` field.
+    fn reflect_path_all<'p>(
+        &self,
+        path: impl ReflectPath<'p>,
+    ) -> PathResult<'p, Vec<&dyn PartialReflect>> {
+        path.reflect_elements(self.as_partial_reflect())
+    }
+
+    /// Calls `f` with a mutable reference to every value matched by `path`,
+    /// which may contain [wildcards](GetPath#wildcards).
+    ///
+    /// The whole path is checked before `f` is first called, so `f` isn't called at all
+    /// if any of the matched values can't be accessed by the rest of the path.
+    fn reflect_path_for_each_mut<'p>(
+        &mut self,
+        path: impl ReflectPath<'p>,
+        f: impl FnMut(&mut dyn PartialReflect),
+    ) -> PathResult<'p, ()> {
+        path.for_each_element_mut(self.as_partial_reflect_mut(), f)
+    }
+
+    /// Resets the value specified by `path` to the default value of the field it is stored in,
+    /// as given by [`NamedField::default_value`] or [`UnnamedField::default_value`].
+    ///
+    /// The last segment of the path must access a field of a struct, tuple struct or enum variant,
+    /// while the rest of the path may contain [wildcards](GetPath#wildcards)
+    /// to reset that field in several values.
+    ///
+    /// The whole path is checked before any value is reset, and this fails with
+    /// [`ReflectPathError::NoDefault`] if the default value of one of the fields isn't known.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_reflect::{prelude::ReflectDefault, GetPath, Reflect};
+    /// #[derive(Reflect)]
+    /// #[reflect(Default)]
+    /// struct Player {
+    ///     health: f32,
+    ///     #[reflect(default = "default_speed")]
+    ///     speed: f32,
+    /// }
+    ///
+    /// impl Default for Player {
+    ///     fn default() -> Self {
+    ///         Self { health: 100.0, speed: 1.0 }
+    ///     }
+    /// }
+    ///
+    /// fn default_speed() -> f32 {
+    ///     5.0
+    /// }
+    ///
+    /// let mut player = Player { health: 10.0, speed: 2.0 };
+    /// player.reset_path("health").unwrap();
+    /// player.reset_path("speed").unwrap();
+    /// assert_eq!(player.health, 100.0);
+    /// assert_eq!(player.speed, 5.0);
+    /// ```
+    ///
+    /// [`NamedField::default_value`]: crate::NamedField::default_value
+    /// [`UnnamedField::default_value`]: crate::UnnamedField::default_value
+    fn reset_path<'p>(&mut self, path: impl ReflectPath<'p>) -> PathResult<'p, ()> {
+        path.reset_element(self.as_partial_reflect_mut())
+    }
 }
 
 // Implement `GetPath` for `dyn Reflect`
@@ -380,6 +560,7 @@ impl ParsedPath {
     /// - Unnamed field access (`.1`)
     /// - Field index access (`#0`)
     /// - Sequence access (`[2]`)
+    /// - Field patterns (`.*` or `.prefix*`) and sequence wildcards (`[*]`)
     ///
     /// # Example
     /// ```
@@ -436,7 +617,134 @@ impl ParsedPath {
         }
         Ok(Self(parts))
     }
+
+    /// Returns `true` if this path contains [wildcards](GetPath#wildcards),
+    /// and so may match more than one element.
+    pub fn has_wildcards(&self) -> bool {
+        self.0.iter().any(|access| access.access.is_wildcard())
+    }
+}
+
+/// Calls `f` with every element matched by `accesses` on `root`.
+fn for_each_element<'r>(
+    accesses: &[OffsetAccess],
+    root: &'r dyn PartialReflect,
+    f: &mut dyn FnMut(&'r dyn PartialReflect) -> Result<(), AccessError<'static>>,
+) -> Result<(), AccessError<'static>> {
+    let Some((OffsetAccess { access, offset }, rest)) = accesses.split_first() else {
+        return f(root);
+    };
+    access.for_each_element(root, *offset, &mut |element| {
+        for_each_element(rest, element, f)
+    })
+}
+
+/// Calls `f` with a mutable reference to every element matched by `accesses` on `root`.
+fn for_each_element_mut(
+    accesses: &[OffsetAccess],
+    root: &mut dyn PartialReflect,
+    f: &mut dyn FnMut(&mut dyn PartialReflect) -> Result<(), AccessError<'static>>,
+) -> Result<(), AccessError<'static>> {
+    let Some((OffsetAccess { access, offset }, rest)) = accesses.split_first() else {
+        return f(root);
+    };
+    access.for_each_element_mut(root, *offset, &mut |element| {
+        for_each_element_mut(rest, element, f)
+    })
+}
+
+/// Calls `f` with a mutable reference to every element matched by `accesses` on `root`,
+/// after checking that the path can be followed to all of them.
+fn visit_elements_mut(
+    accesses: &[OffsetAccess],
+    root: &mut dyn PartialReflect,
+    mut f: impl FnMut(&mut dyn PartialReflect),
+) -> Result<(), AccessError<'static>> {
+    // Check the whole path first, so that `f` is either called for every element or for none.
+    for_each_element(accesses, root, &mut |_| Ok(()))?;
+    for_each_element_mut(accesses, root, &mut |element| {
+        f(element);
+        Ok(())
+    })
+}
+
+/// Resets every element matched by `accesses` on `root` to the default value of its field,
+/// after checking that the path can be followed to all of them and that they all have one.
+fn reset_elements(
+    accesses: &[OffsetAccess],
+    root: &mut dyn PartialReflect,
+) -> PathResult<'static, ()> {
+    let Some((OffsetAccess { access, offset }, parents)) = accesses.split_last() else {
+        return Err(ReflectPathError::NoDefault);
+    };
+
+    let mut has_defaults = true;
+    for_each_element(parents, root, &mut |parent| {
+        access.element(parent, *offset)?;
+        has_defaults &= field_default(parent, access).is_some();
+        Ok(())
+    })?;
+    if !has_defaults {
+        return Err(ReflectPathError::NoDefault);
+    }
+
+    let mut applied = true;
+    for_each_element_mut(parents, root, &mut |parent| {
+        let default = field_default(parent, access).expect("field should have a default value");
+        applied &= set_element(access.element_mut(parent, *offset)?, default);
+        Ok(())
+    })?;
+    match applied {
+        true => Ok(()),
+        false => Err(ReflectPathError::NoDefault),
+    }
+}
+
+/// Returns the default value of the field of `parent` accessed by `access`, if it is known.
+fn field_default(parent: &dyn PartialReflect, access: &Access) -> Option<Box<dyn PartialReflect>> {
+    match (parent.get_represented_type_info()?, access) {
+        (TypeInfo::Struct(info), Access::Field(name)) => info.field_default(name),
+        (TypeInfo::Struct(info), &Access::FieldIndex(index)) => {
+            info.field_at(index)?.default_value()
+        }
+        (TypeInfo::TupleStruct(info), &Access::TupleIndex(index)) => info.field_default(index),
+        (TypeInfo::Enum(info), _) => {
+            let ReflectRef::Enum(enum_ref) = parent.reflect_ref() else {
+                return None;
+            };
+            match (info.variant(enum_ref.variant_name())?, access) {
+                (VariantInfo::Struct(variant), Access::Field(name)) => {
+                    variant.field(name)?.default_value()
+                }
+                (VariantInfo::Struct(variant), &Access::FieldIndex(index)) => {
+                    variant.field_at(index)?.default_value()
+                }
+                (VariantInfo::Tuple(variant), &Access::TupleIndex(index)) => {
+                    variant.field_at(index)?.default_value()
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Replaces `element` with `value`, returning `false` if `value` can't be applied to it.
+fn set_element(element: &mut dyn PartialReflect, value: Box<dyn PartialReflect>) -> bool {
+    // Setting the value replaces it entirely, unlike applying it which keeps
+    // the entries of maps that aren't in `value`.
+    let value = match (element.try_as_reflect_mut(), value.try_into_reflect()) {
+        (Some(element), Ok(value)) => match element.set(value) {
+            Ok(()) => return true,
+            // The element is a dynamic value
+            Err(value) => value.into_partial_reflect(),
+        },
+        (_, Ok(value)) => value.into_partial_reflect(),
+        (_, Err(value)) => value,
+    };
+    element.try_apply(value.as_ref()).is_ok()
 }
+
 impl<'a> ReflectPath<'a> for &'a ParsedPath {
     fn reflect_element(self, mut root: &dyn PartialReflect) -> PathResult<'a, &dyn PartialReflect> {
         for OffsetAccess { access, offset } in &self.0 {
@@ -453,6 +761,27 @@ impl<'a> ReflectPath<'a> for &'a ParsedPath {
         }
         Ok(root)
     }
+    fn reflect_elements(
+        self,
+        root: &dyn PartialReflect,
+    ) -> PathResult<'a, Vec<&dyn PartialReflect>> {
+        let mut elements = Vec::new();
+        for_each_element(&self.0, root, &mut |element| {
+            elements.push(element);
+            Ok(())
+        })?;
+        Ok(elements)
+    }
+    fn for_each_element_mut(
+        self,
+        root: &mut dyn PartialReflect,
+        f: impl FnMut(&mut dyn PartialReflect),
+    ) -> PathResult<'a, ()> {
+        Ok(visit_elements_mut(&self.0, root, f)?)
+    }
+    fn reset_element(self, root: &mut dyn PartialReflect) -> PathResult<'a, ()> {
+        reset_elements(&self.0, root)
+    }
 }
 impl<const N: usize> From<[OffsetAccess; N]> for ParsedPath {
     fn from(value: [OffsetAccess; N]) -> Self {
@@ -512,8 +841,13 @@ impl core::ops::IndexMut<usize> for ParsedPath {
 )]
 mod tests {
     use super::*;
+    use crate::std_traits::ReflectDefault;
     use crate::*;
-    use alloc::vec;
+    use alloc::{
+        string::{String, ToString},
+        vec,
+    };
+    use bevy_platform::collections::HashMap;
 
     #[derive(Reflect, PartialEq, Debug)]
     struct A {
@@ -800,6 +1134,205 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_wildcards() {
+        let path = ParsedPath::parse("y[*].*#0.*_variant").unwrap();
+        assert_eq!(
+            path.0,
+            &[
+                offset(access_field("y"), 1),
+                offset(Access::AllElements, 2),
+                offset(Access::FieldPattern("*".into()), 5),
+                offset(Access::FieldIndex(0), 7),
+                offset(Access::FieldPattern("*_variant".into()), 9),
+            ]
+        );
+        assert!(path.has_wildcards());
+        assert!(!ParsedPath::parse("y[1].mосква").unwrap().has_wildcards());
+        assert_eq!(path.to_string(), ".y[*].*#0.*_variant");
+    }
+
+    #[test]
+    fn reflect_path_wildcards() {
+        let mut a = a_sample();
+
+        let values = |a: &A, path| {
+            a.reflect_path_all(path)
+                .unwrap()
+                .into_iter()
+                .map(|value| value.reflect_type_path().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        let moscow = a.reflect_path_all("y[*].mосква").unwrap();
+        assert_eq!(moscow.len(), 2);
+        assert_eq!(moscow[0].try_downcast_ref::<f32>(), Some(&1.0));
+        assert_eq!(moscow[1].try_downcast_ref::<f32>(), Some(&2.0));
+        assert_eq!(values(&a, "array[*]"), ["i32"; 3]);
+        assert_eq!(values(&a, "tuple.*"), ["bool", "f32"]);
+        assert_eq!(values(&a, "x.*").len(), 2);
+        assert_eq!(values(&a, "tuple_variant.*"), ["u32", "u32"]);
+        assert_eq!(values(&a, "struct_variant.*"), ["char"]);
+        assert!(values(&a, "unit_variant.*").is_empty());
+        assert_eq!(values(&a, "*").len(), 9);
+        assert_eq!(values(&a, "*_variant").len(), 3);
+        assert_eq!(values(&a, "*_var*nt").len(), 3);
+        assert_eq!(values(&a, "t*le").len(), 1);
+        assert_eq!(values(&a, "x.łørđ"), [core::any::type_name::<C>()]);
+
+        let map: HashMap<_, _> =
+            HashMap::from_iter([(1_u32, C { mосква: 1.0 }), (2, C { mосква: 2.0 })]);
+        let mut sum = 0.0;
+        for value in map.reflect_path_all("[*].mосква").unwrap() {
+            sum += value.try_downcast_ref::<f32>().unwrap();
+        }
+        assert_eq!(sum, 3.0);
+
+        let mut count = 0;
+        a.reflect_path_for_each_mut("y[*].mосква", |value| {
+            *value.try_downcast_mut::<f32>().unwrap() *= 10.0;
+            count += 1;
+        })
+        .unwrap();
+        assert_eq!(count, 2);
+        assert_eq!(a.y, vec![C { mосква: 10.0 }, C { mосква: 20.0 }]);
+
+        a.reflect_path_for_each_mut(&ParsedPath::parse("array[*]").unwrap(), |value| {
+            value.apply(&0_i32);
+        })
+        .unwrap();
+        assert_eq!(a.array, [0; 3]);
+    }
+
+    #[test]
+    fn reflect_path_wildcard_errors() {
+        let mut a = a_sample();
+
+        assert_eq!(
+            a.reflect_path("y[*]").err().unwrap(),
+            ReflectPathError::InvalidAccess(AccessError {
+                kind: AccessErrorKind::MultipleTargets,
+                access: Access::AllElements,
+                offset: Some(2),
+            })
+        );
+        assert_eq!(
+            a.reflect_path_all("y.*").err().unwrap(),
+            ReflectPathError::InvalidAccess(AccessError {
+                kind: AccessErrorKind::IncompatibleTypes {
+                    expected: ReflectKind::Struct,
+                    actual: ReflectKind::List,
+                },
+                access: Access::FieldPattern("*".into()),
+                offset: Some(2),
+            })
+        );
+        assert_eq!(
+            a.reflect_path_all("x[*]").err().unwrap(),
+            invalid_access(2, ReflectKind::Struct, ReflectKind::List, "x[*]")
+        );
+
+        // `tuple.0` is a `bool` and has no fields, so nothing is changed.
+        let mut called = false;
+        assert!(a
+            .reflect_path_for_each_mut("tuple.*.0", |_| called = true)
+            .is_err());
+        assert!(!called);
+    }
+
+    #[test]
+    fn reset_path_to_field_defaults() {
+        #[derive(Reflect, Debug, PartialEq)]
+        #[reflect(Default)]
+        struct Settings {
+            volume: f32,
+            #[reflect(default)]
+            bindings: HashMap<String, u32>,
+            #[reflect(default = "default_mode")]
+            mode: Mode,
+            inner: Inner,
+        }
+
+        impl Default for Settings {
+            fn default() -> Self {
+                Self {
+                    volume: 0.5,
+                    bindings: HashMap::from_iter([("jump".to_string(), 1)]),
+                    mode: Mode::Windowed {
+                        width: 1,
+                        height: 1,
+                    },
+                    inner: Inner(2, 3),
+                }
+            }
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        enum Mode {
+            Windowed {
+                #[reflect(default)]
+                width: u32,
+                height: u32,
+            },
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Inner(u32, #[reflect(default)] u32);
+
+        fn default_mode() -> Mode {
+            Mode::Windowed {
+                width: 800,
+                height: 600,
+            }
+        }
+
+        let mut settings = Settings {
+            volume: 1.0,
+            bindings: HashMap::from_iter([("fire".to_string(), 2)]),
+            mode: Mode::Windowed {
+                width: 10,
+                height: 20,
+            },
+            inner: Inner(4, 5),
+        };
+
+        settings.reset_path("volume").unwrap();
+        assert_eq!(settings.volume, 0.5);
+        // The field is replaced, rather than having the default value applied to it
+        settings.reset_path("#1").unwrap();
+        assert_eq!(settings.bindings, HashMap::default());
+        settings.reset_path("mode.width").unwrap();
+        assert_eq!(
+            settings.mode,
+            Mode::Windowed {
+                width: 0,
+                height: 20
+            }
+        );
+        settings.reset_path("inner.1").unwrap();
+        assert_eq!(settings.inner, Inner(4, 0));
+
+        assert_eq!(
+            settings.reset_path("mode.height"),
+            Err(ReflectPathError::NoDefault)
+        );
+        assert_eq!(
+            settings.reset_path("inner.0"),
+            Err(ReflectPathError::NoDefault)
+        );
+        assert_eq!(settings.reset_path(""), Err(ReflectPathError::NoDefault));
+        assert!(settings.reset_path("missing").is_err());
+
+        settings.reset_path("mode").unwrap();
+        assert_eq!(settings.mode, default_mode());
+
+        let mut list = vec![Inner(1, 1), Inner(2, 2)];
+        list.reset_path("[*].1").unwrap();
+        assert_eq!(list, vec![Inner(1, 0), Inner(2, 0)]);
+        assert_eq!(list.reset_path("[*].0"), Err(ReflectPathError::NoDefault));
+        assert_eq!(list, vec![Inner(1, 0), Inner(2, 0)]);
+    }
+
     #[test]
     fn accept_leading_tokens() {
         assert_eq!(
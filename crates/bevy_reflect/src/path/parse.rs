@@ -126,6 +126,9 @@ struct Ident<'a>(&'a str);
 
 impl<'a> Ident<'a> {
     fn field(self) -> Access<'a> {
+        if self.0.contains('*') {
+            return Access::FieldPattern(self.0.into());
+        }
         let field = |_| Access::Field(self.0.into());
         self.0.parse().map(Access::TupleIndex).unwrap_or_else(field)
     }
@@ -133,6 +136,9 @@ impl<'a> Ident<'a> {
         Ok(Access::FieldIndex(self.0.parse()?))
     }
     fn list_index(self) -> Result<Access<'a>, Error<'a>> {
+        if self.0 == "*" {
+            return Ok(Access::AllElements);
+        }
         Ok(Access::ListIndex(self.0.parse()?))
     }
 }
@@ -508,6 +508,25 @@ mod tests {
         assert_eq!(expected, output);
     }
 
+    #[test]
+    fn should_deserialize_with_type_alias() {
+        let input = r#"{
+            "my_crate::old_module::SomeTupleStruct": ("Hello world!"),
+        }"#;
+
+        let mut registry = get_registry();
+        registry.register_alias::<SomeTupleStruct>("my_crate::old_module::SomeTupleStruct");
+        let reflect_deserializer = ReflectDeserializer::new(&registry);
+        let mut ron_deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let dynamic_output = reflect_deserializer
+            .deserialize(&mut ron_deserializer)
+            .unwrap();
+
+        let output =
+            <SomeTupleStruct as FromReflect>::from_reflect(dynamic_output.as_ref()).unwrap();
+        assert_eq!(SomeTupleStruct(String::from("Hello world!")), output);
+    }
+
     #[test]
     fn should_return_error_if_missing_type_data() {
         let mut registry = TypeRegistry::new();
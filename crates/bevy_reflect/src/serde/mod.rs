@@ -10,11 +10,75 @@ pub use type_data::*;
 mod tests {
     use super::*;
     use crate::{
-        type_registry::TypeRegistry, DynamicStruct, DynamicTupleStruct, FromReflect,
-        PartialReflect, Reflect, Struct,
+        std_traits::ReflectDefault, type_registry::TypeRegistry, DynamicStruct, DynamicTupleStruct,
+        FromReflect, PartialReflect, Reflect, Struct,
     };
+    use alloc::string::String;
     use serde::de::DeserializeSeed;
 
+    #[test]
+    fn test_serialization_skipping_defaults() {
+        #[derive(Debug, Reflect, PartialEq)]
+        #[reflect(Default)]
+        struct Player {
+            name: String,
+            health: f32,
+            #[reflect(default = "default_speed")]
+            speed: f32,
+            position: Position,
+        }
+
+        impl Default for Player {
+            fn default() -> Self {
+                Self {
+                    name: "player".into(),
+                    health: 100.0,
+                    speed: 0.0,
+                    position: Position(0.0, 0.0),
+                }
+            }
+        }
+
+        // Tuple struct fields are never skipped
+        #[derive(Debug, Reflect, PartialEq, Default)]
+        #[reflect(Default)]
+        struct Position(f32, f32);
+
+        fn default_speed() -> f32 {
+            5.0
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Player>();
+
+        let player = Player {
+            name: "player".into(),
+            health: 50.0,
+            speed: 5.0,
+            position: Position(0.0, 1.0),
+        };
+
+        let serializer = TypedReflectSerializer::new(&player, &registry);
+        let serialized = ron::ser::to_string(&serializer).unwrap();
+        assert_eq!(
+            serialized,
+            r#"(name:"player",health:50.0,speed:5.0,position:(0.0,1.0))"#
+        );
+
+        let serializer = TypedReflectSerializer::new(&player, &registry).with_skip_defaults(true);
+        let serialized = ron::ser::to_string(&serializer).unwrap();
+        assert_eq!(serialized, "(health:50.0,position:(0.0,1.0))");
+
+        let registration = registry.get(core::any::TypeId::of::<Player>()).unwrap();
+        let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+        let reflect_deserializer = TypedReflectDeserializer::new(registration, &registry);
+        let deserialized = reflect_deserializer.deserialize(&mut deserializer).unwrap();
+        assert_eq!(
+            <Player as FromReflect>::from_reflect(deserialized.as_partial_reflect()),
+            Some(player)
+        );
+    }
+
     #[test]
     fn test_serialization_struct() {
         #[derive(Debug, Reflect, PartialEq)]
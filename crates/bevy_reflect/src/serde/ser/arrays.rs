@@ -8,6 +8,7 @@ pub(super) struct ArraySerializer<'a, P> {
     pub array: &'a dyn Array,
     pub registry: &'a TypeRegistry,
     pub processor: Option<&'a P>,
+    pub skip_defaults: bool,
 }
 
 impl<P: ReflectSerializerProcessor> Serialize for ArraySerializer<'_, P> {
@@ -21,6 +22,7 @@ impl<P: ReflectSerializerProcessor> Serialize for ArraySerializer<'_, P> {
                 value,
                 self.registry,
                 self.processor,
+                self.skip_defaults,
             ))?;
         }
         state.end()
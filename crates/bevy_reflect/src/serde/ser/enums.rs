@@ -14,6 +14,7 @@ pub(super) struct EnumSerializer<'a, P> {
     pub enum_value: &'a dyn Enum,
     pub registry: &'a TypeRegistry,
     pub processor: Option<&'a P>,
+    pub skip_defaults: bool,
 }
 
 impl<P: ReflectSerializerProcessor> Serialize for EnumSerializer<'_, P> {
@@ -84,6 +85,7 @@ impl<P: ReflectSerializerProcessor> Serialize for EnumSerializer<'_, P> {
                             field.value(),
                             self.registry,
                             self.processor,
+                            self.skip_defaults,
                         ),
                     )?;
                 }
@@ -99,13 +101,19 @@ impl<P: ReflectSerializerProcessor> Serialize for EnumSerializer<'_, P> {
                         field,
                         self.registry,
                         self.processor,
+                        self.skip_defaults,
                     ))
                 } else {
                     serializer.serialize_newtype_variant(
                         enum_name,
                         variant_index,
                         variant_name,
-                        &TypedReflectSerializer::new_internal(field, self.registry, self.processor),
+                        &TypedReflectSerializer::new_internal(
+                            field,
+                            self.registry,
+                            self.processor,
+                            self.skip_defaults,
+                        ),
                     )
                 }
             }
@@ -121,6 +129,7 @@ impl<P: ReflectSerializerProcessor> Serialize for EnumSerializer<'_, P> {
                         field.value(),
                         self.registry,
                         self.processor,
+                        self.skip_defaults,
                     ))?;
                 }
                 state.end()
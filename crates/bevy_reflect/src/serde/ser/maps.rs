@@ -8,6 +8,7 @@ pub(super) struct MapSerializer<'a, P> {
     pub map: &'a dyn Map,
     pub registry: &'a TypeRegistry,
     pub processor: Option<&'a P>,
+    pub skip_defaults: bool,
 }
 
 impl<P: ReflectSerializerProcessor> Serialize for MapSerializer<'_, P> {
@@ -18,8 +19,18 @@ impl<P: ReflectSerializerProcessor> Serialize for MapSerializer<'_, P> {
         let mut state = serializer.serialize_map(Some(self.map.len()))?;
         for (key, value) in self.map.iter() {
             state.serialize_entry(
-                &TypedReflectSerializer::new_internal(key, self.registry, self.processor),
-                &TypedReflectSerializer::new_internal(value, self.registry, self.processor),
+                &TypedReflectSerializer::new_internal(
+                    key,
+                    self.registry,
+                    self.processor,
+                    self.skip_defaults,
+                ),
+                &TypedReflectSerializer::new_internal(
+                    value,
+                    self.registry,
+                    self.processor,
+                    self.skip_defaults,
+                ),
             )?;
         }
         state.end()
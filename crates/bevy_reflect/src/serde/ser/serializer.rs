@@ -29,6 +29,8 @@ use super::ReflectSerializerProcessor;
 /// a reference to a [`ReflectSerializerProcessor`] which will take priority
 /// over all other serialization methods - see [`with_processor`].
 ///
+/// Struct fields equal to their default value can be skipped with [`with_skip_defaults`].
+///
 /// # Example
 ///
 /// ```
@@ -54,10 +56,12 @@ use super::ReflectSerializerProcessor;
 /// [`ReflectDeserializer`]: crate::serde::ReflectDeserializer
 /// [type path]: crate::TypePath::type_path
 /// [`with_processor`]: Self::with_processor
+/// [`with_skip_defaults`]: Self::with_skip_defaults
 pub struct ReflectSerializer<'a, P = ()> {
     value: &'a dyn PartialReflect,
     registry: &'a TypeRegistry,
     processor: Option<&'a P>,
+    skip_defaults: bool,
 }
 
 impl<'a> ReflectSerializer<'a, ()> {
@@ -72,6 +76,7 @@ impl<'a> ReflectSerializer<'a, ()> {
             value,
             registry,
             processor: None,
+            skip_defaults: false,
         }
     }
 }
@@ -92,6 +97,26 @@ impl<'a, P: ReflectSerializerProcessor> ReflectSerializer<'a, P> {
             value,
             registry,
             processor: Some(processor),
+            skip_defaults: false,
+        }
+    }
+
+    /// Sets whether the fields of structs that are equal to their [default value] are skipped,
+    /// which can make the output much smaller.
+    ///
+    /// Skipped fields are missing from the deserialized dynamic value,
+    /// and are set back to their default value when converting it with [`FromReflect`].
+    /// This should only be used with self-describing formats, such as RON or JSON,
+    /// where struct fields are identified by name rather than position.
+    ///
+    /// Only the fields of structs are skipped, not those of tuple structs or enum variants.
+    ///
+    /// [default value]: crate::NamedField::default_value
+    /// [`FromReflect`]: crate::FromReflect
+    pub fn with_skip_defaults(self, skip_defaults: bool) -> Self {
+        Self {
+            skip_defaults,
+            ..self
         }
     }
 }
@@ -119,7 +144,12 @@ impl<P: ReflectSerializerProcessor> Serialize for ReflectSerializer<'_, P> {
                     }
                 })?
                 .type_path(),
-            &TypedReflectSerializer::new_internal(self.value, self.registry, self.processor),
+            &TypedReflectSerializer::new_internal(
+                self.value,
+                self.registry,
+                self.processor,
+                self.skip_defaults,
+            ),
         )?;
         state.end()
     }
@@ -143,6 +173,8 @@ impl<P: ReflectSerializerProcessor> Serialize for ReflectSerializer<'_, P> {
 /// a reference to a [`ReflectSerializerProcessor`] which will take priority
 /// over all other serialization methods - see [`with_processor`].
 ///
+/// Struct fields equal to their default value can be skipped with [`with_skip_defaults`].
+///
 /// # Example
 ///
 /// ```
@@ -168,10 +200,12 @@ impl<P: ReflectSerializerProcessor> Serialize for ReflectSerializer<'_, P> {
 /// [`TypedReflectDeserializer`]: crate::serde::TypedReflectDeserializer
 /// [type path]: crate::TypePath::type_path
 /// [`with_processor`]: Self::with_processor
+/// [`with_skip_defaults`]: Self::with_skip_defaults
 pub struct TypedReflectSerializer<'a, P = ()> {
     value: &'a dyn PartialReflect,
     registry: &'a TypeRegistry,
     processor: Option<&'a P>,
+    skip_defaults: bool,
 }
 
 impl<'a> TypedReflectSerializer<'a, ()> {
@@ -189,6 +223,7 @@ impl<'a> TypedReflectSerializer<'a, ()> {
             value,
             registry,
             processor: None,
+            skip_defaults: false,
         }
     }
 }
@@ -212,6 +247,26 @@ impl<'a, P> TypedReflectSerializer<'a, P> {
             value,
             registry,
             processor: Some(processor),
+            skip_defaults: false,
+        }
+    }
+
+    /// Sets whether the fields of structs that are equal to their [default value] are skipped,
+    /// which can make the output much smaller.
+    ///
+    /// Skipped fields are missing from the deserialized dynamic value,
+    /// and are set back to their default value when converting it with [`FromReflect`].
+    /// This should only be used with self-describing formats, such as RON or JSON,
+    /// where struct fields are identified by name rather than position.
+    ///
+    /// Only the fields of structs are skipped, not those of tuple structs or enum variants.
+    ///
+    /// [default value]: crate::NamedField::default_value
+    /// [`FromReflect`]: crate::FromReflect
+    pub fn with_skip_defaults(self, skip_defaults: bool) -> Self {
+        Self {
+            skip_defaults,
+            ..self
         }
     }
 
@@ -220,11 +275,13 @@ impl<'a, P> TypedReflectSerializer<'a, P> {
         value: &'a dyn PartialReflect,
         registry: &'a TypeRegistry,
         processor: Option<&'a P>,
+        skip_defaults: bool,
     ) -> Self {
         Self {
             value,
             registry,
             processor,
+            skip_defaults,
         }
     }
 }
@@ -269,48 +326,56 @@ impl<P: ReflectSerializerProcessor> Serialize for TypedReflectSerializer<'_, P>
                 struct_value,
                 registry: self.registry,
                 processor: self.processor,
+                skip_defaults: self.skip_defaults,
             }
             .serialize(serializer),
             ReflectRef::TupleStruct(tuple_struct) => TupleStructSerializer {
                 tuple_struct,
                 registry: self.registry,
                 processor: self.processor,
+                skip_defaults: self.skip_defaults,
             }
             .serialize(serializer),
             ReflectRef::Tuple(tuple) => TupleSerializer {
                 tuple,
                 registry: self.registry,
                 processor: self.processor,
+                skip_defaults: self.skip_defaults,
             }
             .serialize(serializer),
             ReflectRef::List(list) => ListSerializer {
                 list,
                 registry: self.registry,
                 processor: self.processor,
+                skip_defaults: self.skip_defaults,
             }
             .serialize(serializer),
             ReflectRef::Array(array) => ArraySerializer {
                 array,
                 registry: self.registry,
                 processor: self.processor,
+                skip_defaults: self.skip_defaults,
             }
             .serialize(serializer),
             ReflectRef::Map(map) => MapSerializer {
                 map,
                 registry: self.registry,
                 processor: self.processor,
+                skip_defaults: self.skip_defaults,
             }
             .serialize(serializer),
             ReflectRef::Set(set) => SetSerializer {
                 set,
                 registry: self.registry,
                 processor: self.processor,
+                skip_defaults: self.skip_defaults,
             }
             .serialize(serializer),
             ReflectRef::Enum(enum_value) => EnumSerializer {
                 enum_value,
                 registry: self.registry,
                 processor: self.processor,
+                skip_defaults: self.skip_defaults,
             }
             .serialize(serializer),
             #[cfg(feature = "functions")]
@@ -8,6 +8,7 @@ pub(super) struct SetSerializer<'a, P> {
     pub set: &'a dyn Set,
     pub registry: &'a TypeRegistry,
     pub processor: Option<&'a P>,
+    pub skip_defaults: bool,
 }
 
 impl<P: ReflectSerializerProcessor> Serialize for SetSerializer<'_, P> {
@@ -21,6 +22,7 @@ impl<P: ReflectSerializerProcessor> Serialize for SetSerializer<'_, P> {
                 value,
                 self.registry,
                 self.processor,
+                self.skip_defaults,
             ))?;
         }
         state.end()
@@ -2,6 +2,7 @@ use crate::{
     serde::{ser::error_utils::make_custom_error, SerializationData, TypedReflectSerializer},
     Struct, TypeInfo, TypeRegistry,
 };
+use alloc::vec::Vec;
 use serde::{ser::SerializeStruct, Serialize};
 
 use super::ReflectSerializerProcessor;
@@ -11,6 +12,7 @@ pub(super) struct StructSerializer<'a, P> {
     pub struct_value: &'a dyn Struct,
     pub registry: &'a TypeRegistry,
     pub processor: Option<&'a P>,
+    pub skip_defaults: bool,
 }
 
 impl<P: ReflectSerializerProcessor> Serialize for StructSerializer<'_, P> {
@@ -41,20 +43,36 @@ impl<P: ReflectSerializerProcessor> Serialize for StructSerializer<'_, P> {
             .registry
             .get(type_info.type_id())
             .and_then(|registration| registration.data::<SerializationData>());
-        let ignored_len = serialization_data.map(SerializationData::len).unwrap_or(0);
-        let mut state = serializer.serialize_struct(
-            struct_info.type_path_table().ident().unwrap(),
-            self.struct_value.field_len() - ignored_len,
-        )?;
+        let skipped = self
+            .struct_value
+            .iter_fields()
+            .enumerate()
+            .map(|(index, value)| {
+                serialization_data.is_some_and(|data| data.is_field_skipped(index))
+                    || (self.skip_defaults
+                        && struct_info
+                            .field_at(index)
+                            .and_then(|field| field.is_default(value))
+                            .unwrap_or(false))
+            })
+            .collect::<Vec<_>>();
+        let len = skipped.iter().filter(|skipped| !**skipped).count();
+        let mut state =
+            serializer.serialize_struct(struct_info.type_path_table().ident().unwrap(), len)?;
 
         for (index, value) in self.struct_value.iter_fields().enumerate() {
-            if serialization_data.is_some_and(|data| data.is_field_skipped(index)) {
+            if skipped[index] {
                 continue;
             }
             let key = struct_info.field_at(index).unwrap().name();
             state.serialize_field(
                 key,
-                &TypedReflectSerializer::new_internal(value, self.registry, self.processor),
+                &TypedReflectSerializer::new_internal(
+                    value,
+                    self.registry,
+                    self.processor,
+                    self.skip_defaults,
+                ),
             )?;
         }
         state.end()
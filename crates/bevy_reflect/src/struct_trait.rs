@@ -178,6 +178,13 @@ impl StructInfo {
         self.fields.len()
     }
 
+    /// Returns the default value of the field with the given name, if it is known.
+    ///
+    /// See [`NamedField::default_value`] for where this value comes from.
+    pub fn field_default(&self, name: &str) -> Option<Box<dyn PartialReflect>> {
+        self.field(name)?.default_value()
+    }
+
     impl_type_methods!(ty);
 
     /// The docstring of this struct, if any.
@@ -132,6 +132,13 @@ impl TupleStructInfo {
         self.fields.len()
     }
 
+    /// Returns the default value of the field at the given index, if it is known.
+    ///
+    /// See [`UnnamedField::default_value`] for where this value comes from.
+    pub fn field_default(&self, index: usize) -> Option<Box<dyn PartialReflect>> {
+        self.field_at(index)?.default_value()
+    }
+
     impl_type_methods!(ty);
 
     /// The docstring of this struct, if any.
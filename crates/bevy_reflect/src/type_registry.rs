@@ -2,7 +2,10 @@ use crate::{serde::Serializable, FromReflect, Reflect, TypeInfo, TypePath, Typed
 use alloc::{boxed::Box, string::String};
 use bevy_platform::{
     collections::{HashMap, HashSet},
-    sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard,
+    },
 };
 use bevy_ptr::{Ptr, PtrMut};
 use bevy_utils::TypeIdMap;
@@ -12,6 +15,7 @@ use core::{
     ops::{Deref, DerefMut},
 };
 use downcast_rs::{impl_downcast, Downcast};
+use log::warn;
 use serde::Deserialize;
 
 /// A registry of [reflected] types.
@@ -21,16 +25,30 @@ use serde::Deserialize;
 /// using a type's [`GetTypeRegistration`] implementation
 /// (which is automatically implemented when using [`#[derive(Reflect)]`](derive@crate::Reflect)).
 ///
+/// Types can also be found through [aliases], such as the paths they had before being
+/// moved to another module, and through their [`StableTypeId`].
+///
 /// See the [crate-level documentation] for more information.
 ///
 /// [reflected]: crate
 /// [Registering]: TypeRegistry::register
+/// [aliases]: TypeRegistry::register_alias
 /// [crate-level documentation]: crate
 pub struct TypeRegistry {
     registrations: TypeIdMap<TypeRegistration>,
     short_path_to_id: HashMap<&'static str, TypeId>,
     type_path_to_id: HashMap<&'static str, TypeId>,
+    stable_id_to_id: HashMap<StableTypeId, TypeId>,
     ambiguous_names: HashSet<&'static str>,
+    aliases: HashMap<String, TypeAlias>,
+    stable_id_to_alias: HashMap<StableTypeId, String>,
+}
+
+/// A type an alias refers to, see [`TypeRegistry::register_alias`].
+struct TypeAlias {
+    type_id: TypeId,
+    /// Whether the use of this alias has been warned about.
+    warned: AtomicBool,
 }
 
 // TODO:  remove this wrapper once we migrate to Atelier Assets and the Scene AssetLoader doesn't
@@ -52,6 +70,65 @@ impl Debug for TypeRegistryArc {
     }
 }
 
+/// An identifier of a type that, unlike [`TypeId`], is the same across builds and platforms.
+///
+/// It's a 64-bit hash of the [type path] of the type, so it only changes when the type is
+/// renamed or moved to another module, and [registering an alias] for the old path keeps
+/// the old id working.
+/// The [`TypeRegistry`] assigns it to every registered type, see
+/// [`TypeRegistry::get_with_stable_id`].
+///
+/// This makes it a compact way to refer to types in data that is sent over the network or
+/// saved to disk, such as the [binary format].
+///
+/// ```
+/// # use bevy_reflect::{StableTypeId, TypePath};
+/// assert_eq!(StableTypeId::of::<u32>(), StableTypeId::of_type_path("u32"));
+/// assert_ne!(StableTypeId::of::<u32>(), StableTypeId::of::<i32>());
+/// ```
+///
+/// [type path]: TypePath::type_path
+/// [registering an alias]: TypeRegistry::register_alias
+/// [binary format]: crate::binary
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct StableTypeId(u64);
+
+impl StableTypeId {
+    /// Returns the id of the type with the given [type path].
+    ///
+    /// [type path]: TypePath::type_path
+    pub const fn of_type_path(type_path: &str) -> Self {
+        // 64-bit FNV-1a, which is simple enough to never change.
+        const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const PRIME: u64 = 0x0100_0000_01b3;
+
+        let bytes = type_path.as_bytes();
+        let mut hash = OFFSET_BASIS;
+        let mut index = 0;
+        while index < bytes.len() {
+            hash ^= bytes[index] as u64;
+            hash = hash.wrapping_mul(PRIME);
+            index += 1;
+        }
+        Self(hash)
+    }
+
+    /// Returns the id of the type `T`.
+    pub fn of<T: TypePath + ?Sized>() -> Self {
+        Self::of_type_path(T::type_path())
+    }
+
+    /// Creates an id from its bits, as returned by [`to_bits`](Self::to_bits).
+    pub const fn from_bits(bits: u64) -> Self {
+        Self(bits)
+    }
+
+    /// Returns the bits of this id.
+    pub const fn to_bits(self) -> u64 {
+        self.0
+    }
+}
+
 /// A trait which allows a type to generate its [`TypeRegistration`]
 /// for registration into the [`TypeRegistry`].
 ///
@@ -92,7 +169,10 @@ impl TypeRegistry {
             registrations: Default::default(),
             short_path_to_id: Default::default(),
             type_path_to_id: Default::default(),
+            stable_id_to_id: Default::default(),
             ambiguous_names: Default::default(),
+            aliases: Default::default(),
+            stable_id_to_alias: Default::default(),
         }
     }
 
@@ -232,6 +312,7 @@ impl TypeRegistry {
             &registration,
             &mut self.short_path_to_id,
             &mut self.type_path_to_id,
+            &mut self.stable_id_to_id,
             &mut self.ambiguous_names,
         );
         self.registrations
@@ -260,6 +341,7 @@ impl TypeRegistry {
                     &registration,
                     &mut self.short_path_to_id,
                     &mut self.type_path_to_id,
+                    &mut self.stable_id_to_id,
                     &mut self.ambiguous_names,
                 );
                 entry.insert(registration);
@@ -273,6 +355,7 @@ impl TypeRegistry {
         registration: &TypeRegistration,
         short_path_to_id: &mut HashMap<&'static str, TypeId>,
         type_path_to_id: &mut HashMap<&'static str, TypeId>,
+        stable_id_to_id: &mut HashMap<StableTypeId, TypeId>,
         ambiguous_names: &mut HashSet<&'static str>,
     ) {
         let short_name = registration.type_info().type_path_table().short_path();
@@ -284,6 +367,15 @@ impl TypeRegistry {
             short_path_to_id.insert(short_name, registration.type_id());
         }
         type_path_to_id.insert(registration.type_info().type_path(), registration.type_id());
+        let type_id = *stable_id_to_id
+            .entry(registration.stable_id())
+            .or_insert(registration.type_id());
+        if type_id != registration.type_id() {
+            warn!(
+                "`{}` has the same stable type id as another registered type and can't be found by it",
+                registration.type_info().type_path()
+            );
+        }
     }
 
     /// Registers the type data `D` for type `T`.
@@ -337,55 +429,85 @@ impl TypeRegistry {
     /// Returns a reference to the [`TypeRegistration`] of the type with the
     /// given [type path].
     ///
-    /// If no type with the given path has been registered, returns `None`.
+    /// If no type with the given path has been registered, falls back to the type
+    /// with the given [alias], or returns `None` if there is none.
     ///
     /// [type path]: TypePath::type_path
+    /// [alias]: Self::register_alias
     pub fn get_with_type_path(&self, type_path: &str) -> Option<&TypeRegistration> {
         self.type_path_to_id
             .get(type_path)
-            .and_then(|id| self.get(*id))
+            .copied()
+            .or_else(|| self.resolve_alias(type_path))
+            .and_then(|id| self.get(id))
     }
 
     /// Returns a mutable reference to the [`TypeRegistration`] of the type with
     /// the given [type path].
     ///
-    /// If no type with the given type path has been registered, returns `None`.
+    /// If no type with the given type path has been registered, falls back to the type
+    /// with the given [alias], or returns `None` if there is none.
     ///
     /// [type path]: TypePath::type_path
+    /// [alias]: Self::register_alias
     pub fn get_with_type_path_mut(&mut self, type_path: &str) -> Option<&mut TypeRegistration> {
         self.type_path_to_id
             .get(type_path)
-            .cloned()
+            .copied()
+            .or_else(|| self.resolve_alias(type_path))
             .and_then(move |id| self.get_mut(id))
     }
 
+    /// Returns a reference to the [`TypeRegistration`] of the type with
+    /// the given [`StableTypeId`].
+    ///
+    /// If no type with the given id has been registered, falls back to the type
+    /// whose [alias] has the given id, or returns `None` if there is none.
+    ///
+    /// [alias]: Self::register_alias
+    pub fn get_with_stable_id(&self, id: StableTypeId) -> Option<&TypeRegistration> {
+        self.stable_id_to_id
+            .get(&id)
+            .copied()
+            .or_else(|| self.resolve_alias(self.stable_id_to_alias.get(&id)?))
+            .and_then(|id| self.get(id))
+    }
+
     /// Returns a reference to the [`TypeRegistration`] of the type with
     /// the given [short type path].
     ///
     /// If the short type path is ambiguous, or if no type with the given path
-    /// has been registered, returns `None`.
+    /// has been registered, falls back to the type with the given [alias],
+    /// or returns `None` if there is none.
     ///
     /// [short type path]: TypePath::short_type_path
+    /// [alias]: Self::register_alias
     pub fn get_with_short_type_path(&self, short_type_path: &str) -> Option<&TypeRegistration> {
         self.short_path_to_id
             .get(short_type_path)
-            .and_then(|id| self.registrations.get(id))
+            .copied()
+            .or_else(|| self.resolve_alias(short_type_path))
+            .and_then(|id| self.registrations.get(&id))
     }
 
     /// Returns a mutable reference to the [`TypeRegistration`] of the type with
     /// the given [short type path].
     ///
     /// If the short type path is ambiguous, or if no type with the given path
-    /// has been registered, returns `None`.
+    /// has been registered, falls back to the type with the given [alias],
+    /// or returns `None` if there is none.
     ///
     /// [short type path]: TypePath::short_type_path
+    /// [alias]: Self::register_alias
     pub fn get_with_short_type_path_mut(
         &mut self,
         short_type_path: &str,
     ) -> Option<&mut TypeRegistration> {
         self.short_path_to_id
             .get(short_type_path)
-            .and_then(|id| self.registrations.get_mut(id))
+            .copied()
+            .or_else(|| self.resolve_alias(short_type_path))
+            .and_then(|id| self.registrations.get_mut(&id))
     }
 
     /// Returns `true` if the given [short type path] is ambiguous, that is, it matches multiple registered types.
@@ -414,6 +536,82 @@ impl TypeRegistry {
         self.ambiguous_names.contains(short_type_path)
     }
 
+    /// Registers `alias` as another [type path] or [short type path] of `T`,
+    /// such as the path `T` had before it was renamed or moved to another module.
+    ///
+    /// Lookups by type path, like [`get_with_type_path`] and [`get_with_short_type_path`],
+    /// fall back to aliases when no type is registered with the given path.
+    /// The same goes for lookups by the [`StableTypeId`] of the alias.
+    /// Since the reflection deserializers find types this way, this lets scenes,
+    /// save files and other data that refer to the old path keep working.
+    /// A warning is logged the first time each alias is used, as a reminder to update the data.
+    ///
+    /// Registering an alias that already exists replaces it.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_reflect::{Reflect, TypeRegistry};
+    /// #[derive(Reflect)]
+    /// #[type_path = "my_game::items"]
+    /// struct Sword;
+    ///
+    /// let mut type_registry = TypeRegistry::default();
+    /// type_registry.register::<Sword>();
+    /// type_registry.register_alias::<Sword>("my_game::weapons::Sword");
+    ///
+    /// let registration = type_registry.get_with_type_path("my_game::weapons::Sword").unwrap();
+    /// assert_eq!(registration.type_info().type_path(), "my_game::items::Sword");
+    /// ```
+    ///
+    /// [type path]: TypePath::type_path
+    /// [short type path]: TypePath::short_type_path
+    /// [`get_with_type_path`]: Self::get_with_type_path
+    /// [`get_with_short_type_path`]: Self::get_with_short_type_path
+    pub fn register_alias<T: 'static>(&mut self, alias: impl Into<String>) {
+        self.register_alias_by_id(alias, TypeId::of::<T>());
+    }
+
+    /// Registers `alias` as another [type path] or [short type path] of the type with the given [`TypeId`].
+    ///
+    /// See [`register_alias`](Self::register_alias) for more details.
+    ///
+    /// [type path]: TypePath::type_path
+    /// [short type path]: TypePath::short_type_path
+    pub fn register_alias_by_id(&mut self, alias: impl Into<String>, type_id: TypeId) {
+        let alias = alias.into();
+        self.stable_id_to_alias
+            .insert(StableTypeId::of_type_path(&alias), alias.clone());
+        self.aliases.insert(
+            alias,
+            TypeAlias {
+                type_id,
+                warned: AtomicBool::new(false),
+            },
+        );
+    }
+
+    /// Returns an iterator over the [aliases](Self::register_alias) and the [`TypeId`]
+    /// of the type each of them refers to.
+    pub fn iter_aliases(&self) -> impl Iterator<Item = (&str, TypeId)> {
+        self.aliases
+            .iter()
+            .map(|(alias, TypeAlias { type_id, .. })| (alias.as_str(), *type_id))
+    }
+
+    /// Returns the [`TypeId`] of the registered type with the given alias,
+    /// warning about the use of the alias the first time it is used.
+    fn resolve_alias(&self, alias: &str) -> Option<TypeId> {
+        let TypeAlias { type_id, warned } = self.aliases.get(alias)?;
+        let registration = self.get(*type_id)?;
+        if !warned.swap(true, Ordering::Relaxed) {
+            warn!(
+                "`{alias}` was resolved as an alias of `{}`, data using `{alias}` should be updated to use the new type path",
+                registration.type_info().type_path()
+            );
+        }
+        Some(*type_id)
+    }
+
     /// Returns a reference to the [`TypeData`] of type `T` associated with the given [`TypeId`].
     ///
     /// The returned value may be used to downcast [`Reflect`] trait objects to
@@ -464,6 +662,39 @@ impl TypeRegistry {
             type_data.map(|data| (item, data))
         })
     }
+
+    /// Returns an iterator over the registered types implementing the trait reflected by `T`,
+    /// along with their `T` type data.
+    ///
+    /// This allows operating over an open set of types implementing a trait,
+    /// such as all types implementing a `Damageable` trait generated with
+    /// [`#[reflect_trait]`](crate::reflect_trait).
+    pub fn iter_implementing<T: ReflectTrait>(
+        &self,
+    ) -> impl Iterator<Item = (&TypeRegistration, &T)> {
+        self.iter_with_data::<T>()
+    }
+
+    /// Casts the given value to the trait object of the trait reflected by `T`,
+    /// using the `T` type data registered for the type of the value.
+    ///
+    /// Returns `None` if the type of the value is not registered or doesn't register `T`.
+    pub fn get_trait<'a, T: ReflectTrait>(&self, value: &'a dyn Reflect) -> Option<&'a T::Target> {
+        self.get_type_data::<T>(value.reflect_type_info().type_id())?
+            .get_trait(value)
+    }
+
+    /// Casts the given value to a mutable trait object of the trait reflected by `T`,
+    /// using the `T` type data registered for the type of the value.
+    ///
+    /// Returns `None` if the type of the value is not registered or doesn't register `T`.
+    pub fn get_trait_mut<'a, T: ReflectTrait>(
+        &self,
+        value: &'a mut dyn Reflect,
+    ) -> Option<&'a mut T::Target> {
+        self.get_type_data::<T>(value.reflect_type_info().type_id())?
+            .get_trait_mut(value)
+    }
 }
 
 impl TypeRegistryArc {
@@ -538,6 +769,12 @@ impl TypeRegistration {
         self.type_info
     }
 
+    /// Returns the [`StableTypeId`] of the type.
+    #[inline]
+    pub fn stable_id(&self) -> StableTypeId {
+        StableTypeId::of_type_path(self.type_info.type_path())
+    }
+
     /// Inserts an instance of `T` into this registration's [type data].
     ///
     /// If another instance of `T` was previously inserted, it is replaced.
@@ -575,6 +812,17 @@ impl TypeRegistration {
         self.data.get(&type_id).map(Deref::deref)
     }
 
+    /// Returns the reflected method with the given name.
+    ///
+    /// Returns `None` if the type has no [`ReflectMethods`] type data
+    /// or no method with the given name.
+    ///
+    /// [`ReflectMethods`]: crate::func::ReflectMethods
+    #[cfg(feature = "functions")]
+    pub fn method(&self, name: &str) -> Option<&crate::func::DynamicFunction<'static>> {
+        self.data::<crate::func::ReflectMethods>()?.get(name)
+    }
+
     /// Returns a mutable reference to the value of type `T` in this registration's
     /// [type data].
     ///
@@ -705,6 +953,68 @@ pub trait FromType<T> {
     fn from_type() -> Self;
 }
 
+/// [`TypeData`] for casting reflected values to the trait objects of a trait.
+///
+/// This is implemented by the `ReflectMyTrait` type data generated by the
+/// [`#[reflect_trait]`](crate::reflect_trait) macro, which allows writing code that's generic
+/// over reflected traits, such as [`TypeRegistry::iter_implementing`] and [`TypeRegistry::get_trait`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{reflect_trait, Reflect, ReflectTrait, TypeRegistry};
+/// #[reflect_trait]
+/// trait Damageable {
+///     fn damage(&mut self, amount: u32);
+/// }
+///
+/// #[derive(Reflect)]
+/// #[reflect(Damageable)]
+/// struct Crate {
+///     durability: u32,
+/// }
+///
+/// impl Damageable for Crate {
+///     fn damage(&mut self, amount: u32) {
+///         self.durability = self.durability.saturating_sub(amount);
+///     }
+/// }
+///
+/// let mut registry = TypeRegistry::new();
+/// registry.register::<Crate>();
+/// assert_eq!(registry.iter_implementing::<ReflectDamageable>().count(), 1);
+///
+/// let mut value: Box<dyn Reflect> = Box::new(Crate { durability: 10 });
+/// registry
+///     .get_trait_mut::<ReflectDamageable>(value.as_mut())
+///     .unwrap()
+///     .damage(4);
+/// assert_eq!(value.downcast_ref::<Crate>().unwrap().durability, 6);
+/// ```
+pub trait ReflectTrait: TypeData {
+    /// The trait object type of the reflected trait, such as `dyn MyTrait`.
+    type Target: ?Sized + 'static;
+
+    /// Downcasts a `&dyn Reflect` to a reference to the trait object.
+    ///
+    /// If the type of the value doesn't match the type this type data was created for, returns `None`.
+    fn get_trait<'a>(&self, value: &'a dyn Reflect) -> Option<&'a Self::Target>;
+
+    /// Downcasts a `&mut dyn Reflect` to a mutable reference to the trait object.
+    ///
+    /// If the type of the value doesn't match the type this type data was created for, returns `None`.
+    fn get_trait_mut<'a>(&self, value: &'a mut dyn Reflect) -> Option<&'a mut Self::Target>;
+
+    /// Downcasts a `Box<dyn Reflect>` to a boxed trait object.
+    ///
+    /// If the type of the value doesn't match the type this type data was created for,
+    /// returns the value back as an error.
+    fn get_trait_boxed(
+        &self,
+        value: Box<dyn Reflect>,
+    ) -> Result<Box<Self::Target>, Box<dyn Reflect>>;
+}
+
 /// A struct used to serialize reflected instances of a type.
 ///
 /// A `ReflectSerialize` for type `T` can be obtained via
@@ -985,4 +1295,56 @@ mod test {
         let data = registration.data::<DataA>().unwrap();
         assert_eq!(data.0, 456);
     }
+
+    #[test]
+    fn type_aliases() {
+        #[derive(Reflect)]
+        #[type_path = "my_crate::new_module"]
+        struct Foo;
+
+        #[derive(Reflect)]
+        #[type_path = "my_crate::other_module"]
+        struct Bar;
+
+        let mut registry = TypeRegistry::empty();
+        registry.register::<Foo>();
+        registry.register::<Bar>();
+        registry.register_alias::<Foo>("my_crate::old_module::Foo");
+        registry.register_alias::<Foo>("OldFoo");
+        // Registered paths take precedence over aliases.
+        registry.register_alias::<Foo>("my_crate::other_module::Bar");
+
+        let type_id = |registration: Option<&TypeRegistration>| registration.unwrap().type_id();
+        assert_eq!(
+            type_id(registry.get_with_stable_id(StableTypeId::of::<Bar>())),
+            TypeId::of::<Bar>()
+        );
+        assert_eq!(
+            type_id(registry.get_with_type_path("my_crate::old_module::Foo")),
+            TypeId::of::<Foo>()
+        );
+        assert_eq!(
+            type_id(registry.get_with_short_type_path("OldFoo")),
+            TypeId::of::<Foo>()
+        );
+        assert_eq!(
+            type_id(registry.get_with_type_path("my_crate::other_module::Bar")),
+            TypeId::of::<Bar>()
+        );
+        assert!(registry
+            .get_with_type_path_mut("my_crate::old_module::Foo")
+            .is_some());
+        assert_eq!(registry.iter_aliases().count(), 3);
+        assert_eq!(
+            type_id(
+                registry
+                    .get_with_stable_id(StableTypeId::of_type_path("my_crate::old_module::Foo"))
+            ),
+            TypeId::of::<Foo>()
+        );
+
+        // Aliases of unregistered types are not resolved.
+        registry.register_alias::<u8>("my_crate::Byte");
+        assert!(registry.get_with_type_path("my_crate::Byte").is_none());
+    }
 }
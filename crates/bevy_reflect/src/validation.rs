@@ -0,0 +1,857 @@
+//! Constraints on the values of reflected fields, and checking values against them.
+//!
+//! Reflection makes it possible to set any field to any value of the right type,
+//! which is rarely what a field expects: a volume should stay between `0.0` and `1.0`,
+//! and a name probably shouldn't be empty.
+//! The [custom attributes] in this module describe these expectations,
+//! so that editors and remote clients can respect them,
+//! and [`validate`] checks a value against the constraints of all of its fields.
+//!
+//! | Attribute     | Applies to                                | Requirement                                      |
+//! |---------------|-------------------------------------------|--------------------------------------------------|
+//! | [`Range`]     | numbers and [`Duration`]s                 | the value is between the minimum and the maximum |
+//! | [`Step`]      | numbers and [`Duration`]s                 | the value is a multiple of the step              |
+//! | [`NonEmpty`]  | strings, lists, arrays, maps and sets     | the value isn't empty                            |
+//! | [`Pattern`]   | strings                                   | the value matches the regular expression         |
+//! | [`Validator`] | anything                                  | the validation function succeeds                 |
+//!
+//! Strings are [`String`]s, [`Cow<'static, str>`](Cow)s and `&'static str`s.
+//! [`Pattern`]s can only be checked when the `regex` feature is enabled,
+//! otherwise they are reported as [`ViolationKind::UnsupportedConstraint`].
+//!
+//! # Example
+//!
+//! ```
+//! # use bevy_reflect::{validation::{validate, NonEmpty, Range, Step}, Reflect};
+//! #[derive(Reflect)]
+//! struct Track {
+//!     #[reflect(@NonEmpty)]
+//!     name: String,
+//!     #[reflect(@Range::new(0.0, 1.0), @Step(0.25))]
+//!     volume: f32,
+//! }
+//!
+//! let track = Track {
+//!     name: "theme".to_string(),
+//!     volume: 0.75,
+//! };
+//! assert!(validate(&track).is_ok());
+//!
+//! let track = Track {
+//!     name: String::new(),
+//!     volume: 1.5,
+//! };
+//! let error = validate(&track).unwrap_err();
+//! assert_eq!(error.violations().len(), 2);
+//! assert_eq!(error.violations()[0].path.to_string(), ".name");
+//! ```
+//!
+//! [custom attributes]: crate::attributes::CustomAttributes
+//! [`Duration`]: core::time::Duration
+
+use crate::{
+    access::Access, attributes::CustomAttributes, NamedField, ParsedPath, PartialReflect, Reflect,
+    ReflectRef, UnnamedField, VariantInfo,
+};
+use alloc::{
+    borrow::Cow,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
+    fmt::{self, Debug, Display, Formatter},
+    time::Duration,
+};
+use thiserror::Error;
+
+/// A constraint that limits a number or [`Duration`] to a range.
+///
+/// Both bounds are inclusive.
+/// [`Duration`]s are compared by their number of seconds.
+#[derive(Reflect, Clone, Copy, Debug, PartialEq)]
+pub struct Range {
+    /// The smallest allowed value, if any.
+    pub min: Option<f64>,
+    /// The largest allowed value, if any.
+    pub max: Option<f64>,
+}
+
+impl Range {
+    /// Creates a range between `min` and `max`.
+    pub const fn new(min: f64, max: f64) -> Self {
+        Self {
+            min: Some(min),
+            max: Some(max),
+        }
+    }
+
+    /// Creates a range with no upper bound.
+    pub const fn at_least(min: f64) -> Self {
+        Self {
+            min: Some(min),
+            max: None,
+        }
+    }
+
+    /// Creates a range with no lower bound.
+    pub const fn at_most(max: f64) -> Self {
+        Self {
+            min: None,
+            max: Some(max),
+        }
+    }
+
+    /// Returns `true` if `value` is within this range.
+    pub fn contains(&self, value: f64) -> bool {
+        self.min.is_none_or(|min| value >= min) && self.max.is_none_or(|max| value <= max)
+    }
+}
+
+impl Display for Range {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match (self.min, self.max) {
+            (Some(min), Some(max)) => write!(f, "{min}..={max}"),
+            (Some(min), None) => write!(f, "{min}.."),
+            (None, Some(max)) => write!(f, "..={max}"),
+            (None, None) => write!(f, ".."),
+        }
+    }
+}
+
+/// A constraint that requires a number or [`Duration`] to be a multiple of a step.
+///
+/// If the field also has a [`Range`] with a minimum, the steps start from that minimum
+/// instead of zero.
+#[derive(Reflect, Clone, Copy, Debug, PartialEq)]
+pub struct Step(pub f64);
+
+/// A constraint that requires a string or a collection to have at least one element.
+#[derive(Reflect, Clone, Copy, Debug, PartialEq)]
+pub struct NonEmpty;
+
+/// A constraint that requires a string to match a regular expression.
+///
+/// The expression isn't anchored, so it must start with `^` and end with `$`
+/// to match the whole string.
+///
+/// The expression is compiled the first time it's checked, and reused afterwards.
+#[derive(Reflect, Clone)]
+pub struct Pattern {
+    pattern: Cow<'static, str>,
+    #[reflect(ignore)]
+    #[cfg_attr(
+        not(feature = "regex"),
+        expect(
+            dead_code,
+            reason = "Patterns are only compiled with the `regex` feature."
+        )
+    )]
+    compiled: CompiledPattern,
+}
+
+impl Pattern {
+    /// Creates a constraint from a regular expression.
+    pub fn new(pattern: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            pattern: pattern.into(),
+            compiled: CompiledPattern::default(),
+        }
+    }
+
+    /// Returns the regular expression of this constraint.
+    pub fn as_str(&self) -> &str {
+        &self.pattern
+    }
+}
+
+impl PartialEq for Pattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.pattern == other.pattern
+    }
+}
+
+impl Debug for Pattern {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Pattern").field(&self.pattern).finish()
+    }
+}
+
+/// The compiled regular expression of a [`Pattern`], or the reason it couldn't be compiled.
+#[derive(Clone, Default)]
+struct CompiledPattern {
+    #[cfg(feature = "regex")]
+    regex: std::sync::OnceLock<Result<regex::Regex, String>>,
+}
+
+/// A constraint that checks a value with a function.
+///
+/// The function returns an error message describing why the value is invalid.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{validation::{validate, Validator}, PartialReflect, Reflect};
+/// fn is_even(value: &dyn PartialReflect) -> Result<(), String> {
+///     match value.try_downcast_ref::<u32>() {
+///         Some(value) if value % 2 == 0 => Ok(()),
+///         _ => Err("expected an even number".to_string()),
+///     }
+/// }
+///
+/// #[derive(Reflect)]
+/// struct Pair {
+///     #[reflect(@Validator::new("even", is_even))]
+///     count: u32,
+/// }
+///
+/// assert!(validate(&Pair { count: 2 }).is_ok());
+/// assert!(validate(&Pair { count: 3 }).is_err());
+/// ```
+#[derive(Reflect, Clone, Copy)]
+#[reflect(opaque, Debug, Clone)]
+pub struct Validator {
+    name: &'static str,
+    validate: fn(&dyn PartialReflect) -> Result<(), String>,
+}
+
+impl Validator {
+    /// Creates a validator from a name describing it and a validation function.
+    pub const fn new(
+        name: &'static str,
+        validate: fn(&dyn PartialReflect) -> Result<(), String>,
+    ) -> Self {
+        Self { name, validate }
+    }
+
+    /// Returns the name of this validator.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Checks `value` with the validation function.
+    pub fn validate(&self, value: &dyn PartialReflect) -> Result<(), String> {
+        (self.validate)(value)
+    }
+}
+
+impl Debug for Validator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Validator").field(&self.name).finish()
+    }
+}
+
+/// The reason a value doesn't satisfy a constraint.
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum ViolationKind {
+    /// The value is outside of its [`Range`].
+    #[error("{value} is not in the range {range}")]
+    OutOfRange {
+        /// The value, as a number.
+        value: f64,
+        /// The range the value should be in.
+        range: Range,
+    },
+    /// The value isn't a multiple of its [`Step`].
+    #[error("{value} is not a multiple of {step}")]
+    NotAStep {
+        /// The value, as a number.
+        value: f64,
+        /// The step the value should be a multiple of.
+        step: f64,
+    },
+    /// The value is empty despite being [`NonEmpty`].
+    #[error("the value is empty")]
+    Empty,
+    /// The value doesn't match its [`Pattern`].
+    #[error("the value doesn't match the pattern `{0}`")]
+    PatternMismatch(Cow<'static, str>),
+    /// The [`Pattern`] isn't a valid regular expression.
+    #[error("the pattern `{pattern}` is invalid: {message}")]
+    InvalidPattern {
+        /// The invalid pattern.
+        pattern: Cow<'static, str>,
+        /// Why the pattern is invalid.
+        message: String,
+    },
+    /// A [`Validator`] rejected the value.
+    #[error("the `{validator}` validator rejected the value: {message}")]
+    Rejected {
+        /// The name of the validator.
+        validator: &'static str,
+        /// The message returned by the validator.
+        message: String,
+    },
+    /// The constraint can't be checked because a cargo feature it needs is disabled,
+    /// i.e. a [`Pattern`] without the `regex` feature.
+    #[error("the `{constraint}` constraint can't be checked without the `{feature}` feature")]
+    UnsupportedConstraint {
+        /// The name of the constraint.
+        constraint: &'static str,
+        /// The cargo feature of `bevy_reflect` that the constraint needs.
+        feature: &'static str,
+    },
+    /// The constraint can't be checked on values of this type,
+    /// i.e. a [`Range`] on a string.
+    #[error("the `{constraint}` constraint can't be applied to `{type_path}`")]
+    UnsupportedType {
+        /// The name of the constraint.
+        constraint: &'static str,
+        /// The type path of the value.
+        type_path: String,
+    },
+}
+
+/// A value that doesn't satisfy one of its constraints.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Violation {
+    /// The path to the value from the value that was validated.
+    ///
+    /// Since map and set elements can't be accessed with a path, the path to a value
+    /// within one of them skips the element and continues from the path of the collection.
+    pub path: ParsedPath,
+    /// The reason the value is invalid.
+    pub kind: ViolationKind,
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}`: {}", self.path, self.kind)
+    }
+}
+
+/// An error returned by [`validate`], containing every [`Violation`] that was found.
+#[derive(Error, Debug, Clone, PartialEq)]
+pub struct ValidationError {
+    violations: Vec<Violation>,
+}
+
+impl ValidationError {
+    /// Returns the violations, in the order their fields were visited.
+    pub fn violations(&self) -> &[Violation] {
+        &self.violations
+    }
+
+    /// Returns the violations, consuming the error.
+    pub fn into_violations(self) -> Vec<Violation> {
+        self.violations
+    }
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid value")?;
+        for (i, violation) in self.violations.iter().enumerate() {
+            f.write_str(if i == 0 { ": " } else { ", " })?;
+            write!(f, "{violation}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Checks a value and all of the values it contains against the constraints
+/// on their fields.
+///
+/// Constraints are read from the [type info] the values represent,
+/// so the fields of dynamic values without a represented type aren't checked.
+/// See the [module-level documentation](self) for the supported constraints.
+///
+/// [type info]: PartialReflect::get_represented_type_info
+pub fn validate(value: &dyn PartialReflect) -> Result<(), ValidationError> {
+    let mut violations = Vec::new();
+    visit(value, &mut Vec::new(), &mut violations);
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(ValidationError { violations })
+    }
+}
+
+/// Checks the value of a field against the constraints in the field's attributes.
+///
+/// This doesn't check the values contained in `value`, see [`validate`] for that.
+pub fn check_constraints(
+    value: &dyn PartialReflect,
+    attributes: &CustomAttributes,
+) -> Vec<ViolationKind> {
+    let mut violations = Vec::new();
+    let unsupported = |constraint| ViolationKind::UnsupportedType {
+        constraint,
+        type_path: value.reflect_type_path().to_string(),
+    };
+
+    let range = attributes.get::<Range>();
+    if let Some(range) = range {
+        match as_number(value) {
+            Some(number) if !range.contains(number) => {
+                violations.push(ViolationKind::OutOfRange {
+                    value: number,
+                    range: *range,
+                });
+            }
+            Some(_) => {}
+            None => violations.push(unsupported("range")),
+        }
+    }
+
+    if let Some(&Step(step)) = attributes.get::<Step>() {
+        match as_number(value) {
+            Some(number) => {
+                let start = range.and_then(|range| range.min).unwrap_or(0.0);
+                if !is_multiple(number - start, step) {
+                    violations.push(ViolationKind::NotAStep {
+                        value: number,
+                        step,
+                    });
+                }
+            }
+            None => violations.push(unsupported("step")),
+        }
+    }
+
+    if attributes.contains::<NonEmpty>() {
+        match len(value) {
+            Some(0) => violations.push(ViolationKind::Empty),
+            Some(_) => {}
+            None => violations.push(unsupported("non-empty")),
+        }
+    }
+
+    if let Some(pattern) = attributes.get::<Pattern>() {
+        match as_str(value) {
+            Some(string) => {
+                if let Err(violation) = match_pattern(pattern, string) {
+                    violations.push(violation);
+                }
+            }
+            None => violations.push(unsupported("pattern")),
+        }
+    }
+
+    if let Some(validator) = attributes.get::<Validator>() {
+        if let Err(message) = validator.validate(value) {
+            violations.push(ViolationKind::Rejected {
+                validator: validator.name,
+                message,
+            });
+        }
+    }
+
+    violations
+}
+
+fn visit(
+    value: &dyn PartialReflect,
+    path: &mut Vec<Access<'static>>,
+    violations: &mut Vec<Violation>,
+) {
+    let mut visit_field = |field: &dyn PartialReflect,
+                           access: Access<'static>,
+                           attributes: Option<&CustomAttributes>,
+                           violations: &mut Vec<Violation>| {
+        path.push(access);
+        if let Some(attributes) = attributes {
+            violations.extend(
+                check_constraints(field, attributes)
+                    .into_iter()
+                    .map(|kind| Violation {
+                        path: ParsedPath::from(path.clone()),
+                        kind,
+                    }),
+            );
+        }
+        visit(field, path, violations);
+        path.pop();
+    };
+
+    match value.reflect_ref() {
+        ReflectRef::Struct(value) => {
+            let info = value.get_represented_struct_info();
+            for (index, field) in value.iter_fields().enumerate() {
+                let name = value.name_at(index).unwrap_or_default();
+                let attributes = info
+                    .and_then(|info| info.field_at(index))
+                    .map(NamedField::custom_attributes);
+                visit_field(
+                    field,
+                    Access::Field(name.to_string().into()),
+                    attributes,
+                    violations,
+                );
+            }
+        }
+        ReflectRef::TupleStruct(value) => {
+            let info = value.get_represented_tuple_struct_info();
+            for (index, field) in value.iter_fields().enumerate() {
+                let attributes = info
+                    .and_then(|info| info.field_at(index))
+                    .map(UnnamedField::custom_attributes);
+                visit_field(field, Access::TupleIndex(index), attributes, violations);
+            }
+        }
+        ReflectRef::Tuple(value) => {
+            for (index, field) in value.iter_fields().enumerate() {
+                visit_field(field, Access::TupleIndex(index), None, violations);
+            }
+        }
+        ReflectRef::Enum(value) => {
+            let variant = value
+                .get_represented_enum_info()
+                .and_then(|info| info.variant(value.variant_name()));
+            for (index, field) in value.iter_fields().enumerate() {
+                let (access, attributes) = match variant {
+                    Some(VariantInfo::Struct(variant)) => (
+                        Access::Field(field.name().unwrap_or_default().to_string().into()),
+                        variant.field_at(index).map(NamedField::custom_attributes),
+                    ),
+                    Some(VariantInfo::Tuple(variant)) => (
+                        Access::TupleIndex(index),
+                        variant.field_at(index).map(UnnamedField::custom_attributes),
+                    ),
+                    _ => match field.name() {
+                        Some(name) => (Access::Field(name.to_string().into()), None),
+                        None => (Access::TupleIndex(index), None),
+                    },
+                };
+                visit_field(field.value(), access, attributes, violations);
+            }
+        }
+        ReflectRef::List(value) => {
+            for (index, element) in value.iter().enumerate() {
+                visit_field(element, Access::ListIndex(index), None, violations);
+            }
+        }
+        ReflectRef::Array(value) => {
+            for (index, element) in value.iter().enumerate() {
+                visit_field(element, Access::ListIndex(index), None, violations);
+            }
+        }
+        ReflectRef::Map(value) => {
+            for (_, element) in value.iter() {
+                visit(element, path, violations);
+            }
+        }
+        ReflectRef::Set(value) => {
+            for element in value.iter() {
+                visit(element, path, violations);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Returns a number or [`Duration`] as an `f64`.
+fn as_number(value: &dyn PartialReflect) -> Option<f64> {
+    macro_rules! downcast {
+        ($($ty:ty),*) => {
+            $(
+                if let Some(value) = value.try_downcast_ref::<$ty>() {
+                    return Some(*value as f64);
+                }
+            )*
+        };
+    }
+
+    downcast!(f32, f64, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    value
+        .try_downcast_ref::<Duration>()
+        .map(Duration::as_secs_f64)
+}
+
+/// Returns `true` if `value` is a multiple of `step`, allowing for floating point errors.
+fn is_multiple(value: f64, step: f64) -> bool {
+    if step == 0.0 {
+        return true;
+    }
+    let steps = value / step;
+    let fraction = steps - (steps as i64) as f64;
+    let fraction = if fraction < 0.0 { -fraction } else { fraction };
+    fraction.min(1.0 - fraction) <= 1e-6
+}
+
+fn as_str(value: &dyn PartialReflect) -> Option<&str> {
+    if let Some(value) = value.try_downcast_ref::<String>() {
+        Some(value)
+    } else if let Some(value) = value.try_downcast_ref::<Cow<'static, str>>() {
+        Some(value)
+    } else {
+        value.try_downcast_ref::<&'static str>().copied()
+    }
+}
+
+fn len(value: &dyn PartialReflect) -> Option<usize> {
+    if let Some(string) = as_str(value) {
+        return Some(string.len());
+    }
+    match value.reflect_ref() {
+        ReflectRef::List(list) => Some(list.len()),
+        ReflectRef::Array(array) => Some(array.len()),
+        ReflectRef::Map(map) => Some(map.len()),
+        ReflectRef::Set(set) => Some(set.len()),
+        _ => None,
+    }
+}
+
+#[cfg(feature = "regex")]
+fn match_pattern(pattern: &Pattern, string: &str) -> Result<(), ViolationKind> {
+    let regex = pattern
+        .compiled
+        .regex
+        .get_or_init(|| regex::Regex::new(&pattern.pattern).map_err(|error| error.to_string()));
+    match regex {
+        Ok(regex) if regex.is_match(string) => Ok(()),
+        Ok(_) => Err(ViolationKind::PatternMismatch(pattern.pattern.clone())),
+        Err(message) => Err(ViolationKind::InvalidPattern {
+            pattern: pattern.pattern.clone(),
+            message: message.clone(),
+        }),
+    }
+}
+
+#[cfg(not(feature = "regex"))]
+fn match_pattern(_pattern: &Pattern, _string: &str) -> Result<(), ViolationKind> {
+    Err(ViolationKind::UnsupportedConstraint {
+        constraint: "pattern",
+        feature: "regex",
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{string::ToString, vec};
+    use bevy_platform::collections::HashMap;
+
+    fn is_positive(value: &dyn PartialReflect) -> Result<(), String> {
+        match value.try_downcast_ref::<i32>() {
+            Some(value) if *value > 0 => Ok(()),
+            _ => Err("expected a positive number".to_string()),
+        }
+    }
+
+    #[derive(Reflect, Clone)]
+    struct Settings {
+        #[reflect(@NonEmpty)]
+        name: String,
+        #[reflect(@Range::new(0.0, 1.0))]
+        volume: f32,
+        #[reflect(@Range::at_least(1.0), @Step(0.5))]
+        scale: f64,
+        #[reflect(@Range::at_least(0.0))]
+        delay: Duration,
+        #[reflect(@NonEmpty)]
+        channels: Vec<Channel>,
+        presets: HashMap<String, Channel>,
+        mode: Mode,
+    }
+
+    #[derive(Reflect, Clone)]
+    struct Channel(#[reflect(@Range::new(0.0, 127.0))] u8);
+
+    #[derive(Reflect, Clone)]
+    enum Mode {
+        Fixed(#[reflect(@Validator::new("positive", is_positive))] i32),
+        Scaled {
+            #[reflect(@Range::at_most(10.0))]
+            factor: f32,
+        },
+    }
+
+    fn settings() -> Settings {
+        Settings {
+            name: "main".to_string(),
+            volume: 0.5,
+            scale: 2.5,
+            delay: Duration::from_millis(100),
+            channels: vec![Channel(1), Channel(2)],
+            presets: HashMap::from_iter([("a".to_string(), Channel(3))]),
+            mode: Mode::Fixed(1),
+        }
+    }
+
+    fn violations(value: &dyn PartialReflect) -> Vec<(String, ViolationKind)> {
+        match validate(value) {
+            Ok(()) => Vec::new(),
+            Err(error) => error
+                .into_violations()
+                .into_iter()
+                .map(|violation| (violation.path.to_string(), violation.kind))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn valid_values_pass() {
+        assert!(validate(&settings()).is_ok());
+
+        let mut settings = settings();
+        settings.mode = Mode::Scaled { factor: 10.0 };
+        settings.volume = 1.0;
+        assert!(validate(&settings).is_ok());
+    }
+
+    #[test]
+    fn violations_are_reported_with_paths() {
+        let mut settings = settings();
+        settings.name = String::new();
+        settings.volume = -0.5;
+        settings.scale = 1.75;
+        settings.channels = vec![Channel(1), Channel(200)];
+        settings.presets.insert("b".to_string(), Channel(128));
+        settings.mode = Mode::Fixed(-1);
+
+        assert_eq!(
+            violations(&settings),
+            [
+                (".name".to_string(), ViolationKind::Empty),
+                (
+                    ".volume".to_string(),
+                    ViolationKind::OutOfRange {
+                        value: -0.5,
+                        range: Range::new(0.0, 1.0),
+                    }
+                ),
+                (
+                    ".scale".to_string(),
+                    ViolationKind::NotAStep {
+                        value: 1.75,
+                        step: 0.5,
+                    }
+                ),
+                (
+                    ".channels[1].0".to_string(),
+                    ViolationKind::OutOfRange {
+                        value: 200.0,
+                        range: Range::new(0.0, 127.0),
+                    }
+                ),
+                (
+                    ".presets.0".to_string(),
+                    ViolationKind::OutOfRange {
+                        value: 128.0,
+                        range: Range::new(0.0, 127.0),
+                    }
+                ),
+                (
+                    ".mode.0".to_string(),
+                    ViolationKind::Rejected {
+                        validator: "positive",
+                        message: "expected a positive number".to_string(),
+                    }
+                ),
+            ]
+        );
+
+        settings.mode = Mode::Scaled { factor: 11.0 };
+        settings.channels.clear();
+        let violations = violations(&settings);
+        assert!(violations.contains(&(".channels".to_string(), ViolationKind::Empty)));
+        assert!(violations.iter().any(|(path, _)| path == ".mode.factor"));
+    }
+
+    #[test]
+    fn dynamic_values_are_checked_through_their_represented_type() {
+        let mut settings = settings();
+        settings.volume = 2.0;
+        let dynamic = settings.to_dynamic();
+        assert_eq!(violations(dynamic.as_ref()).len(), 1);
+    }
+
+    #[test]
+    fn unsupported_types_are_reported() {
+        #[derive(Reflect)]
+        struct Invalid {
+            #[reflect(@Range::new(0.0, 1.0))]
+            name: String,
+            #[reflect(@NonEmpty)]
+            count: u32,
+        }
+
+        let invalid = Invalid {
+            name: "name".to_string(),
+            count: 1,
+        };
+        assert!(matches!(
+            violations(&invalid).as_slice(),
+            [
+                (
+                    _,
+                    ViolationKind::UnsupportedType {
+                        constraint: "range",
+                        ..
+                    }
+                ),
+                (
+                    _,
+                    ViolationKind::UnsupportedType {
+                        constraint: "non-empty",
+                        ..
+                    }
+                ),
+            ]
+        ));
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn patterns_are_checked() {
+        #[derive(Reflect)]
+        struct Identifier {
+            #[reflect(@Pattern::new("^[a-z_]+$"))]
+            name: String,
+        }
+
+        assert!(validate(&Identifier {
+            name: "snake_case".to_string(),
+        })
+        .is_ok());
+        assert_eq!(
+            violations(&Identifier {
+                name: "Not Snake".to_string(),
+            }),
+            [(
+                ".name".to_string(),
+                ViolationKind::PatternMismatch("^[a-z_]+$".into())
+            )]
+        );
+
+        #[derive(Reflect)]
+        struct Invalid {
+            #[reflect(@Pattern::new("("))]
+            name: String,
+        }
+
+        let invalid = Invalid {
+            name: "(".to_string(),
+        };
+        // The compiled expression, or the error, is cached on the attribute.
+        for _ in 0..2 {
+            assert!(matches!(
+                &violations(&invalid)[..],
+                [(_, ViolationKind::InvalidPattern { .. })]
+            ));
+        }
+    }
+
+    #[cfg(not(feature = "regex"))]
+    #[test]
+    fn patterns_need_the_regex_feature() {
+        #[derive(Reflect)]
+        struct Identifier {
+            #[reflect(@Pattern::new("^[a-z_]+$"))]
+            name: String,
+        }
+
+        assert_eq!(
+            violations(&Identifier {
+                name: "snake_case".to_string(),
+            }),
+            [(
+                ".name".to_string(),
+                ViolationKind::UnsupportedConstraint {
+                    constraint: "pattern",
+                    feature: "regex",
+                }
+            )]
+        );
+    }
+}
@@ -0,0 +1,271 @@
+//! Access control for the Bevy Remote Protocol.
+//!
+//! The [`RemoteAccess`] resource decides which methods clients may call and which component,
+//! resource and event types they may read or write. It applies to all transports and can be
+//! changed while the app is running.
+//!
+//! Clients of the HTTP and WebSocket transports can additionally be required to authenticate
+//! with a token, see [`RemoteHttpPlugin::with_auth_token`] and
+//! [`RemoteWebSocketPlugin::with_auth_token`].
+//!
+//! [`RemoteHttpPlugin::with_auth_token`]: crate::http::RemoteHttpPlugin::with_auth_token
+//! [`RemoteWebSocketPlugin::with_auth_token`]: crate::websocket::RemoteWebSocketPlugin::with_auth_token
+
+use crate::builtin_methods;
+use bevy_ecs::resource::Resource;
+use bevy_platform::collections::HashSet;
+
+/// The built-in methods that don't modify the world, which are the methods allowed by
+/// [`RemoteAccess::read_only`].
+pub const READ_ONLY_METHODS: &[&str] = &[
+    builtin_methods::BRP_GET_METHOD,
+    builtin_methods::BRP_QUERY_METHOD,
+    builtin_methods::BRP_CHANGE_TICK_METHOD,
+    builtin_methods::BRP_LIST_METHOD,
+    builtin_methods::BRP_GET_AND_WATCH_METHOD,
+    builtin_methods::BRP_LIST_AND_WATCH_METHOD,
+    builtin_methods::BRP_GET_RESOURCE_METHOD,
+    builtin_methods::BRP_LIST_RESOURCES_METHOD,
+    builtin_methods::BRP_REGISTRY_SCHEMA_METHOD,
+    builtin_methods::BRP_EVENTS_AND_WATCH_METHOD,
+    builtin_methods::BRP_LIST_SCHEDULES_METHOD,
+    builtin_methods::BRP_LIST_SYSTEMS_METHOD,
+    #[cfg(feature = "bevy_debug_stepping")]
+    builtin_methods::BRP_STEPPING_STATUS_METHOD,
+    builtin_methods::RPC_DISCOVER_METHOD,
+];
+
+/// A resource controlling which methods and types remote clients may use.
+///
+/// It's inserted by the [`RemotePlugin`](crate::RemotePlugin), see
+/// [`RemotePlugin::with_access`](crate::RemotePlugin::with_access). By default, everything is
+/// allowed.
+///
+/// ```
+/// # use bevy_remote::access::{AccessFilter, RemoteAccess};
+/// // Clients may inspect the world, but not the `Inventory` component.
+/// let access = RemoteAccess::read_only()
+///     .with_types(AccessFilter::default().deny("my_game::Inventory"));
+///
+/// assert!(access.is_method_allowed("bevy/query"));
+/// assert!(!access.is_method_allowed("bevy/destroy"));
+/// assert!(!access.is_type_allowed("my_game::Inventory"));
+/// ```
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct RemoteAccess {
+    /// The names of the methods that clients may call.
+    ///
+    /// Calling any other method fails with an
+    /// [`ACCESS_DENIED`](crate::error_codes::ACCESS_DENIED) error.
+    pub methods: AccessFilter,
+    /// The [full paths] of the component, resource and event types that clients may access
+    /// with the built-in methods.
+    ///
+    /// Requests naming any other type fail with an
+    /// [`ACCESS_DENIED`](crate::error_codes::ACCESS_DENIED) error, and the types are left out
+    /// of listings.
+    ///
+    /// [full paths]: bevy_reflect::TypePath::type_path
+    pub types: AccessFilter,
+}
+
+impl RemoteAccess {
+    /// Returns an access that only allows the [`READ_ONLY_METHODS`] and all types.
+    pub fn read_only() -> Self {
+        Self {
+            methods: AccessFilter::Allowlist(
+                READ_ONLY_METHODS
+                    .iter()
+                    .map(|method| (*method).to_owned())
+                    .collect(),
+            ),
+            types: AccessFilter::Unset,
+        }
+    }
+
+    /// Sets the filter of the methods that clients may call.
+    #[must_use]
+    pub fn with_methods(mut self, methods: AccessFilter) -> Self {
+        self.methods = methods;
+        self
+    }
+
+    /// Sets the filter of the types that clients may access.
+    #[must_use]
+    pub fn with_types(mut self, types: AccessFilter) -> Self {
+        self.types = types;
+        self
+    }
+
+    /// Returns `true` if clients may call the method with the given name.
+    pub fn is_method_allowed(&self, method: &str) -> bool {
+        self.methods.is_allowed(method)
+    }
+
+    /// Returns `true` if clients may access the type with the given full path.
+    pub fn is_type_allowed(&self, type_path: &str) -> bool {
+        self.types.is_allowed(type_path)
+    }
+}
+
+/// A filter that allows or denies names, such as the names of methods or the paths of types.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum AccessFilter {
+    /// Every name is allowed.
+    ///
+    /// Calling [`allow`](Self::allow) turns this into an [`Allowlist`](Self::Allowlist) and
+    /// calling [`deny`](Self::deny) into a [`Denylist`](Self::Denylist).
+    #[default]
+    Unset,
+    /// Only the contained names are allowed.
+    Allowlist(HashSet<String>),
+    /// All names except the contained ones are allowed.
+    Denylist(HashSet<String>),
+}
+
+impl AccessFilter {
+    /// Allows the given name.
+    ///
+    /// Adds the name to an [`Allowlist`](Self::Allowlist), or removes it from a
+    /// [`Denylist`](Self::Denylist).
+    #[must_use]
+    pub fn allow(mut self, name: impl Into<String>) -> Self {
+        let name = name.into();
+        match &mut self {
+            Self::Unset => self = Self::Allowlist(HashSet::from_iter([name])),
+            Self::Allowlist(list) => {
+                list.insert(name);
+            }
+            Self::Denylist(list) => {
+                list.remove(&name);
+            }
+        }
+        self
+    }
+
+    /// Denies the given name.
+    ///
+    /// Adds the name to a [`Denylist`](Self::Denylist), or removes it from an
+    /// [`Allowlist`](Self::Allowlist).
+    #[must_use]
+    pub fn deny(mut self, name: impl Into<String>) -> Self {
+        let name = name.into();
+        match &mut self {
+            Self::Unset => self = Self::Denylist(HashSet::from_iter([name])),
+            Self::Allowlist(list) => {
+                list.remove(&name);
+            }
+            Self::Denylist(list) => {
+                list.insert(name);
+            }
+        }
+        self
+    }
+
+    /// Returns `true` if the given name is allowed.
+    pub fn is_allowed(&self, name: &str) -> bool {
+        match self {
+            Self::Unset => true,
+            Self::Allowlist(list) => list.contains(name),
+            Self::Denylist(list) => !list.contains(name),
+        }
+    }
+}
+
+/// Returns `true` if the value of an `Authorization` header carries the expected bearer token,
+/// or if no token is expected.
+#[cfg(all(
+    not(target_family = "wasm"),
+    any(feature = "http", feature = "websocket")
+))]
+pub(crate) fn is_authorized(expected_token: Option<&str>, authorization: Option<&[u8]>) -> bool {
+    let Some(expected_token) = expected_token else {
+        return true;
+    };
+    let Some(token) = authorization.and_then(|authorization| {
+        let (scheme, token) = authorization.split_at_checked(7)?;
+        scheme.eq_ignore_ascii_case(b"bearer ").then_some(token)
+    }) else {
+        return false;
+    };
+    tokens_match(expected_token.as_bytes(), token)
+}
+
+/// Compares two tokens in a time that doesn't depend on the position of the first difference.
+#[cfg(all(
+    not(target_family = "wasm"),
+    any(feature = "http", feature = "websocket")
+))]
+pub(crate) fn tokens_match(expected: &[u8], actual: &[u8]) -> bool {
+    expected.len() == actual.len()
+        && expected
+            .iter()
+            .zip(actual)
+            .fold(0, |difference, (a, b)| difference | (a ^ b))
+            == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AccessFilter, RemoteAccess};
+
+    #[test]
+    fn filters_allow_and_deny_names() {
+        let filter = AccessFilter::default().deny("a").deny("b").allow("b");
+        assert!(!filter.is_allowed("a"));
+        assert!(filter.is_allowed("b"));
+        assert!(filter.is_allowed("c"));
+
+        let filter = AccessFilter::default().allow("a").allow("b").deny("b");
+        assert!(filter.is_allowed("a"));
+        assert!(!filter.is_allowed("b"));
+        assert!(!filter.is_allowed("c"));
+
+        let access = RemoteAccess::read_only();
+        assert!(access.is_method_allowed("bevy/get"));
+        assert!(!access.is_method_allowed("bevy/insert"));
+        assert!(access.is_type_allowed("my_game::Inventory"));
+    }
+
+    #[test]
+    fn denied_methods_are_not_run() {
+        use crate::{error_codes, BrpMessage, BrpSender, RemotePlugin};
+        use bevy_app::App;
+
+        let mut app = App::new();
+        app.add_plugins(RemotePlugin::default().with_access(RemoteAccess::read_only()));
+        app.update();
+
+        let (sender, receiver) = async_channel::bounded(1);
+        let message = BrpMessage {
+            method: "bevy/destroy".to_owned(),
+            params: Some(serde_json::json!({ "entity": 0 })),
+            sender,
+        };
+        app.world()
+            .resource::<BrpSender>()
+            .try_send(message)
+            .unwrap();
+        app.update();
+
+        let error = receiver.try_recv().unwrap().unwrap_err();
+        assert_eq!(error.code, error_codes::ACCESS_DENIED);
+    }
+
+    #[cfg(all(
+        not(target_family = "wasm"),
+        any(feature = "http", feature = "websocket")
+    ))]
+    #[test]
+    fn bearer_tokens_are_checked() {
+        use super::is_authorized;
+
+        assert!(is_authorized(None, None));
+        assert!(is_authorized(Some("secret"), Some(b"Bearer secret")));
+        assert!(is_authorized(Some("secret"), Some(b"bearer secret")));
+        assert!(!is_authorized(Some("secret"), None));
+        assert!(!is_authorized(Some("secret"), Some(b"Bearer secreT")));
+        assert!(!is_authorized(Some("secret"), Some(b"Basic secret")));
+        assert!(!is_authorized(Some("secret"), Some(b"Bearer")));
+    }
+}
@@ -1,42 +1,56 @@
 //! Built-in verbs for the Bevy Remote Protocol.
 
-use core::any::TypeId;
+use core::{any::TypeId, cmp::Ordering};
 
 use anyhow::{anyhow, Result as AnyhowResult};
 use bevy_ecs::{
-    component::ComponentId,
+    component::{ComponentId, Tick},
     entity::Entity,
     event::EventCursor,
     hierarchy::ChildOf,
     query::QueryBuilder,
-    reflect::{AppTypeRegistry, ReflectComponent, ReflectResource},
+    reflect::{AppTypeRegistry, ReflectComponent, ReflectEvent, ReflectResource},
     removal_detection::RemovedComponentEntity,
+    schedule::{NodeId, Schedule, Schedules},
     system::{In, Local},
-    world::{EntityRef, EntityWorldMut, FilteredEntityRef, World},
+    world::{EntityRef, EntityWorldMut, FilteredEntityRef, Mut, World},
 };
 use bevy_platform::collections::HashMap;
 use bevy_reflect::{
-    serde::{ReflectSerializer, TypedReflectDeserializer},
-    GetPath, PartialReflect, TypeRegistration, TypeRegistry,
+    serde::{ReflectSerializer, TypedReflectDeserializer, TypedReflectSerializer},
+    GetPath, ParsedPath, PartialReflect, ReflectPath, TypeRegistration, TypeRegistry,
 };
 use serde::{de::DeserializeSeed as _, Deserialize, Serialize};
-use serde_json::{Map, Value};
+use serde_json::{Map, Number, Value};
 
 use crate::{
+    access::RemoteAccess,
     error_codes,
     schemas::{json_schema::JsonSchemaBevyType, open_rpc::OpenRpcDocument},
-    BrpError, BrpResult,
+    BrpError, BrpResult, CurrentWatchingRequest, WatchingRequestId,
 };
 
 #[cfg(all(feature = "http", not(target_family = "wasm")))]
 use {crate::schemas::open_rpc::ServerObject, bevy_utils::default};
 
+#[cfg(feature = "bevy_debug_stepping")]
+use bevy_ecs::schedule::Stepping;
+
+#[cfg(feature = "reflect_functions")]
+use bevy_reflect::{
+    func::{args::Ownership, ArgList, ArgValue, DynamicFunction, Return, SignatureInfo},
+    ReflectFromReflect,
+};
+
 /// The method path for a `bevy/get` request.
 pub const BRP_GET_METHOD: &str = "bevy/get";
 
 /// The method path for a `bevy/query` request.
 pub const BRP_QUERY_METHOD: &str = "bevy/query";
 
+/// The method path for a `bevy/change_tick` request.
+pub const BRP_CHANGE_TICK_METHOD: &str = "bevy/change_tick";
+
 /// The method path for a `bevy/spawn` request.
 pub const BRP_SPAWN_METHOD: &str = "bevy/spawn";
 
@@ -58,6 +72,10 @@ pub const BRP_LIST_METHOD: &str = "bevy/list";
 /// The method path for a `bevy/mutate_component` request.
 pub const BRP_MUTATE_COMPONENT_METHOD: &str = "bevy/mutate_component";
 
+/// The method path for a `bevy/call_method` request.
+#[cfg(feature = "reflect_functions")]
+pub const BRP_CALL_METHOD_METHOD: &str = "bevy/call_method";
+
 /// The method path for a `bevy/get+watch` request.
 pub const BRP_GET_AND_WATCH_METHOD: &str = "bevy/get+watch";
 
@@ -82,6 +100,52 @@ pub const BRP_LIST_RESOURCES_METHOD: &str = "bevy/list_resources";
 /// The method path for a `bevy/registry/schema` request.
 pub const BRP_REGISTRY_SCHEMA_METHOD: &str = "bevy/registry/schema";
 
+/// The method path for a `bevy/send_event` request.
+pub const BRP_SEND_EVENT_METHOD: &str = "bevy/send_event";
+
+/// The method path for a `bevy/trigger` request.
+pub const BRP_TRIGGER_METHOD: &str = "bevy/trigger";
+
+/// The method path for a `bevy/events+watch` request.
+pub const BRP_EVENTS_AND_WATCH_METHOD: &str = "bevy/events+watch";
+
+/// The method path for a `bevy/list_schedules` request.
+pub const BRP_LIST_SCHEDULES_METHOD: &str = "bevy/list_schedules";
+
+/// The method path for a `bevy/list_systems` request.
+pub const BRP_LIST_SYSTEMS_METHOD: &str = "bevy/list_systems";
+
+/// The method path for a `bevy/set_system_enabled` request.
+pub const BRP_SET_SYSTEM_ENABLED_METHOD: &str = "bevy/set_system_enabled";
+
+/// The method path for a `bevy/stepping/status` request.
+#[cfg(feature = "bevy_debug_stepping")]
+pub const BRP_STEPPING_STATUS_METHOD: &str = "bevy/stepping/status";
+
+/// The method path for a `bevy/stepping/enable` request.
+#[cfg(feature = "bevy_debug_stepping")]
+pub const BRP_STEPPING_ENABLE_METHOD: &str = "bevy/stepping/enable";
+
+/// The method path for a `bevy/stepping/disable` request.
+#[cfg(feature = "bevy_debug_stepping")]
+pub const BRP_STEPPING_DISABLE_METHOD: &str = "bevy/stepping/disable";
+
+/// The method path for a `bevy/stepping/step` request.
+#[cfg(feature = "bevy_debug_stepping")]
+pub const BRP_STEPPING_STEP_METHOD: &str = "bevy/stepping/step";
+
+/// The method path for a `bevy/stepping/continue` request.
+#[cfg(feature = "bevy_debug_stepping")]
+pub const BRP_STEPPING_CONTINUE_METHOD: &str = "bevy/stepping/continue";
+
+/// The method path for a `bevy/stepping/set_breakpoint` request.
+#[cfg(feature = "bevy_debug_stepping")]
+pub const BRP_STEPPING_SET_BREAKPOINT_METHOD: &str = "bevy/stepping/set_breakpoint";
+
+/// The method path for a `bevy/stepping/clear_breakpoint` request.
+#[cfg(feature = "bevy_debug_stepping")]
+pub const BRP_STEPPING_CLEAR_BREAKPOINT_METHOD: &str = "bevy/stepping/clear_breakpoint";
+
 /// The method path for a `rpc.discover` request.
 pub const RPC_DISCOVER_METHOD: &str = "rpc.discover";
 
@@ -137,6 +201,20 @@ pub struct BrpQueryParams {
     /// than skipping it. Defaults to false.
     #[serde(default)]
     pub strict: bool,
+
+    /// The keys to sort the matching entities by, in order of priority.
+    ///
+    /// Without sort keys, the entities are returned in an unspecified order.
+    #[serde(default)]
+    pub sort: Vec<BrpQuerySort>,
+
+    /// The number of matching entities to skip, after sorting.
+    #[serde(default)]
+    pub offset: usize,
+
+    /// The maximum number of entities to return, after sorting.
+    #[serde(default)]
+    pub limit: Option<usize>,
 }
 
 /// `bevy/spawn`: Creates a new entity with the given components and responds
@@ -276,6 +354,30 @@ pub struct BrpMutateComponentParams {
     pub value: Value,
 }
 
+/// `bevy/call_method`: Calls a [reflected method] of a component.
+///
+/// The server responds with the value returned by the method.
+///
+/// [reflected method]: bevy_reflect::func::ReflectMethods
+#[cfg(feature = "reflect_functions")]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpCallMethodParams {
+    /// The entity of the component to call the method on.
+    pub entity: Entity,
+
+    /// The [full path] of the component to call the method on.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub component: String,
+
+    /// The name of the method.
+    pub method: String,
+
+    /// The arguments passed to the method after the component.
+    #[serde(default)]
+    pub args: Vec<Value>,
+}
+
 /// `bevy/mutate_resource`:
 ///
 /// The server responds with a null.
@@ -295,6 +397,102 @@ pub struct BrpMutateResourceParams {
     pub value: Value,
 }
 
+/// `bevy/send_event`: Sends a buffered event to the world.
+///
+/// The server responds with a null.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpSendEventParams {
+    /// The [full path] of the event type.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub event: String,
+
+    /// The serialized value of the event.
+    pub value: Value,
+}
+
+/// `bevy/trigger`: Triggers an event, running the observers watching for it.
+///
+/// The server responds with a null.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpTriggerParams {
+    /// The [full path] of the event type.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub event: String,
+
+    /// The serialized value of the event.
+    pub value: Value,
+
+    /// The entities to trigger the event for. If empty, the event is
+    /// triggered without targets.
+    #[serde(default)]
+    pub targets: Vec<Entity>,
+}
+
+/// `bevy/events+watch`: Streams the buffered events of a type.
+///
+/// The server responds with a [`BrpEventsWatchingResponse`] whenever events
+/// were sent.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpEventsWatchingParams {
+    /// The [full path] of the event type.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub event: String,
+}
+
+/// `bevy/list_systems`: Lists the systems of a schedule.
+///
+/// The server responds with a [`BrpListSystemsResponse`].
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpListSystemsParams {
+    /// The label of the schedule, as returned by `bevy/list_schedules`.
+    pub schedule: String,
+}
+
+/// `bevy/set_system_enabled`: Enables or disables a system of a schedule.
+///
+/// The server responds with a null.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpSetSystemEnabledParams {
+    /// The label of the schedule containing the system.
+    pub schedule: String,
+
+    /// The ID of the system, as returned by `bevy/list_systems`.
+    pub system: usize,
+
+    /// Whether the system should run when the schedule runs.
+    pub enabled: bool,
+}
+
+/// `bevy/stepping/enable`: Enables system stepping at the start of the next
+/// frame.
+///
+/// The server responds with a null.
+#[cfg(feature = "bevy_debug_stepping")]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpSteppingEnableParams {
+    /// The labels of the schedules to step through, in addition to the ones
+    /// that were added before.
+    #[serde(default)]
+    pub schedules: Vec<String>,
+}
+
+/// `bevy/stepping/set_breakpoint` and `bevy/stepping/clear_breakpoint`: Sets
+/// or clears a breakpoint on a system.
+///
+/// The server responds with a null.
+#[cfg(feature = "bevy_debug_stepping")]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpSteppingBreakpointParams {
+    /// The label of the schedule containing the system.
+    pub schedule: String,
+
+    /// The ID of the system, as returned by `bevy/list_systems`.
+    pub system: usize,
+}
+
 /// Describes the data that is to be fetched in a query.
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
 pub struct BrpQuery {
@@ -337,6 +535,103 @@ pub struct BrpQueryFilter {
     /// [full path]: bevy_reflect::TypePath::type_path
     #[serde(default)]
     pub with: Vec<String>,
+
+    /// Conditions on the values of component fields that an entity must satisfy to be included
+    /// in the results.
+    ///
+    /// Entities without the component or the field never satisfy the condition.
+    #[serde(default)]
+    pub values: Vec<BrpValueFilter>,
+
+    /// If set, only entities where at least one of the fetched `components` or `option`
+    /// components was added or changed after this change tick are included in the results.
+    ///
+    /// The current change tick can be retrieved with `bevy/change_tick`.
+    #[serde(default)]
+    pub changed_since: Option<u32>,
+}
+
+/// A condition on the value of a component field.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpValueFilter {
+    /// The [full path] of the type name of the component.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub component: String,
+
+    /// The [reflection path] of the field within the component. Defaults to the component
+    /// itself.
+    ///
+    /// [reflection path]: bevy_reflect::GetPath
+    #[serde(default)]
+    pub path: String,
+
+    /// How the value of the field is compared to `value`.
+    pub op: BrpComparison,
+
+    /// The serialized value that the field is compared to.
+    pub value: Value,
+}
+
+/// The comparison of a [`BrpValueFilter`].
+///
+/// Numbers, strings and booleans can be ordered; ordering comparisons of other values, or of
+/// values of different kinds, are never satisfied.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum BrpComparison {
+    /// The field is equal to the value.
+    #[serde(rename = "==")]
+    Equal,
+    /// The field is not equal to the value.
+    #[serde(rename = "!=")]
+    NotEqual,
+    /// The field is less than the value.
+    #[serde(rename = "<")]
+    Less,
+    /// The field is less than or equal to the value.
+    #[serde(rename = "<=")]
+    LessOrEqual,
+    /// The field is greater than the value.
+    #[serde(rename = ">")]
+    Greater,
+    /// The field is greater than or equal to the value.
+    #[serde(rename = ">=")]
+    GreaterOrEqual,
+}
+
+impl BrpComparison {
+    /// Returns `true` if `value` compares to `expected` as described by this comparison.
+    fn matches(self, value: &Value, expected: &Value) -> bool {
+        let ordering = compare_values(value, expected);
+        match self {
+            Self::Equal => ordering.map_or(value == expected, Ordering::is_eq),
+            Self::NotEqual => ordering.map_or(value != expected, Ordering::is_ne),
+            Self::Less => ordering.is_some_and(Ordering::is_lt),
+            Self::LessOrEqual => ordering.is_some_and(Ordering::is_le),
+            Self::Greater => ordering.is_some_and(Ordering::is_gt),
+            Self::GreaterOrEqual => ordering.is_some_and(Ordering::is_ge),
+        }
+    }
+}
+
+/// A key to sort the results of a query by.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpQuerySort {
+    /// The [full path] of the type name of the component.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub component: String,
+
+    /// The [reflection path] of the field within the component. Defaults to the component
+    /// itself.
+    ///
+    /// [reflection path]: bevy_reflect::GetPath
+    #[serde(default)]
+    pub path: String,
+
+    /// Whether to sort in descending instead of ascending order.
+    #[serde(default)]
+    pub descending: bool,
 }
 
 /// Constraints that can be placed on a query to include or exclude
@@ -434,6 +729,56 @@ pub type BrpListResponse = Vec<String>;
 /// The response to a `bevy/list_resources` request.
 pub type BrpListResourcesResponse = Vec<String>;
 
+/// A single response from a `bevy/events+watch` request: the serialized
+/// events that were sent since the previous response.
+pub type BrpEventsWatchingResponse = Vec<Value>;
+
+/// The response to a `bevy/list_schedules` request.
+pub type BrpListSchedulesResponse = Vec<String>;
+
+/// The response to a `bevy/list_systems` request.
+pub type BrpListSystemsResponse = Vec<BrpSystem>;
+
+/// A system of a schedule, as returned by `bevy/list_systems`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpSystem {
+    /// The ID of the system within its schedule.
+    pub id: usize,
+
+    /// The name of the system.
+    pub name: String,
+
+    /// Whether the system runs when the schedule runs.
+    pub enabled: bool,
+}
+
+/// The response to a `bevy/stepping/status` request.
+#[cfg(feature = "bevy_debug_stepping")]
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpSteppingStatusResponse {
+    /// Whether stepping is enabled.
+    pub enabled: bool,
+
+    /// The labels of the schedules that are stepped through, in the order
+    /// they run.
+    pub schedules: Vec<String>,
+
+    /// The system that runs next, if stepping is enabled and the frame hasn't
+    /// finished.
+    pub cursor: Option<BrpSteppingCursor>,
+}
+
+/// The position of [`Stepping`] within a frame.
+#[cfg(feature = "bevy_debug_stepping")]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpSteppingCursor {
+    /// The label of the schedule.
+    pub schedule: String,
+
+    /// The ID of the system that runs next.
+    pub system: usize,
+}
+
 /// A single response from a `bevy/list+watch` request.
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct BrpListWatchingResponse {
@@ -444,6 +789,9 @@ pub struct BrpListWatchingResponse {
 /// The response to a `bevy/query` request.
 pub type BrpQueryResponse = Vec<BrpQueryRow>;
 
+/// The response to a `bevy/change_tick` request.
+pub type BrpChangeTickResponse = u32;
+
 /// One query match result: a single entity paired with the requested components.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct BrpQueryRow {
@@ -486,6 +834,7 @@ pub fn process_remote_get_request(In(params): In<Option<Value>>, world: &World)
         components,
         strict,
     } = parse_some(params)?;
+    check_type_access(world, components.iter().map(String::as_str))?;
 
     let app_type_registry = world.resource::<AppTypeRegistry>();
     let type_registry = app_type_registry.read();
@@ -504,6 +853,7 @@ pub fn process_remote_get_resource_request(
     let BrpGetResourceParams {
         resource: resource_path,
     } = parse_some(params)?;
+    check_type_access(world, [resource_path.as_str()])?;
 
     let app_type_registry = world.resource::<AppTypeRegistry>();
     let type_registry = app_type_registry.read();
@@ -546,6 +896,7 @@ pub fn process_remote_get_watching_request(
         components,
         strict,
     } = parse_some(params)?;
+    check_type_access(world, components.iter().map(String::as_str))?;
 
     let app_type_registry = world.resource::<AppTypeRegistry>();
     let type_registry = app_type_registry.read();
@@ -711,13 +1062,56 @@ pub fn process_remote_query_request(In(params): In<Option<Value>>, world: &mut W
             option,
             has,
         },
-        filter: BrpQueryFilter { without, with },
+        filter:
+            BrpQueryFilter {
+                without,
+                with,
+                values,
+                changed_since,
+            },
         strict,
+        sort,
+        offset,
+        limit,
     } = parse_some(params)?;
+    check_type_access(
+        world,
+        [&components, &option, &has, &without, &with]
+            .into_iter()
+            .flatten()
+            .chain(values.iter().map(|filter| &filter.component))
+            .chain(sort.iter().map(|key| &key.component))
+            .map(String::as_str),
+    )?;
 
     let app_type_registry = world.resource::<AppTypeRegistry>().clone();
     let type_registry = app_type_registry.read();
 
+    let mut value_filters = Vec::with_capacity(values.len());
+    for filter in values {
+        let Some(field) =
+            ComponentField::resolve(&type_registry, world, &filter.component, &filter.path)?
+        else {
+            if strict {
+                return Err(BrpError::component_error(anyhow!(
+                    "Component `{}` isn't used in the world",
+                    filter.component
+                )));
+            }
+            // No entity can satisfy a condition on a component that isn't used.
+            return serde_json::to_value(BrpQueryResponse::default()).map_err(BrpError::internal);
+        };
+        value_filters.push((field, filter.op, filter.value));
+    }
+    let mut sort_keys = Vec::with_capacity(sort.len());
+    for key in sort {
+        if let Some(field) =
+            ComponentField::resolve(&type_registry, world, &key.component, &key.path)?
+        {
+            sort_keys.push((field, key.descending));
+        }
+    }
+
     let components = get_component_ids(&type_registry, world, components, strict)
         .map_err(BrpError::component_error)?;
     let option = get_component_ids(&type_registry, world, option, strict)
@@ -729,6 +1123,15 @@ pub fn process_remote_query_request(In(params): In<Option<Value>>, world: &mut W
     let with = get_component_ids(&type_registry, world, with, strict)
         .map_err(BrpError::component_error)?;
 
+    // The components whose changes are considered by `changed_since`:
+    let changed_since = changed_since.map(|tick| {
+        let ids: Vec<ComponentId> = components
+            .iter()
+            .chain(&option)
+            .map(|(_, component_id)| *component_id)
+            .collect();
+        (Tick::new(tick), ids)
+    });
     let mut query = QueryBuilder::<FilteredEntityRef>::new(world);
     for (_, component) in &components {
         query.ref_id(*component);
@@ -749,6 +1152,14 @@ pub fn process_remote_query_request(In(params): In<Option<Value>>, world: &mut W
     for (_, with) in with {
         query.with_id(with);
     }
+    for (field, _, _) in &value_filters {
+        query.ref_id(field.component_id);
+    }
+    for (field, _) in &sort_keys {
+        query.optional(|query| {
+            query.ref_id(field.component_id);
+        });
+    }
 
     // At this point, we can safely unify `components` and `option`, since we only retrieved
     // entities that actually have all the `components` already.
@@ -769,9 +1180,63 @@ pub fn process_remote_query_request(In(params): In<Option<Value>>, world: &mut W
         .collect::<AnyhowResult<Vec<(&str, &ReflectComponent)>>>()
         .map_err(BrpError::component_error)?;
 
-    let mut response = BrpQueryResponse::default();
     let mut query = query.build();
+    let this_run = world.read_change_tick();
+    let mut rows = Vec::new();
     for row in query.iter(world) {
+        if let Some((last_run, ids)) = &changed_since {
+            let changed = ids.iter().any(|id| {
+                row.get_change_ticks_by_id(*id)
+                    .is_some_and(|ticks| ticks.is_changed(*last_run, this_run))
+            });
+            if !changed {
+                continue;
+            }
+        }
+
+        let satisfies_filters = value_filters.iter().all(|(field, op, expected)| {
+            field
+                .value(row.clone(), &type_registry)
+                .is_some_and(|value| op.matches(&value, expected))
+        });
+        if !satisfies_filters {
+            continue;
+        }
+
+        let keys: Vec<Option<Value>> = sort_keys
+            .iter()
+            .map(|(field, _)| field.value(row.clone(), &type_registry))
+            .collect();
+        rows.push((keys, row));
+    }
+
+    if !sort_keys.is_empty() {
+        rows.sort_by(|(a, _), (b, _)| {
+            a.iter()
+                .zip(b)
+                .zip(&sort_keys)
+                .map(|((a, b), (_, descending))| match (a, b) {
+                    (Some(a), Some(b)) => {
+                        let ordering = sort_order(a, b);
+                        if *descending {
+                            ordering.reverse()
+                        } else {
+                            ordering
+                        }
+                    }
+                    // Entities without the sort key come last.
+                    (Some(_), None) => Ordering::Less,
+                    (None, Some(_)) => Ordering::Greater,
+                    (None, None) => Ordering::Equal,
+                })
+                .find(|ordering| ordering.is_ne())
+                .unwrap_or(Ordering::Equal)
+        });
+    }
+
+    let mut response = BrpQueryResponse::default();
+    let rows = rows.into_iter().skip(offset).map(|(_, row)| row);
+    for row in rows.take(limit.unwrap_or(usize::MAX)) {
         // The map of component values:
         let components_map = build_components_map(
             row.clone(),
@@ -795,9 +1260,21 @@ pub fn process_remote_query_request(In(params): In<Option<Value>>, world: &mut W
     serde_json::to_value(response).map_err(BrpError::internal)
 }
 
+/// Handles a `bevy/change_tick` request coming from a client.
+pub fn process_remote_change_tick_request(
+    In(_params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    // Changes made from now on, including by systems that run later in this frame, are
+    // recorded with the current tick or later ones, so report the tick before it.
+    let response: BrpChangeTickResponse = world.read_change_tick().get().wrapping_sub(1);
+    serde_json::to_value(response).map_err(BrpError::internal)
+}
+
 /// Handles a `bevy/spawn` request coming from a client.
 pub fn process_remote_spawn_request(In(params): In<Option<Value>>, world: &mut World) -> BrpResult {
     let BrpSpawnParams { components } = parse_some(params)?;
+    check_type_access(world, components.keys().map(String::as_str))?;
 
     let app_type_registry = world.resource::<AppTypeRegistry>().clone();
     let type_registry = app_type_registry.read();
@@ -858,6 +1335,7 @@ pub fn process_remote_insert_request(
     world: &mut World,
 ) -> BrpResult {
     let BrpInsertParams { entity, components } = parse_some(params)?;
+    check_type_access(world, components.keys().map(String::as_str))?;
 
     let app_type_registry = world.resource::<AppTypeRegistry>().clone();
     let type_registry = app_type_registry.read();
@@ -884,6 +1362,7 @@ pub fn process_remote_insert_resource_request(
         resource: resource_path,
         value,
     } = parse_some(params)?;
+    check_type_access(world, [resource_path.as_str()])?;
 
     let app_type_registry = world.resource::<AppTypeRegistry>().clone();
     let type_registry = app_type_registry.read();
@@ -912,6 +1391,8 @@ pub fn process_remote_mutate_component_request(
         path,
         value,
     } = parse_some(params)?;
+    check_type_access(world, [component.as_str()])?;
+
     let app_type_registry = world.resource::<AppTypeRegistry>().clone();
     let type_registry = app_type_registry.read();
 
@@ -962,6 +1443,89 @@ pub fn process_remote_mutate_component_request(
     Ok(Value::Null)
 }
 
+/// Handles a `bevy/call_method` request coming from a client.
+///
+/// Besides the component type, the [`RemoteAccess`] must allow the method, which it sees as
+/// `<component path>::<method>`.
+#[cfg(feature = "reflect_functions")]
+pub fn process_remote_call_method_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpCallMethodParams {
+        entity,
+        component,
+        method,
+        args,
+    } = parse_some(params)?;
+    check_type_access(world, [component.as_str()])?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+
+    let registration = get_component_type_registration(&type_registry, &component)
+        .map_err(BrpError::component_error)?;
+    let method_path = format!("{}::{method}", registration.type_info().type_path());
+    if world
+        .get_resource::<RemoteAccess>()
+        .is_some_and(|access| !access.is_method_allowed(&method_path))
+    {
+        return Err(BrpError::access_denied(&method_path));
+    }
+
+    let function = registration
+        .method(&method)
+        .ok_or_else(|| BrpError::component_error(anyhow!("Unknown method: `{method_path}`")))?;
+    let signature = function
+        .info()
+        .signatures()
+        .iter()
+        .find(|signature| signature.arg_count() == args.len() + 1)
+        .ok_or_else(|| {
+            BrpError::component_error(anyhow!(
+                "Method `{method_path}` doesn't take {} arguments",
+                args.len()
+            ))
+        })?;
+    let reflect_component = registration.data::<ReflectComponent>().ok_or_else(|| {
+        BrpError::component_error(anyhow!("Component `{}` isn't reflectable", component))
+    })?;
+
+    match signature.args()[0].ownership() {
+        Ownership::Ref => {
+            let reflected = reflect_component
+                .reflect(get_entity(world, entity)?)
+                .ok_or_else(|| {
+                    BrpError::component_error(anyhow!("Cannot reflect component `{}`", component))
+                })?;
+            call_reflected_method(
+                function,
+                signature,
+                ArgValue::Ref(reflected.as_partial_reflect()),
+                args,
+                &type_registry,
+            )
+        }
+        Ownership::Mut => {
+            let mut reflected = reflect_component
+                .reflect_mut(get_entity_mut(world, entity)?)
+                .ok_or_else(|| {
+                    BrpError::component_error(anyhow!("Cannot reflect component `{}`", component))
+                })?;
+            call_reflected_method(
+                function,
+                signature,
+                ArgValue::Mut(reflected.as_partial_reflect_mut()),
+                args,
+                &type_registry,
+            )
+        }
+        Ownership::Owned => Err(BrpError::component_error(anyhow!(
+            "Method `{method_path}` takes the component by value"
+        ))),
+    }
+}
+
 /// Handles a `bevy/mutate_resource` request coming from a client.
 pub fn process_remote_mutate_resource_request(
     In(params): In<Option<Value>>,
@@ -972,6 +1536,7 @@ pub fn process_remote_mutate_resource_request(
         path: field_path,
         value,
     } = parse_some(params)?;
+    check_type_access(world, [resource_path.as_str()])?;
 
     let app_type_registry = world.resource::<AppTypeRegistry>().clone();
     let type_registry = app_type_registry.read();
@@ -1019,6 +1584,7 @@ pub fn process_remote_remove_request(
     world: &mut World,
 ) -> BrpResult {
     let BrpRemoveParams { entity, components } = parse_some(params)?;
+    check_type_access(world, components.iter().map(String::as_str))?;
 
     let app_type_registry = world.resource::<AppTypeRegistry>().clone();
     let type_registry = app_type_registry.read();
@@ -1043,6 +1609,7 @@ pub fn process_remote_remove_resource_request(
     let BrpRemoveResourceParams {
         resource: resource_path,
     } = parse_some(params)?;
+    check_type_access(world, [resource_path.as_str()])?;
 
     let app_type_registry = world.resource::<AppTypeRegistry>().clone();
     let type_registry = app_type_registry.read();
@@ -1122,6 +1689,7 @@ pub fn process_remote_list_request(In(params): In<Option<Value>>, world: &World)
             }
         }
     }
+    response.retain(|type_path| is_type_allowed(world, type_path));
 
     // Sort both for cleanliness and to reduce the risk that clients start
     // accidentally depending on the order.
@@ -1145,6 +1713,7 @@ pub fn process_remote_list_resources_request(
             response.push(registered_type.type_info().type_path().to_owned());
         }
     }
+    response.retain(|type_path| is_type_allowed(world, type_path));
 
     response.sort();
 
@@ -1188,6 +1757,13 @@ pub fn process_remote_list_watching_request(
         }
     }
 
+    response
+        .added
+        .retain(|type_path| is_type_allowed(world, type_path));
+    response
+        .removed
+        .retain(|type_path| is_type_allowed(world, type_path));
+
     if response.added.is_empty() && response.removed.is_empty() {
         Ok(None)
     } else {
@@ -1209,6 +1785,7 @@ pub fn export_registry_types(In(params): In<Option<Value>>, world: &World) -> Br
     let schemas = types
         .iter()
         .map(crate::schemas::json_schema::export_type)
+        .filter(|(type_path, _)| is_type_allowed(world, type_path))
         .filter(|(_, schema)| {
             if let Some(crate_name) = &schema.crate_name {
                 if !filter.with_crates.is_empty()
@@ -1248,55 +1825,501 @@ pub fn export_registry_types(In(params): In<Option<Value>>, world: &World) -> Br
     serde_json::to_value(schemas).map_err(BrpError::internal)
 }
 
-/// Immutably retrieves an entity from the [`World`], returning an error if the
-/// entity isn't present.
-fn get_entity(world: &World, entity: Entity) -> Result<EntityRef<'_>, BrpError> {
-    world
-        .get_entity(entity)
-        .map_err(|_| BrpError::entity_not_found(entity))
-}
-
-/// Mutably retrieves an entity from the [`World`], returning an error if the
-/// entity isn't present.
-fn get_entity_mut(world: &mut World, entity: Entity) -> Result<EntityWorldMut<'_>, BrpError> {
-    world
-        .get_entity_mut(entity)
-        .map_err(|_| BrpError::entity_not_found(entity))
-}
+/// Handles a `bevy/send_event` request coming from a client.
+pub fn process_remote_send_event_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpSendEventParams {
+        event: event_path,
+        value,
+    } = parse_some(params)?;
+    check_type_access(world, [event_path.as_str()])?;
 
-/// Returns the [`TypeId`] and [`ComponentId`] of the components with the given
-/// full path names.
-///
-/// Note that the supplied path names must be *full* path names: e.g.
-/// `bevy_transform::components::transform::Transform` instead of `Transform`.
-fn get_component_ids(
-    type_registry: &TypeRegistry,
-    world: &World,
-    component_paths: Vec<String>,
-    strict: bool,
-) -> AnyhowResult<Vec<(TypeId, ComponentId)>> {
-    let mut component_ids = vec![];
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
 
-    for component_path in component_paths {
-        let type_id = get_component_type_registration(type_registry, &component_path)?.type_id();
-        let Some(component_id) = world.components().get_id(type_id) else {
-            if strict {
-                return Err(anyhow!(
-                    "Component `{}` isn't used in the world",
-                    component_path
-                ));
-            }
-            continue;
-        };
+    let reflect_event =
+        get_reflect_event(&type_registry, &event_path).map_err(BrpError::event_error)?;
+    let reflected_event =
+        deserialize_event(&type_registry, &event_path, value).map_err(BrpError::event_error)?;
 
-        component_ids.push((type_id, component_id));
+    if !reflect_event.send(world, &*reflected_event, &type_registry) {
+        return Err(BrpError::event_error(anyhow!(
+            "Event `{event_path}` was not added to the world"
+        )));
     }
 
-    Ok(component_ids)
+    Ok(Value::Null)
 }
 
-/// Given an entity (`entity_ref`) and a list of reflected component information
-/// (`paths_and_reflect_components`), return a map which associates each component to
+/// Handles a `bevy/trigger` request coming from a client.
+pub fn process_remote_trigger_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpTriggerParams {
+        event: event_path,
+        value,
+        targets,
+    } = parse_some(params)?;
+    check_type_access(world, [event_path.as_str()])?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+
+    let reflect_event =
+        get_reflect_event(&type_registry, &event_path).map_err(BrpError::event_error)?;
+    let reflected_event =
+        deserialize_event(&type_registry, &event_path, value).map_err(BrpError::event_error)?;
+
+    for &target in &targets {
+        get_entity(world, target)?;
+    }
+
+    reflect_event.trigger(world, &*reflected_event, &targets, &type_registry);
+
+    Ok(Value::Null)
+}
+
+/// The events that a `bevy/events+watch` request has already sent.
+pub struct WatchedEvents {
+    /// The [`World::last_change_tick`] of the frame in which the request last ran.
+    frame: Tick,
+    /// The id of the first event that has not been sent yet.
+    cursor: usize,
+}
+
+/// Handles a `bevy/events+watch` request coming from a client.
+///
+/// Each request only receives the events sent after it was made.
+pub fn process_remote_events_watching_request(
+    In(params): In<Option<Value>>,
+    world: &World,
+    mut watched_events: Local<HashMap<WatchingRequestId, WatchedEvents>>,
+    mut last_frame: Local<Option<Tick>>,
+) -> BrpResult<Option<Value>> {
+    let Some(&CurrentWatchingRequest(request_id)) = world.get_resource::<CurrentWatchingRequest>()
+    else {
+        return Err(BrpError::internal(
+            "`bevy/events+watch` can only run as a watching request",
+        ));
+    };
+    let BrpEventsWatchingParams { event: event_path } = parse_some(params)?;
+    check_type_access(world, [event_path.as_str()])?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>();
+    let type_registry = app_type_registry.read();
+
+    let reflect_event =
+        get_reflect_event(&type_registry, &event_path).map_err(BrpError::event_error)?;
+    let Some(event_count) = reflect_event.event_count(world) else {
+        return Err(BrpError::event_error(anyhow!(
+            "Event `{event_path}` was not added to the world"
+        )));
+    };
+
+    let frame = world.last_change_tick();
+    if *last_frame != Some(frame) {
+        // Every ongoing request runs once per frame, so those that did not run in the
+        // previous frame have been closed.
+        watched_events.retain(|_, watched| Some(watched.frame) == *last_frame);
+        *last_frame = Some(frame);
+    }
+
+    let watched = watched_events.entry(request_id).or_insert(WatchedEvents {
+        frame,
+        cursor: event_count,
+    });
+    watched.frame = frame;
+    let start = core::mem::replace(&mut watched.cursor, event_count);
+
+    let mut response = BrpEventsWatchingResponse::default();
+    for id in start..event_count {
+        let Some(event) = reflect_event.get(world, id) else {
+            continue;
+        };
+        let serializer = TypedReflectSerializer::new(event.as_partial_reflect(), &type_registry);
+        response.push(serde_json::to_value(serializer).map_err(BrpError::event_error)?);
+    }
+
+    if response.is_empty() {
+        return Ok(None);
+    }
+
+    serde_json::to_value(response)
+        .map(Some)
+        .map_err(BrpError::internal)
+}
+
+/// Handles a `bevy/list_schedules` request coming from a client.
+pub fn process_remote_list_schedules_request(
+    In(_params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let mut response = BrpListSchedulesResponse::default();
+
+    if let Some(schedules) = world.get_resource::<Schedules>() {
+        for (label, _) in schedules.iter() {
+            response.push(format!("{label:?}"));
+        }
+    }
+
+    response.sort();
+
+    serde_json::to_value(response).map_err(BrpError::internal)
+}
+
+/// Handles a `bevy/list_systems` request coming from a client.
+pub fn process_remote_list_systems_request(
+    In(params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let BrpListSystemsParams { schedule: label } = parse_some(params)?;
+    let schedule = get_schedule(world, &label)?;
+
+    let systems = schedule.systems().map_err(|_| {
+        BrpError::internal(format!("Schedule `{label}` has not been initialized yet"))
+    })?;
+    let response = systems
+        .map(|(id, system)| BrpSystem {
+            id: id.index(),
+            name: system.name().into_owned(),
+            enabled: schedule.is_system_enabled(id),
+        })
+        .collect::<BrpListSystemsResponse>();
+
+    serde_json::to_value(response).map_err(BrpError::internal)
+}
+
+/// Handles a `bevy/set_system_enabled` request coming from a client.
+pub fn process_remote_set_system_enabled_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpSetSystemEnabledParams {
+        schedule: label,
+        system,
+        enabled,
+    } = parse_some(params)?;
+
+    let schedule = get_schedule_mut(world, &label)?;
+    let system = get_system_node(schedule, system, &label)?;
+    schedule.set_system_enabled(system, enabled);
+
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/stepping/status` request coming from a client.
+#[cfg(feature = "bevy_debug_stepping")]
+pub fn process_remote_stepping_status_request(
+    In(_params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let response = world
+        .get_resource::<Stepping>()
+        .map(|stepping| BrpSteppingStatusResponse {
+            enabled: stepping.is_enabled(),
+            // The order of the schedules is only known once all of them have run.
+            schedules: stepping
+                .schedules()
+                .map(|labels| labels.iter().map(|label| format!("{label:?}")).collect())
+                .unwrap_or_default(),
+            cursor: stepping.cursor().map(|(label, system)| BrpSteppingCursor {
+                schedule: format!("{label:?}"),
+                system: system.index(),
+            }),
+        })
+        .unwrap_or_default();
+
+    serde_json::to_value(response).map_err(BrpError::internal)
+}
+
+/// Handles a `bevy/stepping/enable` request coming from a client.
+#[cfg(feature = "bevy_debug_stepping")]
+pub fn process_remote_stepping_enable_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpSteppingEnableParams { schedules } = params.map(parse).transpose()?.unwrap_or_default();
+
+    let labels = schedules
+        .iter()
+        .map(|label| get_schedule(world, label).map(Schedule::label))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut stepping = world.get_resource_or_init::<Stepping>();
+    for label in labels {
+        stepping.add_schedule(label);
+    }
+    stepping.enable();
+
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/stepping/disable` request coming from a client.
+#[cfg(feature = "bevy_debug_stepping")]
+pub fn process_remote_stepping_disable_request(
+    In(_params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    get_stepping_mut(world)?.disable();
+
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/stepping/step` request coming from a client.
+#[cfg(feature = "bevy_debug_stepping")]
+pub fn process_remote_stepping_step_request(
+    In(_params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    get_stepping_mut(world)?.step_frame();
+
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/stepping/continue` request coming from a client.
+#[cfg(feature = "bevy_debug_stepping")]
+pub fn process_remote_stepping_continue_request(
+    In(_params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    get_stepping_mut(world)?.continue_frame();
+
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/stepping/set_breakpoint` request coming from a client.
+#[cfg(feature = "bevy_debug_stepping")]
+pub fn process_remote_stepping_set_breakpoint_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpSteppingBreakpointParams {
+        schedule: label,
+        system,
+    } = parse_some(params)?;
+
+    let schedule = get_schedule(world, &label)?;
+    let system = get_system_node(schedule, system, &label)?;
+    let schedule = schedule.label();
+    get_stepping_mut(world)?.set_breakpoint_node(schedule, system);
+
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/stepping/clear_breakpoint` request coming from a client.
+#[cfg(feature = "bevy_debug_stepping")]
+pub fn process_remote_stepping_clear_breakpoint_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpSteppingBreakpointParams {
+        schedule: label,
+        system,
+    } = parse_some(params)?;
+
+    let schedule = get_schedule(world, &label)?;
+    let system = get_system_node(schedule, system, &label)?;
+    let schedule = schedule.label();
+    get_stepping_mut(world)?.clear_breakpoint_node(schedule, system);
+
+    Ok(Value::Null)
+}
+
+/// Calls a reflected method of a component with the given receiver and serialized arguments,
+/// and serializes the returned value.
+///
+/// Only arguments taken by value can be passed after the receiver.
+#[cfg(feature = "reflect_functions")]
+fn call_reflected_method(
+    function: &DynamicFunction,
+    signature: &SignatureInfo,
+    receiver: ArgValue,
+    args: Vec<Value>,
+    type_registry: &TypeRegistry,
+) -> BrpResult {
+    let mut arg_list = ArgList::new().with_arg(receiver);
+    for (info, value) in signature.args()[1..].iter().zip(args) {
+        if info.ownership() != Ownership::Owned {
+            return Err(BrpError::component_error(anyhow!(
+                "Argument {} of type `{}` must be passed by value",
+                info.index(),
+                info.type_path()
+            )));
+        }
+        let registration = type_registry.get(info.type_id()).ok_or_else(|| {
+            BrpError::component_error(anyhow!("Unknown argument type: `{}`", info.type_path()))
+        })?;
+        let value: Box<dyn PartialReflect> =
+            TypedReflectDeserializer::new(registration, type_registry)
+                .deserialize(&value)
+                .map_err(BrpError::component_error)?;
+        // Functions only accept arguments of their concrete types.
+        let value = match registration.data::<ReflectFromReflect>() {
+            Some(from_reflect) => from_reflect
+                .from_reflect(&*value)
+                .map_or(value, |value| value.into_partial_reflect()),
+            None => value,
+        };
+        arg_list.push_boxed(value);
+    }
+
+    let returned = function.call(arg_list).map_err(BrpError::component_error)?;
+    if returned.is_unit() {
+        return Ok(Value::Null);
+    }
+    let returned = match &returned {
+        Return::Owned(value) => &**value,
+        Return::Ref(value) => *value,
+        Return::Mut(value) => &**value,
+    };
+    serde_json::to_value(TypedReflectSerializer::new(returned, type_registry))
+        .map_err(BrpError::component_error)
+}
+
+/// Returns `true` if the [`RemoteAccess`] allows clients to access the type with the given path.
+fn is_type_allowed(world: &World, type_path: &str) -> bool {
+    world
+        .get_resource::<RemoteAccess>()
+        .is_none_or(|access| access.is_type_allowed(type_path))
+}
+
+/// Returns an error if the [`RemoteAccess`] denies access to any of the types with the given
+/// paths.
+///
+/// The paths are resolved in the [`AppTypeRegistry`] first, so that a denied type can't be
+/// reached through one of its [aliases](bevy_reflect::TypeRegistry::register_alias).
+fn check_type_access<'a>(
+    world: &World,
+    type_paths: impl IntoIterator<Item = &'a str>,
+) -> Result<(), BrpError> {
+    let Some(access) = world.get_resource::<RemoteAccess>() else {
+        return Ok(());
+    };
+    let type_registry = world
+        .get_resource::<AppTypeRegistry>()
+        .map(|type_registry| type_registry.read());
+
+    for type_path in type_paths {
+        let registered_path = type_registry
+            .as_ref()
+            .and_then(|type_registry| type_registry.get_with_type_path(type_path))
+            .map_or(type_path, |registration| {
+                registration.type_info().type_path()
+            });
+        if !access.is_type_allowed(registered_path) {
+            return Err(BrpError::access_denied(type_path));
+        }
+    }
+    Ok(())
+}
+
+/// Immutably retrieves an entity from the [`World`], returning an error if the
+/// entity isn't present.
+fn get_entity(world: &World, entity: Entity) -> Result<EntityRef<'_>, BrpError> {
+    world
+        .get_entity(entity)
+        .map_err(|_| BrpError::entity_not_found(entity))
+}
+
+/// Mutably retrieves an entity from the [`World`], returning an error if the
+/// entity isn't present.
+fn get_entity_mut(world: &mut World, entity: Entity) -> Result<EntityWorldMut<'_>, BrpError> {
+    world
+        .get_entity_mut(entity)
+        .map_err(|_| BrpError::entity_not_found(entity))
+}
+
+/// Immutably retrieves the schedule with the given label from the
+/// [`Schedules`], returning an error if it isn't present.
+///
+/// Schedules that are running while the request is processed, such as
+/// [`RemoteLast`](crate::RemoteLast), are not part of the [`Schedules`].
+fn get_schedule<'w>(world: &'w World, label: &str) -> Result<&'w Schedule, BrpError> {
+    world
+        .get_resource::<Schedules>()
+        .and_then(|schedules| {
+            schedules
+                .iter()
+                .find(|(schedule_label, _)| format!("{schedule_label:?}") == label)
+        })
+        .map(|(_, schedule)| schedule)
+        .ok_or_else(|| BrpError::schedule_not_found(label))
+}
+
+/// Mutably retrieves the schedule with the given label from the [`Schedules`],
+/// returning an error if it isn't present.
+fn get_schedule_mut<'w>(world: &'w mut World, label: &str) -> Result<&'w mut Schedule, BrpError> {
+    world
+        .get_resource_mut::<Schedules>()
+        .map(Mut::into_inner)
+        .and_then(|schedules| {
+            schedules
+                .iter_mut()
+                .find(|(schedule_label, _)| format!("{schedule_label:?}") == label)
+        })
+        .map(|(_, schedule)| schedule)
+        .ok_or_else(|| BrpError::schedule_not_found(label))
+}
+
+/// Returns the [`NodeId`] of the system with the given ID, returning an error
+/// if the schedule doesn't contain it.
+fn get_system_node(schedule: &Schedule, system: usize, label: &str) -> Result<NodeId, BrpError> {
+    let node = NodeId::System(system);
+    if schedule
+        .systems()
+        .is_ok_and(|mut systems| systems.any(|(id, _)| id == node))
+    {
+        Ok(node)
+    } else {
+        Err(BrpError::system_not_found(system, label))
+    }
+}
+
+/// Mutably retrieves the [`Stepping`] resource, returning an error if it isn't
+/// present.
+#[cfg(feature = "bevy_debug_stepping")]
+fn get_stepping_mut(world: &mut World) -> Result<Mut<'_, Stepping>, BrpError> {
+    world
+        .get_resource_mut::<Stepping>()
+        .ok_or_else(|| BrpError::resource_not_present(core::any::type_name::<Stepping>()))
+}
+
+/// Returns the [`TypeId`] and [`ComponentId`] of the components with the given
+/// full path names.
+///
+/// Note that the supplied path names must be *full* path names: e.g.
+/// `bevy_transform::components::transform::Transform` instead of `Transform`.
+fn get_component_ids(
+    type_registry: &TypeRegistry,
+    world: &World,
+    component_paths: Vec<String>,
+    strict: bool,
+) -> AnyhowResult<Vec<(TypeId, ComponentId)>> {
+    let mut component_ids = vec![];
+
+    for component_path in component_paths {
+        let type_id = get_component_type_registration(type_registry, &component_path)?.type_id();
+        let Some(component_id) = world.components().get_id(type_id) else {
+            if strict {
+                return Err(anyhow!(
+                    "Component `{}` isn't used in the world",
+                    component_path
+                ));
+            }
+            continue;
+        };
+
+        component_ids.push((type_id, component_id));
+    }
+
+    Ok(component_ids)
+}
+
+/// Given an entity (`entity_ref`) and a list of reflected component information
+/// (`paths_and_reflect_components`), return a map which associates each component to
 /// its serialized value from the entity.
 ///
 /// This is intended to be used on an entity which has already been filtered; components
@@ -1342,6 +2365,103 @@ fn build_has_map<'a>(
     has_map
 }
 
+/// A field of a component, used to filter and sort the results of a query.
+struct ComponentField<'r> {
+    component_id: ComponentId,
+    reflect_component: &'r ReflectComponent,
+    path: ParsedPath,
+}
+
+impl<'r> ComponentField<'r> {
+    /// Looks up the component with the given type path and parses the path of the field.
+    ///
+    /// Returns `None` if the component isn't used in the world.
+    fn resolve(
+        type_registry: &'r TypeRegistry,
+        world: &World,
+        component_path: &str,
+        field_path: &str,
+    ) -> BrpResult<Option<Self>> {
+        let reflect_component = get_reflect_component(type_registry, component_path)
+            .map_err(BrpError::component_error)?;
+        let path = ParsedPath::parse(field_path).map_err(|err| BrpError {
+            code: error_codes::INVALID_PARAMS,
+            message: err.to_string(),
+            data: None,
+        })?;
+        let type_id = get_component_type_registration(type_registry, component_path)
+            .map_err(BrpError::component_error)?
+            .type_id();
+        Ok(world.components().get_id(type_id).map(|component_id| Self {
+            component_id,
+            reflect_component,
+            path,
+        }))
+    }
+
+    /// Returns the serialized value of the field on the given entity, if it's present.
+    fn value(&self, entity_ref: FilteredEntityRef, type_registry: &TypeRegistry) -> Option<Value> {
+        let component = self.reflect_component.reflect(entity_ref)?;
+        let field = self
+            .path
+            .reflect_element(component.as_partial_reflect())
+            .ok()?;
+        serde_json::to_value(TypedReflectSerializer::new(field, type_registry)).ok()
+    }
+}
+
+/// Compares two serialized values, if they are both numbers, strings or booleans.
+fn compare_values(a: &Value, b: &Value) -> Option<Ordering> {
+    match (a, b) {
+        (Value::Number(a), Value::Number(b)) => Some(compare_numbers(a, b)),
+        (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+        (Value::Bool(a), Value::Bool(b)) => Some(a.cmp(b)),
+        _ => None,
+    }
+}
+
+/// Compares two numbers, exactly if they are both integers.
+fn compare_numbers(a: &Number, b: &Number) -> Ordering {
+    if let (Some(a), Some(b)) = (a.as_i64(), b.as_i64()) {
+        return a.cmp(&b);
+    }
+    if let (Some(a), Some(b)) = (a.as_u64(), b.as_u64()) {
+        return a.cmp(&b);
+    }
+    let as_f64 = |number: &Number| number.as_f64().unwrap_or(f64::NAN);
+    as_f64(a).total_cmp(&as_f64(b))
+}
+
+/// The total order that query results are sorted by.
+///
+/// Values of different kinds are ordered as null, booleans, numbers, strings, arrays and objects.
+/// Arrays and objects are compared element by element.
+fn sort_order(a: &Value, b: &Value) -> Ordering {
+    let rank = |value: &Value| match value {
+        Value::Null => 0,
+        Value::Bool(_) => 1,
+        Value::Number(_) => 2,
+        Value::String(_) => 3,
+        Value::Array(_) => 4,
+        Value::Object(_) => 5,
+    };
+    match (a, b) {
+        (Value::Array(a), Value::Array(b)) => a
+            .iter()
+            .zip(b)
+            .map(|(a, b)| sort_order(a, b))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or_else(|| a.len().cmp(&b.len())),
+        (Value::Object(a), Value::Object(b)) => a
+            .iter()
+            .zip(b)
+            .map(|((a_key, a), (b_key, b))| a_key.cmp(b_key).then_with(|| sort_order(a, b)))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or_else(|| a.len().cmp(&b.len())),
+        _ => compare_values(a, b).unwrap_or_else(|| rank(a).cmp(&rank(b))),
+    }
+}
+
 /// Given a component ID, return the associated [type path] and `ReflectComponent` if possible.
 ///
 /// The `ReflectComponent` part is the meat of this; the type path is only used for error messages.
@@ -1406,6 +2526,23 @@ fn deserialize_resource(
     Ok(reflected)
 }
 
+/// Given a serialized `value` of the event type with the given full path, deserialize it and
+/// return it as a [`PartialReflect`].
+fn deserialize_event(
+    type_registry: &TypeRegistry,
+    event_path: &str,
+    value: Value,
+) -> AnyhowResult<Box<dyn PartialReflect>> {
+    let Some(event_type) = type_registry.get_with_type_path(event_path) else {
+        return Err(anyhow!("Unknown event type: `{}`", event_path));
+    };
+    let reflected: Box<dyn PartialReflect> =
+        TypedReflectDeserializer::new(event_type, type_registry)
+            .deserialize(&value)
+            .map_err(|err| anyhow!("{event_path} is invalid: {err}"))?;
+    Ok(reflected)
+}
+
 /// Given a collection `reflect_components` of reflected component values, insert them into
 /// the given entity (`entity_world_mut`).
 fn insert_reflected_components(
@@ -1470,6 +2607,22 @@ fn get_resource_type_registration<'r>(
         .ok_or_else(|| anyhow!("Unknown resource type: `{}`", resource_path))
 }
 
+/// Given an event's full path, return its [`ReflectEvent`] from the
+/// [`TypeRegistry`].
+fn get_reflect_event<'r>(
+    type_registry: &'r TypeRegistry,
+    event_path: &str,
+) -> AnyhowResult<&'r ReflectEvent> {
+    let Some(event_registration) = type_registry.get_with_type_path(event_path) else {
+        return Err(anyhow!("Unknown event type: `{}`", event_path));
+    };
+    let Some(reflect_event) = event_registration.data::<ReflectEvent>() else {
+        return Err(anyhow!("Event `{}` isn't reflectable", event_path));
+    };
+
+    Ok(reflect_event)
+}
+
 #[cfg(test)]
 mod tests {
     /// A generic function that tests serialization and deserialization of any type
@@ -1491,6 +2644,7 @@ mod tests {
         );
     }
     use super::*;
+    use bevy_reflect::TypePath;
 
     #[test]
     fn serialization_tests() {
@@ -1513,4 +2667,462 @@ mod tests {
             entity: Entity::from_raw(0),
         });
     }
+
+    #[derive(bevy_ecs::event::Event, bevy_reflect::Reflect, Debug, PartialEq)]
+    #[reflect(Event)]
+    struct Ping {
+        value: u32,
+    }
+
+    fn create_event_world() -> World {
+        let mut world = World::new();
+        let registry = AppTypeRegistry::default();
+        registry.write().register::<Ping>();
+        world.insert_resource(registry);
+        world.init_resource::<bevy_ecs::event::Events<Ping>>();
+        world
+    }
+
+    fn send_ping(world: &mut World, value: u32) -> BrpResult {
+        world
+            .run_system_cached_with(
+                process_remote_send_event_request,
+                Some(serde_json::json!({
+                    "event": Ping::type_path(),
+                    "value": { "value": value },
+                })),
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn events_can_be_sent_and_watched() {
+        let mut world = create_event_world();
+        let watch = |world: &mut World, request: u64| {
+            world.insert_resource(CurrentWatchingRequest(WatchingRequestId(request)));
+            world
+                .run_system_cached_with(
+                    process_remote_events_watching_request,
+                    Some(serde_json::json!({ "event": Ping::type_path() })),
+                )
+                .unwrap()
+                .unwrap()
+        };
+
+        // A new watch does not receive the events sent before it.
+        send_ping(&mut world, 1).unwrap();
+        assert_eq!(watch(&mut world, 0), None);
+
+        world.clear_trackers();
+        send_ping(&mut world, 2).unwrap();
+        assert_eq!(
+            watch(&mut world, 0),
+            Some(serde_json::json!([{ "value": 2 }]))
+        );
+        assert_eq!(watch(&mut world, 1), None);
+
+        world.clear_trackers();
+        send_ping(&mut world, 3).unwrap();
+        send_ping(&mut world, 4).unwrap();
+        // Every watch receives the events sent after it.
+        for request in [0, 1] {
+            assert_eq!(
+                watch(&mut world, request),
+                Some(serde_json::json!([{ "value": 3 }, { "value": 4 }]))
+            );
+        }
+
+        world.clear_trackers();
+        assert_eq!(watch(&mut world, 0), None);
+
+        world.remove_resource::<bevy_ecs::event::Events<Ping>>();
+        let error = send_ping(&mut world, 4).unwrap_err();
+        assert_eq!(error.code, error_codes::EVENT_ERROR);
+    }
+
+    #[test]
+    fn events_can_be_triggered() {
+        #[derive(bevy_ecs::resource::Resource, Default)]
+        struct Received(Vec<(Entity, u32)>);
+
+        let mut world = create_event_world();
+        world.init_resource::<Received>();
+        world.add_observer(
+            |trigger: bevy_ecs::observer::Trigger<Ping>,
+             mut received: bevy_ecs::system::ResMut<Received>| {
+                received.0.push((trigger.target(), trigger.event().value));
+            },
+        );
+        let target = world.spawn_empty().id();
+
+        for params in [
+            serde_json::json!({ "event": Ping::type_path(), "value": { "value": 1 } }),
+            serde_json::json!({
+                "event": Ping::type_path(),
+                "value": { "value": 2 },
+                "targets": [target],
+            }),
+        ] {
+            world
+                .run_system_cached_with(process_remote_trigger_request, Some(params))
+                .unwrap()
+                .unwrap();
+        }
+
+        assert_eq!(
+            world.resource::<Received>().0,
+            vec![(Entity::PLACEHOLDER, 1), (target, 2)]
+        );
+    }
+
+    #[derive(bevy_ecs::component::Component, bevy_reflect::Reflect)]
+    #[reflect(Component)]
+    struct Score {
+        value: u32,
+    }
+
+    #[test]
+    fn queries_can_filter_sort_and_paginate() {
+        let mut world = World::new();
+        let registry = AppTypeRegistry::default();
+        registry.write().register::<Score>();
+        world.insert_resource(registry);
+        let entities: Vec<Entity> = [5, 15, 10, 20]
+            .into_iter()
+            .map(|value| world.spawn(Score { value }).id())
+            .collect();
+        world.increment_change_tick();
+
+        let query = |world: &mut World, params: Value| {
+            let rows = world
+                .run_system_cached_with(process_remote_query_request, Some(params))
+                .unwrap()
+                .unwrap();
+            parse::<BrpQueryResponse>(rows)
+                .unwrap()
+                .into_iter()
+                .map(|row| row.entity)
+                .collect::<Vec<_>>()
+        };
+
+        let rows = query(
+            &mut world,
+            serde_json::json!({
+                "data": { "components": [Score::type_path()] },
+                "filter": {
+                    "values": [{ "component": Score::type_path(), "path": "value", "op": ">", "value": 7 }],
+                },
+                "sort": [{ "component": Score::type_path(), "path": "value", "descending": true }],
+                "offset": 1,
+                "limit": 1,
+            }),
+        );
+        assert_eq!(rows, vec![entities[1]]);
+
+        let tick = world
+            .run_system_cached_with(process_remote_change_tick_request, None)
+            .unwrap()
+            .unwrap();
+        world.get_mut::<Score>(entities[2]).unwrap().value = 11;
+        let rows = query(
+            &mut world,
+            serde_json::json!({
+                "data": { "components": [Score::type_path()] },
+                "filter": { "changed_since": tick },
+            }),
+        );
+        assert_eq!(rows, vec![entities[2]]);
+    }
+
+    #[test]
+    fn values_are_totally_ordered() {
+        use super::{compare_values, sort_order};
+        use serde_json::json;
+
+        // Integers are compared exactly, even where they can't be represented as `f64`.
+        assert_eq!(
+            compare_values(&json!(u64::MAX), &json!(u64::MAX - 1)),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(
+            compare_values(&json!(i64::MIN), &json!(i64::MIN + 1)),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            compare_values(&json!(-1), &json!(u64::MAX)),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            compare_values(&json!(1), &json!(1.0)),
+            Some(Ordering::Equal)
+        );
+        assert_eq!(compare_values(&json!(1), &json!("1")), None);
+
+        let mut values = vec![
+            json!({ "b": 1 }),
+            json!([1, 2]),
+            json!("a"),
+            json!(2.5),
+            json!(null),
+            json!([1]),
+            json!(true),
+            json!({ "a": 2 }),
+            json!(-3),
+        ];
+        values.sort_by(sort_order);
+        assert_eq!(
+            values,
+            [
+                json!(null),
+                json!(true),
+                json!(-3),
+                json!(2.5),
+                json!("a"),
+                json!([1]),
+                json!([1, 2]),
+                json!({ "a": 2 }),
+                json!({ "b": 1 }),
+            ]
+        );
+    }
+
+    #[test]
+    fn denied_types_are_rejected_and_hidden() {
+        use crate::access::{AccessFilter, RemoteAccess};
+
+        let mut world = create_event_world();
+        world.insert_resource(
+            RemoteAccess::default().with_types(AccessFilter::default().deny(Ping::type_path())),
+        );
+
+        let error = send_ping(&mut world, 1).unwrap_err();
+        assert_eq!(error.code, error_codes::ACCESS_DENIED);
+        assert!(world.resource::<bevy_ecs::event::Events<Ping>>().is_empty());
+
+        let schemas = world
+            .run_system_cached_with(export_registry_types, None)
+            .unwrap()
+            .unwrap();
+        assert!(schemas.get(Ping::type_path()).is_none());
+        assert!(schemas.get(u32::type_path()).is_some());
+    }
+
+    #[test]
+    fn denied_types_are_rejected_through_aliases() {
+        use crate::access::{AccessFilter, RemoteAccess};
+
+        let mut world = create_event_world();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register_alias::<Ping>("old_game::Ping");
+        let send_aliased_ping = |world: &mut World| {
+            world
+                .run_system_cached_with(
+                    process_remote_send_event_request,
+                    Some(serde_json::json!({
+                        "event": "old_game::Ping",
+                        "value": { "value": 1 },
+                    })),
+                )
+                .unwrap()
+        };
+
+        send_aliased_ping(&mut world).unwrap();
+        assert_eq!(world.resource::<bevy_ecs::event::Events<Ping>>().len(), 1);
+
+        world.insert_resource(
+            RemoteAccess::default().with_types(AccessFilter::default().deny(Ping::type_path())),
+        );
+        let error = send_aliased_ping(&mut world).unwrap_err();
+        assert_eq!(error.code, error_codes::ACCESS_DENIED);
+        assert_eq!(world.resource::<bevy_ecs::event::Events<Ping>>().len(), 1);
+    }
+
+    #[cfg(feature = "reflect_functions")]
+    #[test]
+    fn component_methods_can_be_called() {
+        use crate::access::{AccessFilter, RemoteAccess};
+
+        #[derive(bevy_ecs::component::Component, bevy_reflect::Reflect)]
+        #[reflect(Component, methods(add, total))]
+        struct Tally {
+            count: u32,
+        }
+
+        impl Tally {
+            fn add(&mut self, amount: u32) {
+                self.count += amount;
+            }
+
+            fn total(&self) -> u32 {
+                self.count
+            }
+        }
+
+        let mut world = World::new();
+        let registry = AppTypeRegistry::default();
+        registry.write().register::<Tally>();
+        world.insert_resource(registry);
+        let entity = world.spawn(Tally { count: 1 }).id();
+
+        let call = |world: &mut World, method: &str, args: Value| {
+            world
+                .run_system_cached_with(
+                    process_remote_call_method_request,
+                    Some(serde_json::json!({
+                        "entity": entity,
+                        "component": Tally::type_path(),
+                        "method": method,
+                        "args": args,
+                    })),
+                )
+                .unwrap()
+        };
+
+        assert_eq!(
+            call(&mut world, "add", serde_json::json!([2])).unwrap(),
+            Value::Null
+        );
+        assert_eq!(
+            call(&mut world, "total", serde_json::json!([])).unwrap(),
+            serde_json::json!(3)
+        );
+        for (method, args) in [
+            ("add", serde_json::json!([])),
+            ("add", serde_json::json!(["two"])),
+            ("reset", serde_json::json!([])),
+        ] {
+            let error = call(&mut world, method, args).unwrap_err();
+            assert_eq!(error.code, error_codes::COMPONENT_ERROR);
+        }
+
+        world
+            .insert_resource(RemoteAccess::default().with_methods(
+                AccessFilter::default().deny(format!("{}::add", Tally::type_path())),
+            ));
+        let error = call(&mut world, "add", serde_json::json!([2])).unwrap_err();
+        assert_eq!(error.code, error_codes::ACCESS_DENIED);
+        assert_eq!(
+            call(&mut world, "total", serde_json::json!([])).unwrap(),
+            serde_json::json!(3)
+        );
+
+        world.insert_resource(
+            RemoteAccess::default().with_types(AccessFilter::default().deny(Tally::type_path())),
+        );
+        let error = call(&mut world, "total", serde_json::json!([])).unwrap_err();
+        assert_eq!(error.code, error_codes::ACCESS_DENIED);
+    }
+
+    #[derive(bevy_ecs::schedule::ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+    struct TestSchedule;
+
+    #[derive(bevy_ecs::resource::Resource, Default)]
+    struct Counter(u32);
+
+    fn increment(mut counter: bevy_ecs::system::ResMut<Counter>) {
+        counter.0 += 1;
+    }
+
+    #[test]
+    fn systems_can_be_disabled() {
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        world.add_schedule(Schedule::new(TestSchedule));
+        world.schedule_scope(TestSchedule, |world, schedule| {
+            schedule.add_systems(increment);
+            schedule.run(world);
+        });
+
+        let schedules = world
+            .run_system_cached_with(process_remote_list_schedules_request, None)
+            .unwrap()
+            .unwrap();
+        assert_eq!(schedules, serde_json::json!(["TestSchedule"]));
+
+        let list_systems = |world: &mut World| {
+            let systems = world
+                .run_system_cached_with(
+                    process_remote_list_systems_request,
+                    Some(serde_json::json!({ "schedule": "TestSchedule" })),
+                )
+                .unwrap()
+                .unwrap();
+            parse::<BrpListSystemsResponse>(systems).unwrap()
+        };
+        let systems = list_systems(&mut world);
+        assert_eq!(systems.len(), 1);
+        assert!(systems[0].name.ends_with("increment"));
+        assert!(systems[0].enabled);
+
+        world
+            .run_system_cached_with(
+                process_remote_set_system_enabled_request,
+                Some(serde_json::json!({
+                    "schedule": "TestSchedule",
+                    "system": systems[0].id,
+                    "enabled": false,
+                })),
+            )
+            .unwrap()
+            .unwrap();
+        assert!(!list_systems(&mut world)[0].enabled);
+        world.run_schedule(TestSchedule);
+        assert_eq!(world.resource::<Counter>().0, 1);
+
+        let error = world
+            .run_system_cached_with(
+                process_remote_set_system_enabled_request,
+                Some(serde_json::json!({
+                    "schedule": "TestSchedule",
+                    "system": systems[0].id + 1,
+                    "enabled": true,
+                })),
+            )
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(error.code, error_codes::SYSTEM_NOT_FOUND);
+    }
+
+    #[cfg(feature = "bevy_debug_stepping")]
+    #[test]
+    fn stepping_can_be_controlled() {
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        world.add_schedule(Schedule::new(TestSchedule));
+        world.schedule_scope(TestSchedule, |world, schedule| {
+            schedule.add_systems(increment);
+            schedule.run(world);
+        });
+        let run_frame = |world: &mut World| {
+            world.run_system_cached(Stepping::begin_frame).unwrap();
+            world.run_schedule(TestSchedule);
+        };
+
+        world
+            .run_system_cached_with(
+                process_remote_stepping_enable_request,
+                Some(serde_json::json!({ "schedules": ["TestSchedule"] })),
+            )
+            .unwrap()
+            .unwrap();
+        run_frame(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 1);
+
+        let status = world
+            .run_system_cached_with(process_remote_stepping_status_request, None)
+            .unwrap()
+            .unwrap();
+        let status = parse::<BrpSteppingStatusResponse>(status).unwrap();
+        assert!(status.enabled);
+        assert_eq!(status.schedules, vec!["TestSchedule".to_owned()]);
+
+        world
+            .run_system_cached_with(process_remote_stepping_step_request, None)
+            .unwrap()
+            .unwrap();
+        run_frame(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 2);
+    }
 }
@@ -0,0 +1,311 @@
+//! Request handling shared by the BRP transports that keep a connection open, such as the
+//! WebSocket and stdio transports.
+//!
+//! Unlike HTTP, where every request gets its own response, these transports send all responses
+//! over the same connection. Results of watching methods are pushed as further responses with
+//! the `id` of the watching request, so a single connection can hold any number of concurrent
+//! watches.
+
+use crate::{error_codes, BrpBatch, BrpError, BrpMessage, BrpRequest, BrpResponse, BrpResult};
+use alloc::sync::Arc;
+use async_channel::{Receiver, Sender};
+use bevy_platform::collections::HashMap;
+use bevy_tasks::IoTaskPool;
+use serde::Deserialize;
+use serde_json::Value;
+use std::sync::Mutex;
+
+/// A transport-level method that stops a watch started on the same connection.
+///
+/// `params`:
+/// - `id`: The `id` of the watching request to stop.
+///
+/// `result`: `true` if a watch was stopped, `false` if there was no watch with that `id`.
+pub const RPC_UNWATCH_METHOD: &str = "rpc.unwatch";
+
+/// The number of results of a watching request that are buffered before the watch is closed.
+const WATCH_CHANNEL_SIZE: usize = 8;
+
+#[derive(Deserialize)]
+struct UnwatchParams {
+    id: Value,
+}
+
+/// The requests of a single client connection.
+///
+/// Responses are serialized to JSON and sent to `outgoing`, which the transport writes to the client.
+pub(crate) struct BrpConnection<T> {
+    request_sender: Sender<BrpMessage>,
+    outgoing: Sender<T>,
+    watches: Arc<Mutex<HashMap<String, Receiver<BrpResult>>>>,
+}
+
+impl<T> Clone for BrpConnection<T> {
+    fn clone(&self) -> Self {
+        Self {
+            request_sender: self.request_sender.clone(),
+            outgoing: self.outgoing.clone(),
+            watches: self.watches.clone(),
+        }
+    }
+}
+
+impl<T: From<String> + Send + 'static> BrpConnection<T> {
+    pub(crate) fn new(request_sender: Sender<BrpMessage>, outgoing: Sender<T>) -> Self {
+        Self {
+            request_sender,
+            outgoing,
+            watches: Default::default(),
+        }
+    }
+
+    /// Handles a message received from the client, which is either a single request or a batch.
+    ///
+    /// The requests are processed in the background, so that a slow request doesn't hold up the
+    /// following ones.
+    pub(crate) fn handle_message(&self, message: &str) {
+        let batch = serde_json::from_str::<BrpBatch>(message);
+        let connection = self.clone();
+        IoTaskPool::get()
+            .spawn(async move {
+                match batch {
+                    Err(err) => {
+                        let response = BrpResponse::new(
+                            None,
+                            Err(BrpError {
+                                code: error_codes::PARSE_ERROR,
+                                message: err.to_string(),
+                                data: None,
+                            }),
+                        );
+                        connection.send(&response).await;
+                    }
+                    Ok(BrpBatch::Single(request)) => {
+                        if let Some(response) = connection.process_request(request, true).await {
+                            connection.send(&response).await;
+                        }
+                    }
+                    Ok(BrpBatch::Batch(requests)) => {
+                        let mut responses = Vec::new();
+                        for request in requests {
+                            if let Some(response) = connection.process_request(request, false).await
+                            {
+                                responses.push(response);
+                            }
+                        }
+                        connection.send(&responses).await;
+                    }
+                }
+            })
+            .detach();
+    }
+
+    /// Stops all watches of the connection. Call this when the client disconnects.
+    pub(crate) fn close(&self) {
+        for (_, receiver) in self.watches.lock().unwrap().drain() {
+            receiver.close();
+        }
+    }
+
+    /// Processes a single request, returning its response unless it started a watch.
+    async fn process_request(&self, request: Value, allow_watch: bool) -> Option<BrpResponse> {
+        let request = match BrpRequest::from_value(request) {
+            Ok(request) => request,
+            Err(response) => return Some(response),
+        };
+
+        if request.method == RPC_UNWATCH_METHOD {
+            return Some(BrpResponse::new(request.id, self.unwatch(request.params)));
+        }
+
+        if !request.is_watching() {
+            let (result_sender, result_receiver) = async_channel::bounded(1);
+            let _ = self
+                .request_sender
+                .send(BrpMessage {
+                    method: request.method,
+                    params: request.params,
+                    sender: result_sender,
+                })
+                .await;
+            let result = result_receiver
+                .recv()
+                .await
+                .unwrap_or_else(|err| Err(BrpError::internal(err)));
+            return Some(BrpResponse::new(request.id, result));
+        }
+
+        if !allow_watch {
+            return Some(BrpResponse::new(
+                request.id,
+                Err(BrpError {
+                    code: error_codes::INVALID_REQUEST,
+                    message: "Streaming can not be used in batch requests".to_string(),
+                    data: None,
+                }),
+            ));
+        }
+
+        let key = watch_key(request.id.as_ref());
+        let (result_sender, result_receiver) = async_channel::bounded(WATCH_CHANNEL_SIZE);
+        {
+            let mut watches = self.watches.lock().unwrap();
+            if watches.contains_key(&key) {
+                return Some(BrpResponse::new(
+                    request.id,
+                    Err(BrpError {
+                        code: error_codes::INVALID_REQUEST,
+                        message: format!("A watch with the id {key} is already running"),
+                        data: None,
+                    }),
+                ));
+            }
+            watches.insert(key.clone(), result_receiver.clone());
+        }
+
+        let _ = self
+            .request_sender
+            .send(BrpMessage {
+                method: request.method,
+                params: request.params,
+                sender: result_sender,
+            })
+            .await;
+
+        // Push every result of the watch to the client until it is stopped.
+        while let Ok(result) = result_receiver.recv().await {
+            self.send(&BrpResponse::new(request.id.clone(), result))
+                .await;
+        }
+        let mut watches = self.watches.lock().unwrap();
+        // The watch may have been stopped and replaced by another one with the same id.
+        if watches
+            .get(&key)
+            .is_some_and(|receiver| receiver.same_channel(&result_receiver))
+        {
+            watches.remove(&key);
+        }
+        None
+    }
+
+    fn unwatch(&self, params: Option<Value>) -> BrpResult {
+        let Some(params) = params else {
+            return Err(BrpError {
+                code: error_codes::INVALID_PARAMS,
+                message: String::from("Params not provided"),
+                data: None,
+            });
+        };
+        let UnwatchParams { id } = serde_json::from_value(params).map_err(|err| BrpError {
+            code: error_codes::INVALID_PARAMS,
+            message: err.to_string(),
+            data: None,
+        })?;
+
+        let watch = self.watches.lock().unwrap().remove(&watch_key(Some(&id)));
+        Ok(Value::Bool(watch.is_some_and(|receiver| receiver.close())))
+    }
+
+    async fn send(&self, response: &impl serde::Serialize) {
+        if let Ok(serialized) = serde_json::to_string(response) {
+            let _ = self.outgoing.send(serialized.into()).await;
+        }
+    }
+}
+
+fn watch_key(id: Option<&Value>) -> String {
+    id.map(Value::to_string).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BrpConnection;
+    use crate::BrpMessage;
+    use async_channel::Receiver;
+    use bevy_tasks::{
+        tick_global_task_pools_on_main_thread, AsyncComputeTaskPool, ComputeTaskPool, IoTaskPool,
+        TaskPool,
+    };
+    use serde_json::{json, Value};
+
+    fn init_task_pools() {
+        ComputeTaskPool::get_or_init(TaskPool::new);
+        AsyncComputeTaskPool::get_or_init(TaskPool::new);
+        IoTaskPool::get_or_init(TaskPool::new);
+    }
+
+    /// Receives the next message, running the tasks of the connection in the meantime.
+    fn recv<T>(receiver: &Receiver<T>) -> T {
+        loop {
+            tick_global_task_pools_on_main_thread();
+            if let Ok(message) = receiver.try_recv() {
+                return message;
+            }
+            std::thread::yield_now();
+        }
+    }
+
+    fn response(message: &str) -> (Value, Value) {
+        let response: Value = serde_json::from_str(message).unwrap();
+        let payload = response
+            .get("result")
+            .unwrap_or(&response["error"]["message"]);
+        (response["id"].clone(), payload.clone())
+    }
+
+    #[test]
+    fn watches_run_concurrently_until_stopped() {
+        init_task_pools();
+        let (request_sender, request_receiver) = async_channel::unbounded::<BrpMessage>();
+        let (outgoing_sender, outgoing_receiver) = async_channel::unbounded::<String>();
+        let connection = BrpConnection::new(request_sender, outgoing_sender);
+
+        connection.handle_message(r#"{"jsonrpc":"2.0","id":1,"method":"bevy/get+watch"}"#);
+        let first = recv(&request_receiver);
+        connection.handle_message(r#"{"jsonrpc":"2.0","id":2,"method":"bevy/list+watch"}"#);
+        let second = recv(&request_receiver);
+        assert_eq!(first.method, "bevy/get+watch");
+        assert_eq!(second.method, "bevy/list+watch");
+
+        // Both watches push their results over the same connection.
+        first.sender.try_send(Ok(json!("a"))).unwrap();
+        assert_eq!(response(&recv(&outgoing_receiver)), (json!(1), json!("a")));
+        second.sender.try_send(Ok(json!("b"))).unwrap();
+        assert_eq!(response(&recv(&outgoing_receiver)), (json!(2), json!("b")));
+
+        // Instant requests are answered while the watches keep running.
+        connection
+            .handle_message(r#"{"jsonrpc":"2.0","id":3,"method":"rpc.unwatch","params":{"id":1}}"#);
+        assert_eq!(response(&recv(&outgoing_receiver)), (json!(3), json!(true)));
+        assert!(first.sender.is_closed());
+        assert!(!second.sender.is_closed());
+
+        connection.handle_message(r#"{"jsonrpc":"2.0","id":4,"method":"bevy/list"}"#);
+        let list = recv(&request_receiver);
+        list.sender.try_send(Ok(json!([]))).unwrap();
+        assert_eq!(response(&recv(&outgoing_receiver)), (json!(4), json!([])));
+
+        connection.close();
+        assert!(second.sender.is_closed());
+    }
+
+    #[test]
+    fn invalid_messages_are_reported() {
+        init_task_pools();
+        let (request_sender, _request_receiver) = async_channel::unbounded::<BrpMessage>();
+        let (outgoing_sender, outgoing_receiver) = async_channel::unbounded::<String>();
+        let connection = BrpConnection::new(request_sender, outgoing_sender);
+
+        connection.handle_message("{");
+        let (id, _) = response(&recv(&outgoing_receiver));
+        assert_eq!(id, Value::Null);
+
+        connection.handle_message(r#"[{"jsonrpc":"2.0","id":5,"method":"bevy/get+watch"}]"#);
+        let responses: Value = serde_json::from_str(&recv(&outgoing_receiver)).unwrap();
+        assert_eq!(responses.as_array().unwrap().len(), 1);
+        assert_eq!(
+            responses[0]["error"]["message"],
+            "Streaming can not be used in batch requests"
+        );
+    }
+}
@@ -5,11 +5,16 @@
 //!
 //! Clients are expected to `POST` JSON requests to the root URL; see the `client`
 //! example for a trivial example of use.
+//!
+//! If the server is given a token with [`RemoteHttpPlugin::with_auth_token`], clients must send
+//! it in an `Authorization: Bearer <token>` header; other requests are rejected with
+//! `401 Unauthorized`.
 
 #![cfg(not(target_family = "wasm"))]
 
 use crate::{
-    error_codes, BrpBatch, BrpError, BrpMessage, BrpRequest, BrpResponse, BrpResult, BrpSender,
+    access, error_codes, BrpBatch, BrpError, BrpMessage, BrpRequest, BrpResponse, BrpResult,
+    BrpSender,
 };
 use anyhow::Result as AnyhowResult;
 use async_channel::{Receiver, Sender};
@@ -103,6 +108,8 @@ pub struct RemoteHttpPlugin {
     port: u16,
     /// The headers that Bevy will include in its HTTP responses
     headers: Headers,
+    /// The token that clients must authenticate with, if any.
+    auth_token: Option<String>,
 }
 
 impl Default for RemoteHttpPlugin {
@@ -111,6 +118,7 @@ impl Default for RemoteHttpPlugin {
             address: DEFAULT_ADDR,
             port: DEFAULT_PORT,
             headers: Headers::new(),
+            auth_token: None,
         }
     }
 }
@@ -120,6 +128,7 @@ impl Plugin for RemoteHttpPlugin {
         app.insert_resource(HostAddress(self.address))
             .insert_resource(HostPort(self.port))
             .insert_resource(HostHeaders(self.headers.clone()))
+            .insert_resource(HostAuthToken(self.auth_token.clone()))
             .add_systems(Startup, start_http_server);
     }
 }
@@ -173,6 +182,17 @@ impl RemoteHttpPlugin {
         self.headers = self.headers.insert(name, value);
         self
     }
+
+    /// Require clients to authenticate with the given token.
+    ///
+    /// Clients must send the token in an `Authorization: Bearer <token>` header, otherwise
+    /// their requests are rejected with `401 Unauthorized`. The token is sent in plain text, so
+    /// it should only be relied on in trusted networks or behind a TLS-terminating proxy.
+    #[must_use]
+    pub fn with_auth_token(mut self, token: impl Into<String>) -> Self {
+        self.auth_token = Some(token.into());
+        self
+    }
 }
 
 /// A resource containing the IP address that Bevy will host on.
@@ -194,12 +214,17 @@ pub struct HostPort(pub u16);
 #[derive(Debug, Resource)]
 struct HostHeaders(pub Headers);
 
+/// A resource containing the token that clients must authenticate with, if any.
+#[derive(Resource)]
+struct HostAuthToken(Option<String>);
+
 /// A system that starts up the Bevy Remote Protocol HTTP server.
 fn start_http_server(
     request_sender: Res<BrpSender>,
     address: Res<HostAddress>,
     remote_port: Res<HostPort>,
     headers: Res<HostHeaders>,
+    auth_token: Res<HostAuthToken>,
 ) {
     IoTaskPool::get()
         .spawn(server_main(
@@ -207,6 +232,7 @@ fn start_http_server(
             remote_port.0,
             request_sender.clone(),
             headers.0.clone(),
+            auth_token.0.clone(),
         ))
         .detach();
 }
@@ -217,11 +243,13 @@ async fn server_main(
     port: u16,
     request_sender: Sender<BrpMessage>,
     headers: Headers,
+    auth_token: Option<String>,
 ) -> AnyhowResult<()> {
     listen(
         Async::<TcpListener>::bind((address, port))?,
         &request_sender,
         &headers,
+        auth_token.as_deref(),
     )
     .await
 }
@@ -230,15 +258,17 @@ async fn listen(
     listener: Async<TcpListener>,
     request_sender: &Sender<BrpMessage>,
     headers: &Headers,
+    auth_token: Option<&str>,
 ) -> AnyhowResult<()> {
     loop {
         let (client, _) = listener.accept().await?;
 
         let request_sender = request_sender.clone();
         let headers = headers.clone();
+        let auth_token = auth_token.map(str::to_owned);
         IoTaskPool::get()
             .spawn(async move {
-                let _ = handle_client(client, request_sender, headers).await;
+                let _ = handle_client(client, request_sender, headers, auth_token).await;
             })
             .detach();
     }
@@ -248,13 +278,14 @@ async fn handle_client(
     client: Async<TcpStream>,
     request_sender: Sender<BrpMessage>,
     headers: Headers,
+    auth_token: Option<String>,
 ) -> AnyhowResult<()> {
     http1::Builder::new()
         .timer(SmolTimer::new())
         .serve_connection(
             FuturesIo::new(client),
             service::service_fn(|request| {
-                process_request_batch(request, &request_sender, &headers)
+                process_request_batch(request, &request_sender, &headers, auth_token.as_deref())
             }),
         )
         .await?;
@@ -268,7 +299,13 @@ async fn process_request_batch(
     request: Request<Incoming>,
     request_sender: &Sender<BrpMessage>,
     headers: &Headers,
+    auth_token: Option<&str>,
 ) -> AnyhowResult<Response<BrpHttpBody>> {
+    let authorization = request.headers().get(hyper::header::AUTHORIZATION);
+    if !access::is_authorized(auth_token, authorization.map(HeaderValue::as_bytes)) {
+        return unauthorized_response(headers);
+    }
+
     let batch_bytes = request.into_body().collect().await?.to_bytes();
     let batch: Result<BrpBatch, _> = serde_json::from_slice(&batch_bytes);
 
@@ -344,41 +381,46 @@ async fn process_request_batch(
     Ok(response)
 }
 
+/// Builds the response to a request that didn't carry the expected authentication token.
+fn unauthorized_response(headers: &Headers) -> AnyhowResult<Response<BrpHttpBody>> {
+    let error = BrpResponse::new(
+        None,
+        Err(BrpError {
+            code: error_codes::ACCESS_DENIED,
+            message: "Missing or invalid authentication token".to_string(),
+            data: None,
+        }),
+    );
+    let mut response = Response::new(BrpHttpBody::Complete(Full::new(Bytes::from(
+        serde_json::to_vec(&error)?,
+    ))));
+    *response.status_mut() = hyper::StatusCode::UNAUTHORIZED;
+    response.headers_mut().insert(
+        hyper::header::CONTENT_TYPE,
+        HeaderValue::from_static("application/json"),
+    );
+    response.headers_mut().insert(
+        hyper::header::WWW_AUTHENTICATE,
+        HeaderValue::from_static("Bearer"),
+    );
+    for (key, value) in &headers.headers {
+        response.headers_mut().insert(key, value.clone());
+    }
+    Ok(response)
+}
+
 /// A helper function for the Bevy Remote Protocol server that processes a single
 /// request coming from a client.
 async fn process_single_request(
     request: Value,
     request_sender: &Sender<BrpMessage>,
 ) -> AnyhowResult<BrpHttpResponse<BrpResponse, BrpStream>> {
-    // Reach in and get the request ID early so that we can report it even when parsing fails.
-    let id = request.as_object().and_then(|map| map.get("id")).cloned();
-
-    let request: BrpRequest = match serde_json::from_value(request) {
-        Ok(v) => v,
-        Err(err) => {
-            return Ok(BrpHttpResponse::Complete(BrpResponse::new(
-                id,
-                Err(BrpError {
-                    code: error_codes::INVALID_REQUEST,
-                    message: err.to_string(),
-                    data: None,
-                }),
-            )));
-        }
+    let request = match BrpRequest::from_value(request) {
+        Ok(request) => request,
+        Err(response) => return Ok(BrpHttpResponse::Complete(response)),
     };
 
-    if request.jsonrpc != "2.0" {
-        return Ok(BrpHttpResponse::Complete(BrpResponse::new(
-            id,
-            Err(BrpError {
-                code: error_codes::INVALID_REQUEST,
-                message: String::from("JSON-RPC request requires `\"jsonrpc\": \"2.0\"`"),
-                data: None,
-            }),
-        )));
-    }
-
-    let watch = request.method.contains("+watch");
+    let watch = request.is_watching();
     let size = if watch { 8 } else { 1 };
     let (result_sender, result_receiver) = async_channel::bounded(size);
 
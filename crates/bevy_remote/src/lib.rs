@@ -4,7 +4,9 @@
 //! starting any transports. To start accepting remote connections you will need to
 //! add a second plugin like the [`RemoteHttpPlugin`](http::RemoteHttpPlugin) to enable communication
 //! over HTTP. These *remote clients* can inspect and alter the state of the
-//! entity-component system.
+//! entity-component system. The [`RemoteWebSocketPlugin`](websocket::RemoteWebSocketPlugin)
+//! and [`RemoteStdioPlugin`](stdio::RemoteStdioPlugin) keep a connection open instead, which
+//! lets a client run several watching requests at the same time.
 //!
 //! The Bevy Remote Protocol is based on the JSON-RPC 2.0 protocol.
 //!
@@ -143,8 +145,25 @@
 //!     on entities in order for them to be included in results.
 //!   - `without` (optional): An array of fully-qualified type names of components that must *not* be
 //!     present on entities in order for them to be included in results.
+//!   - `values` (optional): An array of conditions on the values of component fields that entities
+//!     must satisfy in order to be included in results. Each condition is an object containing:
+//!     - `component`: The fully-qualified type name of the component.
+//!     - `path` (optional): The [reflection path] of the field within the component, e.g.
+//!       `translation.y`. Defaults to the whole component.
+//!     - `op`: One of `==`, `!=`, `<`, `<=`, `>` and `>=`. Only numbers, strings and booleans can
+//!       be ordered.
+//!     - `value`: The serialized value to compare the field to.
+//!   - `changed_since` (optional): A change tick, as returned by `bevy/change_tick`. Only entities
+//!     where one of the `components` or `option` components was added or changed after it are
+//!     included in results.
 //! - `strict` (optional): A flag to enable strict mode which will fail if any one of the components
 //!   is not present or can not be reflected. Defaults to false.
+//! - `sort` (optional): An array of keys to sort the results by, in order of priority. Each key is
+//!   an object containing the `component` and optional `path` of a field, like the `values`
+//!   conditions, and an optional `descending` flag. Entities without the field come last. Values of
+//!   different kinds are ordered as null, booleans, numbers, strings, arrays and objects.
+//! - `offset` (optional): The number of results to skip, after sorting. Defaults to 0.
+//! - `limit` (optional): The maximum number of results to return, after sorting.
 //!
 //! `result`: An array, each of which is an object containing:
 //! - `entity`: The ID of a query-matching entity.
@@ -155,6 +174,15 @@
 //!
 //!
 //!
+//! ### `bevy/change_tick`
+//!
+//! Retrieve the current change tick of the world, to be passed as `changed_since` to a later
+//! `bevy/query` request in order to only receive the entities that changed in the meantime.
+//!
+//! `params`: None.
+//!
+//! `result`: The change tick, as a number.
+//!
 //! ### `bevy/spawn`
 //!
 //! Create a new entity with the provided components and return the resulting entity ID.
@@ -207,6 +235,23 @@
 //!
 //! `result`: null.
 //!
+//! ### `bevy/call_method`
+//!
+//! Call a reflected method of a component, registered with `#[reflect(methods(...))]`.
+//! Requires the `reflect_functions` feature.
+//!
+//! `params`:
+//! - `entity`: The ID of the entity with the component.
+//! - `component`: The component's [fully-qualified type name].
+//! - `method`: The name of the method.
+//! - `args` (optional): An array of the arguments passed after the component, which the
+//!   method must take by value.
+//!
+//! The method is only called if the [`RemoteAccess`](access::RemoteAccess) allows both the
+//! component and the method, named `<fully-qualified type name>::<method>`.
+//!
+//! `result`: The value returned by the method, or null if it returns nothing.
+//!
 //! ### `bevy/reparent`
 //!
 //! Assign a new parent to one or more entities.
@@ -321,6 +366,106 @@
 //!
 //! `result`: An array of [fully-qualified type names] of registered resource types.
 //!
+//! ### `bevy/send_event`
+//!
+//! Send a buffered event, to be read by `EventReader`s. The event type must be registered with
+//! `#[reflect(Event)]` and added to the app with `App::add_event`.
+//!
+//! `params`:
+//! - `event`: The [fully-qualified type name] of the event to send.
+//! - `value`: The serialized value of the event.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/trigger`
+//!
+//! Trigger an event, running the observers watching for it. The event type must be registered
+//! with `#[reflect(Event)]`.
+//!
+//! `params`:
+//! - `event`: The [fully-qualified type name] of the event to trigger.
+//! - `value`: The serialized value of the event.
+//! - `targets` (optional): An array of entity IDs to trigger the event for.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/events+watch`
+//!
+//! Watch for buffered events of a type, like `bevy/send_event` sends them.
+//!
+//! `params`:
+//! - `event`: The [fully-qualified type name] of the event to watch.
+//!
+//! `result`: An array of the serialized events that were sent since the last response. The first
+//! response contains the events that are still buffered when the watch starts. Frames without
+//! events produce no response.
+//!
+//! ### `bevy/list_schedules`
+//!
+//! List the labels of all schedules in the world. This method has no parameters.
+//!
+//! Schedules that are running while the request is processed, such as `Main` and
+//! [`RemoteLast`], are not listed and can't be used by the other schedule methods.
+//!
+//! `result`: An array of schedule labels.
+//!
+//! ### `bevy/list_systems`
+//!
+//! List the systems of a schedule. The schedule must have run at least once.
+//!
+//! `params`:
+//! - `schedule`: The label of the schedule, as returned by `bevy/list_schedules`.
+//!
+//! `result`: An array of objects with the following fields:
+//! - `id`: The ID of the system within the schedule.
+//! - `name`: The name of the system.
+//! - `enabled`: Whether the system runs when the schedule runs.
+//!
+//! ### `bevy/set_system_enabled`
+//!
+//! Enable or disable a system. Disabled systems are skipped when their schedule runs.
+//!
+//! `params`:
+//! - `schedule`: The label of the schedule containing the system.
+//! - `system`: The ID of the system, as returned by `bevy/list_systems`.
+//! - `enabled`: Whether the system should run.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/stepping/*`
+//!
+//! With the `bevy_debug_stepping` feature, these methods control [`Stepping`]:
+//!
+//! - `bevy/stepping/status`: Returns an object with the fields `enabled`, `schedules` (the
+//!   labels of the stepped schedules in the order they run) and `cursor` (an object with the
+//!   `schedule` and `system` that run next, or null).
+//! - `bevy/stepping/enable`: Enables stepping at the start of the next frame. The optional
+//!   `schedules` parameter is an array of schedule labels that are added to the stepped schedules.
+//! - `bevy/stepping/disable`: Disables stepping.
+//! - `bevy/stepping/step`: Runs the next system during the next frame.
+//! - `bevy/stepping/continue`: Runs the remaining systems of the stepping frame, up to the next
+//!   breakpoint, during the next frame.
+//! - `bevy/stepping/set_breakpoint` and `bevy/stepping/clear_breakpoint`: Set or clear a
+//!   breakpoint on the system with the ID `system` in the schedule `schedule`.
+//!
+//! Except for `bevy/stepping/status`, these methods return null.
+//!
+//! [`Stepping`]: bevy_ecs::schedule::Stepping
+//!
+//! ## Access control
+//!
+//! By default, clients may call every method and access every type. The
+//! [`RemoteAccess`](access::RemoteAccess) resource, which can be set with
+//! [`RemotePlugin::with_access`], restricts this: denied methods and requests naming denied
+//! component, resource or event types fail with an [`ACCESS_DENIED`](error_codes::ACCESS_DENIED)
+//! error, and denied types are left out of listings. For example, a playtest build can use
+//! [`RemoteAccess::read_only`](access::RemoteAccess::read_only) to let clients inspect the world
+//! without changing it.
+//!
+//! The HTTP and WebSocket transports can additionally require clients to authenticate with a
+//! token, see `RemoteHttpPlugin::with_auth_token` and `RemoteWebSocketPlugin::with_auth_token`.
+//! The stdio transport is only reachable by the parent process and doesn't use a token.
+//!
 //! ## Custom methods
 //!
 //! In addition to the provided methods, the Bevy Remote Protocol can be extended to include custom
@@ -363,6 +508,9 @@
 //! [the `serde` documentation]: https://serde.rs/
 //! [fully-qualified type names]: bevy_reflect::TypePath::type_path
 //! [fully-qualified type name]: bevy_reflect::TypePath::type_path
+//! [reflection path]: bevy_reflect::GetPath
+
+extern crate alloc;
 
 use async_channel::{Receiver, Sender};
 use bevy_app::{prelude::*, MainScheduleOrder};
@@ -380,10 +528,22 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::sync::RwLock;
 
+use crate::access::RemoteAccess;
+
+pub mod access;
 pub mod builtin_methods;
+#[cfg(all(
+    not(target_family = "wasm"),
+    any(feature = "websocket", feature = "stdio")
+))]
+mod connection;
 #[cfg(feature = "http")]
 pub mod http;
 pub mod schemas;
+#[cfg(feature = "stdio")]
+pub mod stdio;
+#[cfg(feature = "websocket")]
+pub mod websocket;
 
 const CHANNEL_SIZE: usize = 16;
 
@@ -396,6 +556,8 @@ const CHANNEL_SIZE: usize = 16;
 pub struct RemotePlugin {
     /// The verbs that the server will recognize and respond to.
     methods: RwLock<Vec<(String, RemoteMethodHandler)>>,
+    /// The methods and types that clients may use.
+    access: RemoteAccess,
 }
 
 impl RemotePlugin {
@@ -404,9 +566,20 @@ impl RemotePlugin {
     fn empty() -> Self {
         Self {
             methods: RwLock::new(vec![]),
+            access: RemoteAccess::default(),
         }
     }
 
+    /// Restrict the methods and types that clients may use, see [`RemoteAccess`].
+    ///
+    /// By default, everything is allowed. The access can be changed at runtime through the
+    /// [`RemoteAccess`] resource.
+    #[must_use]
+    pub fn with_access(mut self, access: RemoteAccess) -> Self {
+        self.access = access;
+        self
+    }
+
     /// Add a remote method to the plugin using the given `name` and `handler`.
     #[must_use]
     pub fn with_method<M>(
@@ -438,7 +611,7 @@ impl RemotePlugin {
 
 impl Default for RemotePlugin {
     fn default() -> Self {
-        Self::empty()
+        let plugin = Self::empty()
             .with_method(
                 builtin_methods::BRP_GET_METHOD,
                 builtin_methods::process_remote_get_request,
@@ -447,6 +620,10 @@ impl Default for RemotePlugin {
                 builtin_methods::BRP_QUERY_METHOD,
                 builtin_methods::process_remote_query_request,
             )
+            .with_method(
+                builtin_methods::BRP_CHANGE_TICK_METHOD,
+                builtin_methods::process_remote_change_tick_request,
+            )
             .with_method(
                 builtin_methods::BRP_SPAWN_METHOD,
                 builtin_methods::process_remote_spawn_request,
@@ -511,6 +688,69 @@ impl Default for RemotePlugin {
                 builtin_methods::BRP_REGISTRY_SCHEMA_METHOD,
                 builtin_methods::export_registry_types,
             )
+            .with_method(
+                builtin_methods::BRP_LIST_SCHEDULES_METHOD,
+                builtin_methods::process_remote_list_schedules_request,
+            )
+            .with_method(
+                builtin_methods::BRP_LIST_SYSTEMS_METHOD,
+                builtin_methods::process_remote_list_systems_request,
+            )
+            .with_method(
+                builtin_methods::BRP_SET_SYSTEM_ENABLED_METHOD,
+                builtin_methods::process_remote_set_system_enabled_request,
+            )
+            .with_method(
+                builtin_methods::BRP_SEND_EVENT_METHOD,
+                builtin_methods::process_remote_send_event_request,
+            )
+            .with_method(
+                builtin_methods::BRP_TRIGGER_METHOD,
+                builtin_methods::process_remote_trigger_request,
+            )
+            .with_watching_method(
+                builtin_methods::BRP_EVENTS_AND_WATCH_METHOD,
+                builtin_methods::process_remote_events_watching_request,
+            );
+
+        #[cfg(feature = "reflect_functions")]
+        let plugin = plugin.with_method(
+            builtin_methods::BRP_CALL_METHOD_METHOD,
+            builtin_methods::process_remote_call_method_request,
+        );
+
+        #[cfg(feature = "bevy_debug_stepping")]
+        let plugin = plugin
+            .with_method(
+                builtin_methods::BRP_STEPPING_STATUS_METHOD,
+                builtin_methods::process_remote_stepping_status_request,
+            )
+            .with_method(
+                builtin_methods::BRP_STEPPING_ENABLE_METHOD,
+                builtin_methods::process_remote_stepping_enable_request,
+            )
+            .with_method(
+                builtin_methods::BRP_STEPPING_DISABLE_METHOD,
+                builtin_methods::process_remote_stepping_disable_request,
+            )
+            .with_method(
+                builtin_methods::BRP_STEPPING_STEP_METHOD,
+                builtin_methods::process_remote_stepping_step_request,
+            )
+            .with_method(
+                builtin_methods::BRP_STEPPING_CONTINUE_METHOD,
+                builtin_methods::process_remote_stepping_continue_request,
+            )
+            .with_method(
+                builtin_methods::BRP_STEPPING_SET_BREAKPOINT_METHOD,
+                builtin_methods::process_remote_stepping_set_breakpoint_request,
+            )
+            .with_method(
+                builtin_methods::BRP_STEPPING_CLEAR_BREAKPOINT_METHOD,
+                builtin_methods::process_remote_stepping_clear_breakpoint_request,
+            );
+
+        plugin
     }
 }
 
@@ -539,6 +779,7 @@ impl Plugin for RemotePlugin {
             .insert_after(Last, RemoteLast);
 
         app.insert_resource(remote_methods)
+            .insert_resource(self.access.clone())
             .init_resource::<RemoteWatchingRequests>()
             .add_systems(PreStartup, setup_mailbox_channel)
             .configure_sets(
@@ -645,7 +886,21 @@ impl RemoteMethods {
 
 /// Holds the [`BrpMessage`]'s of all ongoing watching requests along with their handlers.
 #[derive(Debug, Resource, Default)]
-pub struct RemoteWatchingRequests(Vec<(BrpMessage, RemoteWatchingMethodSystemId)>);
+pub struct RemoteWatchingRequests {
+    requests: Vec<(WatchingRequestId, BrpMessage, RemoteWatchingMethodSystemId)>,
+    next_id: u64,
+}
+
+/// A unique id of an ongoing watching request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WatchingRequestId(u64);
+
+/// The [`WatchingRequestId`] of the watching request whose handler is currently running.
+///
+/// Watching handlers can use it to keep state per request, e.g. to only send the changes
+/// that happened after the request was made.
+#[derive(Debug, Resource, Clone, Copy, PartialEq, Eq, Deref)]
+pub struct CurrentWatchingRequest(pub WatchingRequestId);
 
 /// A single request from a Bevy Remote Protocol client to the server,
 /// serialized in JSON.
@@ -706,6 +961,48 @@ pub struct BrpRequest {
     pub params: Option<Value>,
 }
 
+impl BrpRequest {
+    /// Parses a request sent by a client, which is useful when implementing a custom transport.
+    ///
+    /// If the request is invalid, the error response that should be sent to the client is returned instead.
+    pub fn from_value(request: Value) -> Result<Self, BrpResponse> {
+        // Reach in and get the request ID early so that we can report it even when parsing fails.
+        let id = request.as_object().and_then(|map| map.get("id")).cloned();
+
+        let request: BrpRequest = match serde_json::from_value(request) {
+            Ok(v) => v,
+            Err(err) => {
+                return Err(BrpResponse::new(
+                    id,
+                    Err(BrpError {
+                        code: error_codes::INVALID_REQUEST,
+                        message: err.to_string(),
+                        data: None,
+                    }),
+                ));
+            }
+        };
+
+        if request.jsonrpc != "2.0" {
+            return Err(BrpResponse::new(
+                id,
+                Err(BrpError {
+                    code: error_codes::INVALID_REQUEST,
+                    message: String::from("JSON-RPC request requires `\"jsonrpc\": \"2.0\"`"),
+                    data: None,
+                }),
+            ));
+        }
+
+        Ok(request)
+    }
+
+    /// Returns `true` if the request is for a watching method, which responds with a stream of results.
+    pub fn is_watching(&self) -> bool {
+        self.method.contains("+watch")
+    }
+}
+
 /// A response according to BRP.
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct BrpResponse {
@@ -764,6 +1061,16 @@ pub struct BrpError {
 }
 
 impl BrpError {
+    /// Access to a method or type was denied.
+    #[must_use]
+    pub fn access_denied(name: &str) -> Self {
+        Self {
+            code: error_codes::ACCESS_DENIED,
+            message: format!("Access to `{name}` is denied"),
+            data: None,
+        }
+    }
+
     /// Entity wasn't found.
     #[must_use]
     pub fn entity_not_found(entity: Entity) -> Self {
@@ -814,6 +1121,16 @@ impl BrpError {
         }
     }
 
+    /// An arbitrary event error. Possibly related to reflection.
+    #[must_use]
+    pub fn event_error<E: ToString>(error: E) -> Self {
+        Self {
+            code: error_codes::EVENT_ERROR,
+            message: error.to_string(),
+            data: None,
+        }
+    }
+
     /// An arbitrary internal error.
     #[must_use]
     pub fn internal<E: ToString>(error: E) -> Self {
@@ -833,6 +1150,26 @@ impl BrpError {
             data: None,
         }
     }
+
+    /// Schedule wasn't found in the world.
+    #[must_use]
+    pub fn schedule_not_found(schedule: &str) -> Self {
+        Self {
+            code: error_codes::SCHEDULE_NOT_FOUND,
+            message: format!("Schedule `{schedule}` not found"),
+            data: None,
+        }
+    }
+
+    /// System wasn't found in a schedule.
+    #[must_use]
+    pub fn system_not_found(system: usize, schedule: &str) -> Self {
+        Self {
+            code: error_codes::SYSTEM_NOT_FOUND,
+            message: format!("System {system} not found in schedule `{schedule}`"),
+            data: None,
+        }
+    }
 }
 
 /// Error codes used by BRP.
@@ -857,6 +1194,9 @@ pub mod error_codes {
 
     // Bevy errors (i.e. application errors)
 
+    /// Access to a method or type was denied by the [`RemoteAccess`](crate::access::RemoteAccess).
+    pub const ACCESS_DENIED: i16 = -23001;
+
     /// Entity not found.
     pub const ENTITY_NOT_FOUND: i16 = -23401;
 
@@ -874,6 +1214,15 @@ pub mod error_codes {
 
     /// Could not find resource in the world.
     pub const RESOURCE_NOT_PRESENT: i16 = -23502;
+
+    /// Could not find schedule in the world.
+    pub const SCHEDULE_NOT_FOUND: i16 = -23601;
+
+    /// Could not find system in the schedule.
+    pub const SYSTEM_NOT_FOUND: i16 = -23602;
+
+    /// Could not reflect, find or send event.
+    pub const EVENT_ERROR: i16 = -23701;
 }
 
 /// The result of a request.
@@ -937,6 +1286,16 @@ fn process_remote_requests(world: &mut World) {
     }
 
     while let Ok(message) = world.resource_mut::<BrpReceiver>().try_recv() {
+        if world
+            .get_resource::<RemoteAccess>()
+            .is_some_and(|access| !access.is_method_allowed(&message.method))
+        {
+            let _ = message
+                .sender
+                .force_send(Err(BrpError::access_denied(&message.method)));
+            continue;
+        }
+
         // Fetch the handler for the method. If there's no such handler
         // registered, return an error.
         let Some(&handler) = world.resource::<RemoteMethods>().get(&message.method) else {
@@ -965,10 +1324,10 @@ fn process_remote_requests(world: &mut World) {
                 let _ = message.sender.force_send(result);
             }
             RemoteMethodSystemId::Watching(id) => {
-                world
-                    .resource_mut::<RemoteWatchingRequests>()
-                    .0
-                    .push((message, id));
+                let mut requests = world.resource_mut::<RemoteWatchingRequests>();
+                let request_id = WatchingRequestId(requests.next_id);
+                requests.next_id += 1;
+                requests.requests.push((request_id, message, id));
             }
         }
     }
@@ -978,7 +1337,8 @@ fn process_remote_requests(world: &mut World) {
 /// and handles it if so.
 fn process_ongoing_watching_requests(world: &mut World) {
     world.resource_scope::<RemoteWatchingRequests, ()>(|world, requests| {
-        for (message, system_id) in requests.0.iter() {
+        for (request_id, message, system_id) in requests.requests.iter() {
+            world.insert_resource(CurrentWatchingRequest(*request_id));
             let handler_result = process_single_ongoing_watching_request(world, message, system_id);
             let sender_result = match handler_result {
                 Ok(Some(value)) => message.sender.try_send(Ok(value)),
@@ -991,6 +1351,7 @@ fn process_ongoing_watching_requests(world: &mut World) {
                 message.sender.close();
             }
         }
+        world.remove_resource::<CurrentWatchingRequest>();
     });
 }
 
@@ -1009,13 +1370,13 @@ fn process_single_ongoing_watching_request(
 }
 
 fn remove_closed_watching_requests(mut requests: ResMut<RemoteWatchingRequests>) {
-    for i in (0..requests.0.len()).rev() {
-        let Some((message, _)) = requests.0.get(i) else {
+    for i in (0..requests.requests.len()).rev() {
+        let Some((_, message, _)) = requests.requests.get(i) else {
             unreachable!()
         };
 
         if message.sender.is_closed() {
-            requests.0.swap_remove(i);
+            requests.requests.swap_remove(i);
         }
     }
 }
@@ -3,14 +3,20 @@
 use bevy_ecs::reflect::{ReflectComponent, ReflectResource};
 use bevy_platform::collections::HashMap;
 use bevy_reflect::{
-    prelude::ReflectDefault, NamedField, OpaqueInfo, ReflectDeserialize, ReflectSerialize,
-    TypeInfo, TypeRegistration, VariantInfo,
+    attributes::CustomAttributes,
+    prelude::ReflectDefault,
+    validation::{NonEmpty, Pattern, Range, Step, Validator},
+    NamedField, OpaqueInfo, ReflectDeserialize, ReflectSerialize, TypeInfo, TypeRegistration,
+    VariantInfo,
 };
 use core::any::TypeId;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Map, Value};
 
 /// Exports schema info for a given type
+///
+/// The [validation constraints](bevy_reflect::validation) of its fields are exported
+/// along with the fields.
 pub fn export_type(reg: &TypeRegistration) -> (String, JsonSchemaBevyType) {
     (reg.type_info().type_path().to_owned(), reg.into())
 }
@@ -52,7 +58,16 @@ impl From<&TypeRegistration> for JsonSchemaBevyType {
             TypeInfo::Struct(info) => {
                 typed_schema.properties = info
                     .iter()
-                    .map(|field| (field.name().to_owned(), field.ty().ref_type()))
+                    .map(|field| {
+                        let mut schema = field.ty().ref_type();
+                        add_constraints(
+                            &mut schema,
+                            field.custom_attributes(),
+                            field.type_path(),
+                            field.type_info(),
+                        );
+                        (field.name().to_owned(), schema)
+                    })
                     .collect::<HashMap<_, _>>();
                 typed_schema.required = info
                     .iter()
@@ -337,14 +352,84 @@ impl SchemaJsonReference for &bevy_reflect::Type {
 impl SchemaJsonReference for &bevy_reflect::UnnamedField {
     fn ref_type(self) -> Value {
         let path = self.type_path();
-        json!({"type": json!({ "$ref": format!("#/$defs/{path}") })})
+        let mut schema = json!({"type": json!({ "$ref": format!("#/$defs/{path}") })});
+        add_constraints(
+            &mut schema,
+            self.custom_attributes(),
+            path,
+            self.type_info(),
+        );
+        schema
     }
 }
 
 impl SchemaJsonReference for &NamedField {
     fn ref_type(self) -> Value {
         let type_path = self.type_path();
-        json!({"type": json!({ "$ref": format!("#/$defs/{type_path}") }), "typePath": self.name()})
+        let mut schema = json!({"type": json!({ "$ref": format!("#/$defs/{type_path}") }), "typePath": self.name()});
+        add_constraints(
+            &mut schema,
+            self.custom_attributes(),
+            type_path,
+            self.type_info(),
+        );
+        schema
+    }
+}
+
+/// Adds the [validation constraints](bevy_reflect::validation) of a field to its schema.
+///
+/// Constraints are mapped to the matching JSON Schema keywords where there is one.
+/// [`Step`]s are also exported as a Bevy specific `step` keyword, since `multipleOf`
+/// can't express steps that don't start from zero, and [`Validator`]s are exported
+/// by name as a Bevy specific `validator` keyword.
+fn add_constraints(
+    schema: &mut Value,
+    attributes: &CustomAttributes,
+    type_path: &str,
+    type_info: Option<&TypeInfo>,
+) {
+    let Some(schema) = schema.as_object_mut() else {
+        return;
+    };
+
+    let range = attributes.get::<Range>();
+    if let Some(range) = range {
+        if let Some(min) = range.min {
+            schema.insert("minimum".to_owned(), min.into());
+        }
+        if let Some(max) = range.max {
+            schema.insert("maximum".to_owned(), max.into());
+        }
+    }
+    if let Some(&Step(step)) = attributes.get::<Step>() {
+        schema.insert("step".to_owned(), step.into());
+        if range.and_then(|range| range.min).unwrap_or(0.0) == 0.0 {
+            schema.insert("multipleOf".to_owned(), step.into());
+        }
+    }
+    if attributes.contains::<NonEmpty>() {
+        let keyword = match type_info {
+            Some(TypeInfo::List(_) | TypeInfo::Array(_) | TypeInfo::Set(_)) => Some("minItems"),
+            Some(TypeInfo::Map(_)) => Some("minProperties"),
+            _ if matches!(
+                type_path,
+                "alloc::string::String" | "alloc::borrow::Cow<str>" | "&str"
+            ) =>
+            {
+                Some("minLength")
+            }
+            _ => None,
+        };
+        if let Some(keyword) = keyword {
+            schema.insert(keyword.to_owned(), 1.into());
+        }
+    }
+    if let Some(pattern) = attributes.get::<Pattern>() {
+        schema.insert("pattern".to_owned(), pattern.as_str().into());
+    }
+    if let Some(validator) = attributes.get::<Validator>() {
+        schema.insert("validator".to_owned(), validator.name().into());
     }
 }
 
@@ -540,4 +625,84 @@ mod tests {
         });
         assert_eq!(schema_as_value, value);
     }
+
+    #[test]
+    fn reflect_export_constraints() {
+        fn is_even(value: &dyn bevy_reflect::PartialReflect) -> Result<(), String> {
+            match value.try_downcast_ref::<u32>() {
+                Some(value) if value % 2 == 0 => Ok(()),
+                _ => Err("expected an even number".to_owned()),
+            }
+        }
+
+        #[derive(Reflect)]
+        struct Track {
+            #[reflect(@NonEmpty, @Pattern::new("^[a-z]+$"))]
+            name: String,
+            #[reflect(@Range::new(0.0, 1.0), @Step(0.25))]
+            volume: f32,
+            #[reflect(@NonEmpty)]
+            tags: Vec<String>,
+        }
+
+        #[derive(Reflect)]
+        struct Channel(#[reflect(@Range::at_least(1.0), @Step(2.0))] u8);
+
+        #[derive(Reflect)]
+        enum Mode {
+            Repeat {
+                #[reflect(@Validator::new("even", is_even))]
+                count: u32,
+            },
+        }
+
+        let atr = AppTypeRegistry::default();
+        {
+            let mut register = atr.write();
+            register.register::<Track>();
+            register.register::<Channel>();
+            register.register::<Mode>();
+        }
+        let type_registry = atr.read();
+        let export = |type_id| {
+            serde_json::to_value(export_type(type_registry.get(type_id).unwrap()).1).unwrap()
+        };
+
+        let track = export(TypeId::of::<Track>());
+        assert_eq!(
+            track["properties"]["name"],
+            json!({
+                "type": { "$ref": "#/$defs/alloc::string::String" },
+                "minLength": 1,
+                "pattern": "^[a-z]+$",
+            })
+        );
+        assert_eq!(
+            track["properties"]["volume"],
+            json!({
+                "type": { "$ref": "#/$defs/f32" },
+                "minimum": 0.0,
+                "maximum": 1.0,
+                "step": 0.25,
+                "multipleOf": 0.25,
+            })
+        );
+        assert_eq!(track["properties"]["tags"]["minItems"], json!(1));
+
+        let channel = export(TypeId::of::<Channel>());
+        assert_eq!(
+            channel["prefixItems"][0],
+            json!({
+                "type": { "$ref": "#/$defs/u8" },
+                "minimum": 1.0,
+                "step": 2.0,
+            })
+        );
+
+        let mode = export(TypeId::of::<Mode>());
+        assert_eq!(
+            mode["oneOf"][0]["properties"]["count"]["validator"],
+            json!("even")
+        );
+    }
 }
@@ -0,0 +1,76 @@
+//! The BRP transport using JSON-RPC over the standard input and output of the process.
+//!
+//! Adding the [`RemoteStdioPlugin`] to your [`App`] lets a tool that launches your app as a
+//! child process talk to it through its stdin and stdout pipes, without opening a port.
+//!
+//! Every line written to stdin is handled as a JSON-RPC request or batch, and every response
+//! is written to stdout as a single line. Like the WebSocket transport, watching methods push
+//! a response line with the `id` of the watching request for every change until the watch is
+//! stopped with an [`rpc.unwatch`](RPC_UNWATCH_METHOD) request or stdin is closed.
+//!
+//! Other output of the app, such as logs, is written to stdout as well, so clients should
+//! ignore lines that aren't JSON-RPC responses.
+//!
+//! Since only the parent process can reach this transport, it doesn't require an authentication
+//! token. The [`RemoteAccess`](crate::access::RemoteAccess) restrictions still apply.
+
+#![cfg(not(target_family = "wasm"))]
+
+pub use crate::connection::RPC_UNWATCH_METHOD;
+use crate::{connection::BrpConnection, BrpSender};
+use bevy_app::{App, Plugin, Startup};
+use bevy_ecs::system::Res;
+use std::io::{BufRead, Write};
+
+/// The number of responses that are queued before further responses wait for stdout.
+const OUTGOING_CHANNEL_SIZE: usize = 64;
+
+/// Add this plugin to your [`App`] to allow a parent process to inspect and modify entities
+/// through the stdin and stdout of your app. It requires the [`RemotePlugin`](super::RemotePlugin).
+///
+/// This BRP transport cannot be used when targeting WASM.
+#[derive(Default)]
+pub struct RemoteStdioPlugin;
+
+impl Plugin for RemoteStdioPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, start_stdio_transport);
+    }
+}
+
+/// A system that starts the threads reading requests from stdin and writing responses to stdout.
+fn start_stdio_transport(request_sender: Res<BrpSender>) {
+    let (outgoing_sender, outgoing_receiver) =
+        async_channel::bounded::<String>(OUTGOING_CHANNEL_SIZE);
+    let connection = BrpConnection::new(request_sender.clone(), outgoing_sender);
+
+    std::thread::Builder::new()
+        .name("BRP stdin".to_string())
+        .spawn(move || {
+            for line in std::io::stdin().lock().lines() {
+                let Ok(line) = line else {
+                    break;
+                };
+                if !line.trim().is_empty() {
+                    connection.handle_message(&line);
+                }
+            }
+            connection.close();
+        })
+        .expect("failed to spawn the BRP stdin thread");
+
+    std::thread::Builder::new()
+        .name("BRP stdout".to_string())
+        .spawn(move || {
+            while let Ok(response) = outgoing_receiver.recv_blocking() {
+                let mut stdout = std::io::stdout().lock();
+                if writeln!(stdout, "{response}")
+                    .and_then(|()| stdout.flush())
+                    .is_err()
+                {
+                    break;
+                }
+            }
+        })
+        .expect("failed to spawn the BRP stdout thread");
+}
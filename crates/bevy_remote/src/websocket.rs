@@ -0,0 +1,292 @@
+//! The BRP transport using JSON-RPC over WebSocket.
+//!
+//! Adding the [`RemoteWebSocketPlugin`] to your [`App`] causes Bevy to accept
+//! WebSocket connections (by default, on port 15703) while your app is running.
+//!
+//! Clients send JSON-RPC requests as text messages and receive the responses as text
+//! messages on the same connection. Unlike the HTTP transport, a single connection can
+//! run any number of watching methods (`bevy/get+watch`, `bevy/list+watch`, etc.) at the
+//! same time: every change is pushed to the client as a response carrying the `id` of the
+//! watching request. A watch runs until the connection is closed or the client sends an
+//! [`rpc.unwatch`](RPC_UNWATCH_METHOD) request with the `id` of the watch.
+//!
+//! If the server is given a token with [`RemoteWebSocketPlugin::with_auth_token`], the handshake
+//! must carry it, either in an `Authorization: Bearer <token>` header or, for clients such as
+//! browsers that can't set headers, in an `access_token` query parameter.
+
+#![cfg(not(target_family = "wasm"))]
+
+pub use crate::connection::RPC_UNWATCH_METHOD;
+use crate::{access, connection::BrpConnection, BrpMessage, BrpSender};
+use alloc::borrow::Cow;
+use anyhow::Result as AnyhowResult;
+use async_channel::Sender;
+use async_io::Async;
+use async_tungstenite::{
+    accept_hdr_async_with_config,
+    tungstenite::{
+        handshake::server::{Callback, ErrorResponse, Request, Response},
+        http::{header, StatusCode},
+        protocol::{frame::coding::CloseCode, CloseFrame, WebSocketConfig},
+        Message,
+    },
+};
+use bevy_app::{App, Plugin, Startup};
+use bevy_ecs::system::Res;
+use bevy_tasks::{futures_lite::StreamExt, IoTaskPool};
+use core::net::{IpAddr, Ipv4Addr};
+use percent_encoding::percent_decode_str;
+use std::net::{TcpListener, TcpStream};
+
+/// The default port that Bevy will listen on for WebSocket connections.
+///
+/// This is the port after the default HTTP port.
+pub const DEFAULT_PORT: u16 = 15703;
+
+/// The default host address that Bevy will use for its WebSocket server.
+pub const DEFAULT_ADDR: IpAddr = IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1));
+
+/// The largest message a client may send, in bytes.
+const MAX_MESSAGE_SIZE: usize = 16 * 1024 * 1024;
+
+/// The number of messages that are queued for a client before further responses wait.
+const OUTGOING_CHANNEL_SIZE: usize = 64;
+
+/// Add this plugin to your [`App`] to allow remote connections over WebSocket to inspect and
+/// modify entities. It requires the [`RemotePlugin`](super::RemotePlugin).
+///
+/// This BRP transport cannot be used when targeting WASM.
+///
+/// The defaults are:
+/// - [`DEFAULT_ADDR`] : 127.0.0.1.
+/// - [`DEFAULT_PORT`] : 15703.
+pub struct RemoteWebSocketPlugin {
+    /// The address that Bevy will bind to.
+    address: IpAddr,
+    /// The port that Bevy will listen on.
+    port: u16,
+    /// The token that clients must authenticate with, if any.
+    auth_token: Option<String>,
+}
+
+impl Default for RemoteWebSocketPlugin {
+    fn default() -> Self {
+        Self {
+            address: DEFAULT_ADDR,
+            port: DEFAULT_PORT,
+            auth_token: None,
+        }
+    }
+}
+
+impl Plugin for RemoteWebSocketPlugin {
+    fn build(&self, app: &mut App) {
+        let address = self.address;
+        let port = self.port;
+        let auth_token = self.auth_token.clone();
+        app.add_systems(Startup, move |request_sender: Res<BrpSender>| {
+            IoTaskPool::get()
+                .spawn(server_main(
+                    address,
+                    port,
+                    request_sender.clone(),
+                    auth_token.clone(),
+                ))
+                .detach();
+        });
+    }
+}
+
+impl RemoteWebSocketPlugin {
+    /// Set the IP address that the server will use.
+    #[must_use]
+    pub fn with_address(mut self, address: impl Into<IpAddr>) -> Self {
+        self.address = address.into();
+        self
+    }
+
+    /// Set the remote port that the server will listen on.
+    #[must_use]
+    pub fn with_port(mut self, port: u16) -> Self {
+        self.port = port;
+        self
+    }
+
+    /// Require clients to authenticate with the given token.
+    ///
+    /// The handshake must carry the token in an `Authorization: Bearer <token>` header or a
+    /// percent-encoded `access_token` query parameter, otherwise it's rejected with
+    /// `401 Unauthorized`. The token is sent in plain text, so it should only be relied on in
+    /// trusted networks or behind a TLS-terminating proxy.
+    #[must_use]
+    pub fn with_auth_token(mut self, token: impl Into<String>) -> Self {
+        self.auth_token = Some(token.into());
+        self
+    }
+}
+
+/// The Bevy Remote Protocol WebSocket server main loop.
+async fn server_main(
+    address: IpAddr,
+    port: u16,
+    request_sender: Sender<BrpMessage>,
+    auth_token: Option<String>,
+) -> AnyhowResult<()> {
+    let listener = Async::<TcpListener>::bind((address, port))?;
+    loop {
+        let (client, _) = listener.accept().await?;
+
+        let request_sender = request_sender.clone();
+        let auth_token = auth_token.clone();
+        IoTaskPool::get()
+            .spawn(async move {
+                let _ = handle_client(client, request_sender, auth_token.as_deref()).await;
+            })
+            .detach();
+    }
+}
+
+async fn handle_client(
+    client: Async<TcpStream>,
+    request_sender: Sender<BrpMessage>,
+    auth_token: Option<&str>,
+) -> AnyhowResult<()> {
+    let config = WebSocketConfig::default().max_message_size(Some(MAX_MESSAGE_SIZE));
+    let stream = accept_hdr_async_with_config(client, Authorize(auth_token), Some(config)).await?;
+    let (mut writer, mut reader) = stream.split();
+
+    let (outgoing_sender, outgoing_receiver) =
+        async_channel::bounded::<Message>(OUTGOING_CHANNEL_SIZE);
+    let connection = BrpConnection::new(request_sender, outgoing_sender.clone());
+
+    let write_task = IoTaskPool::get().spawn(async move {
+        while let Ok(message) = outgoing_receiver.recv().await {
+            let close = message.is_close();
+            if writer.send(message).await.is_err() || close {
+                break;
+            }
+        }
+        outgoing_receiver.close();
+    });
+
+    // Pings are answered and closing handshakes are completed by the WebSocket stream itself.
+    let mut result = Ok(());
+    while let Some(message) = reader.next().await {
+        match message {
+            Ok(Message::Text(text)) => connection.handle_message(text.as_str()),
+            Ok(Message::Binary(bytes)) => match core::str::from_utf8(&bytes) {
+                Ok(text) => connection.handle_message(text),
+                Err(error) => {
+                    result = Err(error.into());
+                    break;
+                }
+            },
+            Ok(Message::Close(_)) => break,
+            Ok(_) => {}
+            Err(error) => {
+                result = Err(error.into());
+                break;
+            }
+        }
+    }
+    if result.is_err() {
+        let _ = outgoing_sender
+            .send(Message::Close(Some(CloseFrame {
+                code: CloseCode::Protocol,
+                reason: Default::default(),
+            })))
+            .await;
+    }
+    connection.close();
+    outgoing_sender.close();
+    write_task.await;
+    result
+}
+
+/// Checks the opening handshake of a WebSocket connection.
+///
+/// If an auth token is given, the handshake is rejected with `401 Unauthorized` unless it
+/// carries that token.
+struct Authorize<'a>(Option<&'a str>);
+
+impl Callback for Authorize<'_> {
+    fn on_request(self, request: &Request, response: Response) -> Result<Response, ErrorResponse> {
+        let Authorize(auth_token) = self;
+        let authorization = request
+            .headers()
+            .get(header::AUTHORIZATION)
+            .map(header::HeaderValue::as_bytes);
+        let authorized = access::is_authorized(auth_token, authorization)
+            || auth_token.is_some_and(|auth_token| {
+                request
+                    .uri()
+                    .query()
+                    .and_then(query_access_token)
+                    .is_some_and(|token| access::tokens_match(auth_token.as_bytes(), &token))
+            });
+        if authorized {
+            return Ok(response);
+        }
+        let mut response = ErrorResponse::new(None);
+        *response.status_mut() = StatusCode::UNAUTHORIZED;
+        response.headers_mut().insert(
+            header::WWW_AUTHENTICATE,
+            header::HeaderValue::from_static("Bearer"),
+        );
+        Err(response)
+    }
+}
+
+/// Returns the percent-decoded value of the `access_token` parameter in the query of a handshake.
+fn query_access_token(query: &str) -> Option<Cow<'_, [u8]>> {
+    query
+        .split('&')
+        .find_map(|parameter| parameter.strip_prefix("access_token="))
+        .map(|token| percent_decode_str(token).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Authorize;
+    use async_tungstenite::tungstenite::{
+        handshake::server::{Callback, Request, Response},
+        http::StatusCode,
+    };
+
+    #[test]
+    fn handshake_checks_the_auth_token() {
+        let authorize_with = |target: &str, authorization: Option<&str>| {
+            let mut request = Request::builder().uri(target);
+            if let Some(authorization) = authorization {
+                request = request.header("Authorization", authorization);
+            }
+            Authorize(Some("secret"))
+                .on_request(&request.body(()).unwrap(), Response::new(()))
+                .err()
+        };
+
+        let response = authorize_with("/", None).unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(response.headers()["WWW-Authenticate"], "Bearer");
+        assert!(authorize_with("/?access_token=wrong", None).is_some());
+        assert!(authorize_with("/", Some("Bearer wrong")).is_some());
+
+        assert!(authorize_with("/?v=1&access_token=secret", None).is_none());
+        assert!(authorize_with("/", Some("Bearer secret")).is_none());
+
+        // Tokens in the query are percent-decoded.
+        let response = Authorize(Some("a+b/c=")).on_request(
+            &Request::builder()
+                .uri("/?access_token=a%2Bb%2Fc%3D")
+                .body(())
+                .unwrap(),
+            Response::new(()),
+        );
+        assert!(response.is_ok());
+
+        let request = Request::builder().uri("/").body(()).unwrap();
+        assert!(Authorize(None)
+            .on_request(&request, Response::new(()))
+            .is_ok());
+    }
+}
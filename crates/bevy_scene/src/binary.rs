@@ -0,0 +1,461 @@
+use crate::{
+    migration::scene_version,
+    ron,
+    scene_loader::redirect_scene_paths,
+    serde::{deserialize_scene_with_version, SceneDeserializer},
+    DynamicScene,
+};
+use alloc::{string::String, vec::Vec};
+use bevy_asset::{
+    io::{Reader, Writer},
+    saver::{AssetSaver, SavedAsset},
+    AssetLoader, AsyncWriteExt, LoadContext,
+};
+use bevy_ecs::{
+    reflect::AppTypeRegistry,
+    world::{FromWorld, World},
+};
+use bevy_reflect::{TypeRegistry, TypeRegistryArc};
+use serde::de::DeserializeSeed;
+use thiserror::Error;
+
+/// The magic number at the start of every binary scene file.
+const BINARY_SCENE_MAGIC: u64 = 0x4e45_4353_5942_0001;
+
+/// The version of the binary scene format written by [`DynamicScene::serialize_binary`].
+/// Files with a different version are rejected by [`BinarySceneLoader`].
+pub const BINARY_SCENE_VERSION: u64 = 2;
+
+/// The magic number, the format version and the [scene version](crate::scene_version).
+const HEADER_LEN: usize = 20;
+
+/// Possible errors that can be produced when reading or writing binary scenes.
+#[derive(Error, Debug)]
+pub enum BinarySceneError {
+    /// The file does not start with the binary scene magic number.
+    #[error("expected a binary scene file")]
+    WrongFileType,
+    /// The file was written with an unsupported version of the binary scene format.
+    #[error("expected binary scene version {BINARY_SCENE_VERSION} but found version {found}")]
+    WrongVersion {
+        /// The version of the file.
+        found: u64,
+    },
+    /// The scene was written with a newer [scene version](crate::scene_version) than the one of the type registry.
+    #[error("scene version {found} is newer than the current scene version {current}")]
+    NewerSceneVersion {
+        /// The scene version of the file.
+        found: u32,
+        /// The current scene version of the type registry.
+        current: u32,
+    },
+    /// The scene could not be encoded or decoded.
+    #[error("Could not encode or decode binary scene: {0}")]
+    Postcard(#[from] postcard::Error),
+    /// An [IO Error](std::io::Error)
+    #[error("Error while trying to read or write the scene file: {0}")]
+    Io(#[from] std::io::Error),
+    /// The scene could not be written as RON.
+    #[error("Could not write RON: {0}")]
+    Ron(#[from] ron::Error),
+    /// The scene could not be parsed from RON.
+    #[error("Could not parse RON: {0}")]
+    RonSpannedError(#[from] ron::error::SpannedError),
+}
+
+impl DynamicScene {
+    /// Serialize this dynamic scene into the compact binary scene format (`.scn.bin`).
+    ///
+    /// The binary format stores the same data as [`DynamicScene::serialize`], but is considerably smaller and faster
+    /// to load, which makes it a good fit for shipping scenes that were authored in RON.
+    /// To deserialize the scene, use the [`BinarySceneLoader`] or [`DynamicScene::deserialize_binary`].
+    pub fn serialize_binary(&self, registry: &TypeRegistry) -> Result<Vec<u8>, BinarySceneError> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&BINARY_SCENE_MAGIC.to_le_bytes());
+        bytes.extend_from_slice(&BINARY_SCENE_VERSION.to_le_bytes());
+        bytes.extend_from_slice(&scene_version(registry).to_le_bytes());
+        let serializer = crate::serde::SceneSerializer::new(self, registry);
+        Ok(postcard::to_extend(&serializer, bytes)?)
+    }
+
+    /// Deserialize a dynamic scene written by [`DynamicScene::serialize_binary`].
+    pub fn deserialize_binary(
+        bytes: &[u8],
+        registry: &TypeRegistry,
+    ) -> Result<DynamicScene, BinarySceneError> {
+        if bytes.len() < HEADER_LEN || bytes[..8] != BINARY_SCENE_MAGIC.to_le_bytes() {
+            return Err(BinarySceneError::WrongFileType);
+        }
+        let version = u64::from_le_bytes(bytes[8..16].try_into().unwrap());
+        if version != BINARY_SCENE_VERSION {
+            return Err(BinarySceneError::WrongVersion { found: version });
+        }
+        let scene_version_found = u32::from_le_bytes(bytes[16..HEADER_LEN].try_into().unwrap());
+        let current = scene_version(registry);
+        if scene_version_found > current {
+            return Err(BinarySceneError::NewerSceneVersion {
+                found: scene_version_found,
+                current,
+            });
+        }
+        let mut deserializer = postcard::Deserializer::from_bytes(&bytes[HEADER_LEN..]);
+        Ok(deserialize_scene_with_version(
+            &mut deserializer,
+            registry,
+            scene_version_found,
+        )?)
+    }
+}
+
+/// Converts a scene in the RON scene format (`.scn.ron`) to the binary scene format (`.scn.bin`).
+pub fn ron_to_binary(ron: &[u8], registry: &TypeRegistry) -> Result<Vec<u8>, BinarySceneError> {
+    let mut deserializer = ron::de::Deserializer::from_bytes(ron)?;
+    let scene_deserializer = SceneDeserializer {
+        type_registry: registry,
+    };
+    let scene = scene_deserializer
+        .deserialize(&mut deserializer)
+        .map_err(|e| deserializer.span_error(e))?;
+    scene.serialize_binary(registry)
+}
+
+/// Converts a scene in the binary scene format (`.scn.bin`) to the RON scene format (`.scn.ron`).
+pub fn binary_to_ron(bytes: &[u8], registry: &TypeRegistry) -> Result<String, BinarySceneError> {
+    Ok(DynamicScene::deserialize_binary(bytes, registry)?.serialize(registry)?)
+}
+
+/// Asset loader for a Bevy dynamic scene in the binary scene format (`.scn.bin`).
+///
+/// The loader handles assets serialized with [`DynamicScene::serialize_binary`].
+#[derive(Debug)]
+pub struct BinarySceneLoader {
+    type_registry: TypeRegistryArc,
+}
+
+impl FromWorld for BinarySceneLoader {
+    fn from_world(world: &mut World) -> Self {
+        let type_registry = world.resource::<AppTypeRegistry>();
+        BinarySceneLoader {
+            type_registry: type_registry.0.clone(),
+        }
+    }
+}
+
+impl AssetLoader for BinarySceneLoader {
+    type Asset = DynamicScene;
+    type Settings = ();
+    type Error = BinarySceneError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let mut scene = DynamicScene::deserialize_binary(&bytes, &self.type_registry.read())?;
+        redirect_scene_paths(&mut scene, load_context);
+        Ok(scene)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["scn.bin"]
+    }
+}
+
+/// Asset saver that writes a [`DynamicScene`] in the binary scene format, to be loaded with the
+/// [`BinarySceneLoader`].
+///
+/// Combined with the [`SceneLoader`](crate::SceneLoader) in an asset processor, this converts scenes authored in RON
+/// into binary scenes for shipping.
+#[derive(Debug)]
+pub struct BinarySceneSaver {
+    type_registry: TypeRegistryArc,
+}
+
+impl FromWorld for BinarySceneSaver {
+    fn from_world(world: &mut World) -> Self {
+        let type_registry = world.resource::<AppTypeRegistry>();
+        BinarySceneSaver {
+            type_registry: type_registry.0.clone(),
+        }
+    }
+}
+
+impl AssetSaver for BinarySceneSaver {
+    type Asset = DynamicScene;
+    type Settings = ();
+    type OutputLoader = BinarySceneLoader;
+    type Error = BinarySceneError;
+
+    async fn save(
+        &self,
+        writer: &mut Writer,
+        asset: SavedAsset<'_, DynamicScene>,
+        _settings: &(),
+    ) -> Result<(), BinarySceneError> {
+        let bytes = asset.serialize_binary(&self.type_registry.read())?;
+        writer.write_all(&bytes).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{binary_to_ron, ron_to_binary, BinarySceneError};
+    use crate::{DynamicScene, SceneMigrationApp};
+    use alloc::{
+        string::{String, ToString},
+        vec,
+        vec::Vec,
+    };
+    use bevy_app::App;
+    use bevy_ecs::{
+        component::Component,
+        entity::{Entity, EntityHashMap},
+        prelude::{AppTypeRegistry, ReflectComponent, World},
+    };
+    use bevy_platform::collections::HashMap;
+    use bevy_reflect::Reflect;
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Health(u32);
+
+    fn create_world() -> World {
+        let mut world = World::new();
+        let registry = AppTypeRegistry::default();
+        registry.write().register::<(Health, Unit, Follows)>();
+        world.insert_resource(registry);
+        world
+    }
+
+    #[test]
+    fn binary_scene_roundtrip() {
+        let mut world = create_world();
+        world.spawn(Health(10));
+        world.spawn(Health(20));
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+
+        let scene = DynamicScene::from_world(&world);
+        let ron = scene.serialize(&registry).unwrap();
+        let binary = scene.serialize_binary(&registry).unwrap();
+        assert!(binary.len() < ron.len());
+
+        assert_eq!(ron_to_binary(ron.as_bytes(), &registry).unwrap(), binary);
+        assert_eq!(binary_to_ron(&binary, &registry).unwrap(), ron);
+
+        let mut dst_world = create_world();
+        DynamicScene::deserialize_binary(&binary, &registry)
+            .unwrap()
+            .write_to_world(&mut dst_world, &mut EntityHashMap::default())
+            .unwrap();
+        let mut health = dst_world
+            .query::<&Health>()
+            .iter(&dst_world)
+            .map(|health| health.0)
+            .collect::<Vec<_>>();
+        health.sort();
+        assert_eq!(health, [10, 20]);
+    }
+
+    #[derive(Reflect, Default, Debug, Clone, PartialEq)]
+    struct Stats {
+        strength: u8,
+        speed: f32,
+    }
+
+    #[derive(Reflect, Default, Debug, Clone, PartialEq)]
+    enum Mode {
+        #[default]
+        Idle,
+        Moving {
+            speed: f32,
+        },
+        Attacking(u8, Option<String>),
+    }
+
+    #[derive(Component, Reflect, Default, Debug, Clone, PartialEq)]
+    #[reflect(Component)]
+    struct Unit {
+        mode: Mode,
+        name: Option<String>,
+        level: Option<u32>,
+        inventory: HashMap<String, u32>,
+        stats: Stats,
+        history: Vec<Stats>,
+    }
+
+    #[derive(Component, Reflect, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Follows(#[entities] Entity);
+
+    #[test]
+    fn binary_scene_roundtrip_complex_components() {
+        let mut world = create_world();
+        let leader = Unit {
+            mode: Mode::Moving { speed: 2.5 },
+            name: Some("Leader".to_string()),
+            level: None,
+            inventory: [("sword".to_string(), 1), ("arrow".to_string(), 20)].into(),
+            stats: Stats {
+                strength: 8,
+                speed: 1.5,
+            },
+            history: vec![
+                Stats::default(),
+                Stats {
+                    strength: 3,
+                    speed: 0.5,
+                },
+            ],
+        };
+        let follower = Unit {
+            mode: Mode::Attacking(3, Some("Leader".to_string())),
+            level: Some(2),
+            ..Default::default()
+        };
+        let idle = Unit {
+            mode: Mode::Attacking(1, None),
+            ..Default::default()
+        };
+        let leader_entity = world.spawn(leader.clone()).id();
+        world.spawn((follower.clone(), Follows(leader_entity)));
+        world.spawn(idle.clone());
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+
+        let scene = DynamicScene::from_world(&world);
+        let ron = scene.serialize(&registry).unwrap();
+        let binary = scene.serialize_binary(&registry).unwrap();
+        // Map entries may be reordered when converting, so compare the loaded components instead of the bytes.
+        let converted = ron_to_binary(ron.as_bytes(), &registry).unwrap();
+        let converted_ron = binary_to_ron(&binary, &registry).unwrap();
+        let reconverted = ron_to_binary(converted_ron.as_bytes(), &registry).unwrap();
+
+        for binary in [binary, converted, reconverted] {
+            let mut dst_world = create_world();
+            // Offset the entities of the destination world, so that entity references must be mapped.
+            dst_world.spawn_empty();
+            dst_world.spawn_empty();
+            let mut entity_map = EntityHashMap::default();
+            DynamicScene::deserialize_binary(&binary, &registry)
+                .unwrap()
+                .write_to_world(&mut dst_world, &mut entity_map)
+                .unwrap();
+
+            let mapped_leader = entity_map[&leader_entity];
+            assert_eq!(dst_world.get::<Unit>(mapped_leader), Some(&leader));
+            let (follows, unit) = dst_world
+                .query::<(&Follows, &Unit)>()
+                .single(&dst_world)
+                .unwrap();
+            assert_eq!(follows.0, mapped_leader);
+            assert_eq!(unit, &follower);
+            let mut units = dst_world
+                .query::<&Unit>()
+                .iter(&dst_world)
+                .cloned()
+                .collect::<Vec<_>>();
+            units.retain(|unit| unit != &leader && unit != &follower);
+            assert_eq!(units.len(), 1);
+            assert_eq!(units[0], idle);
+        }
+    }
+
+    #[test]
+    fn binary_scene_rejects_other_files() {
+        let world = create_world();
+        let registry = world.resource::<AppTypeRegistry>().read();
+
+        assert!(matches!(
+            DynamicScene::deserialize_binary(b"(entities: {})", &registry),
+            Err(BinarySceneError::WrongFileType)
+        ));
+
+        let mut binary = DynamicScene::default().serialize_binary(&registry).unwrap();
+        binary[8] = 3;
+        assert!(matches!(
+            DynamicScene::deserialize_binary(&binary, &registry),
+            Err(BinarySceneError::WrongVersion { found: 3 })
+        ));
+    }
+
+    /// The `Door` component as it was stored before scene version 1.
+    mod v0 {
+        use bevy_ecs::{component::Component, reflect::ReflectComponent};
+        use bevy_reflect::Reflect;
+
+        #[derive(Component, Reflect)]
+        #[reflect(Component)]
+        #[type_path = "bevy_scene::binary::tests"]
+        pub struct Door {
+            pub size: f32,
+        }
+    }
+
+    #[derive(Reflect)]
+    struct DoorV0 {
+        size: f32,
+    }
+
+    #[derive(Component, Reflect, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Door {
+        width: f32,
+        height: f32,
+    }
+
+    #[test]
+    fn binary_scene_migration() {
+        let mut old_world = World::new();
+        let old_registry = AppTypeRegistry::default();
+        old_registry.write().register::<v0::Door>();
+        old_world.insert_resource(old_registry.clone());
+        old_world.spawn(v0::Door { size: 1.5 });
+        let binary = DynamicScene::from_world(&old_world)
+            .serialize_binary(&old_registry.read())
+            .unwrap();
+
+        let mut app = App::new();
+        app.register_type::<Door>()
+            .register_scene_migration::<Door, DoorV0, Door>(1, |old| Door {
+                width: old.size,
+                height: 2.0,
+            });
+        let registry = app.world().resource::<AppTypeRegistry>().clone();
+        let scene = DynamicScene::deserialize_binary(&binary, &registry.read()).unwrap();
+        let mut world = World::new();
+        scene
+            .write_to_world_with(&mut world, &mut EntityHashMap::default(), &registry)
+            .unwrap();
+        assert_eq!(
+            world.query::<&Door>().single(&world).unwrap(),
+            &Door {
+                width: 1.5,
+                height: 2.0
+            }
+        );
+
+        // Scenes written with the current version are not migrated again.
+        let binary = scene.serialize_binary(&registry.read()).unwrap();
+        let scene = DynamicScene::deserialize_binary(&binary, &registry.read()).unwrap();
+        assert!(scene.entities[0].components[0]
+            .reflect_partial_eq(&Door {
+                width: 1.5,
+                height: 2.0
+            })
+            .unwrap());
+
+        // Scenes written with a newer version can't be read.
+        assert!(matches!(
+            DynamicScene::deserialize_binary(&binary, &old_registry.read()),
+            Err(BinarySceneError::NewerSceneVersion {
+                found: 1,
+                current: 0
+            })
+        ));
+    }
+}
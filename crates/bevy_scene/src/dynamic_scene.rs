@@ -69,6 +69,60 @@ impl DynamicScene {
         world: &mut World,
         entity_map: &mut EntityHashMap<Entity>,
         type_registry: &AppTypeRegistry,
+    ) -> Result<(), SceneSpawnError> {
+        self.write_components_to_world(world, entity_map, type_registry, |_, _| true)?;
+        let type_registry = type_registry.read();
+
+        // Insert resources after all entities have been added to the world.
+        // This ensures the entities are available for the resources to reference during mapping.
+        for resource in &self.resources {
+            let type_info = resource.get_represented_type_info().ok_or_else(|| {
+                SceneSpawnError::NoRepresentedType {
+                    type_path: resource.reflect_type_path().to_string(),
+                }
+            })?;
+            let registration = type_registry.get(type_info.type_id()).ok_or_else(|| {
+                SceneSpawnError::UnregisteredButReflectedType {
+                    type_path: type_info.type_path().to_string(),
+                }
+            })?;
+            let reflect_resource = registration.data::<ReflectResource>().ok_or_else(|| {
+                SceneSpawnError::UnregisteredResource {
+                    type_path: type_info.type_path().to_string(),
+                }
+            })?;
+
+            // If this component references entities in the scene, update
+            // them to the entities in the world.
+            let mut cloned_resource;
+            let partial_reflect_resource = if let Some(map_entities) =
+                registration.data::<ReflectMapEntities>()
+            {
+                cloned_resource = clone_reflect_value(resource.as_partial_reflect(), registration);
+                SceneEntityMapper::world_scope(entity_map, world, |_, mapper| {
+                    map_entities.map_entities(cloned_resource.as_partial_reflect_mut(), mapper);
+                });
+                cloned_resource.as_partial_reflect()
+            } else {
+                resource.as_partial_reflect()
+            };
+
+            // If the world already contains an instance of the given resource
+            // just apply the (possibly) new value, otherwise insert the resource
+            reflect_resource.apply_or_insert(world, partial_reflect_resource, &type_registry);
+        }
+
+        Ok(())
+    }
+
+    /// Writes the components of the dynamic entities for which `filter` returns `true` to the given world, without the
+    /// resources. Entities of the scene that are not in `entity_map` yet are spawned.
+    pub(crate) fn write_components_to_world(
+        &self,
+        world: &mut World,
+        entity_map: &mut EntityHashMap<Entity>,
+        type_registry: &AppTypeRegistry,
+        mut filter: impl FnMut(Entity, &dyn PartialReflect) -> bool,
     ) -> Result<(), SceneSpawnError> {
         let type_registry = type_registry.read();
 
@@ -91,6 +145,9 @@ impl DynamicScene {
 
             // Apply/ add each component to the given entity.
             for component in &scene_entity.components {
+                if !filter(scene_entity.entity, component.as_ref()) {
+                    continue;
+                }
                 let type_info = component.get_represented_type_info().ok_or_else(|| {
                     SceneSpawnError::NoRepresentedType {
                         type_path: component.reflect_type_path().to_string(),
@@ -131,45 +188,6 @@ impl DynamicScene {
             }
         }
 
-        // Insert resources after all entities have been added to the world.
-        // This ensures the entities are available for the resources to reference during mapping.
-        for resource in &self.resources {
-            let type_info = resource.get_represented_type_info().ok_or_else(|| {
-                SceneSpawnError::NoRepresentedType {
-                    type_path: resource.reflect_type_path().to_string(),
-                }
-            })?;
-            let registration = type_registry.get(type_info.type_id()).ok_or_else(|| {
-                SceneSpawnError::UnregisteredButReflectedType {
-                    type_path: type_info.type_path().to_string(),
-                }
-            })?;
-            let reflect_resource = registration.data::<ReflectResource>().ok_or_else(|| {
-                SceneSpawnError::UnregisteredResource {
-                    type_path: type_info.type_path().to_string(),
-                }
-            })?;
-
-            // If this component references entities in the scene, update
-            // them to the entities in the world.
-            let mut cloned_resource;
-            let partial_reflect_resource = if let Some(map_entities) =
-                registration.data::<ReflectMapEntities>()
-            {
-                cloned_resource = clone_reflect_value(resource.as_partial_reflect(), registration);
-                SceneEntityMapper::world_scope(entity_map, world, |_, mapper| {
-                    map_entities.map_entities(cloned_resource.as_partial_reflect_mut(), mapper);
-                });
-                cloned_resource.as_partial_reflect()
-            } else {
-                resource.as_partial_reflect()
-            };
-
-            // If the world already contains an instance of the given resource
-            // just apply the (possibly) new value, otherwise insert the resource
-            reflect_resource.apply_or_insert(world, partial_reflect_resource, &type_registry);
-        }
-
         Ok(())
     }
 
@@ -187,18 +205,33 @@ impl DynamicScene {
         self.write_to_world_with(world, entity_map, &registry)
     }
 
-    // TODO: move to AssetSaver when it is implemented
     /// Serialize this dynamic scene into the official Bevy scene format (`.scn` / `.scn.ron`).
     ///
     /// The Bevy scene format is based on [Rusty Object Notation (RON)]. It describes the scene
     /// in a human-friendly format. To deserialize the scene, use the [`SceneLoader`].
+    /// To save the scene as an asset, use the [`SceneSaver`].
     ///
     /// [`SceneLoader`]: crate::SceneLoader
+    /// [`SceneSaver`]: crate::SceneSaver
     /// [Rusty Object Notation (RON)]: https://crates.io/crates/ron
     #[cfg(feature = "serialize")]
     pub fn serialize(&self, registry: &TypeRegistry) -> Result<String, ron::Error> {
         serialize_ron(SceneSerializer::new(self, registry))
     }
+
+    /// Serialize this dynamic scene like [`DynamicScene::serialize`], but without the fields
+    /// of components and resources that are equal to their default value.
+    ///
+    /// This can make scene files much smaller. The skipped fields are set back to their default value
+    /// when the components and resources are inserted into a world, see
+    /// [`TypedReflectSerializer::with_skip_defaults`](bevy_reflect::serde::TypedReflectSerializer::with_skip_defaults).
+    #[cfg(feature = "serialize")]
+    pub fn serialize_without_defaults(
+        &self,
+        registry: &TypeRegistry,
+    ) -> Result<String, ron::Error> {
+        serialize_ron(SceneSerializer::new(self, registry).with_skip_defaults(true))
+    }
 }
 
 /// Serialize a given Rust data structure into rust object notation (ron).
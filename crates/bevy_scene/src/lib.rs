@@ -12,13 +12,21 @@
 
 extern crate alloc;
 
+#[cfg(feature = "binary")]
+mod binary;
 mod components;
 mod dynamic_scene;
 mod dynamic_scene_builder;
+#[cfg(feature = "serialize")]
+mod migration;
+mod prefab;
 mod reflect_utils;
+#[cfg(feature = "serialize")]
+mod save_game;
 mod scene;
 mod scene_filter;
 mod scene_loader;
+mod scene_patch;
 mod scene_spawner;
 
 #[cfg(feature = "serialize")]
@@ -27,12 +35,20 @@ pub mod serde;
 /// Rusty Object Notation, a crate used to serialize and deserialize bevy scenes.
 pub use bevy_asset::ron;
 
+#[cfg(feature = "binary")]
+pub use binary::*;
 pub use components::*;
 pub use dynamic_scene::*;
 pub use dynamic_scene_builder::*;
+#[cfg(feature = "serialize")]
+pub use migration::*;
+pub use prefab::*;
+#[cfg(feature = "serialize")]
+pub use save_game::*;
 pub use scene::*;
 pub use scene_filter::*;
 pub use scene_loader::*;
+pub use scene_patch::*;
 pub use scene_spawner::*;
 
 /// The scene prelude.
@@ -41,15 +57,22 @@ pub use scene_spawner::*;
 pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
-        DynamicScene, DynamicSceneBuilder, DynamicSceneRoot, Scene, SceneFilter, SceneRoot,
-        SceneSpawner,
+        DynamicScene, DynamicSceneBuilder, DynamicSceneRoot, Scene, SceneFilter, SceneOverrides,
+        ScenePrefab, SceneRoot, SceneSpawner,
     };
+
+    #[cfg(feature = "serialize")]
+    #[doc(hidden)]
+    pub use crate::{LoadGame, SaveGame, SaveGamePlugin, Saveable, SceneMigrationApp};
 }
 
 use bevy_app::prelude::*;
 
 #[cfg(feature = "serialize")]
-use {bevy_asset::AssetApp, bevy_ecs::schedule::IntoScheduleConfigs};
+use {
+    bevy_asset::{transformer::IdentityAssetTransformer, AssetApp},
+    bevy_ecs::{schedule::IntoScheduleConfigs, world::FromWorld},
+};
 
 /// Plugin that provides scene functionality to an [`App`].
 #[derive(Default)]
@@ -64,7 +87,30 @@ impl Plugin for ScenePlugin {
             .init_resource::<SceneSpawner>()
             .register_type::<SceneRoot>()
             .register_type::<DynamicSceneRoot>()
-            .add_systems(SpawnScene, (scene_spawner, scene_spawner_system).chain());
+            .register_type::<ScenePrefab>()
+            .register_type::<SceneOverrides>()
+            .add_systems(
+                SpawnScene,
+                (
+                    scene_prefab_spawner,
+                    scene_spawner,
+                    scene_overrides_system,
+                    scene_spawner_system,
+                )
+                    .chain(),
+            );
+
+        #[cfg(feature = "binary")]
+        app.init_asset_loader::<BinarySceneLoader>();
+
+        // Process scenes, so that the asset paths stored in them are redirected.
+        let scene_saver = SceneSaver::from_world(app.world_mut());
+        app.register_asset_processor(SceneProcessor::new(
+            IdentityAssetTransformer::new(),
+            scene_saver,
+        ))
+        .set_default_asset_processor::<SceneProcessor>("scn")
+        .set_default_asset_processor::<SceneProcessor>("scn.ron");
 
         // Register component hooks for DynamicSceneRoot
         app.world_mut()
@@ -0,0 +1,445 @@
+use alloc::{boxed::Box, format, string::String, sync::Arc, vec::Vec};
+use bevy_app::App;
+use bevy_ecs::reflect::AppTypeRegistry;
+use bevy_reflect::{
+    FromReflect, GetTypeRegistration, PartialReflect, TypePath, TypeRegistration, TypeRegistry,
+};
+use core::any::TypeId;
+
+type MigrateFn =
+    Arc<dyn Fn(&dyn PartialReflect) -> Result<Box<dyn PartialReflect>, String> + Send + Sync>;
+
+#[derive(Clone)]
+struct MigrationStep {
+    version: u32,
+    stored_type: TypeId,
+    stored_type_path: &'static str,
+    migrated_type: TypeId,
+    migrated_type_name: &'static str,
+    migrate: MigrateFn,
+}
+
+/// Type data describing how values of a type that were written by older scenes are upgraded when a scene is
+/// deserialized.
+///
+/// Scenes are written with the current scene version, which is the highest version of all migrations in the
+/// [`TypeRegistry`] (see [`scene_version`]). A migration registered for version `N` describes the type that was
+/// stored by scenes written before version `N` and how to convert it to the representation of version `N`.
+/// When a scene with an older version is loaded, every migration with a higher version is applied in order, so
+/// migrations of consecutive versions can be chained.
+///
+/// Renamed types can be found through their old [type paths](TypePath::type_path), see
+/// [`TypeRegistry::register_alias`].
+///
+/// Use [`SceneMigrationApp`] to register migrations with an [`App`]:
+///
+/// ```
+/// # use bevy_app::App;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_reflect::Reflect;
+/// # use bevy_scene::SceneMigrationApp;
+/// // The component as it was stored before scene version 1.
+/// #[derive(Reflect)]
+/// struct DoorV0 {
+///     size: f32,
+/// }
+///
+/// #[derive(Component, Reflect)]
+/// #[reflect(Component)]
+/// struct Door {
+///     width: f32,
+/// }
+///
+/// App::new()
+///     .register_type::<Door>()
+///     .register_scene_migration::<Door, DoorV0, Door>(1, |old| Door { width: old.size })
+///     .register_scene_type_alias::<Door>("my_game::OldDoor");
+/// ```
+#[derive(Clone, Default)]
+pub struct SceneMigrations {
+    steps: Vec<MigrationStep>,
+}
+
+impl SceneMigrations {
+    /// Adds a migration for scenes written before `version`, which stored the type as `Old`.
+    ///
+    /// `migrate` converts the stored value into `New`, which is either the current type or the `Old` type of the
+    /// migration with the next version. `Old` must be registered in the type registry that the scene is deserialized
+    /// with.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a migration with the same version was already added, if the migration with the next lower version
+    /// does not convert into `Old`, or if the migration with the next higher version does not store the type as `New`.
+    pub fn add<Old, New>(
+        &mut self,
+        version: u32,
+        migrate: impl Fn(Old) -> New + Send + Sync + 'static,
+    ) -> &mut Self
+    where
+        Old: FromReflect + TypePath,
+        New: PartialReflect,
+    {
+        let Err(index) = self
+            .steps
+            .binary_search_by_key(&version, |step| step.version)
+        else {
+            panic!(
+                "a scene migration for version {version} of `{}` was already added",
+                Old::type_path()
+            );
+        };
+        let new_type_name = core::any::type_name::<New>();
+        if let Some(previous) = index.checked_sub(1).map(|index| &self.steps[index]) {
+            assert!(
+                previous.migrated_type == TypeId::of::<Old>(),
+                "the scene migration for version {} converts into `{}`, but the migration for version {version} \
+                stores `{}`",
+                previous.version,
+                previous.migrated_type_name,
+                Old::type_path()
+            );
+        }
+        if let Some(next) = self.steps.get(index) {
+            assert!(
+                next.stored_type == TypeId::of::<New>(),
+                "the scene migration for version {version} converts into `{new_type_name}`, but the migration for \
+                version {} stores `{}`",
+                next.version,
+                next.stored_type_path
+            );
+        }
+        self.steps.insert(
+            index,
+            MigrationStep {
+                version,
+                stored_type: TypeId::of::<Old>(),
+                stored_type_path: Old::type_path(),
+                migrated_type: TypeId::of::<New>(),
+                migrated_type_name: new_type_name,
+                migrate: Arc::new(move |value| {
+                    let old = Old::from_reflect(value).ok_or_else(|| {
+                        format!("expected a value of type `{}`", Old::type_path())
+                    })?;
+                    Ok(Box::new(migrate(old)))
+                }),
+            },
+        );
+        self
+    }
+
+    /// Returns `true` if one of the added migrations stores the type as the given type.
+    fn is_stored_type(&self, type_id: TypeId) -> bool {
+        self.steps.iter().any(|step| step.stored_type == type_id)
+    }
+
+    /// Returns the highest version of the added migrations, or `0` if there are none.
+    pub fn version(&self) -> u32 {
+        self.steps.last().map_or(0, |step| step.version)
+    }
+
+    /// Returns the registration of the type that scenes with the given `version` stored the type as, or `None`
+    /// if they stored the current type.
+    pub(crate) fn stored_type<'a>(
+        &self,
+        version: u32,
+        registry: &'a TypeRegistry,
+    ) -> Option<Result<&'a TypeRegistration, String>> {
+        let step = self.steps.iter().find(|step| step.version > version)?;
+        Some(registry.get(step.stored_type).ok_or_else(|| {
+            format!(
+                "the stored type `{}` of a scene migration is not registered",
+                step.stored_type_path
+            )
+        }))
+    }
+
+    /// Upgrades a value that was stored by a scene with the given `version` to the current type.
+    pub(crate) fn migrate(
+        &self,
+        version: u32,
+        mut value: Box<dyn PartialReflect>,
+    ) -> Result<Box<dyn PartialReflect>, String> {
+        for step in self.steps.iter().filter(|step| step.version > version) {
+            value = (step.migrate)(value.as_ref())
+                .map_err(|error| format!("failed to migrate scene version {version}: {error}"))?;
+        }
+        Ok(value)
+    }
+}
+
+/// Returns the current scene version of `registry`, which is the highest version of all registered
+/// [`SceneMigrations`], or `0` if there are none.
+pub fn scene_version(registry: &TypeRegistry) -> u32 {
+    registry
+        .iter_with_data::<SceneMigrations>()
+        .map(|(_, migrations)| migrations.version())
+        .max()
+        .unwrap_or(0)
+}
+
+/// Adds scene migration methods to [`App`].
+pub trait SceneMigrationApp {
+    /// Registers a migration of `T` for scenes written before `version`, which stored `T` as `Old`.
+    ///
+    /// Registers `T` and `Old` if they aren't registered yet. See [`SceneMigrations::add`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `New` is neither `T` nor the type stored by another migration of `T`, or if the migration does
+    /// not fit between the migrations of `T` with the next lower and higher versions. Chained migrations therefore
+    /// have to be registered from the highest version down.
+    fn register_scene_migration<T, Old, New>(
+        &mut self,
+        version: u32,
+        migrate: impl Fn(Old) -> New + Send + Sync + 'static,
+    ) -> &mut Self
+    where
+        T: GetTypeRegistration,
+        Old: FromReflect + TypePath + GetTypeRegistration,
+        New: PartialReflect;
+
+    /// Registers an old type path of `T`, so that scenes which stored `T` under that path keep loading after
+    /// `T` was renamed or moved.
+    ///
+    /// Registers `T` if it isn't registered yet. This is a shorthand for [`TypeRegistry::register_alias`].
+    fn register_scene_type_alias<T: GetTypeRegistration>(
+        &mut self,
+        type_path: impl Into<String>,
+    ) -> &mut Self;
+}
+
+impl SceneMigrationApp for App {
+    fn register_scene_migration<T, Old, New>(
+        &mut self,
+        version: u32,
+        migrate: impl Fn(Old) -> New + Send + Sync + 'static,
+    ) -> &mut Self
+    where
+        T: GetTypeRegistration,
+        Old: FromReflect + TypePath + GetTypeRegistration,
+        New: PartialReflect,
+    {
+        {
+            let mut registry = self.world().resource::<AppTypeRegistry>().write();
+            registry.register::<Old>();
+            let migrations = scene_migrations_mut::<T>(&mut registry);
+            migrations.add(version, migrate);
+            let converts_into_t = TypeId::of::<New>() == TypeId::of::<T>();
+            assert!(
+                converts_into_t || migrations.is_stored_type(TypeId::of::<New>()),
+                "the scene migration for version {version} converts into `{}`, which is neither `{}` nor the type \
+                stored by a later migration. register the later migrations first",
+                core::any::type_name::<New>(),
+                core::any::type_name::<T>(),
+            );
+        }
+        self
+    }
+
+    fn register_scene_type_alias<T: GetTypeRegistration>(
+        &mut self,
+        type_path: impl Into<String>,
+    ) -> &mut Self {
+        {
+            let mut registry = self.world().resource::<AppTypeRegistry>().write();
+            registry.register::<T>();
+            registry.register_alias::<T>(type_path);
+        }
+        self
+    }
+}
+
+fn scene_migrations_mut<T: GetTypeRegistration>(
+    registry: &mut TypeRegistry,
+) -> &mut SceneMigrations {
+    registry.register::<T>();
+    let registration = registry.get_mut(TypeId::of::<T>()).unwrap();
+    if registration.data::<SceneMigrations>().is_none() {
+        registration.insert(SceneMigrations::default());
+    }
+    registration.data_mut::<SceneMigrations>().unwrap()
+}
+
+impl core::fmt::Debug for SceneMigrations {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SceneMigrations")
+            .field(
+                "versions",
+                &self
+                    .steps
+                    .iter()
+                    .map(|step| (step.version, step.stored_type_path))
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{scene_version, SceneMigrationApp};
+    use crate::{ron, serde::SceneDeserializer, DynamicScene};
+    use bevy_app::App;
+    use bevy_ecs::{
+        component::Component,
+        entity::EntityHashMap,
+        prelude::{AppTypeRegistry, ReflectComponent, World},
+    };
+    use bevy_reflect::Reflect;
+    use serde::de::DeserializeSeed;
+
+    #[derive(Reflect)]
+    struct DoorV0 {
+        size: f32,
+    }
+
+    #[derive(Reflect)]
+    struct DoorV1 {
+        width: f32,
+    }
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Door {
+        width: f32,
+        height: f32,
+    }
+
+    fn create_app() -> App {
+        let mut app = App::new();
+        app.register_type::<Door>()
+            .register_scene_migration::<Door, DoorV1, Door>(2, |old| Door {
+                width: old.width,
+                height: 2.0,
+            })
+            .register_scene_migration::<Door, DoorV0, DoorV1>(1, |old| DoorV1 { width: old.size })
+            .register_scene_type_alias::<Door>("my_game::Gate");
+        app
+    }
+
+    fn load(input: &str, registry: &AppTypeRegistry) -> Result<Door, ron::error::SpannedError> {
+        let mut deserializer = ron::de::Deserializer::from_str(input)?;
+        let scene = SceneDeserializer {
+            type_registry: &registry.read(),
+        }
+        .deserialize(&mut deserializer)
+        .map_err(|e| deserializer.span_error(e))?;
+
+        let mut world = World::new();
+        scene
+            .write_to_world_with(&mut world, &mut EntityHashMap::default(), registry)
+            .unwrap();
+        let door = world.query::<&Door>().single(&world).unwrap();
+        Ok(Door { ..*door })
+    }
+
+    #[test]
+    fn migrates_components_of_older_scenes() {
+        let app = create_app();
+        let registry = app.world().resource::<AppTypeRegistry>();
+        assert_eq!(scene_version(&registry.read()), 2);
+
+        // Written before any migration, so without a version.
+        let door = load(
+            r#"(
+  resources: {},
+  entities: {
+    4294967296: (
+      components: {
+        "bevy_scene::migration::tests::Door": (size: 1.5),
+      },
+    ),
+  },
+)"#,
+            registry,
+        )
+        .unwrap();
+        assert_eq!(
+            door,
+            Door {
+                width: 1.5,
+                height: 2.0
+            }
+        );
+
+        // Written at version 1 under the old type path.
+        let door = load(
+            r#"(
+  version: 1,
+  resources: {},
+  entities: {
+    4294967296: (
+      components: {
+        "my_game::Gate": (width: 3.0),
+      },
+    ),
+  },
+)"#,
+            registry,
+        )
+        .unwrap();
+        assert_eq!(
+            door,
+            Door {
+                width: 3.0,
+                height: 2.0
+            }
+        );
+    }
+
+    #[test]
+    fn current_scenes_roundtrip() {
+        let app = create_app();
+        let registry = app.world().resource::<AppTypeRegistry>().clone();
+
+        let mut world = World::new();
+        world.insert_resource(registry.clone());
+        world.spawn(Door {
+            width: 1.0,
+            height: 4.0,
+        });
+        let serialized = DynamicScene::from_world(&world)
+            .serialize(&registry.read())
+            .unwrap();
+        assert!(serialized.starts_with("(\n  version: 2,\n"));
+        assert_eq!(
+            load(&serialized, &registry).unwrap(),
+            Door {
+                width: 1.0,
+                height: 4.0
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_newer_scenes() {
+        let app = create_app();
+        let registry = app.world().resource::<AppTypeRegistry>();
+        let error = load("(version: 3, resources: {}, entities: {})", registry).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("scene version 3 is newer than the current scene version 2"));
+    }
+
+    #[test]
+    #[should_panic(expected = "neither")]
+    fn migration_must_convert_into_the_current_type() {
+        App::new()
+            .register_scene_migration::<Door, DoorV0, DoorV1>(1, |old| DoorV1 { width: old.size });
+    }
+
+    #[test]
+    #[should_panic(expected = "stores")]
+    fn chained_migrations_must_match() {
+        App::new()
+            .register_scene_migration::<Door, DoorV1, Door>(2, |old| Door {
+                width: old.width,
+                height: 2.0,
+            })
+            .register_scene_migration::<Door, DoorV0, Door>(1, |old| Door {
+                width: old.size,
+                height: 2.0,
+            });
+    }
+}
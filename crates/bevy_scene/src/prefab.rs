@@ -0,0 +1,823 @@
+use crate::{reflect_utils::map_reflected_entities, DynamicSceneRoot, SceneInstance, SceneSpawner};
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use bevy_asset::{AssetPath, AssetServer};
+use bevy_ecs::{
+    change_detection::ResMut,
+    component::Component,
+    entity::{Entity, EntityHashMap, EntityMapper},
+    prelude::{Changed, RemovedComponents},
+    reflect::ReflectComponent,
+    system::{Commands, Query, Res},
+    world::World,
+};
+use bevy_reflect::{
+    prelude::ReflectDefault, ApplyError, PartialReflect, Reflect, ReflectPath, TypeRegistry,
+};
+use thiserror::Error;
+
+#[cfg(feature = "serialize")]
+use {
+    bevy_reflect::serde::{
+        DeserializeWithRegistry, ReflectDeserializeWithRegistry, ReflectDeserializer,
+        ReflectSerializeWithRegistry, ReflectSerializer, SerializeWithRegistry,
+    },
+    core::fmt::Formatter,
+    serde::{
+        de::{DeserializeSeed, Error as _, MapAccess, SeqAccess, Visitor},
+        ser::{SerializeSeq, SerializeStruct},
+        Deserialize, Deserializer, Serialize, Serializer,
+    },
+};
+
+/// Spawns the [`DynamicScene`](crate::DynamicScene) at the given [`AssetPath`] as a linked instance, in the same
+/// way as [`DynamicSceneRoot`].
+///
+/// Unlike [`DynamicSceneRoot`], this component only stores the path of the source scene, so it can itself be saved in
+/// a scene file. This is how scenes reference other scenes: when a scene containing a [`ScenePrefab`] is spawned, the
+/// referenced scene is loaded and spawned as a child of that entity, recursively.
+///
+/// The instance stays linked to its source: when the source scene asset is modified (for example by hot reloading),
+/// the changes are re-applied to the instance, followed by any [`SceneOverrides`] on the same entity.
+#[derive(Component, Clone, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq, Clone)]
+pub struct ScenePrefab(pub AssetPath<'static>);
+
+impl ScenePrefab {
+    /// Creates a new [`ScenePrefab`] referencing the scene at `path`.
+    pub fn new<'a>(path: impl Into<AssetPath<'a>>) -> Self {
+        Self(path.into().into_owned())
+    }
+}
+
+/// A single per-instance change to a field of a spawned scene, stored in [`SceneOverrides`].
+#[derive(Debug)]
+pub struct SceneOverride {
+    /// The entity in the _source_ scene whose component is overridden.
+    pub entity: Entity,
+    /// The [type path](bevy_reflect::TypePath::type_path) of the overridden component.
+    pub component: String,
+    /// The [reflection path](bevy_reflect::ParsedPath) of the overridden field within the component.
+    /// An empty path overrides the whole component.
+    pub path: String,
+    /// The value that is [applied](PartialReflect::try_apply) to the field.
+    pub value: Box<dyn PartialReflect>,
+}
+
+impl Clone for SceneOverride {
+    fn clone(&self) -> Self {
+        Self {
+            entity: self.entity,
+            component: self.component.clone(),
+            path: self.path.clone(),
+            value: self.value.to_dynamic(),
+        }
+    }
+}
+
+impl SceneOverride {
+    /// Applies this override to the instance described by `entity_map`, which maps entities of the source scene to
+    /// the entities of the instance. Entities referenced by the value are mapped with `entity_map` as well.
+    pub fn apply(
+        &self,
+        world: &mut World,
+        entity_map: &EntityHashMap<Entity>,
+        type_registry: &TypeRegistry,
+    ) -> Result<(), SceneOverrideError> {
+        let missing_entity = || SceneOverrideError::MissingEntity {
+            entity: self.entity,
+        };
+        let entity = *entity_map.get(&self.entity).ok_or_else(missing_entity)?;
+        let reflect_component = type_registry
+            .get_with_type_path(&self.component)
+            .and_then(|registration| registration.data::<ReflectComponent>())
+            .ok_or_else(|| SceneOverrideError::UnregisteredComponent {
+                type_path: self.component.clone(),
+            })?;
+        let entity_mut = world.get_entity_mut(entity).map_err(|_| missing_entity())?;
+        let mut component = reflect_component.reflect_mut(entity_mut).ok_or_else(|| {
+            SceneOverrideError::MissingComponent {
+                entity: self.entity,
+                type_path: self.component.clone(),
+            }
+        })?;
+        let mut value = self.value.to_dynamic();
+        map_reflected_entities(
+            &mut *value,
+            type_registry,
+            &mut InstanceEntityMapper(entity_map),
+        );
+        apply_at_path(component.as_partial_reflect_mut(), &self.path, &*value)
+    }
+
+    /// Applies this override to a value of the overridden component, ignoring [`SceneOverride::entity`].
+    pub fn apply_to_component(
+        &self,
+        component: &mut dyn PartialReflect,
+    ) -> Result<(), SceneOverrideError> {
+        apply_at_path(component, &self.path, &*self.value)
+    }
+}
+
+fn apply_at_path(
+    component: &mut dyn PartialReflect,
+    path: &str,
+    value: &dyn PartialReflect,
+) -> Result<(), SceneOverrideError> {
+    let field = if path.is_empty() {
+        component
+    } else {
+        path.reflect_element_mut(component)
+            .map_err(|err| SceneOverrideError::InvalidPath {
+                path: path.to_string(),
+                message: err.to_string(),
+            })?
+    };
+    field.try_apply(value)?;
+    Ok(())
+}
+
+/// Maps entities of the source scene to the entities of an instance, leaving entities outside the scene unchanged.
+pub(crate) struct InstanceEntityMapper<'a>(pub(crate) &'a EntityHashMap<Entity>);
+
+impl EntityMapper for InstanceEntityMapper<'_> {
+    fn get_mapped(&mut self, source: Entity) -> Entity {
+        self.0.get(&source).copied().unwrap_or(source)
+    }
+
+    fn set_mapped(&mut self, _source: Entity, _target: Entity) {}
+}
+
+/// Per-instance overrides of the scene spawned by a [`ScenePrefab`] or [`DynamicSceneRoot`] on the same entity.
+///
+/// The overrides are applied whenever the instance is spawned, whenever the source scene is modified, and whenever
+/// this component changes. Removing an override restores the value from the source scene.
+///
+/// Overrides address entities by their id in the source scene, so they stay valid for every instance of it.
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(opaque)]
+#[reflect(Component, Default, Clone)]
+#[cfg_attr(
+    feature = "serialize",
+    reflect(SerializeWithRegistry, DeserializeWithRegistry)
+)]
+pub struct SceneOverrides(pub Vec<SceneOverride>);
+
+impl SceneOverrides {
+    /// Overrides the field at `path` of the `component` on the source scene's `entity` with `value`, replacing any
+    /// previous override of the same field.
+    pub fn set(
+        &mut self,
+        entity: Entity,
+        component: impl Into<String>,
+        path: impl Into<String>,
+        value: Box<dyn PartialReflect>,
+    ) {
+        let component = component.into();
+        let path = path.into();
+        self.remove(entity, &component, &path);
+        self.0.push(SceneOverride {
+            entity,
+            component,
+            path,
+            value,
+        });
+    }
+
+    /// Removes the override of the field at `path` of the `component` on the source scene's `entity`, returning it.
+    pub fn remove(&mut self, entity: Entity, component: &str, path: &str) -> Option<SceneOverride> {
+        let index = self
+            .0
+            .iter()
+            .position(|o| o.entity == entity && o.component == component && o.path == path)?;
+        Some(self.0.remove(index))
+    }
+
+    /// Applies all overrides to the instance described by `entity_map`, in order.
+    ///
+    /// An override that fails does not prevent the remaining overrides from being applied; the errors of all failed
+    /// overrides are returned.
+    pub fn apply(
+        &self,
+        world: &mut World,
+        entity_map: &EntityHashMap<Entity>,
+        type_registry: &TypeRegistry,
+    ) -> Vec<SceneOverrideError> {
+        self.0
+            .iter()
+            .filter_map(|o| o.apply(world, entity_map, type_registry).err())
+            .collect()
+    }
+}
+
+/// An error that occurs when applying a [`SceneOverride`].
+#[derive(Error, Debug)]
+pub enum SceneOverrideError {
+    /// The overridden entity is not part of the scene instance.
+    #[error("the overridden entity {entity} is not part of the scene instance")]
+    MissingEntity {
+        /// The entity in the source scene.
+        entity: Entity,
+    },
+    /// The overridden component type is not registered with [`ReflectComponent`].
+    #[error("the overridden component `{type_path}` is not registered. consider adding `#[reflect(Component)]` to your type")]
+    UnregisteredComponent {
+        /// The type path of the component.
+        type_path: String,
+    },
+    /// The overridden entity does not have the overridden component.
+    #[error("the overridden entity {entity} does not have the component `{type_path}`")]
+    MissingComponent {
+        /// The entity in the source scene.
+        entity: Entity,
+        /// The type path of the component.
+        type_path: String,
+    },
+    /// The overridden field path is invalid for the component.
+    #[error("invalid override path `{path}`: {message}")]
+    InvalidPath {
+        /// The invalid path.
+        path: String,
+        /// A description of why the path is invalid.
+        message: String,
+    },
+    /// The override value could not be applied to the field.
+    #[error(transparent)]
+    Apply(#[from] ApplyError),
+}
+
+/// System that spawns the scenes referenced by [`ScenePrefab`] components.
+pub fn scene_prefab_spawner(
+    mut commands: Commands,
+    prefabs: Query<(Entity, &ScenePrefab, Option<&DynamicSceneRoot>), Changed<ScenePrefab>>,
+    asset_server: Res<AssetServer>,
+) {
+    for (entity, prefab, root) in &prefabs {
+        // Re-applying the scene that contains the prefab marks it as changed without changing the path.
+        if root.is_some_and(|root| root.0.path() == Some(&prefab.0)) {
+            continue;
+        }
+        commands
+            .entity(entity)
+            .insert(DynamicSceneRoot(asset_server.load(prefab.0.clone())));
+    }
+}
+
+/// System that schedules re-applying [`SceneOverrides`] to scene instances when they change or are removed.
+pub fn scene_overrides_system(
+    changed: Query<&SceneInstance, Changed<SceneOverrides>>,
+    instances: Query<&SceneInstance>,
+    mut removed: RemovedComponents<SceneOverrides>,
+    mut scene_spawner: ResMut<SceneSpawner>,
+) {
+    let removed = instances.iter_many(removed.read());
+    for instance in changed.iter().chain(removed) {
+        scene_spawner.reapply_instance(**instance);
+    }
+}
+
+#[cfg(feature = "serialize")]
+const OVERRIDE_STRUCT: &str = "SceneOverride";
+#[cfg(feature = "serialize")]
+const OVERRIDE_FIELDS: &[&str] = &["entity", "component", "path", "value"];
+
+#[cfg(feature = "serialize")]
+impl SerializeWithRegistry for SceneOverrides {
+    fn serialize<S>(&self, serializer: S, registry: &TypeRegistry) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        SceneOverridesSerializer {
+            overrides: &self.0,
+            registry,
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Serializes a list of [`SceneOverride`]s.
+#[cfg(feature = "serialize")]
+pub(crate) struct SceneOverridesSerializer<'a> {
+    pub(crate) overrides: &'a [SceneOverride],
+    pub(crate) registry: &'a TypeRegistry,
+}
+
+#[cfg(feature = "serialize")]
+impl Serialize for SceneOverridesSerializer<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.overrides.len()))?;
+        for o in self.overrides {
+            seq.serialize_element(&SceneOverrideSerializer {
+                scene_override: o,
+                registry: self.registry,
+            })?;
+        }
+        seq.end()
+    }
+}
+
+#[cfg(feature = "serialize")]
+struct SceneOverrideSerializer<'a> {
+    scene_override: &'a SceneOverride,
+    registry: &'a TypeRegistry,
+}
+
+#[cfg(feature = "serialize")]
+impl Serialize for SceneOverrideSerializer<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let o = self.scene_override;
+        let mut state = serializer.serialize_struct(OVERRIDE_STRUCT, OVERRIDE_FIELDS.len())?;
+        state.serialize_field(OVERRIDE_FIELDS[0], &o.entity)?;
+        state.serialize_field(OVERRIDE_FIELDS[1], &o.component)?;
+        state.serialize_field(OVERRIDE_FIELDS[2], &o.path)?;
+        state.serialize_field(
+            OVERRIDE_FIELDS[3],
+            &ReflectSerializer::new(&*o.value, self.registry),
+        )?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<'de> DeserializeWithRegistry<'de> for SceneOverrides {
+    fn deserialize<D>(deserializer: D, registry: &TypeRegistry) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        SceneOverridesDeserializer { registry }
+            .deserialize(deserializer)
+            .map(SceneOverrides)
+    }
+}
+
+/// Deserializes a list of [`SceneOverride`]s.
+#[cfg(feature = "serialize")]
+pub(crate) struct SceneOverridesDeserializer<'a> {
+    pub(crate) registry: &'a TypeRegistry,
+}
+
+#[cfg(feature = "serialize")]
+impl<'de> DeserializeSeed<'de> for SceneOverridesDeserializer<'_> {
+    type Value = Vec<SceneOverride>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<'de> Visitor<'de> for SceneOverridesDeserializer<'_> {
+    type Value = Vec<SceneOverride>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("a list of scene overrides")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut overrides = Vec::new();
+        while let Some(o) = seq.next_element_seed(SceneOverrideDeserializer {
+            registry: self.registry,
+        })? {
+            overrides.push(o);
+        }
+        Ok(overrides)
+    }
+}
+
+#[cfg(feature = "serialize")]
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum OverrideField {
+    Entity,
+    Component,
+    Path,
+    Value,
+}
+
+#[cfg(feature = "serialize")]
+struct SceneOverrideDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+#[cfg(feature = "serialize")]
+impl<'de> DeserializeSeed<'de> for SceneOverrideDeserializer<'_> {
+    type Value = SceneOverride;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(OVERRIDE_STRUCT, OVERRIDE_FIELDS, self)
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<'de> Visitor<'de> for SceneOverrideDeserializer<'_> {
+    type Value = SceneOverride;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("a scene override")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let entity = seq
+            .next_element()?
+            .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+        let component = seq
+            .next_element()?
+            .ok_or_else(|| A::Error::invalid_length(1, &self))?;
+        let path = seq
+            .next_element()?
+            .ok_or_else(|| A::Error::invalid_length(2, &self))?;
+        let value = seq
+            .next_element_seed(ReflectDeserializer::new(self.registry))?
+            .ok_or_else(|| A::Error::invalid_length(3, &self))?;
+        Ok(SceneOverride {
+            entity,
+            component,
+            path,
+            value,
+        })
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut entity = None;
+        let mut component = None;
+        let mut path = None;
+        let mut value = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                OverrideField::Entity => {
+                    if entity.is_some() {
+                        return Err(A::Error::duplicate_field(OVERRIDE_FIELDS[0]));
+                    }
+                    entity = Some(map.next_value()?);
+                }
+                OverrideField::Component => {
+                    if component.is_some() {
+                        return Err(A::Error::duplicate_field(OVERRIDE_FIELDS[1]));
+                    }
+                    component = Some(map.next_value()?);
+                }
+                OverrideField::Path => {
+                    if path.is_some() {
+                        return Err(A::Error::duplicate_field(OVERRIDE_FIELDS[2]));
+                    }
+                    path = Some(map.next_value()?);
+                }
+                OverrideField::Value => {
+                    if value.is_some() {
+                        return Err(A::Error::duplicate_field(OVERRIDE_FIELDS[3]));
+                    }
+                    value = Some(map.next_value_seed(ReflectDeserializer::new(self.registry))?);
+                }
+            }
+        }
+        Ok(SceneOverride {
+            entity: entity.ok_or_else(|| A::Error::missing_field(OVERRIDE_FIELDS[0]))?,
+            component: component.ok_or_else(|| A::Error::missing_field(OVERRIDE_FIELDS[1]))?,
+            // The path may be omitted to override the whole component.
+            path: path.unwrap_or_default(),
+            value: value.ok_or_else(|| A::Error::missing_field(OVERRIDE_FIELDS[3]))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SceneOverrides, ScenePrefab};
+    use crate::{DynamicScene, DynamicSceneRoot, ScenePlugin};
+    use alloc::boxed::Box;
+    use bevy_app::{App, ScheduleRunnerPlugin, TaskPoolPlugin};
+    use bevy_asset::{AssetPath, AssetPlugin, Assets};
+    use bevy_ecs::{
+        component::Component,
+        entity::Entity,
+        prelude::{AppTypeRegistry, ReflectComponent, World},
+    };
+    use bevy_reflect::{Reflect, TypePath};
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Door {
+        width: f32,
+        open: bool,
+    }
+
+    fn door_scene(app: &App, door: Door) -> (DynamicScene, Entity) {
+        let mut world = World::new();
+        world.insert_resource(app.world().resource::<AppTypeRegistry>().clone());
+        let entity = world.spawn(door).id();
+        (DynamicScene::from_world(&world), entity)
+    }
+
+    fn spawned_door(app: &mut App) -> &Door {
+        app.world_mut()
+            .query::<&Door>()
+            .single(app.world())
+            .unwrap()
+    }
+
+    #[test]
+    fn overrides_are_preserved_when_source_changes() {
+        let mut app = App::new();
+        app.add_plugins((
+            ScheduleRunnerPlugin::default(),
+            AssetPlugin::default(),
+            ScenePlugin,
+        ))
+        .register_type::<Door>();
+        app.update();
+
+        let (scene, door) = door_scene(
+            &app,
+            Door {
+                width: 1.0,
+                open: false,
+            },
+        );
+        let handle = app
+            .world_mut()
+            .resource_mut::<Assets<DynamicScene>>()
+            .add(scene);
+
+        let mut overrides = SceneOverrides::default();
+        overrides.set(door, Door::type_path(), "width", Box::new(2.0f32));
+        let instance = app
+            .world_mut()
+            .spawn((DynamicSceneRoot(handle.clone()), overrides))
+            .id();
+        app.update();
+        assert_eq!(
+            spawned_door(&mut app),
+            &Door {
+                width: 2.0,
+                open: false
+            }
+        );
+
+        // Editing the source scene updates the instance, but keeps the override.
+        let (scene, _) = door_scene(
+            &app,
+            Door {
+                width: 1.0,
+                open: true,
+            },
+        );
+        app.world_mut()
+            .resource_mut::<Assets<DynamicScene>>()
+            .insert(&handle, scene);
+        app.update();
+        app.update();
+        assert_eq!(
+            spawned_door(&mut app),
+            &Door {
+                width: 2.0,
+                open: true
+            }
+        );
+
+        // Removing the override restores the value from the source scene.
+        app.world_mut()
+            .get_mut::<SceneOverrides>(instance)
+            .unwrap()
+            .remove(door, Door::type_path(), "width");
+        app.update();
+        assert_eq!(
+            spawned_door(&mut app),
+            &Door {
+                width: 1.0,
+                open: true
+            }
+        );
+    }
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Knob(u32);
+
+    #[test]
+    fn reapplying_keeps_unchanged_components() {
+        let mut app = App::new();
+        app.add_plugins((
+            ScheduleRunnerPlugin::default(),
+            AssetPlugin::default(),
+            ScenePlugin,
+        ))
+        .register_type::<Door>()
+        .register_type::<Knob>();
+        app.update();
+
+        let door_with_knob = |app: &App, open: bool| {
+            let mut world = World::new();
+            world.insert_resource(app.world().resource::<AppTypeRegistry>().clone());
+            let entity = world.spawn((Door { width: 1.0, open }, Knob(1))).id();
+            (DynamicScene::from_world(&world), entity)
+        };
+        let (scene, door) = door_with_knob(&app, false);
+        let handle = app
+            .world_mut()
+            .resource_mut::<Assets<DynamicScene>>()
+            .add(scene);
+        let instance = app
+            .world_mut()
+            .spawn((DynamicSceneRoot(handle.clone()), SceneOverrides::default()))
+            .id();
+        app.update();
+
+        // Change the instance at runtime, then change its overrides.
+        app.world_mut()
+            .query::<&mut Knob>()
+            .single_mut(app.world_mut())
+            .unwrap()
+            .0 = 5;
+        app.world_mut()
+            .get_mut::<SceneOverrides>(instance)
+            .unwrap()
+            .set(door, Door::type_path(), "width", Box::new(2.0f32));
+        app.update();
+        assert_eq!(
+            spawned_door(&mut app),
+            &Door {
+                width: 2.0,
+                open: false
+            }
+        );
+
+        // Modifying the source scene only writes the components that changed.
+        let (scene, _) = door_with_knob(&app, true);
+        app.world_mut()
+            .resource_mut::<Assets<DynamicScene>>()
+            .insert(&handle, scene);
+        app.update();
+        app.update();
+        assert_eq!(
+            spawned_door(&mut app),
+            &Door {
+                width: 2.0,
+                open: true
+            }
+        );
+        let knob = app
+            .world_mut()
+            .query::<&Knob>()
+            .single(app.world())
+            .unwrap();
+        assert_eq!(knob, &Knob(5));
+    }
+
+    #[test]
+    fn prefab_spawns_referenced_scene() {
+        let mut app = App::new();
+        app.add_plugins((
+            TaskPoolPlugin::default(),
+            ScheduleRunnerPlugin::default(),
+            AssetPlugin::default(),
+            ScenePlugin,
+        ));
+
+        let entity = app.world_mut().spawn(ScenePrefab::new("door.scn.ron")).id();
+        app.update();
+
+        let root = app.world().get::<DynamicSceneRoot>(entity).unwrap();
+        assert_eq!(root.0.path(), Some(&AssetPath::from("door.scn.ron")));
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn overrides_roundtrip_through_scene_format() {
+        use crate::{ron, serde::SceneDeserializer};
+        use serde::de::DeserializeSeed;
+
+        let mut world = World::new();
+        let registry = AppTypeRegistry::default();
+        {
+            let mut registry = registry.write();
+            registry.register::<Door>();
+            registry.register::<ScenePrefab>();
+            registry.register::<SceneOverrides>();
+        }
+        world.insert_resource(registry.clone());
+
+        let door = Entity::from_raw(7);
+        let mut overrides = SceneOverrides::default();
+        overrides.set(door, Door::type_path(), "open", Box::new(true));
+        overrides.set(
+            door,
+            Door::type_path(),
+            "",
+            Box::new(Door {
+                width: 3.0,
+                open: false,
+            }),
+        );
+        world.spawn((ScenePrefab::new("door.scn.ron"), overrides));
+
+        let serialized = DynamicScene::from_world(&world)
+            .serialize(&registry.read())
+            .unwrap();
+        let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+        let scene = SceneDeserializer {
+            type_registry: &registry.read(),
+        }
+        .deserialize(&mut deserializer)
+        .unwrap();
+
+        let mut dst_world = World::new();
+        dst_world.insert_resource(registry);
+        scene
+            .write_to_world(&mut dst_world, &mut Default::default())
+            .unwrap();
+        let overrides = dst_world
+            .query::<&SceneOverrides>()
+            .single(&dst_world)
+            .unwrap();
+        assert_eq!(overrides.0.len(), 2);
+        assert_eq!(overrides.0[0].entity, door);
+        assert_eq!(overrides.0[0].path, "open");
+        assert!(overrides.0[0].value.reflect_partial_eq(&true).unwrap());
+        assert_eq!(overrides.0[1].path, "");
+        assert_eq!(overrides.0[1].component, Door::type_path());
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn loaded_scenes_use_redirected_prefab_paths() {
+        use bevy_asset::{
+            io::{memory::Dir, memory::MemoryAssetReader, AssetSource, AssetSourceId},
+            AssetApp, AssetServer, Handle,
+        };
+        use bevy_reflect::FromReflect;
+        use std::path::Path;
+
+        let mut world = World::new();
+        let registry = AppTypeRegistry::default();
+        registry.write().register::<ScenePrefab>();
+        world.insert_resource(registry.clone());
+        world.spawn(ScenePrefab::new("old.scn.ron"));
+        let serialized = DynamicScene::from_world(&world)
+            .serialize(&registry.read())
+            .unwrap();
+
+        let dir = Dir::default();
+        dir.insert_asset_text(Path::new("level.scn.ron"), &serialized);
+        dir.insert_asset_text(
+            Path::new("redirects.ron"),
+            r#"{ "old.scn.ron": "prefabs/new.scn.ron" }"#,
+        );
+        let mut app = App::new();
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSource::build()
+                .with_reader(move || Box::new(MemoryAssetReader { root: dir.clone() })),
+        )
+        .add_plugins((
+            TaskPoolPlugin::default(),
+            ScheduleRunnerPlugin::default(),
+            AssetPlugin {
+                redirects_file_path: Some("redirects.ron".into()),
+                ..Default::default()
+            },
+            ScenePlugin,
+        ));
+
+        let handle: Handle<DynamicScene> =
+            app.world().resource::<AssetServer>().load("level.scn.ron");
+        for _ in 0..1000 {
+            app.update();
+            if app
+                .world()
+                .resource::<Assets<DynamicScene>>()
+                .contains(&handle)
+            {
+                break;
+            }
+            std::thread::yield_now();
+        }
+        let scenes = app.world().resource::<Assets<DynamicScene>>();
+        let scene = scenes.get(&handle).expect("scene should be loaded");
+        let prefab = ScenePrefab::from_reflect(scene.entities[0].components[0].as_ref()).unwrap();
+        assert_eq!(prefab.0, AssetPath::from("prefabs/new.scn.ron"));
+    }
+}
@@ -1,4 +1,10 @@
-use bevy_reflect::{PartialReflect, ReflectFromReflect, TypeRegistration};
+use bevy_ecs::{
+    entity::{Entity, EntityMapper},
+    reflect::ReflectMapEntities,
+};
+use bevy_reflect::{
+    PartialReflect, ReflectFromReflect, ReflectMut, TypeRegistration, TypeRegistry,
+};
 
 /// Attempts to clone a [`PartialReflect`] value using various methods.
 ///
@@ -23,3 +29,77 @@ pub(super) fn clone_reflect_value(
                 .unwrap_or_else(|| value.to_dynamic())
         })
 }
+
+/// Maps the entities referenced by a [`PartialReflect`] value, which may be a part of a component.
+///
+/// Values whose type registers [`ReflectMapEntities`] are mapped with it. Other values are traversed, mapping every
+/// [`Entity`] they contain. Entities in map keys and sets are left unchanged, since they cannot be mutated in place.
+pub(super) fn map_reflected_entities(
+    value: &mut dyn PartialReflect,
+    type_registry: &TypeRegistry,
+    mapper: &mut dyn EntityMapper,
+) {
+    if let Some(map_entities) = value.get_represented_type_info().and_then(|type_info| {
+        type_registry.get_type_data::<ReflectMapEntities>(type_info.type_id())
+    }) {
+        map_entities.map_entities(value, mapper);
+        return;
+    }
+    match value.reflect_mut() {
+        ReflectMut::Struct(value) => {
+            for index in 0..value.field_len() {
+                if let Some(field) = value.field_at_mut(index) {
+                    map_reflected_entities(field, type_registry, mapper);
+                }
+            }
+        }
+        ReflectMut::TupleStruct(value) => {
+            for index in 0..value.field_len() {
+                if let Some(field) = value.field_mut(index) {
+                    map_reflected_entities(field, type_registry, mapper);
+                }
+            }
+        }
+        ReflectMut::Tuple(value) => {
+            for index in 0..value.field_len() {
+                if let Some(field) = value.field_mut(index) {
+                    map_reflected_entities(field, type_registry, mapper);
+                }
+            }
+        }
+        ReflectMut::List(value) => {
+            for index in 0..value.len() {
+                if let Some(item) = value.get_mut(index) {
+                    map_reflected_entities(item, type_registry, mapper);
+                }
+            }
+        }
+        ReflectMut::Array(value) => {
+            for index in 0..value.len() {
+                if let Some(item) = value.get_mut(index) {
+                    map_reflected_entities(item, type_registry, mapper);
+                }
+            }
+        }
+        ReflectMut::Map(value) => {
+            for index in 0..value.len() {
+                if let Some((_, item)) = value.get_at_mut(index) {
+                    map_reflected_entities(item, type_registry, mapper);
+                }
+            }
+        }
+        ReflectMut::Enum(value) => {
+            for index in 0..value.field_len() {
+                if let Some(field) = value.field_at_mut(index) {
+                    map_reflected_entities(field, type_registry, mapper);
+                }
+            }
+        }
+        ReflectMut::Opaque(value) => {
+            if let Some(entity) = value.try_downcast_mut::<Entity>() {
+                *entity = mapper.get_mapped(*entity);
+            }
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,708 @@
+use crate::{
+    ron,
+    serde::{SceneDeserializer, SceneSerializer},
+    serialize_ron, DynamicScene, DynamicSceneBuilder, SceneFilter, SceneSpawnError,
+};
+use alloc::{format, string::String, vec::Vec};
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_asset::{
+    io::{
+        AssetReaderError, AssetSourceId, AssetWriterError, MissingAssetSourceError,
+        MissingAssetWriterError,
+    },
+    AssetServer,
+};
+use bevy_ecs::{
+    component::Component,
+    entity::{Entity, EntityHashMap},
+    event::{Event, EventCursor, Events},
+    prelude::With,
+    reflect::{AppTypeRegistry, ReflectComponent},
+    resource::Resource,
+    schedule::IntoScheduleConfigs,
+    system::Local,
+    world::World,
+};
+use bevy_reflect::{prelude::ReflectDefault, Reflect, TypeRegistry};
+use bevy_tasks::{block_on, poll_once, IoTaskPool, Task};
+use core::fmt::Formatter;
+use serde::{
+    de::{Error as _, IgnoredAny, MapAccess, SeqAccess, Visitor},
+    ser::SerializeStruct,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use std::path::{Component as PathComponent, Path, PathBuf};
+use thiserror::Error;
+
+/// Marks an entity to be saved by the [`SaveGamePlugin`].
+///
+/// Only entities with this component are written to save slots, and all entities with this component are replaced
+/// when a save slot is loaded.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component, Default, Debug, Clone)]
+pub struct Saveable;
+
+/// Adds saving and loading of [`Saveable`] entities to save slots.
+///
+/// Send a [`SaveGame`] or [`LoadGame`] event to save or load a slot. The slot is written to or read from
+/// `<directory>/<slot>.save.ron` in the configured asset source, and a [`SaveGameCompleted`] or [`LoadGameCompleted`]
+/// event is sent once the operation has finished. The settings can be changed at runtime through the
+/// [`SaveGameSettings`] resource.
+#[derive(Default)]
+pub struct SaveGamePlugin {
+    /// The initial [`SaveGameSettings`].
+    pub settings: SaveGameSettings,
+}
+
+impl Plugin for SaveGamePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Saveable>()
+            .insert_resource(self.settings.clone())
+            .init_resource::<SaveGameTasks>()
+            .add_event::<SaveGame>()
+            .add_event::<LoadGame>()
+            .add_event::<SaveGameCompleted>()
+            .add_event::<LoadGameCompleted>()
+            .add_systems(
+                PostUpdate,
+                (start_save_game_tasks, finish_save_game_tasks).chain(),
+            );
+    }
+}
+
+/// Settings of the [`SaveGamePlugin`].
+#[derive(Resource, Clone, Debug)]
+pub struct SaveGameSettings {
+    /// The asset source that save slots are written to and read from. It must have an
+    /// [`AssetWriter`](bevy_asset::io::AssetWriter).
+    pub source: AssetSourceId<'static>,
+    /// The directory within [`SaveGameSettings::source`] that contains the save slots.
+    pub directory: PathBuf,
+    /// The version written to save slots. Loading a slot that was written with a newer version fails with
+    /// [`SaveGameError::WrongVersion`]. Slots written with an older version are loaded, and their components and
+    /// resources are upgraded with the registered [`SceneMigrations`](crate::SceneMigrations).
+    pub version: u32,
+    /// Filters the components of [`Saveable`] entities that are saved.
+    pub component_filter: SceneFilter,
+    /// Filters the resources that are saved.
+    pub resource_filter: SceneFilter,
+}
+
+impl Default for SaveGameSettings {
+    fn default() -> Self {
+        Self {
+            source: AssetSourceId::Default,
+            directory: PathBuf::from("saves"),
+            version: 0,
+            component_filter: SceneFilter::allow_all(),
+            resource_filter: SceneFilter::deny_all(),
+        }
+    }
+}
+
+impl SaveGameSettings {
+    /// Returns the path of the given save slot within [`SaveGameSettings::source`].
+    ///
+    /// Fails with [`SaveGameError::InvalidSlot`] if the slot name is empty, contains a path separator, or is a
+    /// relative or absolute path component such as `..`, so that slots cannot escape
+    /// [`SaveGameSettings::directory`].
+    pub fn slot_path(&self, slot: &str) -> Result<PathBuf, SaveGameError> {
+        let mut components = Path::new(slot).components();
+        let is_file_name = matches!(
+            (components.next(), components.next()),
+            (Some(PathComponent::Normal(_)), None)
+        );
+        if !is_file_name || slot.contains(['/', '\\']) {
+            return Err(SaveGameError::InvalidSlot(slot.into()));
+        }
+        Ok(self.directory.join(format!("{slot}.save.ron")))
+    }
+}
+
+/// Saves all [`Saveable`] entities to a save slot.
+#[derive(Event, Clone, Debug)]
+pub struct SaveGame {
+    /// The name of the save slot.
+    pub slot: String,
+}
+
+/// Replaces all [`Saveable`] entities with the entities of a save slot.
+#[derive(Event, Clone, Debug)]
+pub struct LoadGame {
+    /// The name of the save slot.
+    pub slot: String,
+}
+
+/// Sent when a [`SaveGame`] request has finished.
+#[derive(Event, Debug)]
+pub struct SaveGameCompleted {
+    /// The name of the save slot.
+    pub slot: String,
+    /// Whether the slot was saved successfully.
+    pub result: Result<(), SaveGameError>,
+}
+
+/// Sent when a [`LoadGame`] request has finished.
+#[derive(Event, Debug)]
+pub struct LoadGameCompleted {
+    /// The name of the save slot.
+    pub slot: String,
+    /// Whether the slot was loaded successfully.
+    pub result: Result<(), SaveGameError>,
+}
+
+/// An error that occurs when saving or loading a save slot.
+#[derive(Error, Debug)]
+pub enum SaveGameError {
+    /// The configured asset source does not exist.
+    #[error(transparent)]
+    MissingAssetSource(#[from] MissingAssetSourceError),
+    /// The configured asset source cannot be written to.
+    #[error(transparent)]
+    MissingAssetWriter(#[from] MissingAssetWriterError),
+    /// The save slot could not be read.
+    #[error("Failed to read save slot: {0}")]
+    Read(#[from] AssetReaderError),
+    /// The save slot could not be written.
+    #[error("Failed to write save slot: {0}")]
+    Write(#[from] AssetWriterError),
+    /// An [IO Error](std::io::Error)
+    #[error("Error while trying to read the save slot: {0}")]
+    Io(#[from] std::io::Error),
+    /// The save slot could not be serialized.
+    #[error("Could not write RON: {0}")]
+    Ron(#[from] ron::Error),
+    /// The save slot could not be parsed.
+    #[error("Could not parse RON: {0}")]
+    RonSpannedError(#[from] ron::error::SpannedError),
+    /// The save slot name is not a plain file name, see [`SaveGameSettings::slot_path`].
+    #[error("invalid save slot name `{0}`")]
+    InvalidSlot(String),
+    /// The save slot was written with a newer [`SaveGameSettings::version`] than the running game.
+    #[error("expected save version {expected} or older but found version {found}")]
+    WrongVersion {
+        /// The version of the running game.
+        expected: u32,
+        /// The version of the save slot.
+        found: u32,
+    },
+    /// The loaded entities could not be spawned.
+    #[error(transparent)]
+    Spawn(#[from] SceneSpawnError),
+}
+
+/// The save and load operations that are currently running.
+#[derive(Resource, Default)]
+pub struct SaveGameTasks {
+    saving: Vec<(String, Task<Result<(), SaveGameError>>)>,
+    loading: Vec<(String, Task<Result<DynamicScene, SaveGameError>>)>,
+}
+
+impl SaveGameTasks {
+    /// Returns `true` if no save or load operation is running.
+    pub fn is_idle(&self) -> bool {
+        self.saving.is_empty() && self.loading.is_empty()
+    }
+}
+
+/// Extracts the [`Saveable`] entities of `world` into a [`DynamicScene`], using the filters of `settings`.
+pub fn extract_saveable(world: &mut World, settings: &SaveGameSettings) -> DynamicScene {
+    let entities = world
+        .query_filtered::<Entity, With<Saveable>>()
+        .iter(world)
+        .collect::<Vec<_>>();
+    DynamicSceneBuilder::from_world(world)
+        .with_component_filter(settings.component_filter.clone())
+        .with_resource_filter(settings.resource_filter.clone())
+        .extract_entities(entities.into_iter())
+        .extract_resources()
+        .build()
+}
+
+/// Replaces the [`Saveable`] entities of `world` with the entities of `scene`.
+///
+/// Entity references within the scene are mapped to the newly spawned entities.
+pub fn replace_saveable(world: &mut World, scene: &DynamicScene) -> Result<(), SceneSpawnError> {
+    let entities = world
+        .query_filtered::<Entity, With<Saveable>>()
+        .iter(world)
+        .collect::<Vec<_>>();
+    for entity in entities {
+        // Despawning an entity also despawns its children, which may have been in the list.
+        if let Ok(entity_mut) = world.get_entity_mut(entity) {
+            entity_mut.despawn();
+        }
+    }
+
+    let mut entity_map = EntityHashMap::default();
+    scene.write_to_world(world, &mut entity_map)?;
+    for entity in entity_map.values() {
+        world.entity_mut(*entity).insert(Saveable);
+    }
+    Ok(())
+}
+
+/// System that starts the save and load operations requested with [`SaveGame`] and [`LoadGame`] events.
+pub fn start_save_game_tasks(
+    world: &mut World,
+    mut save_requests: Local<EventCursor<SaveGame>>,
+    mut load_requests: Local<EventCursor<LoadGame>>,
+) {
+    let saves = save_requests
+        .read(world.resource::<Events<SaveGame>>())
+        .map(|request| request.slot.clone())
+        .collect::<Vec<_>>();
+    let loads = load_requests
+        .read(world.resource::<Events<LoadGame>>())
+        .map(|request| request.slot.clone())
+        .collect::<Vec<_>>();
+    if saves.is_empty() && loads.is_empty() {
+        return;
+    }
+
+    let settings = world.resource::<SaveGameSettings>().clone();
+    let asset_server = world.resource::<AssetServer>().clone();
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+
+    for slot in saves {
+        let scene = extract_saveable(world, &settings);
+        let bytes = serialize_save(&scene, settings.version, &type_registry.read());
+        let asset_server = asset_server.clone();
+        let source = settings.source.clone();
+        let path = settings.slot_path(&slot);
+        let task = IoTaskPool::get().spawn(async move {
+            let path = path?;
+            let bytes = bytes?;
+            let writer = asset_server.get_source(source)?.writer()?;
+            writer.write_bytes(&path, bytes.as_bytes()).await?;
+            Ok(())
+        });
+        world
+            .resource_mut::<SaveGameTasks>()
+            .saving
+            .push((slot, task));
+    }
+
+    for slot in loads {
+        let asset_server = asset_server.clone();
+        let type_registry = type_registry.clone();
+        let source = settings.source.clone();
+        let path = settings.slot_path(&slot);
+        let version = settings.version;
+        let task = IoTaskPool::get().spawn(async move {
+            let path = path?;
+            let mut reader = asset_server
+                .get_source(source)?
+                .reader()
+                .read(&path)
+                .await?;
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            deserialize_save(&bytes, version, &type_registry.read())
+        });
+        world
+            .resource_mut::<SaveGameTasks>()
+            .loading
+            .push((slot, task));
+    }
+}
+
+/// System that finishes the save and load operations started by [`start_save_game_tasks`], spawning the loaded
+/// entities and sending [`SaveGameCompleted`] and [`LoadGameCompleted`] events.
+pub fn finish_save_game_tasks(world: &mut World) {
+    let mut tasks = core::mem::take(&mut *world.resource_mut::<SaveGameTasks>());
+
+    tasks.saving.retain_mut(|(slot, task)| {
+        let Some(result) = block_on(poll_once(task)) else {
+            return true;
+        };
+        world.send_event(SaveGameCompleted {
+            slot: slot.clone(),
+            result,
+        });
+        false
+    });
+
+    tasks.loading.retain_mut(|(slot, task)| {
+        let Some(result) = block_on(poll_once(task)) else {
+            return true;
+        };
+        let result = result.and_then(|scene| Ok(replace_saveable(world, &scene)?));
+        world.send_event(LoadGameCompleted {
+            slot: slot.clone(),
+            result,
+        });
+        false
+    });
+
+    let mut current = world.resource_mut::<SaveGameTasks>();
+    current.saving.append(&mut tasks.saving);
+    current.loading.append(&mut tasks.loading);
+}
+
+const SAVE_STRUCT: &str = "SaveGame";
+const SAVE_VERSION: &str = "version";
+const SAVE_SCENE: &str = "scene";
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum SaveField {
+    Version,
+    Scene,
+}
+
+/// Serializes a save slot as its version followed by the scene.
+fn serialize_save(
+    scene: &DynamicScene,
+    version: u32,
+    registry: &TypeRegistry,
+) -> Result<String, SaveGameError> {
+    struct SaveSerializer<'a> {
+        version: u32,
+        scene: SceneSerializer<'a>,
+    }
+
+    impl Serialize for SaveSerializer<'_> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let mut state = serializer.serialize_struct(SAVE_STRUCT, 2)?;
+            state.serialize_field(SAVE_VERSION, &self.version)?;
+            state.serialize_field(SAVE_SCENE, &self.scene)?;
+            state.end()
+        }
+    }
+
+    Ok(serialize_ron(SaveSerializer {
+        version,
+        scene: SceneSerializer::new(scene, registry),
+    })?)
+}
+
+/// Deserializes a save slot written by [`serialize_save`], checking that it is not newer than the `expected` version.
+///
+/// The scene of an older save is upgraded by the [`SceneMigrations`](crate::SceneMigrations) according to the scene
+/// version stored in it.
+fn deserialize_save(
+    bytes: &[u8],
+    expected: u32,
+    registry: &TypeRegistry,
+) -> Result<DynamicScene, SaveGameError> {
+    struct SaveVisitor<'a> {
+        expected: u32,
+        registry: &'a TypeRegistry,
+    }
+
+    impl<'a> SaveVisitor<'a> {
+        fn scene_deserializer(&self) -> SceneDeserializer<'a> {
+            SceneDeserializer {
+                type_registry: self.registry,
+            }
+        }
+    }
+
+    impl<'de> Visitor<'de> for SaveVisitor<'_> {
+        type Value = Result<DynamicScene, u32>;
+
+        fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+            formatter.write_str("save game struct")
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let version: u32 = seq
+                .next_element()?
+                .ok_or_else(|| A::Error::missing_field(SAVE_VERSION))?;
+            if version > self.expected {
+                while seq.next_element::<IgnoredAny>()?.is_some() {}
+                return Ok(Err(version));
+            }
+            let scene = seq
+                .next_element_seed(self.scene_deserializer())?
+                .ok_or_else(|| A::Error::missing_field(SAVE_SCENE))?;
+            Ok(Ok(scene))
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut version = None;
+            let mut scene = None;
+            while let Some(key) = map.next_key()? {
+                match key {
+                    SaveField::Version => {
+                        let found: u32 = map.next_value()?;
+                        if found > self.expected {
+                            // The scene of a newer version may not deserialize, so skip it.
+                            while map.next_entry::<IgnoredAny, IgnoredAny>()?.is_some() {}
+                            return Ok(Err(found));
+                        }
+                        version = Some(found);
+                    }
+                    SaveField::Scene => {
+                        if version.is_none() {
+                            // The version decides whether the scene can be read at all.
+                            return Err(A::Error::custom("save version must precede the scene"));
+                        }
+                        scene = Some(map.next_value_seed(self.scene_deserializer())?);
+                    }
+                }
+            }
+            version.ok_or_else(|| A::Error::missing_field(SAVE_VERSION))?;
+            Ok(Ok(scene.ok_or_else(|| A::Error::missing_field(SAVE_SCENE))?))
+        }
+    }
+
+    let mut deserializer = ron::de::Deserializer::from_bytes(bytes)?;
+    let visitor = SaveVisitor { expected, registry };
+    let result = deserializer
+        .deserialize_struct(SAVE_STRUCT, &[SAVE_VERSION, SAVE_SCENE], visitor)
+        .map_err(|e| deserializer.span_error(e))?;
+    result.map_err(|found| SaveGameError::WrongVersion { expected, found })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        LoadGame, LoadGameCompleted, SaveGame, SaveGameCompleted, SaveGameError, SaveGamePlugin,
+        SaveGameSettings, SaveGameTasks, Saveable,
+    };
+    use crate::{SceneMigrationApp, ScenePlugin};
+    use alloc::{boxed::Box, string::ToString, vec::Vec};
+    use bevy_app::{App, ScheduleRunnerPlugin, TaskPoolPlugin};
+    use bevy_asset::{
+        io::{
+            memory::{Dir, MemoryAssetReader, MemoryAssetWriter},
+            AssetSource, AssetSourceId,
+        },
+        AssetApp, AssetPlugin,
+    };
+    use bevy_ecs::{
+        component::Component,
+        entity::{Entity, MapEntities},
+        event::Events,
+        prelude::{ReflectComponent, With},
+        reflect::ReflectMapEntities,
+    };
+    use bevy_reflect::Reflect;
+    use std::path::Path;
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Health(u32);
+
+    #[derive(Component, Reflect, MapEntities, Debug, PartialEq)]
+    #[reflect(Component, MapEntities)]
+    struct Target(#[entities] Entity);
+
+    fn test_app(dir: Dir, version: u32) -> App {
+        let mut app = App::new();
+        let reader_dir = dir.clone();
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSource::build()
+                .with_reader(move || {
+                    Box::new(MemoryAssetReader {
+                        root: reader_dir.clone(),
+                    })
+                })
+                .with_writer(move |_| Some(Box::new(MemoryAssetWriter { root: dir.clone() }))),
+        )
+        .add_plugins((
+            TaskPoolPlugin::default(),
+            ScheduleRunnerPlugin::default(),
+            AssetPlugin::default(),
+            ScenePlugin,
+            SaveGamePlugin {
+                settings: SaveGameSettings {
+                    version,
+                    ..Default::default()
+                },
+            },
+        ))
+        .register_type::<Health>()
+        .register_type::<Target>();
+        app
+    }
+
+    fn run_until_idle(app: &mut App) {
+        for _ in 0..1000 {
+            app.update();
+            if app.world().resource::<SaveGameTasks>().is_idle() {
+                return;
+            }
+            std::thread::yield_now();
+        }
+        panic!("save game tasks did not finish");
+    }
+
+    #[test]
+    fn save_and_load_slot() {
+        let dir = Dir::default();
+        let mut app = test_app(dir.clone(), 1);
+        let world = app.world_mut();
+        let enemy = world.spawn((Saveable, Health(10))).id();
+        world.spawn((Saveable, Health(20), Target(enemy)));
+        world.spawn(Health(30));
+
+        world.send_event(SaveGame {
+            slot: "slot1".to_string(),
+        });
+        run_until_idle(&mut app);
+        let completed = app
+            .world_mut()
+            .resource_mut::<Events<SaveGameCompleted>>()
+            .drain()
+            .collect::<Vec<_>>();
+        assert_eq!(completed.len(), 1);
+        assert_eq!(completed[0].slot, "slot1");
+        assert!(completed[0].result.is_ok());
+        assert!(dir.get_asset(Path::new("saves/slot1.save.ron")).is_some());
+
+        // Change the world, then restore the saved state.
+        let world = app.world_mut();
+        world.entity_mut(enemy).despawn();
+        world.spawn((Saveable, Health(40)));
+        world.send_event(LoadGame {
+            slot: "slot1".to_string(),
+        });
+        run_until_idle(&mut app);
+        let completed = app
+            .world_mut()
+            .resource_mut::<Events<LoadGameCompleted>>()
+            .drain()
+            .collect::<Vec<_>>();
+        assert_eq!(completed.len(), 1);
+        assert!(completed[0].result.is_ok());
+
+        let world = app.world_mut();
+        let mut health = world
+            .query_filtered::<&Health, With<Saveable>>()
+            .iter(world)
+            .map(|health| health.0)
+            .collect::<Vec<_>>();
+        health.sort();
+        assert_eq!(health, [10, 20]);
+        assert_eq!(world.query::<&Health>().iter(world).len(), 3);
+
+        // The entity reference points at the restored entity.
+        let target = world.query::<&Target>().single(world).unwrap().0;
+        assert_eq!(world.get::<Health>(target), Some(&Health(10)));
+    }
+
+    #[test]
+    fn load_rejects_newer_version() {
+        let dir = Dir::default();
+        dir.insert_asset_text(
+            Path::new("saves/new.save.ron"),
+            "(version: 3, scene: (resources: {}, entities: {}))",
+        );
+        let mut app = test_app(dir, 2);
+        app.world_mut().send_event(LoadGame {
+            slot: "new".to_string(),
+        });
+        run_until_idle(&mut app);
+
+        let completed = app
+            .world_mut()
+            .resource_mut::<Events<LoadGameCompleted>>()
+            .drain()
+            .collect::<Vec<_>>();
+        assert!(matches!(
+            completed[0].result,
+            Err(SaveGameError::WrongVersion {
+                expected: 2,
+                found: 3
+            })
+        ));
+
+        // Missing slots are reported as well.
+        app.world_mut().send_event(LoadGame {
+            slot: "missing".to_string(),
+        });
+        run_until_idle(&mut app);
+        let completed = app
+            .world_mut()
+            .resource_mut::<Events<LoadGameCompleted>>()
+            .drain()
+            .collect::<Vec<_>>();
+        assert!(matches!(completed[0].result, Err(SaveGameError::Read(_))));
+    }
+
+    #[derive(Reflect)]
+    struct HealthV0 {
+        hp: u32,
+    }
+
+    #[test]
+    fn load_migrates_older_version() {
+        let dir = Dir::default();
+        // Written before the migration was registered, so the scene has no version.
+        dir.insert_asset_text(
+            Path::new("saves/old.save.ron"),
+            r#"(
+  version: 1,
+  scene: (
+    resources: {},
+    entities: {
+      4294967296: (
+        components: {
+          "bevy_scene::save_game::tests::Health": (hp: 7),
+        },
+      ),
+    },
+  ),
+)"#,
+        );
+        let mut app = test_app(dir, 2);
+        app.register_scene_migration::<Health, HealthV0, Health>(1, |old| Health(old.hp));
+        app.world_mut().send_event(LoadGame {
+            slot: "old".to_string(),
+        });
+        run_until_idle(&mut app);
+
+        let completed = app
+            .world_mut()
+            .resource_mut::<Events<LoadGameCompleted>>()
+            .drain()
+            .collect::<Vec<_>>();
+        assert!(completed[0].result.is_ok());
+        let world = app.world_mut();
+        let health = world.query::<&Health>().single(world).unwrap();
+        assert_eq!(health, &Health(7));
+    }
+
+    #[test]
+    fn slots_cannot_escape_the_save_directory() {
+        let settings = SaveGameSettings::default();
+        assert_eq!(
+            settings.slot_path("slot1").unwrap(),
+            Path::new("saves/slot1.save.ron")
+        );
+        for slot in ["", "..", ".", "a/b", "../up", "a\\b", "/root"] {
+            assert!(
+                matches!(settings.slot_path(slot), Err(SaveGameError::InvalidSlot(_))),
+                "{slot:?} should be rejected"
+            );
+        }
+
+        let mut app = test_app(Dir::default(), 1);
+        app.world_mut().send_event(SaveGame {
+            slot: "../escape".to_string(),
+        });
+        run_until_idle(&mut app);
+        let completed = app
+            .world_mut()
+            .resource_mut::<Events<SaveGameCompleted>>()
+            .drain()
+            .collect::<Vec<_>>();
+        assert!(matches!(
+            completed[0].result,
+            Err(SaveGameError::InvalidSlot(_))
+        ));
+    }
+}
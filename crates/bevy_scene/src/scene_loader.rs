@@ -9,8 +9,14 @@ use thiserror::Error;
 #[cfg(feature = "serialize")]
 use {
     crate::{serde::SceneDeserializer, DynamicScene},
-    bevy_asset::{io::Reader, AssetLoader, LoadContext},
-    serde::de::DeserializeSeed,
+    bevy_asset::{
+        io::{Reader, Writer},
+        processor::LoadTransformAndSave,
+        saver::{AssetSaver, SavedAsset},
+        transformer::IdentityAssetTransformer,
+        AssetLoader, AsyncWriteExt, LoadContext,
+    },
+    serde::{de::DeserializeSeed, Deserialize, Serialize},
 };
 
 /// Asset loader for a Bevy dynamic scene (`.scn` / `.scn.ron`).
@@ -56,7 +62,7 @@ impl AssetLoader for SceneLoader {
         &self,
         reader: &mut dyn Reader,
         _settings: &(),
-        _load_context: &mut LoadContext<'_>,
+        load_context: &mut LoadContext<'_>,
     ) -> Result<Self::Asset, Self::Error> {
         let mut bytes = Vec::new();
         reader.read_to_end(&mut bytes).await?;
@@ -64,12 +70,99 @@ impl AssetLoader for SceneLoader {
         let scene_deserializer = SceneDeserializer {
             type_registry: &self.type_registry.read(),
         };
-        Ok(scene_deserializer
+        let mut scene = scene_deserializer
             .deserialize(&mut deserializer)
-            .map_err(|e| deserializer.span_error(e))?)
+            .map_err(|e| deserializer.span_error(e))?;
+        redirect_scene_paths(&mut scene, load_context);
+        Ok(scene)
     }
 
     fn extensions(&self) -> &[&str] {
         &["scn", "scn.ron"]
     }
 }
+
+/// Replaces the [`AssetPath`](bevy_asset::AssetPath)s stored in the components and resources of `scene`, such as the
+/// path of a [`ScenePrefab`](crate::ScenePrefab), with the paths they are redirected to.
+#[cfg(feature = "serialize")]
+pub(crate) fn redirect_scene_paths(scene: &mut DynamicScene, load_context: &LoadContext) {
+    let components = scene
+        .entities
+        .iter_mut()
+        .flat_map(|entity| entity.components.iter_mut());
+    for value in scene.resources.iter_mut().chain(components) {
+        load_context.redirect_paths(value.as_mut());
+    }
+}
+
+/// Asset saver that writes a [`DynamicScene`] in the RON scene format, to be loaded with the [`SceneLoader`].
+#[derive(Debug)]
+pub struct SceneSaver {
+    #[cfg_attr(
+        not(feature = "serialize"),
+        expect(dead_code, reason = "only used with `serialize` feature")
+    )]
+    type_registry: TypeRegistryArc,
+}
+
+impl FromWorld for SceneSaver {
+    fn from_world(world: &mut World) -> Self {
+        let type_registry = world.resource::<AppTypeRegistry>();
+        SceneSaver {
+            type_registry: type_registry.0.clone(),
+        }
+    }
+}
+
+/// Settings of the [`SceneSaver`].
+#[cfg(feature = "serialize")]
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+pub struct SceneSaverSettings {
+    /// Whether to skip the fields of components and resources that are equal to their default value,
+    /// see [`DynamicScene::serialize_without_defaults`].
+    pub skip_defaults: bool,
+}
+
+/// Possible errors that can be produced by [`SceneSaver`]
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum SceneSaverError {
+    /// An [IO Error](std::io::Error)
+    #[error("Error while trying to write the scene file: {0}")]
+    Io(#[from] std::io::Error),
+    /// A [RON Error](ron::Error)
+    #[error("Could not write RON: {0}")]
+    Ron(#[from] ron::Error),
+}
+
+#[cfg(feature = "serialize")]
+impl AssetSaver for SceneSaver {
+    type Asset = DynamicScene;
+    type Settings = SceneSaverSettings;
+    type OutputLoader = SceneLoader;
+    type Error = SceneSaverError;
+
+    async fn save(
+        &self,
+        writer: &mut Writer,
+        asset: SavedAsset<'_, DynamicScene>,
+        settings: &SceneSaverSettings,
+    ) -> Result<(), SceneSaverError> {
+        let ron = match settings.skip_defaults {
+            true => asset.serialize_without_defaults(&self.type_registry.read())?,
+            false => asset.serialize(&self.type_registry.read())?,
+        };
+        writer.write_all(ron.as_bytes()).await?;
+        Ok(())
+    }
+}
+
+/// An [`AssetProcessor`](bevy_asset::processor::AssetProcessor) for RON scenes, which loads them with the
+/// [`SceneLoader`] and saves them again with the [`SceneSaver`]. This applies the
+/// [`AssetRedirects`](bevy_asset::AssetRedirects) to the asset paths stored in the scene, so the processed scene
+/// references the new paths of moved assets.
+///
+/// The [`ScenePlugin`](crate::ScenePlugin) makes this the default processor for `.scn` and `.scn.ron` files.
+#[cfg(feature = "serialize")]
+pub type SceneProcessor =
+    LoadTransformAndSave<SceneLoader, IdentityAssetTransformer<DynamicScene>, SceneSaver>;
@@ -0,0 +1,872 @@
+use crate::{
+    prefab::InstanceEntityMapper,
+    reflect_utils::{clone_reflect_value, map_reflected_entities},
+    DynamicEntity, DynamicScene, SceneOverride, SceneOverrideError, SceneSpawnError,
+};
+use alloc::{borrow::ToOwned, boxed::Box, format, string::String, vec::Vec};
+use bevy_ecs::{
+    entity::{Entity, EntityHashMap, EntityHashSet},
+    name::Name,
+    reflect::{AppTypeRegistry, ReflectComponent, ReflectResource},
+    world::World,
+};
+use bevy_platform::collections::HashMap;
+use bevy_reflect::{FromReflect, PartialReflect, ReflectRef, TypeInfo, TypePath, TypeRegistry};
+use thiserror::Error;
+
+#[cfg(feature = "serialize")]
+use {
+    crate::ron, crate::serde::ScenePatchSerializer, crate::serialize_ron,
+    bevy_reflect::serde::TypedReflectSerializer,
+};
+
+/// The difference between two [`DynamicScene`]s, which turns the first scene into the second when applied to it.
+///
+/// A patch is computed with [`ScenePatch::diff`] and can be applied to a scene with [`ScenePatch::apply_to_scene`] or
+/// to a spawned instance of the scene with [`ScenePatch::write_to_world`]. This allows storing the state of a level as
+/// a small delta against the authored level scene, for example in save games.
+///
+/// Entities are matched by their [`Name`] if they have one, and by their id otherwise. To match them in another way,
+/// for example with the entity map of a spawned instance, use [`ScenePatch::diff_with_entity_map`]. The patch always
+/// uses the ids of the base scene. The target scene is usually extracted from a world with a
+/// [`DynamicSceneBuilder`](crate::DynamicSceneBuilder), using a [`SceneFilter`](crate::SceneFilter) to select the
+/// components and resources that should be part of the patch.
+///
+/// When the patch is written to a world, entity references in added components and [changed fields](ScenePatch::changed)
+/// are mapped to the entities of the instance, like in [`DynamicScene::write_to_world_with`].
+#[derive(Default)]
+pub struct ScenePatch {
+    /// Entities, components and resources added by the patch.
+    ///
+    /// This contains every entity that is only part of the target scene with all its components, as well as the
+    /// components that were added to existing entities. Resources that were added or changed are stored here in full.
+    pub added: DynamicScene,
+    /// Entities removed by the patch.
+    pub removed_entities: Vec<Entity>,
+    /// Components removed from existing entities, as pairs of entity and component type path.
+    pub removed_components: Vec<(Entity, String)>,
+    /// Type paths of the resources removed by the patch.
+    pub removed_resources: Vec<String>,
+    /// Fields of existing components that changed, addressed by [reflection paths](bevy_reflect::ParsedPath).
+    pub changed: Vec<SceneOverride>,
+}
+
+/// An error that occurs when applying a [`ScenePatch`].
+#[derive(Error, Debug)]
+pub enum ScenePatchError {
+    /// Adding or removing entities, components, or resources failed.
+    #[error(transparent)]
+    Spawn(#[from] SceneSpawnError),
+    /// Changing a field failed.
+    #[error(transparent)]
+    Change(#[from] SceneOverrideError),
+}
+
+impl ScenePatch {
+    /// Computes the patch that turns `base` into `target`.
+    ///
+    /// Entities are matched by their [`Name`] if they have one, and by their id otherwise. Values without a
+    /// represented type are ignored, and changed fields are found by comparing the values with
+    /// [`PartialReflect::reflect_partial_eq`]. Values that do not support it are compared in their serialized form
+    /// instead, if the `serialize` feature is enabled.
+    pub fn diff(base: &DynamicScene, target: &DynamicScene, type_registry: &TypeRegistry) -> Self {
+        let entity_map = match_entities(base, target);
+        Self::diff_with_entity_map(base, target, &entity_map, type_registry)
+    }
+
+    /// Computes the patch that turns `base` into `target`, where `entity_map` maps entities of `target` to the
+    /// matching entities of `base`.
+    ///
+    /// Entities of `target` that are not in `entity_map` are added by the patch, and entities of `base` that no entity
+    /// is mapped to are removed. Entity references in the values of `target` are mapped to the entities of `base` as
+    /// well. Added entities keep their id, unless it is already used by `base`.
+    pub fn diff_with_entity_map(
+        base: &DynamicScene,
+        target: &DynamicScene,
+        entity_map: &EntityHashMap<Entity>,
+        type_registry: &TypeRegistry,
+    ) -> Self {
+        let mut patch = ScenePatch::default();
+        let base_entities: EntityHashMap<&DynamicEntity> = base
+            .entities
+            .iter()
+            .map(|entity| (entity.entity, entity))
+            .collect();
+
+        // Map every entity of the target scene to an entity of the base scene, or to an unused id.
+        let mut to_base = EntityHashMap::default();
+        let mut used: EntityHashSet = base.entities.iter().map(|entity| entity.entity).collect();
+        for target_entity in &target.entities {
+            if let Some(&base_entity) = entity_map
+                .get(&target_entity.entity)
+                .filter(|entity| base_entities.contains_key(*entity))
+            {
+                to_base.insert(target_entity.entity, base_entity);
+            }
+        }
+        let mut next_index = 0;
+        for target_entity in &target.entities {
+            if to_base.contains_key(&target_entity.entity) {
+                continue;
+            }
+            let mut entity = target_entity.entity;
+            while used.contains(&entity) {
+                entity = Entity::from_raw(next_index);
+                next_index += 1;
+            }
+            used.insert(entity);
+            to_base.insert(target_entity.entity, entity);
+        }
+        let map_value = |value: &dyn PartialReflect| {
+            let mut value = clone_value(value, type_registry);
+            map_reflected_entities(
+                &mut *value,
+                type_registry,
+                &mut InstanceEntityMapper(&to_base),
+            );
+            value
+        };
+
+        let target_resources: Vec<_> = target
+            .resources
+            .iter()
+            .map(|resource| map_value(&**resource))
+            .collect();
+        let base_resources = values_by_type_path(&base.resources);
+        let target_resource_types = values_by_type_path(&target_resources);
+        for (type_path, resource) in iter_by_type_path(&target_resources) {
+            let unchanged = base_resources
+                .get(type_path)
+                .is_some_and(|base| values_equal(*base, resource, type_registry));
+            if !unchanged {
+                patch
+                    .added
+                    .resources
+                    .push(clone_value(resource, type_registry));
+            }
+        }
+        patch.removed_resources = iter_by_type_path(&base.resources)
+            .filter(|(type_path, _)| !target_resource_types.contains_key(type_path))
+            .map(|(type_path, _)| type_path.to_owned())
+            .collect();
+
+        let matched: EntityHashSet = to_base.values().copied().collect();
+        patch.removed_entities = base
+            .entities
+            .iter()
+            .map(|entity| entity.entity)
+            .filter(|entity| !matched.contains(entity))
+            .collect();
+
+        for target_entity in &target.entities {
+            let entity = to_base[&target_entity.entity];
+            let target_components: Vec<_> = target_entity
+                .components
+                .iter()
+                .map(|component| map_value(&**component))
+                .collect();
+            let Some(base_entity) = base_entities.get(&entity) else {
+                patch.added.entities.push(DynamicEntity {
+                    entity,
+                    components: target_components,
+                });
+                continue;
+            };
+
+            let base_components = values_by_type_path(&base_entity.components);
+            let target_component_types = values_by_type_path(&target_components);
+            let mut added_components = Vec::new();
+            for (type_path, component) in iter_by_type_path(&target_components) {
+                let Some(base_component) = base_components.get(type_path) else {
+                    added_components.push(clone_value(component, type_registry));
+                    continue;
+                };
+                let mut changed_fields = Vec::new();
+                diff_fields(
+                    String::new(),
+                    *base_component,
+                    component,
+                    type_registry,
+                    &mut changed_fields,
+                );
+                patch
+                    .changed
+                    .extend(
+                        changed_fields
+                            .into_iter()
+                            .map(|(path, value)| SceneOverride {
+                                entity,
+                                component: type_path.to_owned(),
+                                path,
+                                value: clone_value(value, type_registry),
+                            }),
+                    );
+            }
+            patch.removed_components.extend(
+                iter_by_type_path(&base_entity.components)
+                    .filter(|(type_path, _)| !target_component_types.contains_key(type_path))
+                    .map(|(type_path, _)| (entity, type_path.to_owned())),
+            );
+            if !added_components.is_empty() {
+                patch.added.entities.push(DynamicEntity {
+                    entity,
+                    components: added_components,
+                });
+            }
+        }
+
+        patch
+    }
+
+    /// Returns `true` if applying the patch does not change anything.
+    pub fn is_empty(&self) -> bool {
+        self.added.resources.is_empty()
+            && self.added.entities.is_empty()
+            && self.removed_entities.is_empty()
+            && self.removed_components.is_empty()
+            && self.removed_resources.is_empty()
+            && self.changed.is_empty()
+    }
+
+    /// Applies the patch to `scene`.
+    pub fn apply_to_scene(
+        &self,
+        scene: &mut DynamicScene,
+        type_registry: &TypeRegistry,
+    ) -> Result<(), ScenePatchError> {
+        scene
+            .entities
+            .retain(|entity| !self.removed_entities.contains(&entity.entity));
+        scene.resources.retain(|resource| {
+            type_path_of(&**resource)
+                .is_none_or(|type_path| !self.removed_resources.iter().any(|r| r == type_path))
+        });
+        for (entity, type_path) in &self.removed_components {
+            if let Some(scene_entity) = scene.entities.iter_mut().find(|e| e.entity == *entity) {
+                scene_entity
+                    .components
+                    .retain(|component| type_path_of(&**component) != Some(type_path));
+            }
+        }
+
+        for resource in &self.added.resources {
+            insert_value(
+                &mut scene.resources,
+                clone_value(&**resource, type_registry),
+            );
+        }
+        for added_entity in &self.added.entities {
+            let scene_entity = match scene
+                .entities
+                .iter()
+                .position(|e| e.entity == added_entity.entity)
+            {
+                Some(index) => &mut scene.entities[index],
+                None => {
+                    scene.entities.push(DynamicEntity {
+                        entity: added_entity.entity,
+                        components: Vec::new(),
+                    });
+                    scene.entities.last_mut().unwrap()
+                }
+            };
+            for component in &added_entity.components {
+                insert_value(
+                    &mut scene_entity.components,
+                    clone_value(&**component, type_registry),
+                );
+            }
+        }
+
+        for change in &self.changed {
+            let missing_component = || SceneOverrideError::MissingComponent {
+                entity: change.entity,
+                type_path: change.component.clone(),
+            };
+            let scene_entity = scene
+                .entities
+                .iter_mut()
+                .find(|e| e.entity == change.entity)
+                .ok_or(SceneOverrideError::MissingEntity {
+                    entity: change.entity,
+                })?;
+            let component = scene_entity
+                .components
+                .iter_mut()
+                .find(|component| type_path_of(&***component) == Some(&change.component))
+                .ok_or_else(missing_component)?;
+            change.apply_to_component(&mut **component)?;
+        }
+        Ok(())
+    }
+
+    /// Applies the patch to an instance of the patched scene in `world`.
+    ///
+    /// The `entity_map` maps entities of the scene to entities of the world, like in
+    /// [`DynamicScene::write_to_world_with`], and is updated with the entities spawned by the patch.
+    pub fn write_to_world_with(
+        &self,
+        world: &mut World,
+        entity_map: &mut EntityHashMap<Entity>,
+        type_registry: &AppTypeRegistry,
+    ) -> Result<(), ScenePatchError> {
+        for entity in &self.removed_entities {
+            if let Some(entity) = entity_map.remove(entity) {
+                if let Ok(entity_mut) = world.get_entity_mut(entity) {
+                    entity_mut.despawn();
+                }
+            }
+        }
+
+        {
+            let registry = type_registry.read();
+            for (entity, type_path) in &self.removed_components {
+                let reflect_component = registry
+                    .get_with_type_path(type_path)
+                    .and_then(|registration| registration.data::<ReflectComponent>())
+                    .ok_or_else(|| SceneSpawnError::UnregisteredComponent {
+                        type_path: type_path.clone(),
+                    })?;
+                if let Some(mut entity_mut) = entity_map
+                    .get(entity)
+                    .and_then(|entity| world.get_entity_mut(*entity).ok())
+                {
+                    reflect_component.remove(&mut entity_mut);
+                }
+            }
+            for type_path in &self.removed_resources {
+                let reflect_resource = registry
+                    .get_with_type_path(type_path)
+                    .and_then(|registration| registration.data::<ReflectResource>())
+                    .ok_or_else(|| SceneSpawnError::UnregisteredResource {
+                        type_path: type_path.clone(),
+                    })?;
+                reflect_resource.remove(world);
+            }
+        }
+
+        self.added
+            .write_to_world_with(world, entity_map, type_registry)?;
+
+        let registry = type_registry.read();
+        for change in &self.changed {
+            change.apply(world, entity_map, &registry)?;
+        }
+        Ok(())
+    }
+
+    /// Applies the patch to an instance of the patched scene in `world`, using the world's [`AppTypeRegistry`].
+    ///
+    /// See [`ScenePatch::write_to_world_with`].
+    pub fn write_to_world(
+        &self,
+        world: &mut World,
+        entity_map: &mut EntityHashMap<Entity>,
+    ) -> Result<(), ScenePatchError> {
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        self.write_to_world_with(world, entity_map, &registry)
+    }
+
+    /// Serialize this patch into RON, in the same style as [`DynamicScene::serialize`].
+    ///
+    /// To deserialize the patch, use the [`ScenePatchDeserializer`](crate::serde::ScenePatchDeserializer).
+    #[cfg(feature = "serialize")]
+    pub fn serialize(&self, registry: &TypeRegistry) -> Result<String, ron::Error> {
+        serialize_ron(ScenePatchSerializer::new(self, registry))
+    }
+}
+
+fn type_path_of(value: &dyn PartialReflect) -> Option<&'static str> {
+    value.get_represented_type_info().map(TypeInfo::type_path)
+}
+
+/// Iterates over the values with a represented type, in order.
+fn iter_by_type_path(
+    values: &[Box<dyn PartialReflect>],
+) -> impl Iterator<Item = (&'static str, &dyn PartialReflect)> {
+    values
+        .iter()
+        .filter_map(|value| Some((type_path_of(&**value)?, &**value)))
+}
+
+fn values_by_type_path(
+    values: &[Box<dyn PartialReflect>],
+) -> HashMap<&'static str, &dyn PartialReflect> {
+    iter_by_type_path(values).collect()
+}
+
+fn clone_value(
+    value: &dyn PartialReflect,
+    type_registry: &TypeRegistry,
+) -> Box<dyn PartialReflect> {
+    match value
+        .get_represented_type_info()
+        .and_then(|type_info| type_registry.get(type_info.type_id()))
+    {
+        Some(registration) => clone_reflect_value(value, registration),
+        None => value.to_dynamic(),
+    }
+}
+
+/// Inserts `value` into `values`, replacing the existing value of the same type.
+fn insert_value(values: &mut Vec<Box<dyn PartialReflect>>, value: Box<dyn PartialReflect>) {
+    let type_path = type_path_of(&*value);
+    match values
+        .iter_mut()
+        .find(|existing| type_path.is_some() && type_path_of(&***existing) == type_path)
+    {
+        Some(existing) => *existing = value,
+        None => values.push(value),
+    }
+}
+
+/// Matches the entities of `target` to the entities of `base` with the same [`Name`], or with the same id if they do
+/// not have a name. Each entity of `base` is matched at most once.
+fn match_entities(base: &DynamicScene, target: &DynamicScene) -> EntityHashMap<Entity> {
+    #[derive(PartialEq, Eq, Hash)]
+    enum Key {
+        Name(String),
+        Id(Entity),
+    }
+    fn key(entity: &DynamicEntity) -> Key {
+        entity
+            .components
+            .iter()
+            .filter(|component| type_path_of(&***component) == Some(Name::type_path()))
+            .find_map(|component| Name::from_reflect(&**component))
+            .map_or(Key::Id(entity.entity), |name| {
+                Key::Name(name.as_str().into())
+            })
+    }
+
+    let mut base_keys = HashMap::<Key, Entity>::default();
+    for entity in &base.entities {
+        base_keys.entry(key(entity)).or_insert(entity.entity);
+    }
+    target
+        .entities
+        .iter()
+        .filter_map(|entity| Some((entity.entity, base_keys.remove(&key(entity))?)))
+        .collect()
+}
+
+/// Returns `true` if `base` and `target` are equal according to [`PartialReflect::reflect_partial_eq`], or according
+/// to their serialized form if they do not support it.
+fn values_equal(
+    base: &dyn PartialReflect,
+    target: &dyn PartialReflect,
+    type_registry: &TypeRegistry,
+) -> bool {
+    match base.reflect_partial_eq(target) {
+        Some(equal) => equal,
+        None => serialized_equal(base, target, type_registry),
+    }
+}
+
+#[cfg(feature = "serialize")]
+fn serialized_equal(
+    base: &dyn PartialReflect,
+    target: &dyn PartialReflect,
+    type_registry: &TypeRegistry,
+) -> bool {
+    let serialize = |value| ron::to_string(&TypedReflectSerializer::new(value, type_registry)).ok();
+    match (serialize(base), serialize(target)) {
+        (Some(base), Some(target)) => base == target,
+        _ => false,
+    }
+}
+
+#[cfg(not(feature = "serialize"))]
+fn serialized_equal(
+    _base: &dyn PartialReflect,
+    _target: &dyn PartialReflect,
+    _type_registry: &TypeRegistry,
+) -> bool {
+    false
+}
+
+/// Collects the paths of the fields that differ between `base` and `target`, descending into structs and tuples
+/// of the same type. Any other change is recorded as a change of the whole value at `path`.
+fn diff_fields<'a>(
+    path: String,
+    base: &dyn PartialReflect,
+    target: &'a dyn PartialReflect,
+    type_registry: &TypeRegistry,
+    changes: &mut Vec<(String, &'a dyn PartialReflect)>,
+) {
+    if values_equal(base, target, type_registry) {
+        return;
+    }
+    let same_type = match (
+        base.get_represented_type_info(),
+        target.get_represented_type_info(),
+    ) {
+        (Some(base), Some(target)) => base.type_id() == target.type_id(),
+        _ => false,
+    };
+    if same_type {
+        match (base.reflect_ref(), target.reflect_ref()) {
+            (ReflectRef::Struct(base), ReflectRef::Struct(target)) => {
+                for (index, field) in target.iter_fields().enumerate() {
+                    let name = target.name_at(index).unwrap();
+                    match base.field(name) {
+                        Some(base_field) => {
+                            diff_fields(
+                                format!("{path}.{name}"),
+                                base_field,
+                                field,
+                                type_registry,
+                                changes,
+                            );
+                        }
+                        None => changes.push((format!("{path}.{name}"), field)),
+                    }
+                }
+                return;
+            }
+            (ReflectRef::TupleStruct(base), ReflectRef::TupleStruct(target))
+                if base.field_len() == target.field_len() =>
+            {
+                for (index, field) in target.iter_fields().enumerate() {
+                    let base_field = base.field(index).unwrap();
+                    diff_fields(
+                        format!("{path}.{index}"),
+                        base_field,
+                        field,
+                        type_registry,
+                        changes,
+                    );
+                }
+                return;
+            }
+            (ReflectRef::Tuple(base), ReflectRef::Tuple(target))
+                if base.field_len() == target.field_len() =>
+            {
+                for (index, field) in target.iter_fields().enumerate() {
+                    let base_field = base.field(index).unwrap();
+                    diff_fields(
+                        format!("{path}.{index}"),
+                        base_field,
+                        field,
+                        type_registry,
+                        changes,
+                    );
+                }
+                return;
+            }
+            _ => {}
+        }
+    }
+    changes.push((path, target));
+}
+
+impl DynamicScene {
+    /// Computes the [`ScenePatch`] that turns this scene into `target`.
+    ///
+    /// See [`ScenePatch::diff`].
+    pub fn diff(&self, target: &DynamicScene, type_registry: &TypeRegistry) -> ScenePatch {
+        ScenePatch::diff(self, target, type_registry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ScenePatch;
+    use crate::{DynamicScene, DynamicSceneBuilder, SceneOverride};
+    use alloc::{boxed::Box, string::ToString, vec::Vec};
+    use bevy_ecs::{
+        component::Component,
+        entity::{Entity, EntityHashMap},
+        name::Name,
+        prelude::{AppTypeRegistry, ReflectComponent, ReflectResource, World},
+        resource::Resource,
+    };
+    use bevy_reflect::{Reflect, TypePath};
+
+    #[derive(Component, Reflect, Default, Debug, Clone, PartialEq)]
+    #[reflect(Component)]
+    struct Position {
+        x: f32,
+        y: f32,
+    }
+
+    #[derive(Component, Reflect, Default, Debug, Clone, PartialEq)]
+    #[reflect(Component)]
+    struct Health(u32);
+
+    #[derive(Component, Reflect, Default, Debug, Clone, PartialEq)]
+    #[reflect(Component)]
+    struct Door;
+
+    #[derive(Resource, Reflect, Default, Debug, Clone, PartialEq)]
+    #[reflect(Resource)]
+    struct Score(u32);
+
+    #[derive(Component, Reflect, Debug, Clone, PartialEq)]
+    #[reflect(Component)]
+    struct Follows {
+        #[entities]
+        target: Entity,
+    }
+
+    fn create_world() -> World {
+        let mut world = World::new();
+        let registry = AppTypeRegistry::default();
+        {
+            let mut registry = registry.write();
+            registry.register::<Position>();
+            registry.register::<Health>();
+            registry.register::<Door>();
+            registry.register::<Score>();
+        }
+        world.insert_resource(registry);
+        world
+    }
+
+    fn extract(world: &World) -> DynamicScene {
+        DynamicSceneBuilder::from_world(world)
+            .deny_resource::<AppTypeRegistry>()
+            .extract_entities(world.iter_entities().map(|entity| entity.id()))
+            .extract_resources()
+            .build()
+    }
+
+    /// Builds a level world, spawns the level into a game world and modifies the game.
+    fn level_and_game() -> (World, World, EntityHashMap<Entity>) {
+        let mut level_world = create_world();
+        level_world.spawn((Position { x: 1.0, y: 2.0 }, Health(10)));
+        level_world.spawn((Position { x: 5.0, y: 5.0 }, Door));
+        level_world.spawn(Health(3));
+        level_world.insert_resource(Score(0));
+        let level = extract(&level_world);
+
+        let mut game = create_world();
+        let mut entity_map = EntityHashMap::default();
+        level.write_to_world(&mut game, &mut entity_map).unwrap();
+
+        let player = entity_map[&level.entities[0].entity];
+        let door = entity_map[&level.entities[1].entity];
+        let enemy = entity_map[&level.entities[2].entity];
+        game.get_mut::<Position>(player).unwrap().x = 4.0;
+        game.entity_mut(door).remove::<Door>().insert(Health(1));
+        game.despawn(enemy);
+        game.spawn(Position { x: 9.0, y: 9.0 });
+        game.resource_mut::<Score>().0 = 42;
+
+        (level_world, game, entity_map)
+    }
+
+    /// Extracts the game state, using the ids of the level for the entities that were spawned from it.
+    fn extract_game(game: &World, entity_map: &EntityHashMap<Entity>) -> DynamicScene {
+        let to_level: EntityHashMap<Entity> = entity_map
+            .iter()
+            .map(|(level, game)| (*game, *level))
+            .collect();
+        let mut saved = extract(game);
+        for entity in &mut saved.entities {
+            if let Some(level_entity) = to_level.get(&entity.entity) {
+                entity.entity = *level_entity;
+            }
+        }
+        saved
+    }
+
+    #[test]
+    fn diff_scenes() {
+        let (level_world, game, entity_map) = level_and_game();
+        let registry = game.resource::<AppTypeRegistry>().read();
+        let level = extract(&level_world);
+        let saved = extract_game(&game, &entity_map);
+
+        assert!(level.diff(&extract(&level_world), &registry).is_empty());
+
+        let patch = level.diff(&saved, &registry);
+        assert_eq!(patch.added.resources.len(), 1);
+        // The new entity, and the door's new `Health` component.
+        assert_eq!(patch.added.entities.len(), 2);
+        assert_eq!(patch.removed_entities, [level.entities[2].entity]);
+        assert_eq!(
+            patch.removed_components,
+            [(level.entities[1].entity, Door::type_path().to_string())]
+        );
+        assert_eq!(patch.changed.len(), 1);
+        assert_eq!(patch.changed[0].path, ".x");
+    }
+
+    #[test]
+    fn apply_patch_to_scene_and_world() {
+        let (level_world, game, entity_map) = level_and_game();
+        let registry = game.resource::<AppTypeRegistry>().clone();
+        let level = extract(&level_world);
+        let saved = extract_game(&game, &entity_map);
+        let patch = level.diff(&saved, &registry.read());
+
+        // Patching the level scene produces the saved scene.
+        let mut patched = extract(&level_world);
+        patch
+            .apply_to_scene(&mut patched, &registry.read())
+            .unwrap();
+        assert!(saved.diff(&patched, &registry.read()).is_empty());
+
+        // Patching a fresh instance of the level reproduces the game state.
+        let mut loaded = create_world();
+        let mut loaded_map = EntityHashMap::default();
+        level.write_to_world(&mut loaded, &mut loaded_map).unwrap();
+        patch.write_to_world(&mut loaded, &mut loaded_map).unwrap();
+        assert_game_state(&mut loaded);
+    }
+
+    #[test]
+    fn changed_entity_references_are_mapped() {
+        let mut level_world = create_world();
+        level_world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Follows>();
+        let leader = level_world.spawn(Health(10)).id();
+        let other = level_world.spawn(Health(5)).id();
+        level_world.spawn(Follows { target: leader });
+        let level = extract(&level_world);
+
+        let mut patch = ScenePatch::default();
+        patch.changed.push(SceneOverride {
+            entity: level.entities[2].entity,
+            component: Follows::type_path().to_string(),
+            path: ".target".to_string(),
+            value: Box::new(other),
+        });
+
+        let mut loaded = create_world();
+        loaded
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Follows>();
+        // Offset the entity ids, so that unmapped references would point at the wrong entities.
+        loaded.spawn_batch([Door, Door, Door]);
+        let mut loaded_map = EntityHashMap::default();
+        level.write_to_world(&mut loaded, &mut loaded_map).unwrap();
+        patch.write_to_world(&mut loaded, &mut loaded_map).unwrap();
+
+        let follower = loaded_map[&level.entities[2].entity];
+        assert_eq!(
+            loaded.get::<Follows>(follower).unwrap().target,
+            loaded_map[&other]
+        );
+    }
+
+    #[test]
+    fn named_entities_are_matched_by_name() {
+        let mut level_world = create_world();
+        level_world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Name>();
+        let player = level_world.spawn((Name::new("player"), Health(10))).id();
+        level_world.spawn((Name::new("enemy"), Health(3)));
+        let level = extract(&level_world);
+
+        // The same entities, spawned in another order, so their ids differ.
+        let mut game = create_world();
+        game.spawn(Door);
+        game.spawn((Name::new("enemy"), Health(3)));
+        game.spawn((Name::new("player"), Health(7)));
+        let registry = game.resource::<AppTypeRegistry>().clone();
+        registry.write().register::<Name>();
+        let patch = level.diff(&extract(&game), &registry.read());
+
+        assert!(patch.removed_entities.is_empty());
+        assert_eq!(patch.changed.len(), 1);
+        assert_eq!(patch.changed[0].entity, player);
+        assert_eq!(patch.changed[0].path, ".0");
+        // The unnamed entity is added with an id that is not used by the level.
+        assert_eq!(patch.added.entities.len(), 1);
+        assert!(level
+            .entities
+            .iter()
+            .all(|entity| entity.entity != patch.added.entities[0].entity));
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn values_without_partial_eq_are_compared_serialized() {
+        use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
+
+        #[derive(Reflect, Clone, Debug, serde::Serialize, serde::Deserialize)]
+        #[reflect(opaque)]
+        #[reflect(Clone, Serialize, Deserialize)]
+        struct Tag(String);
+
+        #[derive(Component, Reflect, Clone, Debug)]
+        #[reflect(Component)]
+        struct Tagged {
+            tag: Tag,
+            count: u32,
+        }
+
+        let mut level_world = create_world();
+        level_world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Tagged>();
+        let tagged = |count| Tagged {
+            tag: Tag("door".to_string()),
+            count,
+        };
+        level_world.spawn(tagged(1));
+        let level = extract(&level_world);
+        let registry = level_world.resource::<AppTypeRegistry>().read();
+
+        assert!(level.diff(&extract(&level_world), &registry).is_empty());
+
+        let mut changed = extract(&level_world);
+        changed.entities[0].components[0] = Box::new(tagged(2));
+        let patch = level.diff(&changed, &registry);
+        assert_eq!(patch.changed.len(), 1);
+        assert_eq!(patch.changed[0].path, ".count");
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn patch_serialization_roundtrip() {
+        use crate::{ron, serde::ScenePatchDeserializer};
+        use serde::de::DeserializeSeed;
+
+        let (level_world, game, entity_map) = level_and_game();
+        let registry = game.resource::<AppTypeRegistry>().clone();
+        let level = extract(&level_world);
+        let patch = level.diff(&extract_game(&game, &entity_map), &registry.read());
+
+        let serialized = patch.serialize(&registry.read()).unwrap();
+        let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+        let patch = ScenePatchDeserializer {
+            type_registry: &registry.read(),
+        }
+        .deserialize(&mut deserializer)
+        .unwrap();
+
+        let mut loaded = create_world();
+        let mut loaded_map = EntityHashMap::default();
+        level.write_to_world(&mut loaded, &mut loaded_map).unwrap();
+        patch.write_to_world(&mut loaded, &mut loaded_map).unwrap();
+        assert_game_state(&mut loaded);
+    }
+
+    fn assert_game_state(world: &mut World) {
+        let mut positions = world
+            .query::<&Position>()
+            .iter(world)
+            .map(|p| (p.x, p.y))
+            .collect::<Vec<_>>();
+        positions.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(positions, [(4.0, 2.0), (5.0, 5.0), (9.0, 9.0)]);
+        assert_eq!(world.query::<&Door>().iter(world).count(), 0);
+        let mut health = world
+            .query::<&Health>()
+            .iter(world)
+            .map(|h| h.0)
+            .collect::<Vec<_>>();
+        health.sort();
+        assert_eq!(health, [1, 10]);
+        assert_eq!(world.resource::<Score>().0, 42);
+    }
+}
@@ -1,4 +1,5 @@
-use crate::{DynamicScene, Scene};
+use crate::{DynamicScene, Scene, SceneOverrides};
+use alloc::{boxed::Box, string::String, vec::Vec};
 use bevy_asset::{AssetEvent, AssetId, Assets, Handle};
 use bevy_ecs::{
     entity::{Entity, EntityHashMap},
@@ -9,8 +10,9 @@ use bevy_ecs::{
     world::{Mut, World},
 };
 use bevy_platform::collections::{HashMap, HashSet};
-use bevy_reflect::Reflect;
+use bevy_reflect::{PartialReflect, Reflect, TypeInfo};
 use thiserror::Error;
+use tracing::{error, warn};
 use uuid::Uuid;
 
 use crate::{DynamicSceneRoot, SceneRoot};
@@ -37,6 +39,68 @@ pub struct SceneInstanceReady {
 pub struct InstanceInfo {
     /// Mapping of entities from the scene world to the instance world.
     pub entity_map: EntityHashMap<Entity>,
+    /// The entity the instance was spawned as a child of, whose [`SceneOverrides`] apply to the instance.
+    parent: Option<Entity>,
+    /// The source entities and component type paths changed by the [`SceneOverrides`] that were last applied.
+    overridden: Vec<(Entity, String)>,
+}
+
+impl InstanceInfo {
+    fn new(entity_map: EntityHashMap<Entity>, parent: Option<Entity>) -> Self {
+        Self {
+            entity_map,
+            parent,
+            overridden: Vec::new(),
+        }
+    }
+
+    /// Returns the entity the instance was spawned as a child of, whose [`SceneOverrides`] apply to the instance.
+    pub fn parent(&self) -> Option<Entity> {
+        self.parent
+    }
+}
+
+/// The components of a [`DynamicScene`] as they were last written to its instances, so that only the components
+/// that changed are written again when the scene is modified.
+#[derive(Default)]
+struct SceneSnapshot(HashMap<(Entity, String), Box<dyn PartialReflect>>);
+
+impl SceneSnapshot {
+    fn new(scene: &DynamicScene) -> Self {
+        let mut components = HashMap::default();
+        for entity in &scene.entities {
+            for component in &entity.components {
+                let Some(type_path) = component_type_path(component.as_ref()) else {
+                    continue;
+                };
+                // Components that cannot be cloned are not stored, so they are always written again.
+                let value = if component.is_dynamic() {
+                    component.to_dynamic()
+                } else {
+                    match component.reflect_clone() {
+                        Ok(value) => value.into_partial_reflect(),
+                        Err(_) => continue,
+                    }
+                };
+                components.insert((entity.entity, type_path.into()), value);
+            }
+        }
+        Self(components)
+    }
+
+    /// Returns `true` if the `component` of the source scene's `entity` is equal to the one in the snapshot.
+    fn is_unchanged(&self, entity: Entity, component: &dyn PartialReflect) -> bool {
+        component_type_path(component)
+            .and_then(|type_path| self.0.get(&(entity, type_path.into())))
+            .and_then(|old| old.reflect_partial_eq(component))
+            .unwrap_or(false)
+    }
+}
+
+fn component_type_path(component: &dyn PartialReflect) -> Option<&'static str> {
+    component
+        .get_represented_type_info()
+        .map(TypeInfo::type_path)
 }
 
 /// Unique id identifying a scene instance.
@@ -61,6 +125,7 @@ impl InstanceId {
 /// - [`spawn_queued_scenes`](Self::spawn_queued_scenes)
 /// - [`despawn_queued_scenes`](Self::despawn_queued_scenes)
 /// - [`despawn_queued_instances`](Self::despawn_queued_instances)
+/// - [`reapply_queued_instances`](Self::reapply_queued_instances)
 ///
 /// Deferred methods: (Scene operations will be processed when the [`scene_spawner_system`] is run)
 /// - [`spawn_dynamic`](Self::spawn_dynamic)
@@ -69,6 +134,7 @@ impl InstanceId {
 /// - [`spawn_as_child`](Self::spawn_as_child)
 /// - [`despawn`](Self::despawn)
 /// - [`despawn_instance`](Self::despawn_instance)
+/// - [`reapply_instance`](Self::reapply_instance)
 #[derive(Default, Resource)]
 pub struct SceneSpawner {
     pub(crate) spawned_dynamic_scenes: HashMap<AssetId<DynamicScene>, HashSet<InstanceId>>,
@@ -79,6 +145,8 @@ pub struct SceneSpawner {
     scenes_to_despawn: Vec<AssetId<DynamicScene>>,
     instances_to_despawn: Vec<InstanceId>,
     scenes_with_parent: Vec<(InstanceId, Entity)>,
+    instances_to_reapply: Vec<InstanceId>,
+    scene_snapshots: HashMap<AssetId<DynamicScene>, SceneSnapshot>,
 }
 
 /// Errors that can occur when spawning a scene.
@@ -187,6 +255,14 @@ impl SceneSpawner {
         self.instances_to_despawn.push(instance_id);
     }
 
+    /// Schedule re-applying the source scene and the parent's [`SceneOverrides`] to a scene instance.
+    ///
+    /// This is done automatically when the [`SceneOverrides`] of the instance change, see
+    /// [`scene_overrides_system`](crate::scene_overrides_system).
+    pub fn reapply_instance(&mut self, instance_id: InstanceId) {
+        self.instances_to_reapply.push(instance_id);
+    }
+
     /// This will remove all records of this instance, without despawning any entities.
     pub fn unregister_instance(&mut self, instance_id: InstanceId) {
         self.spawned_instances.remove(&instance_id);
@@ -198,7 +274,9 @@ impl SceneSpawner {
         world: &mut World,
         id: impl Into<AssetId<DynamicScene>>,
     ) -> Result<(), SceneSpawnError> {
-        if let Some(instance_ids) = self.spawned_dynamic_scenes.remove(&id.into()) {
+        let id = id.into();
+        self.scene_snapshots.remove(&id);
+        if let Some(instance_ids) = self.spawned_dynamic_scenes.remove(&id) {
             for instance_id in instance_ids {
                 self.despawn_instance_sync(world, &instance_id);
             }
@@ -226,9 +304,10 @@ impl SceneSpawner {
         let mut entity_map = EntityHashMap::default();
         let id = id.into();
         Self::spawn_dynamic_internal(world, id, &mut entity_map)?;
+        self.snapshot_scene(world, id);
         let instance_id = InstanceId::new();
         self.spawned_instances
-            .insert(instance_id, InstanceInfo { entity_map });
+            .insert(instance_id, InstanceInfo::new(entity_map, None));
         let spawned = self.spawned_dynamic_scenes.entry(id).or_default();
         spawned.insert(instance_id);
         Ok(instance_id)
@@ -248,6 +327,32 @@ impl SceneSpawner {
         })
     }
 
+    /// Writes the components of the dynamic scene for which `filter` returns `true` to an existing instance.
+    fn write_dynamic_components(
+        world: &mut World,
+        id: AssetId<DynamicScene>,
+        entity_map: &mut EntityHashMap<Entity>,
+        filter: impl FnMut(Entity, &dyn PartialReflect) -> bool,
+    ) -> Result<(), SceneSpawnError> {
+        world.resource_scope(|world, scenes: Mut<Assets<DynamicScene>>| {
+            let scene = scenes
+                .get(id)
+                .ok_or(SceneSpawnError::NonExistentScene { id })?;
+            let type_registry = world.resource::<AppTypeRegistry>().clone();
+            scene.write_components_to_world(world, entity_map, &type_registry, filter)
+        })
+    }
+
+    /// Stores a snapshot of the dynamic scene the first time it is spawned.
+    fn snapshot_scene(&mut self, world: &World, id: AssetId<DynamicScene>) {
+        if self.scene_snapshots.contains_key(&id) {
+            return;
+        }
+        if let Some(scene) = world.resource::<Assets<DynamicScene>>().get(id) {
+            self.scene_snapshots.insert(id, SceneSnapshot::new(scene));
+        }
+    }
+
     /// Immediately spawns a new instance of the provided scene.
     pub fn spawn_sync(
         &mut self,
@@ -259,7 +364,7 @@ impl SceneSpawner {
         Self::spawn_sync_internal(world, id, &mut entity_map)?;
         let instance_id = InstanceId::new();
         self.spawned_instances
-            .insert(instance_id, InstanceInfo { entity_map });
+            .insert(instance_id, InstanceInfo::new(entity_map, None));
         Ok(instance_id)
     }
 
@@ -284,6 +389,8 @@ impl SceneSpawner {
     /// Iterate through all instances of the provided scenes and update those immediately.
     ///
     /// Useful for updating already spawned scene instances after their corresponding scene has been modified.
+    /// Only the components that changed since the scene was last written to its instances are written again, so
+    /// other changes made to the instances are kept. The [`SceneOverrides`] of each instance are re-applied afterwards.
     pub fn update_spawned_scenes(
         &mut self,
         world: &mut World,
@@ -291,16 +398,89 @@ impl SceneSpawner {
     ) -> Result<(), SceneSpawnError> {
         for id in scene_ids {
             if let Some(spawned_instances) = self.spawned_dynamic_scenes.get(id) {
+                let snapshot = self.scene_snapshots.get(id);
                 for instance_id in spawned_instances {
                     if let Some(instance_info) = self.spawned_instances.get_mut(instance_id) {
-                        Self::spawn_dynamic_internal(world, *id, &mut instance_info.entity_map)?;
+                        Self::write_dynamic_components(
+                            world,
+                            *id,
+                            &mut instance_info.entity_map,
+                            |entity, component| {
+                                !snapshot.is_some_and(|s| s.is_unchanged(entity, component))
+                            },
+                        )?;
+                        Self::apply_overrides(world, instance_info);
                     }
                 }
             }
+            self.scene_snapshots.remove(id);
+            self.snapshot_scene(world, *id);
         }
         Ok(())
     }
 
+    /// Immediately re-applies the source scene and the parent's [`SceneOverrides`] to all scene instances scheduled
+    /// with [`reapply_instance`](Self::reapply_instance).
+    ///
+    /// Only the components that are overridden now or were overridden before are reset to the source scene, so
+    /// other changes made to the instance are kept. Instances of a [`Scene`] are not reset to their source, so only
+    /// the overrides are re-applied.
+    pub fn reapply_queued_instances(&mut self, world: &mut World) -> Result<(), SceneSpawnError> {
+        let instances_to_reapply = core::mem::take(&mut self.instances_to_reapply);
+
+        for instance_id in instances_to_reapply {
+            let Some(instance_info) = self.spawned_instances.get_mut(&instance_id) else {
+                continue;
+            };
+            let scene_id = self
+                .spawned_dynamic_scenes
+                .iter()
+                .find_map(|(id, instances)| instances.contains(&instance_id).then_some(*id));
+            if let Some(scene_id) = scene_id {
+                let mut reset: HashSet<(Entity, String)> =
+                    instance_info.overridden.drain(..).collect();
+                if let Some(overrides) = instance_info
+                    .parent
+                    .and_then(|parent| world.get::<SceneOverrides>(parent))
+                {
+                    reset.extend(overrides.0.iter().map(|o| (o.entity, o.component.clone())));
+                }
+                Self::write_dynamic_components(
+                    world,
+                    scene_id,
+                    &mut instance_info.entity_map,
+                    |entity, component| {
+                        component_type_path(component).is_some_and(|type_path| {
+                            reset.contains(&(entity, String::from(type_path)))
+                        })
+                    },
+                )?;
+            }
+            Self::apply_overrides(world, instance_info);
+        }
+        Ok(())
+    }
+
+    fn apply_overrides(world: &mut World, instance_info: &mut InstanceInfo) {
+        let Some(overrides) = instance_info
+            .parent
+            .and_then(|parent| world.get::<SceneOverrides>(parent))
+            .cloned()
+        else {
+            instance_info.overridden.clear();
+            return;
+        };
+        let type_registry = world.resource::<AppTypeRegistry>().clone();
+        for err in overrides.apply(world, &instance_info.entity_map, &type_registry.read()) {
+            warn!("Failed to apply scene override: {err}");
+        }
+        instance_info.overridden = overrides
+            .0
+            .iter()
+            .map(|o| (o.entity, o.component.clone()))
+            .collect();
+    }
+
     /// Immediately despawns all scenes scheduled for despawn by despawning their instances.
     pub fn despawn_queued_scenes(&mut self, world: &mut World) -> Result<(), SceneSpawnError> {
         let scenes_to_despawn = core::mem::take(&mut self.scenes_to_despawn);
@@ -329,8 +509,9 @@ impl SceneSpawner {
 
             match Self::spawn_dynamic_internal(world, handle.id(), &mut entity_map) {
                 Ok(_) => {
+                    self.snapshot_scene(world, handle.id());
                     self.spawned_instances
-                        .insert(instance_id, InstanceInfo { entity_map });
+                        .insert(instance_id, InstanceInfo::new(entity_map, parent));
                     let spawned = self.spawned_dynamic_scenes.entry(handle.id()).or_default();
                     spawned.insert(instance_id);
 
@@ -357,7 +538,7 @@ impl SceneSpawner {
             match Self::spawn_sync_internal(world, scene_handle.id(), &mut entity_map) {
                 Ok(_) => {
                     self.spawned_instances
-                        .insert(instance_id, InstanceInfo { entity_map });
+                        .insert(instance_id, InstanceInfo::new(entity_map, parent));
 
                     // Scenes with parents need more setup before they are ready.
                     // See `set_scene_instance_parent_sync()`.
@@ -381,7 +562,7 @@ impl SceneSpawner {
         let scenes_with_parent = core::mem::take(&mut self.scenes_with_parent);
 
         for (instance_id, parent) in scenes_with_parent {
-            if let Some(instance) = self.spawned_instances.get(&instance_id) {
+            if let Some(instance) = self.spawned_instances.get_mut(&instance_id) {
                 for &entity in instance.entity_map.values() {
                     // Add the `ChildOf` component to the scene root, and update the `Children` component of
                     // the scene parent
@@ -397,6 +578,7 @@ impl SceneSpawner {
                         world.entity_mut(parent).add_child(entity);
                     }
                 }
+                Self::apply_overrides(world, instance);
 
                 // Defer via commands otherwise SceneSpawner is not available in the observer.
                 world
@@ -477,6 +659,9 @@ pub fn scene_spawner_system(world: &mut World) {
             .update_spawned_scenes(world, &updated_spawned_scenes)
             .unwrap();
         scene_spawner.set_scene_instance_parent_sync(world);
+        if let Err(err) = scene_spawner.reapply_queued_instances(world) {
+            error!("Failed to reapply scene instance: {err}");
+        }
     });
 }
 
@@ -1,6 +1,10 @@
 //! `serde` serialization and deserialization implementation for Bevy scenes.
 
-use crate::{DynamicEntity, DynamicScene};
+use crate::{
+    migration::{scene_version, SceneMigrations},
+    prefab::{SceneOverridesDeserializer, SceneOverridesSerializer},
+    DynamicEntity, DynamicScene, ScenePatch,
+};
 use bevy_ecs::entity::Entity;
 use bevy_platform::collections::HashSet;
 use bevy_reflect::{
@@ -19,6 +23,8 @@ use serde::{
 
 /// Name of the serialized scene struct type.
 pub const SCENE_STRUCT: &str = "Scene";
+/// Name of the serialized version field in a scene struct.
+pub const SCENE_VERSION: &str = "version";
 /// Name of the serialized resources field in a scene struct.
 pub const SCENE_RESOURCES: &str = "resources";
 /// Name of the serialized entities field in a scene struct.
@@ -29,6 +35,27 @@ pub const ENTITY_STRUCT: &str = "Entity";
 /// Name of the serialized component field in an entity struct.
 pub const ENTITY_FIELD_COMPONENTS: &str = "components";
 
+/// Name of the serialized scene patch struct type.
+pub const PATCH_STRUCT: &str = "ScenePatch";
+/// Name of the serialized added scene field in a scene patch struct.
+pub const PATCH_ADDED: &str = "added";
+/// Name of the serialized removed entities field in a scene patch struct.
+pub const PATCH_REMOVED_ENTITIES: &str = "removed_entities";
+/// Name of the serialized removed components field in a scene patch struct.
+pub const PATCH_REMOVED_COMPONENTS: &str = "removed_components";
+/// Name of the serialized removed resources field in a scene patch struct.
+pub const PATCH_REMOVED_RESOURCES: &str = "removed_resources";
+/// Name of the serialized changed fields field in a scene patch struct.
+pub const PATCH_CHANGED: &str = "changed";
+
+const PATCH_FIELDS: &[&str] = &[
+    PATCH_ADDED,
+    PATCH_REMOVED_ENTITIES,
+    PATCH_REMOVED_COMPONENTS,
+    PATCH_REMOVED_RESOURCES,
+    PATCH_CHANGED,
+];
+
 /// Serializer for a [`DynamicScene`].
 ///
 /// Helper object defining Bevy's serialize format for a [`DynamicScene`] and implementing
@@ -59,6 +86,9 @@ pub struct SceneSerializer<'a> {
     pub scene: &'a DynamicScene,
     /// The type registry containing the types present in the scene.
     pub registry: &'a TypeRegistry,
+    /// Whether to skip the struct fields that are equal to their default value,
+    /// see [`TypedReflectSerializer::with_skip_defaults`].
+    skip_defaults: bool,
 }
 
 impl<'a> SceneSerializer<'a> {
@@ -69,7 +99,22 @@ impl<'a> SceneSerializer<'a> {
     ///
     /// [`World`]: bevy_ecs::world::World
     pub fn new(scene: &'a DynamicScene, registry: &'a TypeRegistry) -> Self {
-        SceneSerializer { scene, registry }
+        SceneSerializer {
+            scene,
+            registry,
+            skip_defaults: false,
+        }
+    }
+
+    /// Sets whether the fields of components and resources that are equal to their default value are skipped.
+    ///
+    /// This can make scene files much smaller, and only works with self-describing formats like RON.
+    /// See [`TypedReflectSerializer::with_skip_defaults`] for more details.
+    pub fn with_skip_defaults(self, skip_defaults: bool) -> Self {
+        Self {
+            skip_defaults,
+            ..self
+        }
     }
 }
 
@@ -78,20 +123,23 @@ impl<'a> Serialize for SceneSerializer<'a> {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct(SCENE_STRUCT, 2)?;
+        // The version is only written to self-describing formats, which can omit it. Other formats have to store
+        // it elsewhere, like the binary scene format does in its header.
+        let version = scene_version(self.registry);
+        let write_version = version > 0 && serializer.is_human_readable();
+        let mut state = serializer.serialize_struct(SCENE_STRUCT, 2 + write_version as usize)?;
+        if write_version {
+            state.serialize_field(SCENE_VERSION, &version)?;
+        }
         state.serialize_field(
             SCENE_RESOURCES,
-            &SceneMapSerializer {
-                entries: &self.scene.resources,
-                registry: self.registry,
-            },
+            &SceneMapSerializer::new(&self.scene.resources, self.registry)
+                .with_skip_defaults(self.skip_defaults),
         )?;
         state.serialize_field(
             SCENE_ENTITIES,
-            &EntitiesSerializer {
-                entities: &self.scene.entities,
-                registry: self.registry,
-            },
+            &EntitiesSerializer::new(&self.scene.entities, self.registry)
+                .with_skip_defaults(self.skip_defaults),
         )?;
         state.end()
     }
@@ -103,6 +151,30 @@ pub struct EntitiesSerializer<'a> {
     pub entities: &'a [DynamicEntity],
     /// Type registry in which the component types used by the entities are registered.
     pub registry: &'a TypeRegistry,
+    /// Whether to skip the struct fields that are equal to their default value,
+    /// see [`TypedReflectSerializer::with_skip_defaults`].
+    skip_defaults: bool,
+}
+
+impl<'a> EntitiesSerializer<'a> {
+    /// Creates a new serializer for the given entities, whose components are registered in `registry`.
+    pub fn new(entities: &'a [DynamicEntity], registry: &'a TypeRegistry) -> Self {
+        Self {
+            entities,
+            registry,
+            skip_defaults: false,
+        }
+    }
+
+    /// Sets whether the fields of components that are equal to their default value are skipped.
+    ///
+    /// See [`SceneSerializer::with_skip_defaults`] for more details.
+    pub fn with_skip_defaults(self, skip_defaults: bool) -> Self {
+        Self {
+            skip_defaults,
+            ..self
+        }
+    }
 }
 
 impl<'a> Serialize for EntitiesSerializer<'a> {
@@ -114,10 +186,8 @@ impl<'a> Serialize for EntitiesSerializer<'a> {
         for entity in self.entities {
             state.serialize_entry(
                 &entity.entity,
-                &EntitySerializer {
-                    entity,
-                    registry: self.registry,
-                },
+                &EntitySerializer::new(entity, self.registry)
+                    .with_skip_defaults(self.skip_defaults),
             )?;
         }
         state.end()
@@ -130,6 +200,30 @@ pub struct EntitySerializer<'a> {
     pub entity: &'a DynamicEntity,
     /// Type registry in which the component types used by the entity are registered.
     pub registry: &'a TypeRegistry,
+    /// Whether to skip the struct fields that are equal to their default value,
+    /// see [`TypedReflectSerializer::with_skip_defaults`].
+    skip_defaults: bool,
+}
+
+impl<'a> EntitySerializer<'a> {
+    /// Creates a new serializer for the given entity, whose components are registered in `registry`.
+    pub fn new(entity: &'a DynamicEntity, registry: &'a TypeRegistry) -> Self {
+        Self {
+            entity,
+            registry,
+            skip_defaults: false,
+        }
+    }
+
+    /// Sets whether the fields of components that are equal to their default value are skipped.
+    ///
+    /// See [`SceneSerializer::with_skip_defaults`] for more details.
+    pub fn with_skip_defaults(self, skip_defaults: bool) -> Self {
+        Self {
+            skip_defaults,
+            ..self
+        }
+    }
 }
 
 impl<'a> Serialize for EntitySerializer<'a> {
@@ -140,10 +234,8 @@ impl<'a> Serialize for EntitySerializer<'a> {
         let mut state = serializer.serialize_struct(ENTITY_STRUCT, 1)?;
         state.serialize_field(
             ENTITY_FIELD_COMPONENTS,
-            &SceneMapSerializer {
-                entries: &self.entity.components,
-                registry: self.registry,
-            },
+            &SceneMapSerializer::new(&self.entity.components, self.registry)
+                .with_skip_defaults(self.skip_defaults),
         )?;
         state.end()
     }
@@ -161,6 +253,30 @@ pub struct SceneMapSerializer<'a> {
     pub entries: &'a [Box<dyn PartialReflect>],
     /// Type registry in which the types used in `entries` are registered.
     pub registry: &'a TypeRegistry,
+    /// Whether to skip the struct fields that are equal to their default value,
+    /// see [`TypedReflectSerializer::with_skip_defaults`].
+    skip_defaults: bool,
+}
+
+impl<'a> SceneMapSerializer<'a> {
+    /// Creates a new serializer for the given entries, whose types are registered in `registry`.
+    pub fn new(entries: &'a [Box<dyn PartialReflect>], registry: &'a TypeRegistry) -> Self {
+        Self {
+            entries,
+            registry,
+            skip_defaults: false,
+        }
+    }
+
+    /// Sets whether the fields of the entries that are equal to their default value are skipped.
+    ///
+    /// See [`SceneSerializer::with_skip_defaults`] for more details.
+    pub fn with_skip_defaults(self, skip_defaults: bool) -> Self {
+        Self {
+            skip_defaults,
+            ..self
+        }
+    }
 }
 
 impl<'a> Serialize for SceneMapSerializer<'a> {
@@ -187,7 +303,8 @@ impl<'a> Serialize for SceneMapSerializer<'a> {
         for (type_path, partial_reflect) in sorted_entries {
             state.serialize_entry(
                 type_path,
-                &TypedReflectSerializer::new(partial_reflect, self.registry),
+                &TypedReflectSerializer::new(partial_reflect, self.registry)
+                    .with_skip_defaults(self.skip_defaults),
             )?;
         }
         state.end()
@@ -197,6 +314,7 @@ impl<'a> Serialize for SceneMapSerializer<'a> {
 #[derive(Deserialize)]
 #[serde(field_identifier, rename_all = "lowercase")]
 enum SceneField {
+    Version,
     Resources,
     Entities,
 }
@@ -222,16 +340,39 @@ impl<'a, 'de> DeserializeSeed<'de> for SceneDeserializer<'a> {
     {
         deserializer.deserialize_struct(
             SCENE_STRUCT,
-            &[SCENE_RESOURCES, SCENE_ENTITIES],
+            &[SCENE_VERSION, SCENE_RESOURCES, SCENE_ENTITIES],
             SceneVisitor {
                 type_registry: self.type_registry,
+                version: None,
             },
         )
     }
 }
 
+/// Deserializes a scene from a format that is not self-describing, which was written with the given scene `version`.
+///
+/// Such formats don't store the version in the scene, so it has to be stored elsewhere, like in the header of the
+/// [binary scene format](crate::BinarySceneLoader).
+#[cfg(feature = "binary")]
+pub(crate) fn deserialize_scene_with_version<'de, D: Deserializer<'de>>(
+    deserializer: D,
+    type_registry: &TypeRegistry,
+    version: u32,
+) -> Result<DynamicScene, D::Error> {
+    deserializer.deserialize_struct(
+        SCENE_STRUCT,
+        &[SCENE_VERSION, SCENE_RESOURCES, SCENE_ENTITIES],
+        SceneVisitor {
+            type_registry,
+            version: Some(version),
+        },
+    )
+}
+
 struct SceneVisitor<'a> {
     pub type_registry: &'a TypeRegistry,
+    /// The version of a scene in a format that is not self-describing, or `None` to use the current version.
+    pub version: Option<u32>,
 }
 
 impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
@@ -245,15 +386,22 @@ impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
     where
         A: SeqAccess<'de>,
     {
+        // Formats that are not self-describing don't store the version, so they use the current one unless the
+        // version was stored elsewhere.
+        let version = self
+            .version
+            .unwrap_or_else(|| scene_version(self.type_registry));
         let resources = seq
             .next_element_seed(SceneMapDeserializer {
                 registry: self.type_registry,
+                version,
             })?
             .ok_or_else(|| Error::missing_field(SCENE_RESOURCES))?;
 
         let entities = seq
             .next_element_seed(SceneEntitiesDeserializer {
                 type_registry: self.type_registry,
+                version,
             })?
             .ok_or_else(|| Error::missing_field(SCENE_ENTITIES))?;
 
@@ -267,16 +415,37 @@ impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
     where
         A: MapAccess<'de>,
     {
+        // Scenes without a version were written before any migration was registered.
+        let mut version = None;
         let mut resources = None;
         let mut entities = None;
         while let Some(key) = map.next_key()? {
             match key {
+                SceneField::Version => {
+                    if version.is_some() {
+                        return Err(Error::duplicate_field(SCENE_VERSION));
+                    }
+                    if resources.is_some() || entities.is_some() {
+                        return Err(Error::custom(format_args!(
+                            "`{SCENE_VERSION}` must be the first field of a scene"
+                        )));
+                    }
+                    let found: u32 = map.next_value()?;
+                    let current = scene_version(self.type_registry);
+                    if found > current {
+                        return Err(Error::custom(format_args!(
+                            "scene version {found} is newer than the current scene version {current}"
+                        )));
+                    }
+                    version = Some(found);
+                }
                 SceneField::Resources => {
                     if resources.is_some() {
                         return Err(Error::duplicate_field(SCENE_RESOURCES));
                     }
                     resources = Some(map.next_value_seed(SceneMapDeserializer {
                         registry: self.type_registry,
+                        version: version.unwrap_or(0),
                     })?);
                 }
                 SceneField::Entities => {
@@ -285,6 +454,7 @@ impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
                     }
                     entities = Some(map.next_value_seed(SceneEntitiesDeserializer {
                         type_registry: self.type_registry,
+                        version: version.unwrap_or(0),
                     })?);
                 }
             }
@@ -304,6 +474,19 @@ impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
 pub struct SceneEntitiesDeserializer<'a> {
     /// Type registry in which the component types used by the entities to deserialize are registered.
     pub type_registry: &'a TypeRegistry,
+    /// Version of the scene that the entities belong to, used to apply [`SceneMigrations`].
+    pub version: u32,
+}
+
+impl<'a> SceneEntitiesDeserializer<'a> {
+    /// Creates a deserializer for entities of a scene with the current [`scene_version`] of `type_registry`, so no
+    /// [`SceneMigrations`] are applied.
+    pub fn new(type_registry: &'a TypeRegistry) -> Self {
+        Self {
+            type_registry,
+            version: scene_version(type_registry),
+        }
+    }
 }
 
 impl<'a, 'de> DeserializeSeed<'de> for SceneEntitiesDeserializer<'a> {
@@ -315,12 +498,14 @@ impl<'a, 'de> DeserializeSeed<'de> for SceneEntitiesDeserializer<'a> {
     {
         deserializer.deserialize_map(SceneEntitiesVisitor {
             type_registry: self.type_registry,
+            version: self.version,
         })
     }
 }
 
 struct SceneEntitiesVisitor<'a> {
     pub type_registry: &'a TypeRegistry,
+    pub version: u32,
 }
 
 impl<'a, 'de> Visitor<'de> for SceneEntitiesVisitor<'a> {
@@ -339,6 +524,7 @@ impl<'a, 'de> Visitor<'de> for SceneEntitiesVisitor<'a> {
             let entity = map.next_value_seed(SceneEntityDeserializer {
                 entity,
                 type_registry: self.type_registry,
+                version: self.version,
             })?;
             entities.push(entity);
         }
@@ -353,6 +539,20 @@ pub struct SceneEntityDeserializer<'a> {
     pub entity: Entity,
     /// Type registry in which the component types used by the entity to deserialize are registered.
     pub type_registry: &'a TypeRegistry,
+    /// Version of the scene that the entity belongs to, used to apply [`SceneMigrations`].
+    pub version: u32,
+}
+
+impl<'a> SceneEntityDeserializer<'a> {
+    /// Creates a deserializer for an entity of a scene with the current [`scene_version`] of `type_registry`, so no
+    /// [`SceneMigrations`] are applied.
+    pub fn new(entity: Entity, type_registry: &'a TypeRegistry) -> Self {
+        Self {
+            entity,
+            type_registry,
+            version: scene_version(type_registry),
+        }
+    }
 }
 
 impl<'a, 'de> DeserializeSeed<'de> for SceneEntityDeserializer<'a> {
@@ -368,6 +568,7 @@ impl<'a, 'de> DeserializeSeed<'de> for SceneEntityDeserializer<'a> {
             SceneEntityVisitor {
                 entity: self.entity,
                 registry: self.type_registry,
+                version: self.version,
             },
         )
     }
@@ -376,6 +577,7 @@ impl<'a, 'de> DeserializeSeed<'de> for SceneEntityDeserializer<'a> {
 struct SceneEntityVisitor<'a> {
     pub entity: Entity,
     pub registry: &'a TypeRegistry,
+    pub version: u32,
 }
 
 impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
@@ -392,6 +594,7 @@ impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
         let components = seq
             .next_element_seed(SceneMapDeserializer {
                 registry: self.registry,
+                version: self.version,
             })?
             .ok_or_else(|| Error::missing_field(ENTITY_FIELD_COMPONENTS))?;
 
@@ -415,6 +618,7 @@ impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
 
                     components = Some(map.next_value_seed(SceneMapDeserializer {
                         registry: self.registry,
+                        version: self.version,
                     })?);
                 }
             }
@@ -434,6 +638,19 @@ impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
 pub struct SceneMapDeserializer<'a> {
     /// Type registry in which the types of the values to deserialize are registered.
     pub registry: &'a TypeRegistry,
+    /// Version of the scene that the values belong to, used to apply [`SceneMigrations`].
+    pub version: u32,
+}
+
+impl<'a> SceneMapDeserializer<'a> {
+    /// Creates a deserializer for values of a scene with the current [`scene_version`] of `registry`, so no
+    /// [`SceneMigrations`] are applied.
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self {
+            registry,
+            version: scene_version(registry),
+        }
+    }
 }
 
 impl<'a, 'de> DeserializeSeed<'de> for SceneMapDeserializer<'a> {
@@ -445,12 +662,14 @@ impl<'a, 'de> DeserializeSeed<'de> for SceneMapDeserializer<'a> {
     {
         deserializer.deserialize_map(SceneMapVisitor {
             registry: self.registry,
+            version: self.version,
         })
     }
 }
 
 struct SceneMapVisitor<'a> {
     pub registry: &'a TypeRegistry,
+    pub version: u32,
 }
 
 impl<'a, 'de> Visitor<'de> for SceneMapVisitor<'a> {
@@ -488,8 +707,25 @@ impl<'a, 'de> Visitor<'de> for SceneMapVisitor<'a> {
                 )));
             }
 
-            let value =
-                map.next_value_seed(TypedReflectDeserializer::new(registration, self.registry))?;
+            let migrations = registration
+                .data::<SceneMigrations>()
+                .filter(|migrations| migrations.version() > self.version);
+            let value = match migrations {
+                Some(migrations) => {
+                    let stored = migrations
+                        .stored_type(self.version, self.registry)
+                        .unwrap()
+                        .map_err(Error::custom)?;
+                    let value =
+                        map.next_value_seed(TypedReflectDeserializer::new(stored, self.registry))?;
+                    migrations
+                        .migrate(self.version, value)
+                        .map_err(Error::custom)?
+                }
+                None => {
+                    map.next_value_seed(TypedReflectDeserializer::new(registration, self.registry))?
+                }
+            };
 
             // Attempt to convert using FromReflect.
             let value = self
@@ -507,11 +743,180 @@ impl<'a, 'de> Visitor<'de> for SceneMapVisitor<'a> {
     }
 }
 
+/// Serializer for a [`ScenePatch`].
+///
+/// The added entities, components and resources are serialized like a scene with [`SceneSerializer`].
+pub struct ScenePatchSerializer<'a> {
+    /// The patch to serialize.
+    pub patch: &'a ScenePatch,
+    /// The type registry containing the types present in the patch.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> ScenePatchSerializer<'a> {
+    /// Create a new serializer from a [`ScenePatch`] and an associated [`TypeRegistry`].
+    pub fn new(patch: &'a ScenePatch, registry: &'a TypeRegistry) -> Self {
+        ScenePatchSerializer { patch, registry }
+    }
+}
+
+impl<'a> Serialize for ScenePatchSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct(PATCH_STRUCT, PATCH_FIELDS.len())?;
+        state.serialize_field(
+            PATCH_ADDED,
+            &SceneSerializer::new(&self.patch.added, self.registry),
+        )?;
+        state.serialize_field(PATCH_REMOVED_ENTITIES, &self.patch.removed_entities)?;
+        state.serialize_field(PATCH_REMOVED_COMPONENTS, &self.patch.removed_components)?;
+        state.serialize_field(PATCH_REMOVED_RESOURCES, &self.patch.removed_resources)?;
+        state.serialize_field(
+            PATCH_CHANGED,
+            &SceneOverridesSerializer {
+                overrides: &self.patch.changed,
+                registry: self.registry,
+            },
+        )?;
+        state.end()
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "snake_case")]
+enum PatchField {
+    Added,
+    RemovedEntities,
+    RemovedComponents,
+    RemovedResources,
+    Changed,
+}
+
+/// Handles scene patch deserialization.
+pub struct ScenePatchDeserializer<'a> {
+    /// Type registry in which the components and resources types used in the patch to deserialize are registered.
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ScenePatchDeserializer<'a> {
+    type Value = ScenePatch;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(PATCH_STRUCT, PATCH_FIELDS, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for ScenePatchDeserializer<'a> {
+    type Value = ScenePatch;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("scene patch struct")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let added = seq
+            .next_element_seed(SceneDeserializer {
+                type_registry: self.type_registry,
+            })?
+            .ok_or_else(|| Error::missing_field(PATCH_ADDED))?;
+        let removed_entities = seq
+            .next_element()?
+            .ok_or_else(|| Error::missing_field(PATCH_REMOVED_ENTITIES))?;
+        let removed_components = seq
+            .next_element()?
+            .ok_or_else(|| Error::missing_field(PATCH_REMOVED_COMPONENTS))?;
+        let removed_resources = seq
+            .next_element()?
+            .ok_or_else(|| Error::missing_field(PATCH_REMOVED_RESOURCES))?;
+        let changed = seq
+            .next_element_seed(SceneOverridesDeserializer {
+                registry: self.type_registry,
+            })?
+            .ok_or_else(|| Error::missing_field(PATCH_CHANGED))?;
+
+        Ok(ScenePatch {
+            added,
+            removed_entities,
+            removed_components,
+            removed_resources,
+            changed,
+        })
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut added = None;
+        let mut removed_entities = None;
+        let mut removed_components = None;
+        let mut removed_resources = None;
+        let mut changed = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                PatchField::Added => {
+                    if added.is_some() {
+                        return Err(Error::duplicate_field(PATCH_ADDED));
+                    }
+                    added = Some(map.next_value_seed(SceneDeserializer {
+                        type_registry: self.type_registry,
+                    })?);
+                }
+                PatchField::RemovedEntities => {
+                    if removed_entities.is_some() {
+                        return Err(Error::duplicate_field(PATCH_REMOVED_ENTITIES));
+                    }
+                    removed_entities = Some(map.next_value()?);
+                }
+                PatchField::RemovedComponents => {
+                    if removed_components.is_some() {
+                        return Err(Error::duplicate_field(PATCH_REMOVED_COMPONENTS));
+                    }
+                    removed_components = Some(map.next_value()?);
+                }
+                PatchField::RemovedResources => {
+                    if removed_resources.is_some() {
+                        return Err(Error::duplicate_field(PATCH_REMOVED_RESOURCES));
+                    }
+                    removed_resources = Some(map.next_value()?);
+                }
+                PatchField::Changed => {
+                    if changed.is_some() {
+                        return Err(Error::duplicate_field(PATCH_CHANGED));
+                    }
+                    changed = Some(map.next_value_seed(SceneOverridesDeserializer {
+                        registry: self.type_registry,
+                    })?);
+                }
+            }
+        }
+
+        Ok(ScenePatch {
+            added: added.ok_or_else(|| Error::missing_field(PATCH_ADDED))?,
+            removed_entities: removed_entities
+                .ok_or_else(|| Error::missing_field(PATCH_REMOVED_ENTITIES))?,
+            removed_components: removed_components
+                .ok_or_else(|| Error::missing_field(PATCH_REMOVED_COMPONENTS))?,
+            removed_resources: removed_resources
+                .ok_or_else(|| Error::missing_field(PATCH_REMOVED_RESOURCES))?,
+            changed: changed.ok_or_else(|| Error::missing_field(PATCH_CHANGED))?,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
         ron,
-        serde::{SceneDeserializer, SceneSerializer},
+        serde::{SceneDeserializer, SceneMapDeserializer, SceneSerializer},
         DynamicScene, DynamicSceneBuilder,
     };
     use bevy_ecs::{
@@ -521,7 +926,9 @@ mod tests {
         reflect::AppTypeRegistry,
         world::FromWorld,
     };
-    use bevy_reflect::{Reflect, ReflectDeserialize, ReflectSerialize};
+    use bevy_reflect::{
+        prelude::ReflectDefault, FromReflect, Reflect, ReflectDeserialize, ReflectSerialize,
+    };
     use serde::{de::DeserializeSeed, Deserialize, Serialize};
     use std::io::BufReader;
 
@@ -738,6 +1145,67 @@ mod tests {
         (scene, deserialized_scene)
     }
 
+    #[test]
+    fn should_roundtrip_without_defaults() {
+        #[derive(Component, Reflect, Debug, PartialEq)]
+        #[reflect(Component, Default)]
+        struct Light {
+            intensity: f32,
+            range: f32,
+        }
+
+        impl Default for Light {
+            fn default() -> Self {
+                Self {
+                    intensity: 1.0,
+                    range: 10.0,
+                }
+            }
+        }
+
+        let mut world = create_world();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Light>();
+        world.spawn(Light {
+            intensity: 2.0,
+            range: 10.0,
+        });
+
+        let scene = DynamicScene::from_world(&world);
+        let registry = world.resource::<AppTypeRegistry>().read();
+        let serialized = scene.serialize_without_defaults(&registry).unwrap();
+        assert!(serialized.contains("intensity: 2.0"));
+        assert!(!serialized.contains("range"));
+
+        let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+        let scene_deserializer = SceneDeserializer {
+            type_registry: &registry,
+        };
+        let deserialized_scene = scene_deserializer.deserialize(&mut deserializer).unwrap();
+
+        let mut dst_world = create_world();
+        dst_world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Light>();
+        deserialized_scene
+            .write_to_world(&mut dst_world, &mut EntityHashMap::default())
+            .unwrap();
+        let lights = dst_world
+            .query::<&Light>()
+            .iter(&dst_world)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            lights,
+            [&Light {
+                intensity: 2.0,
+                range: 10.0,
+            }]
+        );
+    }
+
     #[test]
     fn should_roundtrip_with_later_generations_and_obsolete_references() {
         let mut world = create_world();
@@ -795,6 +1263,21 @@ mod tests {
         assert_eq!(&qux, world.query::<&Qux>().single(&world).unwrap());
     }
 
+    #[test]
+    fn should_deserialize_map_with_default_version() {
+        let world = create_world();
+        let registry = world.resource::<AppTypeRegistry>().read();
+
+        let input = r#"{ "bevy_scene::serde::tests::Foo": (123) }"#;
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let components = SceneMapDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+
+        assert_eq!(1, components.len());
+        assert_eq!(123, Foo::from_reflect(&*components[0]).unwrap().0);
+    }
+
     #[test]
     fn should_roundtrip_postcard() {
         let mut world = create_world();
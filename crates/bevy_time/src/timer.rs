@@ -17,7 +17,20 @@ use core::time::Duration;
 #[cfg_attr(
     feature = "bevy_reflect",
     derive(Reflect),
-    reflect(Default, Clone, PartialEq)
+    reflect(Default, Clone, PartialEq),
+    reflect(methods(
+        pause,
+        unpause,
+        paused,
+        reset,
+        finished,
+        just_finished,
+        elapsed_secs,
+        set_elapsed,
+        set_duration,
+        fraction,
+        remaining_secs,
+    ))
 )]
 pub struct Timer {
     stopwatch: Stopwatch,
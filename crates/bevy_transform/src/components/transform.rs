@@ -71,7 +71,18 @@ fn assert_is_normalized(message: &str, length_squared: f32) {
 #[cfg_attr(
     feature = "bevy_reflect",
     derive(Reflect),
-    reflect(Component, Default, PartialEq, Debug, Clone)
+    reflect(Component, Default, PartialEq, Debug, Clone),
+    reflect(methods(
+        rotate,
+        rotate_x,
+        rotate_y,
+        rotate_z,
+        rotate_local,
+        translate_around,
+        rotate_around,
+        transform_point,
+        look_at = |transform: &mut Transform, target: Vec3, up: Dir3| transform.look_at(target, up),
+    ))
 )]
 #[cfg_attr(
     all(feature = "bevy_reflect", feature = "serialize"),
@@ -59,6 +59,9 @@ fn main() -> AnyhowResult<()> {
                 },
                 strict: false,
                 filter: BrpQueryFilter::default(),
+                sort: Vec::default(),
+                offset: 0,
+                limit: None,
             })
             .expect("Unable to convert query parameters to a valid JSON value"),
         ),